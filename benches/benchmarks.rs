@@ -1,7 +1,8 @@
-use byteable::{Byteable, RawRepr};
+use byteable::{BigEndian, Byteable, LittleEndian, RawRepr, ReadFixed, WriteFixed};
 use core::f32;
 use core::hint::black_box;
-use criterion::{Criterion, criterion_group, criterion_main};
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use std::io::Cursor;
 
 #[derive(Clone, Copy, Debug, Byteable)]
 struct MyStruct {
@@ -16,6 +17,94 @@ struct MyStruct {
     e: u128,
 }
 
+// ── Realistic structs ─────────────────────────────────────────────────────────
+
+/// Defines a big-endian and a little-endian variant of the same struct, so the
+/// benchmarks can compare byte-swapped and native-order encodings on one host.
+macro_rules! endian_variants {
+    ($be:ident, $le:ident { $($field:ident: $ty:ty),* $(,)? }) => {
+        #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+        #[byteable(big_endian)]
+        struct $be {
+            $($field: $ty),*
+        }
+
+        #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+        #[byteable(little_endian)]
+        struct $le {
+            $($field: $ty),*
+        }
+    };
+}
+
+endian_variants!(
+    NetworkPacketBe,
+    NetworkPacketLe {
+        version: u8,
+        flags: u8,
+        sequence: u16,
+        source: u32,
+        destination: u32,
+        timestamp: u64,
+        payload_len: u16,
+        checksum: u16,
+    }
+);
+
+endian_variants!(
+    DeviceConfigBe,
+    DeviceConfigLe {
+        device_id: u32,
+        firmware: u32,
+        sample_rate: u32,
+        gain: f32,
+        offset: f64,
+        channels: u16,
+        enabled: u8,
+        mode: u8,
+        serial: u64,
+    }
+);
+
+macro_rules! network_packet {
+    ($ty:ident, $i:expr) => {
+        $ty {
+            version: 4,
+            flags: 0b1010,
+            sequence: $i as u16,
+            source: 0xC0A8_0001,
+            destination: 0x0A00_0001 + $i as u32,
+            timestamp: 1_700_000_000_000 + $i as u64,
+            payload_len: 512,
+            checksum: 0xBEEF,
+        }
+    };
+}
+
+macro_rules! device_config {
+    ($ty:ident, $i:expr) => {
+        $ty {
+            device_id: $i as u32,
+            firmware: 0x0001_0200,
+            sample_rate: 48_000,
+            gain: 1.5,
+            offset: -0.25,
+            channels: 8,
+            enabled: 1,
+            mode: 3,
+            serial: 0x0123_4567_89AB_CDEF ^ $i as u64,
+        }
+    };
+}
+
+/// Number of records written or read per iteration of the struct benchmarks.
+const RECORDS: usize = 1024;
+
+/// Number of elements in the large-array read benchmarks.
+const ARRAY_LEN: usize = 4096;
+
+// ── Benchmarks ────────────────────────────────────────────────────────────────
+
 fn benchmarks(c: &mut Criterion) {
     c.bench_function("as_bytearray_mystruct", |b| {
         b.iter(|| {
@@ -40,5 +129,126 @@ fn benchmarks(c: &mut Criterion) {
     });
 }
 
-criterion_group!(all_benchmarks, benchmarks);
+/// Benchmarks `write_fixed` and `read_fixed` of `RECORDS` values over a `Cursor`.
+macro_rules! bench_struct_io {
+    ($c:expr, $group:literal, $make:ident, $($ty:ident => $label:literal),+ $(,)?) => {{
+        let mut group = $c.benchmark_group($group);
+        $(
+            let records: Vec<$ty> = (0..RECORDS).map(|i| $make!($ty, i)).collect();
+            let size = RECORDS * core::mem::size_of::<<$ty as RawRepr>::Raw>();
+            group.throughput(Throughput::Bytes(size as u64));
+
+            group.bench_function(BenchmarkId::new("write_fixed", $label), |b| {
+                let mut cursor = Cursor::new(Vec::with_capacity(size));
+                b.iter(|| {
+                    cursor.set_position(0);
+                    for record in &records {
+                        cursor.write_fixed(black_box(record)).unwrap();
+                    }
+                    black_box(cursor.get_ref().len())
+                })
+            });
+
+            let mut encoded = Vec::with_capacity(size);
+            for record in &records {
+                encoded.write_fixed(record).unwrap();
+            }
+            group.bench_function(BenchmarkId::new("read_fixed", $label), |b| {
+                b.iter(|| {
+                    let mut cursor = Cursor::new(black_box(encoded.as_slice()));
+                    for _ in 0..RECORDS {
+                        black_box(cursor.read_fixed::<$ty>().unwrap());
+                    }
+                })
+            });
+        )+
+        group.finish();
+    }};
+}
+
+fn struct_io_benchmarks(c: &mut Criterion) {
+    bench_struct_io!(
+        c,
+        "network_packet",
+        network_packet,
+        NetworkPacketBe => "big_endian",
+        NetworkPacketLe => "little_endian",
+    );
+    bench_struct_io!(
+        c,
+        "device_config",
+        device_config,
+        DeviceConfigBe => "big_endian",
+        DeviceConfigLe => "little_endian",
+    );
+}
+
+/// Benchmarks a single `read_fixed` of a `[T; ARRAY_LEN]` from a `Cursor`.
+macro_rules! bench_array_read {
+    ($group:expr, $($ty:ty => $label:literal),+ $(,)?) => {{
+        $(
+            let bytes = vec![0xA5u8; ARRAY_LEN * core::mem::size_of::<$ty>()];
+            $group.throughput(Throughput::Bytes(bytes.len() as u64));
+            $group.bench_function($label, |b| {
+                b.iter(|| {
+                    let mut cursor = Cursor::new(black_box(bytes.as_slice()));
+                    black_box(cursor.read_fixed::<[$ty; ARRAY_LEN]>().unwrap())
+                })
+            });
+        )+
+    }};
+}
+
+fn array_read_benchmarks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("read_fixed_array_4096");
+    bench_array_read!(
+        group,
+        u8 => "u8",
+        BigEndian<u32> => "big_endian_u32",
+        LittleEndian<u32> => "little_endian_u32",
+        BigEndian<u64> => "big_endian_u64",
+        LittleEndian<u64> => "little_endian_u64",
+    );
+    group.finish();
+}
+
+/// Number of records written per iteration by `batched_write_benchmarks`.
+const BATCH_LEN: usize = 1024;
+
+fn batched_write_benchmarks(c: &mut Criterion) {
+    let packets: Vec<NetworkPacketBe> = (0..BATCH_LEN)
+        .map(|i| network_packet!(NetworkPacketBe, i))
+        .collect();
+    let mut group = c.benchmark_group("write_network_packets_1024");
+    group.throughput(Throughput::Bytes(
+        (BATCH_LEN * core::mem::size_of::<<NetworkPacketBe as RawRepr>::Raw>()) as u64,
+    ));
+    group.bench_function("write_fixed_loop", |b| {
+        let mut out = Vec::new();
+        b.iter(|| {
+            out.clear();
+            for packet in black_box(&packets) {
+                out.write_fixed(packet).unwrap();
+            }
+            black_box(&out);
+        })
+    });
+    group.bench_function("write_fixed_all", |b| {
+        let mut out = Vec::new();
+        b.iter(|| {
+            out.clear();
+            out.write_fixed_all(black_box(&packets)).unwrap();
+            black_box(&out);
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    all_benchmarks,
+    benchmarks,
+    struct_io_benchmarks,
+    array_read_benchmarks,
+    batched_write_benchmarks
+);
 criterion_main!(all_benchmarks);
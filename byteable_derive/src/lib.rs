@@ -1,51 +1,1013 @@
 use proc_macro_crate::{FoundCrate, crate_name};
 use proc_macro2::Span;
 use quote::{format_ident, quote};
-use syn::{Data, DeriveInput, Fields, Ident, Meta, Type, parse_macro_input};
+use syn::{
+    Data, DeriveInput, Fields, Ident, Meta, Token, Type, parse_macro_input, punctuated::Punctuated,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum AttributeType {
     LittleEndian,
     BigEndian,
+    Transparent,
     TryTransparent,
     IoOnly,
+    Bytemuck,
     None,
 }
 
-fn parse_byteable_attr(attrs: &[syn::Attribute]) -> AttributeType {
-    for attr in attrs {
-        if attr.path().is_ident("byteable") {
-            if let Meta::List(meta_list) = &attr.meta {
-                return match meta_list.tokens.to_string().as_str() {
+impl AttributeType {
+    fn name(self) -> &'static str {
+        match self {
+            AttributeType::LittleEndian => "little_endian",
+            AttributeType::BigEndian => "big_endian",
+            AttributeType::Transparent => "transparent",
+            AttributeType::TryTransparent => "try_transparent",
+            AttributeType::IoOnly => "io_only",
+            AttributeType::Bytemuck => "bytemuck",
+            AttributeType::None => "none",
+        }
+    }
+}
+
+/// Every attribute accepted inside `#[byteable(...)]`, for the unknown-attribute error.
+const VALID_ATTRIBUTES: &str = "little_endian, big_endian, transparent, try_transparent, \
+     io_only, bytemuck, arbitrary, raw_derive(...), expose_raw, raw_name = Name, default, \
+     default = Variant, byte_eq, pad_before = N, pad_after = N, trailer = Type, validate = path, \
+     crc32, crc32 = POLYNOMIAL, magic = b\"...\", c_union, tag_repr = TYPE, payload_size = N, \
+     tagged, count = field, with = path, skip, assert_size = N";
+
+/// The layout requested by `#[byteable(c_union, tag_repr = TYPE, payload_size = N)]`.
+struct CUnion {
+    tag_repr: Ident,
+    payload_size: syn::Expr,
+}
+
+/// Every `#[byteable(...)]` entry of one container, variant or field, parsed in a single pass.
+///
+/// Both repeated attributes (`#[byteable(a)] #[byteable(b)]`) and comma-separated lists
+/// (`#[byteable(a, b)]`) are accepted. Whether an entry is allowed where it appears is up to
+/// the derive that reads it.
+#[derive(Default)]
+struct ByteableAttrs {
+    /// Every entry in declaration order, for errors pointing at a misplaced one.
+    metas: Vec<Meta>,
+    /// The layout and mode flags (`little_endian`, `transparent`, `io_only`, ...) in
+    /// declaration order, with the entry that set each one.
+    flags: Vec<(AttributeType, Meta)>,
+    /// The derive paths listed in every `raw_derive(...)`.
+    raw_derives: Vec<syn::Path>,
+    /// The raw struct's public name from `raw_name = Name`, or `{name}Raw` for `expose_raw`.
+    raw_name: Option<RawName>,
+    /// The fallback variant of `default = Variant`.
+    default_variant: Option<Ident>,
+    /// A bare `default`.
+    zero_default: Option<()>,
+    pad_before: Option<syn::Expr>,
+    pad_after: Option<syn::Expr>,
+    count: Option<Ident>,
+    with: Option<syn::Path>,
+    trailer: Option<Type>,
+    validate: Option<syn::Path>,
+    /// `crc32` (`Some(None)`, the IEEE polynomial) or `crc32 = POLYNOMIAL`.
+    crc32: Option<Option<syn::Expr>>,
+    assert_size: Option<syn::Expr>,
+    /// The bytes of `magic = b"..."` or `magic = [..]`, one `u8` expression per byte.
+    magic: Option<Vec<proc_macro2::TokenStream>>,
+    c_union: Option<CUnion>,
+    tagged: bool,
+    byte_eq: bool,
+    arbitrary: bool,
+    skip: bool,
+}
+
+/// How the raw struct was given a public name.
+enum RawName {
+    /// `#[byteable(expose_raw)]`: `{name}Raw`.
+    Exposed,
+    /// `#[byteable(raw_name = Name)]`.
+    Named(Ident),
+}
+
+impl ByteableAttrs {
+    fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut parsed = Self::default();
+        let mut tag_repr = None;
+        let mut payload_size = None;
+        let mut c_union = None;
+        for attr in attrs {
+            if !attr.path().is_ident("byteable") {
+                continue;
+            }
+            let metas = attr
+                .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .map_err(|error| {
+                    syn::Error::new(
+                        error.span(),
+                        format!(
+                            "Unknown byteable attribute. Valid attributes are: {VALID_ATTRIBUTES}"
+                        ),
+                    )
+                })?;
+            for meta in metas {
+                parsed.parse_meta(&meta, &mut tag_repr, &mut payload_size, &mut c_union)?;
+                parsed.metas.push(meta);
+            }
+        }
+        parsed.c_union = match c_union {
+            Some(c_union) => match (tag_repr, payload_size) {
+                (Some((tag_repr, _)), Some(payload_size)) => Some(CUnion {
+                    tag_repr,
+                    payload_size,
+                }),
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        c_union,
+                        "#[byteable(c_union)] requires both #[byteable(tag_repr = TYPE)] and \
+                         #[byteable(payload_size = N)]",
+                    ));
+                }
+            },
+            None => {
+                if let Some(stray) = parsed
+                    .find("tag_repr")
+                    .or_else(|| parsed.find("payload_size"))
+                {
+                    return Err(syn::Error::new_spanned(
+                        stray,
+                        "#[byteable(tag_repr = ...)] and #[byteable(payload_size = ...)] require \
+                         #[byteable(c_union)]",
+                    ));
+                }
+                None
+            }
+        };
+        Ok(parsed)
+    }
+
+    /// Records one entry. `c_union` and its `tag_repr` / `payload_size` are collected
+    /// separately and checked together once every entry has been seen.
+    fn parse_meta(
+        &mut self,
+        meta: &Meta,
+        tag_repr: &mut Option<(Ident, Meta)>,
+        payload_size: &mut Option<syn::Expr>,
+        c_union: &mut Option<Meta>,
+    ) -> syn::Result<()> {
+        let key = meta.path().get_ident().map(ToString::to_string);
+        match (key.as_deref(), meta) {
+            (Some("raw_derive"), Meta::List(list)) => {
+                let paths = list
+                    .parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+                    .map_err(|_| {
+                        syn::Error::new_spanned(
+                            list,
+                            "#[byteable(raw_derive(...))] expects a list of derive macro paths",
+                        )
+                    })?;
+                self.raw_derives.extend(paths);
+            }
+            (Some("expose_raw"), Meta::Path(_)) => {
+                set_once(
+                    &mut self.raw_name,
+                    RawName::Exposed,
+                    meta,
+                    "#[byteable(raw_name = ...)] may only be specified once, and not together \
+                     with #[byteable(expose_raw)]",
+                )?;
+            }
+            (Some("raw_name"), Meta::NameValue(nv)) => {
+                let ident =
+                    expect_ident(&nv.value, "#[byteable(raw_name = ...)] expects a type name")?;
+                set_once(
+                    &mut self.raw_name,
+                    RawName::Named(ident),
+                    meta,
+                    "#[byteable(raw_name = ...)] may only be specified once, and not together \
+                     with #[byteable(expose_raw)]",
+                )?;
+            }
+            (Some("default"), Meta::Path(_)) => set_once(
+                &mut self.zero_default,
+                (),
+                meta,
+                "#[byteable(default)] may only be specified once",
+            )?,
+            (Some("default"), Meta::NameValue(nv)) => {
+                let ident = expect_ident(
+                    &nv.value,
+                    "#[byteable(default = ...)] expects a variant name",
+                )?;
+                set_once(
+                    &mut self.default_variant,
+                    ident,
+                    meta,
+                    "#[byteable(default = ...)] may only be specified once",
+                )?;
+            }
+            (Some("pad_before"), Meta::NameValue(nv)) => set_once(
+                &mut self.pad_before,
+                nv.value.clone(),
+                meta,
+                "#[byteable(pad_before = ...)] may only be specified once per field",
+            )?,
+            (Some("pad_after"), Meta::NameValue(nv)) => set_once(
+                &mut self.pad_after,
+                nv.value.clone(),
+                meta,
+                "#[byteable(pad_after = ...)] may only be specified once per field",
+            )?,
+            (Some("count"), Meta::NameValue(nv)) => {
+                let ident = expect_ident(
+                    &nv.value,
+                    "#[byteable(count = ...)] expects the name of a field",
+                )?;
+                set_once(
+                    &mut self.count,
+                    ident,
+                    meta,
+                    "#[byteable(count = ...)] may only be specified once per field",
+                )?;
+            }
+            (Some("with"), Meta::NameValue(nv)) => {
+                let syn::Expr::Path(path) = &nv.value else {
+                    return Err(syn::Error::new_spanned(
+                        &nv.value,
+                        "#[byteable(with = ...)] expects a module path",
+                    ));
+                };
+                set_once(
+                    &mut self.with,
+                    path.path.clone(),
+                    meta,
+                    "#[byteable(with = ...)] may only be specified once per field",
+                )?;
+            }
+            (Some("trailer"), Meta::NameValue(nv)) => {
+                let syn::Expr::Path(path) = &nv.value else {
+                    return Err(syn::Error::new_spanned(
+                        &nv.value,
+                        "#[byteable(trailer = ...)] expects a type path",
+                    ));
+                };
+                let ty = Type::Path(syn::TypePath {
+                    qself: path.qself.clone(),
+                    path: path.path.clone(),
+                });
+                set_once(
+                    &mut self.trailer,
+                    ty,
+                    meta,
+                    "#[byteable(trailer = ...)] may only be specified once",
+                )?;
+            }
+            (Some("validate"), Meta::NameValue(nv)) => {
+                let syn::Expr::Path(path) = &nv.value else {
+                    return Err(syn::Error::new_spanned(
+                        &nv.value,
+                        "#[byteable(validate = ...)] expects a function path",
+                    ));
+                };
+                set_once(
+                    &mut self.validate,
+                    path.path.clone(),
+                    meta,
+                    "#[byteable(validate = ...)] may only be specified once",
+                )?;
+            }
+            (Some("crc32"), Meta::Path(_) | Meta::NameValue(_)) => {
+                let polynomial = match meta {
+                    Meta::NameValue(nv) => Some(nv.value.clone()),
+                    _ => None,
+                };
+                set_once(
+                    &mut self.crc32,
+                    polynomial,
+                    meta,
+                    "#[byteable(crc32)] may only be specified once",
+                )?;
+            }
+            (Some("assert_size"), Meta::NameValue(nv)) => set_once(
+                &mut self.assert_size,
+                nv.value.clone(),
+                meta,
+                "#[byteable(assert_size = ...)] may only be specified once",
+            )?,
+            (Some("magic"), Meta::NameValue(nv)) => {
+                let bytes = parse_magic_bytes(&nv.value)?;
+                set_once(
+                    &mut self.magic,
+                    bytes,
+                    meta,
+                    "#[byteable(magic = ...)] may only be specified once",
+                )?;
+            }
+            (Some("c_union"), Meta::Path(_)) => *c_union = Some(meta.clone()),
+            (Some("tag_repr"), Meta::NameValue(nv)) => {
+                let ident = match &nv.value {
+                    syn::Expr::Path(path) => path.path.get_ident().cloned(),
+                    _ => None,
+                };
+                let Some(ident) = ident.filter(|ident| {
+                    matches!(
+                        ident.to_string().as_str(),
+                        "u8" | "i8" | "u16" | "i16" | "u32" | "i32" | "u64" | "i64"
+                    )
+                }) else {
+                    return Err(syn::Error::new_spanned(
+                        &nv.value,
+                        "#[byteable(tag_repr = ...)] expects an integer type: u8, i8, u16, \
+                         i16, u32, i32, u64 or i64",
+                    ));
+                };
+                set_once(
+                    tag_repr,
+                    (ident, meta.clone()),
+                    meta,
+                    "#[byteable(tag_repr = ...)] may only be specified once",
+                )?;
+            }
+            (Some("payload_size"), Meta::NameValue(nv)) => set_once(
+                payload_size,
+                nv.value.clone(),
+                meta,
+                "#[byteable(payload_size = ...)] may only be specified once",
+            )?,
+            (Some("tagged"), Meta::Path(_)) => self.tagged = true,
+            (Some("byte_eq"), Meta::Path(_)) => self.byte_eq = true,
+            (Some("arbitrary"), Meta::Path(_)) => self.arbitrary = true,
+            (Some("skip"), Meta::Path(_)) => self.skip = true,
+            (Some(flag), Meta::Path(_)) => {
+                let flag = match flag {
                     "little_endian" => AttributeType::LittleEndian,
                     "big_endian" => AttributeType::BigEndian,
-                    "transparent" => AttributeType::None,
+                    "transparent" => AttributeType::Transparent,
                     "try_transparent" => AttributeType::TryTransparent,
                     "io_only" => AttributeType::IoOnly,
-                    other => panic!(
-                        "Unknown byteable attribute: {other}. \
-                         Valid attributes are: little_endian, big_endian, try_transparent, io_only"
-                    ),
+                    "bytemuck" => AttributeType::Bytemuck,
+                    _ => return Err(unknown_attribute(meta)),
                 };
+                self.flags.push((flag, meta.clone()));
             }
-            panic!(
-                "Unknown byteable attribute. \
-                 Valid attributes are: little_endian, big_endian, try_transparent, io_only"
-            );
+            _ => return Err(unknown_attribute(meta)),
+        }
+        Ok(())
+    }
+
+    /// Returns the first entry whose path is `key`.
+    fn find(&self, key: &str) -> Option<&Meta> {
+        self.metas.iter().find(|meta| meta.path().is_ident(key))
+    }
+
+    /// An error pointing at the `#[byteable(key ...)]` entry, for attributes that are valid on
+    /// their own but not where they are used.
+    fn error(&self, key: &str, message: impl core::fmt::Display) -> syn::Error {
+        match self.find(key) {
+            Some(meta) => syn::Error::new_spanned(meta, message),
+            None => syn::Error::new(Span::call_site(), message),
+        }
+    }
+
+    /// Returns `true` for `#[byteable(flag)]`, such as `io_only` or `bytemuck`.
+    fn has_flag(&self, flag: AttributeType) -> bool {
+        self.flags.iter().any(|(found, _)| *found == flag)
+    }
+
+    fn is_io_only(&self) -> bool {
+        self.has_flag(AttributeType::IoOnly)
+    }
+
+    fn is_bytemuck(&self) -> bool {
+        self.has_flag(AttributeType::Bytemuck)
+    }
+
+    /// Returns the single layout attribute, or [`AttributeType::None`] if absent.
+    ///
+    /// Conflicting attributes (e.g. both `little_endian` and `big_endian`, or an endianness on
+    /// a `transparent` field) are rejected.
+    fn layout(&self) -> syn::Result<AttributeType> {
+        check_layout_conflicts(self)?;
+        // A mode flag on a field comes first, so that it is reported as misplaced.
+        let is_mode =
+            |flag: &AttributeType| matches!(flag, AttributeType::IoOnly | AttributeType::Bytemuck);
+        let flags = || self.flags.iter().map(|(flag, _)| *flag);
+        Ok(flags()
+            .find(is_mode)
+            .or_else(|| flags().next())
+            .unwrap_or(AttributeType::None))
+    }
+
+    /// Returns the container-level layout attribute, ignoring the mode flags `io_only` and
+    /// `bytemuck` (which may be combined with a default endianness).
+    fn container_layout(&self) -> syn::Result<AttributeType> {
+        let mut flags = self
+            .flags
+            .iter()
+            .filter(|(flag, _)| !matches!(flag, AttributeType::IoOnly | AttributeType::Bytemuck));
+        if let Some((flag, meta)) = flags.clone().find(|(flag, _)| {
+            matches!(
+                flag,
+                AttributeType::Transparent | AttributeType::TryTransparent
+            )
+        }) {
+            return Err(syn::Error::new_spanned(
+                meta,
+                format!(
+                    "#[byteable({})] is a field-level attribute and cannot be used on a struct or enum",
+                    flag.name()
+                ),
+            ));
+        }
+        check_layout_conflicts(self)?;
+        Ok(flags.next().map_or(AttributeType::None, |(flag, _)| *flag))
+    }
+
+    /// Returns the public name of the raw struct of `name`, or `None` if it keeps its hidden
+    /// `__byteable_raw_` name.
+    fn raw_name(&self, name: &Ident) -> Option<Ident> {
+        self.raw_name.as_ref().map(|raw_name| match raw_name {
+            RawName::Exposed => format_ident!("{}Raw", name),
+            RawName::Named(ident) => ident.clone(),
+        })
+    }
+
+    /// The entry that named the raw struct, for errors about it.
+    fn raw_name_key(&self) -> &'static str {
+        match self.raw_name {
+            Some(RawName::Exposed) => "expose_raw",
+            _ => "raw_name",
+        }
+    }
+}
+
+/// Stores `value` in `slot`, or fails with `message` pointing at `meta` if it is already set.
+fn set_once<T>(slot: &mut Option<T>, value: T, meta: &Meta, message: &str) -> syn::Result<()> {
+    if slot.replace(value).is_some() {
+        return Err(syn::Error::new_spanned(meta, message));
+    }
+    Ok(())
+}
+
+/// Returns `value` as a bare identifier, or fails with `message`.
+fn expect_ident(value: &syn::Expr, message: &str) -> syn::Result<Ident> {
+    match value {
+        syn::Expr::Path(path) => path.path.get_ident().cloned(),
+        _ => None,
+    }
+    .ok_or_else(|| syn::Error::new_spanned(value, message))
+}
+
+fn unknown_attribute(meta: &Meta) -> syn::Error {
+    syn::Error::new_spanned(
+        meta,
+        format!(
+            "Unknown byteable attribute: {}. Valid attributes are: {VALID_ATTRIBUTES}",
+            quote!(#meta)
+        ),
+    )
+}
+
+/// Returns the bytes of a `magic = b"..."` or `magic = [..]` value, one `u8` expression per
+/// byte.
+fn parse_magic_bytes(value: &syn::Expr) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+    let bytes: Vec<proc_macro2::TokenStream> = match value {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::ByteStr(lit),
+            ..
+        }) => lit
+            .value()
+            .into_iter()
+            .map(|b| {
+                let b = proc_macro2::Literal::u8_suffixed(b);
+                quote! { #b }
+            })
+            .collect(),
+        syn::Expr::Array(array) => array.elems.iter().map(|e| quote! { #e }).collect(),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                value,
+                "#[byteable(magic = ...)] expects a byte string (b\"...\") or an array literal",
+            ));
+        }
+    };
+    if bytes.is_empty() || bytes.len() > 16 {
+        return Err(syn::Error::new_spanned(
+            value,
+            "#[byteable(magic = ...)] must be between 1 and 16 bytes long",
+        ));
+    }
+    Ok(bytes)
+}
+
+/// The attribute placed on a generated raw struct: hidden by default, documented when
+/// `#[byteable(expose_raw)]` or `#[byteable(raw_name = ...)]` gives it a public name.
+fn raw_struct_doc(exposed: bool, name: &Ident) -> proc_macro2::TokenStream {
+    if exposed {
+        let doc = format!(
+            "The raw byte layout of [`{name}`], generated by `#[derive(Byteable)]`.\n\n\
+             Its fields hold encoded values that have not been validated; convert with \
+             `FromRawRepr` / `TryFromRawRepr` rather than interpreting them directly."
+        );
+        quote! { #[doc = #doc] }
+    } else {
+        quote! { #[doc(hidden)] }
+    }
+}
+
+/// Parses the `#[byteable(...)]` attributes of each of `fields`, in order.
+fn parse_field_attrs(fields: &Fields) -> syn::Result<Vec<ByteableAttrs>> {
+    fields
+        .iter()
+        .map(|field| ByteableAttrs::parse(&field.attrs))
+        .collect()
+}
+
+/// Rejects `count` on a field outside an `io_only` struct: only its sequential encoding can
+/// honour it.
+fn reject_count(attrs: &ByteableAttrs) -> syn::Result<()> {
+    if attrs.count.is_some() {
+        return Err(attrs.error(
+            "count",
+            "#[byteable(count = ...)] is only supported on io_only structs",
+        ));
+    }
+    Ok(())
+}
+
+/// Rejects layout, padding, magic and `with` attributes on a `skip` field, which has no bytes
+/// for them to describe.
+fn reject_on_skipped_field(attrs: &ByteableAttrs) -> syn::Result<()> {
+    let key = if let Some((flag, _)) = attrs.flags.first() {
+        flag.name()
+    } else if let Some(key) = ["pad_before", "pad_after", "magic", "with"]
+        .into_iter()
+        .find(|key| attrs.find(key).is_some())
+    {
+        key
+    } else {
+        return Ok(());
+    };
+    Err(attrs.error(
+        key,
+        format!(
+            "#[byteable({key})] cannot be combined with #[byteable(skip)]: a skipped field is \
+             not serialized"
+        ),
+    ))
+}
+
+/// `PartialEq`, `Eq` and `Hash` impls that compare and hash the serialized bytes, for
+/// `#[byteable(byte_eq)]`.
+fn byte_eq_impls(input: &DeriveInput, bc: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let mut generics = input.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote! { #name #ty_generics: #bc::IntoByteArray });
+    let where_clause = &generics.where_clause;
+    quote! {
+        impl #impl_generics ::core::cmp::PartialEq for #name #ty_generics #where_clause {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                #bc::ByteArray::as_byte_slice(&#bc::IntoByteArray::into_byte_array(self))
+                    == #bc::ByteArray::as_byte_slice(&#bc::IntoByteArray::into_byte_array(other))
+            }
+        }
+
+        impl #impl_generics ::core::cmp::Eq for #name #ty_generics #where_clause {}
+
+        impl #impl_generics ::core::hash::Hash for #name #ty_generics #where_clause {
+            #[inline]
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                state.write(#bc::ByteArray::as_byte_slice(
+                    &#bc::IntoByteArray::into_byte_array(self),
+                ));
+            }
+        }
+    }
+}
+
+/// Generates `arbitrary::Arbitrary` for a struct or enum by generating each field in turn.
+///
+/// Enums pick one of their declared variants, so every generated value has a valid
+/// discriminant.
+fn arbitrary_impl(
+    input: &DeriveInput,
+    attrs: &ByteableAttrs,
+    bc: &proc_macro2::TokenStream,
+) -> syn::Result<proc_macro2::TokenStream> {
+    if !cfg!(feature = "arbitrary") {
+        return Err(attrs.error(
+            "arbitrary",
+            "#[byteable(arbitrary)] requires the `arbitrary` feature of byteable",
+        ));
+    }
+    // Fields are generated independently, so values a validator or a `count` field would
+    // reject could be produced.
+    if attrs.validate.is_some() {
+        return Err(attrs.error(
+            "arbitrary",
+            "#[byteable(arbitrary)] cannot be combined with #[byteable(validate = ...)]: \
+             generated values are not checked by the validator; implement `Arbitrary` by hand",
+        ));
+    }
+    let name = &input.ident;
+    let arb = quote! { #bc::__arbitrary };
+    // `skip` fields are not serialized, so they take their `Default` like a decoded value.
+    let field_value = |field: &syn::Field| {
+        let field_attrs = ByteableAttrs::parse(&field.attrs)?;
+        if field_attrs.count.is_some() {
+            return Err(field_attrs.error(
+                "count",
+                "#[byteable(count = ...)] cannot be used with #[byteable(arbitrary)]: the \
+                 generated length would not match the count field; implement `Arbitrary` by hand",
+            ));
+        }
+        if field_attrs.skip {
+            Ok(quote! { ::core::default::Default::default() })
+        } else {
+            Ok(quote! { #arb::Arbitrary::arbitrary(__byteable_u)? })
+        }
+    };
+    let construct = |path: proc_macro2::TokenStream, fields: &Fields| {
+        let values = fields
+            .iter()
+            .map(field_value)
+            .collect::<syn::Result<Vec<_>>>()?;
+        Ok::<_, syn::Error>(match fields {
+            Fields::Named(named) => {
+                let names = named.named.iter().map(|f| &f.ident);
+                quote! { #path { #(#names: #values),* } }
+            }
+            Fields::Unnamed(_) => quote! { #path(#(#values),*) },
+            Fields::Unit => path,
+        })
+    };
+    let body = match &input.data {
+        Data::Enum(data) => {
+            let count = data.variants.len();
+            let arms = data
+                .variants
+                .iter()
+                .enumerate()
+                .map(|(index, variant)| {
+                    let ident = &variant.ident;
+                    let value = construct(quote! { Self::#ident }, &variant.fields)?;
+                    Ok(quote! { #index => #value, })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            quote! {
+                match __byteable_u.choose_index(#count)? {
+                    #(#arms)*
+                    _ => ::core::unreachable!(),
+                }
+            }
+        }
+        Data::Struct(data) => construct(quote! { Self }, &data.fields)?,
+        Data::Union(_) => unreachable!(),
+    };
+
+    let mut generics = input.generics.clone();
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+    let lifetime: syn::Lifetime = syn::parse_quote! { '__byteable_arbitrary };
+    for param in generics.type_params_mut() {
+        param
+            .bounds
+            .push(syn::parse_quote! { #arb::Arbitrary<#lifetime> });
+    }
+    generics.params.insert(0, syn::parse_quote! { #lifetime });
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #arb::Arbitrary<#lifetime> for #name #ty_generics #where_clause {
+            fn arbitrary(
+                __byteable_u: &mut #arb::Unstructured<#lifetime>,
+            ) -> #arb::Result<Self> {
+                Ok(#body)
+            }
+        }
+    })
+}
+
+/// Rejects `pad_before`/`pad_after`, `magic`, `with` or `skip` on a field written by
+/// `Readable`/`Writable`, which has no raw struct to hold the extra or converted bytes or to
+/// leave the field out of.
+fn reject_field_padding(attrs: &ByteableAttrs) -> syn::Result<()> {
+    if attrs.pad_before.is_some() || attrs.pad_after.is_some() {
+        let key = if attrs.pad_before.is_some() {
+            "pad_before"
+        } else {
+            "pad_after"
+        };
+        return Err(attrs.error(
+            key,
+            "#[byteable(pad_before = ...)] and #[byteable(pad_after = ...)] are only \
+             supported on fixed-size structs and the variants of c_union and tagged enums",
+        ));
+    }
+    reject_struct_field_attrs(attrs)
+}
+
+/// Rejects `magic`, `with` or `skip` on a field: they are only supported on the fields of
+/// fixed-size structs.
+fn reject_struct_field_attrs(attrs: &ByteableAttrs) -> syn::Result<()> {
+    let key = if attrs.magic.is_some() {
+        "magic = ..."
+    } else if attrs.with.is_some() {
+        "with = ..."
+    } else if attrs.skip {
+        "skip"
+    } else {
+        return Ok(());
+    };
+    Err(attrs.error(
+        key.trim_end_matches(" = ..."),
+        format!("#[byteable({key})] is only supported on fixed-size structs"),
+    ))
+}
+
+/// Returns `"bool"` or `"char"` if `ty` is one of those primitives.
+fn bool_or_char(ty: &Type) -> Option<&'static str> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if path.qself.is_some() || !segment.arguments.is_none() {
+        return None;
+    }
+    if segment.ident == "bool" {
+        Some("bool")
+    } else if segment.ident == "char" {
+        Some("char")
+    } else {
+        None
+    }
+}
+
+/// Returns `true` if `ty` is `()` or a `PhantomData<T>`, which serialize to no bytes at all.
+fn is_zero_sized(ty: &Type) -> bool {
+    match ty {
+        Type::Tuple(tuple) => tuple.elems.is_empty(),
+        Type::Path(path) => {
+            path.qself.is_none()
+                && path
+                    .path
+                    .segments
+                    .last()
+                    .is_some_and(|segment| segment.ident == "PhantomData")
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `ty` is a primitive integer or float wider than one byte.
+fn is_multi_byte_primitive(ty: &Type) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+    path.qself.is_none()
+        && path.path.get_ident().is_some_and(|ident| {
+            [
+                "u16", "u32", "u64", "u128", "i16", "i32", "i64", "i128", "f32", "f64",
+            ]
+            .iter()
+            .any(|name| ident == name)
+        })
+}
+
+/// Returns `true` if `ty` is `u8` or `i8`, which have no byte order.
+fn is_single_byte_primitive(ty: &Type) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+    path.qself.is_none()
+        && path
+            .path
+            .get_ident()
+            .is_some_and(|ident| ident == "u8" || ident == "i8")
+}
+
+/// Returns the element type of a (possibly nested) array type, or `ty` itself. Invisible
+/// groups from `macro_rules!` fragments and parentheses are looked through.
+fn innermost_element(mut ty: &Type) -> &Type {
+    loop {
+        ty = match ty {
+            Type::Array(array) => &array.elem,
+            Type::Group(group) => &group.elem,
+            Type::Paren(paren) => &paren.elem,
+            _ => return ty,
+        };
+    }
+}
+
+/// Returns the layout of a fixed-size field that has no layout attribute of its own.
+///
+/// The container's endianness applies to multi-byte integers and floats, and to arrays of
+/// them, element by element. `u8`, `i8`, arrays of them and zero-sized fields have no byte
+/// order and keep their own layout. Any other field is rejected under a container
+/// endianness: its bytes are fixed by its type (a nested struct, an already-wrapped
+/// `BigEndian<T>`), so it must say so with `transparent` or `try_transparent`, or name an
+/// endianness of its own.
+fn inherited_field_attr(
+    field: &dyn core::fmt::Display,
+    ty: &Type,
+    container_attr: AttributeType,
+) -> syn::Result<AttributeType> {
+    let element = innermost_element(ty);
+    if !matches!(
+        container_attr,
+        AttributeType::LittleEndian | AttributeType::BigEndian
+    ) || is_single_byte_primitive(element)
+        || is_zero_sized(element)
+    {
+        return Ok(AttributeType::None);
+    }
+    if inherits_container_endian(ty) {
+        return Ok(container_attr);
+    }
+    let suggestion = match bool_or_char(element) {
+        Some(_) => "mark it #[byteable(try_transparent)]",
+        None => {
+            "mark it #[byteable(transparent)] to keep the layout of its type \
+             (#[byteable(try_transparent)] if decoding it can fail), or give it an endianness \
+             of its own"
         }
+    };
+    Err(syn::Error::new_spanned(
+        ty,
+        format!(
+            "field `{field}` does not inherit the container's #[byteable({})]: it only applies \
+             to multi-byte integer and float fields; {suggestion}",
+            container_attr.name()
+        ),
+    ))
+}
+
+/// Returns `true` if a field of type `ty` without a layout attribute takes the container's
+/// endianness: it is a multi-byte integer or float, or a (nested) array of them. This is the
+/// rule for fixed-size structs, tagged enums, `io_only` structs and field enums alike.
+fn inherits_container_endian(ty: &Type) -> bool {
+    is_multi_byte_primitive(innermost_element(ty))
+}
+
+/// Returns the layout attribute of a field written by `Readable`/`Writable` (`io_only`
+/// structs and field enums). A field without its own attribute takes the container's
+/// endianness under the same rule as fixed-size fields (see [`inherits_container_endian`]);
+/// other types, such as `String` or a nested struct, keep their own encoding.
+fn io_field_attr(
+    attrs: &ByteableAttrs,
+    ty: &Type,
+    container_attr: AttributeType,
+) -> syn::Result<AttributeType> {
+    Ok(match attrs.layout()? {
+        AttributeType::None if inherits_container_endian(ty) => container_attr,
+        other => other,
+    })
+}
+
+/// Rejects an endianness attribute on a `bool` or `char` field. Neither type has an endian
+/// representation, and both need fallible decoding. `suggestion` names the fix.
+fn reject_endian_on_bool_char(
+    field: &dyn core::fmt::Display,
+    ty: &Type,
+    attr: AttributeType,
+    suggestion: &str,
+) -> syn::Result<()> {
+    if !matches!(attr, AttributeType::LittleEndian | AttributeType::BigEndian) {
+        return Ok(());
+    }
+    match bool_or_char(ty) {
+        Some(prim) => Err(syn::Error::new_spanned(
+            ty,
+            format!(
+                "#[byteable({})] cannot be used on field `{field}` of type `{prim}`: `{prim}` has \
+                 no endian representation and not every bit pattern is a valid `{prim}`; \
+                 {suggestion}",
+                attr.name()
+            ),
+        )),
+        None => Ok(()),
     }
-    AttributeType::None
+}
+
+/// Rejects contradictory layout attributes on the container, a variant or a field, with an
+/// error pointing at the attribute that introduced the conflict.
+///
+/// An item may carry at most one of `little_endian`, `big_endian`, `transparent` and
+/// `try_transparent` (repeating the same one is harmless), and a field converted with
+/// `with = path` may carry none of them. These are checked up front, across every
+/// `#[byteable(...)]` on the item, so the order of the attributes does not matter.
+fn check_attribute_conflicts(input: &DeriveInput, attrs: &ByteableAttrs) -> syn::Result<()> {
+    if attrs.skip {
+        return Err(attrs.error(
+            "skip",
+            "#[byteable(skip)] is a field-level attribute and cannot be used on a struct or enum",
+        ));
+    }
+    let mut attr_lists = Vec::new();
+    match &input.data {
+        Data::Struct(data) => attr_lists.extend(data.fields.iter().map(|field| &field.attrs)),
+        Data::Enum(data) => {
+            for variant in &data.variants {
+                attr_lists.push(&variant.attrs);
+                attr_lists.extend(variant.fields.iter().map(|field| &field.attrs));
+            }
+        }
+        Data::Union(_) => {}
+    }
+    // Report every conflicting item at once, not just the first.
+    let mut errors: Option<syn::Error> = check_layout_conflicts(attrs).err();
+    for error in attr_lists.into_iter().filter_map(|attrs| {
+        ByteableAttrs::parse(attrs)
+            .and_then(|attrs| check_layout_conflicts(&attrs))
+            .err()
+    }) {
+        match &mut errors {
+            Some(errors) => errors.combine(error),
+            None => errors = Some(error),
+        }
+    }
+    errors.map_or(Ok(()), Err)
+}
+
+fn check_layout_conflicts(attrs: &ByteableAttrs) -> syn::Result<()> {
+    let mut layout: Option<&(AttributeType, Meta)> = None;
+    for entry @ (flag, meta) in &attrs.flags {
+        if matches!(flag, AttributeType::IoOnly | AttributeType::Bytemuck) {
+            continue;
+        }
+        match layout {
+            None => layout = Some(entry),
+            Some((first, _)) if first == flag => {}
+            Some((first, _)) => {
+                return Err(syn::Error::new_spanned(
+                    meta,
+                    layout_conflict_message(*first, *flag),
+                ));
+            }
+        }
+    }
+    if let (Some(_), Some((flag, meta))) = (&attrs.with, layout) {
+        return Err(syn::Error::new_spanned(
+            meta,
+            format!(
+                "#[byteable(with = ...)] cannot be combined with #[byteable({})]: the \
+                 conversion functions decide the field's bytes",
+                flag.name()
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// The message for two different layout attributes on one item, `first` seen before `second`.
+fn layout_conflict_message(first: AttributeType, second: AttributeType) -> String {
+    let is_endian = |a| matches!(a, AttributeType::LittleEndian | AttributeType::BigEndian);
+    if is_endian(first) && is_endian(second) {
+        return format!(
+            "conflicting attributes #[byteable({})] and #[byteable({})]: choose one byte order",
+            first.name(),
+            second.name()
+        );
+    }
+    if is_endian(first) || is_endian(second) {
+        let (endian, transparent) = if is_endian(first) {
+            (first, second)
+        } else {
+            (second, first)
+        };
+        return format!(
+            "#[byteable({})] cannot be combined with #[byteable({})]: a {} field keeps the \
+             byte layout of its own type",
+            endian.name(),
+            transparent.name(),
+            transparent.name(),
+        );
+    }
+    format!(
+        "conflicting attributes #[byteable({})] and #[byteable({})]: use try_transparent if \
+         decoding the field can fail, transparent otherwise",
+        first.name(),
+        second.name()
+    )
 }
 
 /// Resolves the path to the `byteable` crate (handles renamed imports and in-crate use).
-fn byteable_crate_path() -> proc_macro2::TokenStream {
-    match crate_name("byteable").expect("byteable is present in `Cargo.toml`") {
+fn byteable_crate_path() -> syn::Result<proc_macro2::TokenStream> {
+    let found = crate_name("byteable").map_err(|error| {
+        syn::Error::new(
+            Span::call_site(),
+            format!("#[derive(Byteable)] requires the `byteable` crate as a dependency: {error}"),
+        )
+    })?;
+    Ok(match found {
         FoundCrate::Itself => quote!(::byteable),
         FoundCrate::Name(name) => {
             let ident = Ident::new(&name, Span::call_site());
             quote!(#ident)
         }
-    }
+    })
 }
 
 /// Derive macro that generates byte-serialization impls for structs and enums.
@@ -54,8 +1016,19 @@ fn byteable_crate_path() -> proc_macro2::TokenStream {
 /// traits depending on whether `#[byteable(io_only)]` is present:
 ///
 /// - **Fixed-size** (default for structs): generates [`RawRepr`], [`FromRawRepr`] or
-///   [`TryFromRawRepr`], [`IntoByteArray`], and [`FromByteArray`] or [`TryFromByteArray`].
+///   [`TryFromRawRepr`], [`IntoByteArray`], [`FromByteArray`] or [`TryFromByteArray`], and
+///   [`ByteableLayout`] (the byte offset of each field).
 ///   A hidden `#[repr(C, packed)]` raw struct is created to hold the on-wire layout.
+///   Fields are copied into it one by one, so the struct's own `#[repr]` does not matter and
+///   its padding, including the tail added by `align(N)`, is never serialized: a `#[repr(C)]`
+///   struct whose wire format keeps the C padding spells it out with `pad_before`/`pad_after`,
+///   which is always written as zeros.
+///   Only the raw struct is plain old data: the struct itself is read through `&self` and
+///   built field by field, so it need not be `Copy` or `Clone` and may implement `Drop`.
+///   Generic structs get a generic raw struct, with each field type bounded by the trait its
+///   conversion needs (e.g. `T: RawRepr` for a `transparent` field). Since their size
+///   depends on the type parameters, their `ByteArray` is a [`ByteConcat`] chain of the
+///   fields' byte arrays rather than a `[u8; N]`; it has the same bytes and size.
 ///
 /// - **I/O streaming** (`#[byteable(io_only)]` on structs, always for field enums):
 ///   generates [`Readable`] and [`Writable`], reading/writing fields sequentially.
@@ -63,6 +1036,24 @@ fn byteable_crate_path() -> proc_macro2::TokenStream {
 /// - **Unit enums** (all variants are unit): generates [`TryFromRawRepr`],
 ///   [`IntoByteArray`], and [`TryFromByteArray`] using an automatically-chosen
 ///   discriminant integer type (`u8` → `u16` → `u32` → `u64` based on variant count).
+///   With `#[byteable(default = Variant)]`, unknown discriminants decode to `Variant` and
+///   the infallible [`FromRawRepr`] and [`FromByteArray`] are generated instead.
+///
+/// - **C tagged unions** (`#[byteable(c_union, tag_repr = TYPE, payload_size = N)]` on an
+///   enum): generates [`RawRepr`], [`TryFromRawRepr`], [`IntoByteArray`], and
+///   [`TryFromByteArray`] for the layout of `struct { TYPE tag; union { ... } payload; }`.
+///   The tag is the variant's discriminant; the payload holds the variant's fields back to
+///   back, zero-padded to `N` bytes. The payload follows the tag directly, so `N` should be
+///   the C union's `sizeof`, and any padding C inserts after the tag needs a wider `TYPE`.
+///   The fields of a variant are packed: where C pads a member struct, as in
+///   `struct { uint8_t a; uint32_t b; }`, mark the field after the gap
+///   `#[byteable(pad_before = 3)]`. Every variant must fit in `N` bytes, which is checked at
+///   compile time.
+///
+/// - **Fixed-size field enums** (`#[byteable(tagged)]` on an enum): the same traits and
+///   layout as `c_union`, with the tag type taken from `#[repr(...)]` (or auto-selected like a
+///   unit enum) and the payload sized to the largest variant. Every value of the enum then
+///   has the same wire size, so it can be a field of a fixed-size struct.
 ///
 /// [`RawRepr`]: byteable::RawRepr
 /// [`FromRawRepr`]: byteable::FromRawRepr
@@ -70,6 +1061,8 @@ fn byteable_crate_path() -> proc_macro2::TokenStream {
 /// [`IntoByteArray`]: byteable::IntoByteArray
 /// [`FromByteArray`]: byteable::FromByteArray
 /// [`TryFromByteArray`]: byteable::TryFromByteArray
+/// [`ByteableLayout`]: byteable::ByteableLayout
+/// [`ByteConcat`]: byteable::ByteConcat
 /// [`Readable`]: byteable::Readable
 /// [`Writable`]: byteable::Writable
 ///
@@ -82,6 +1075,34 @@ fn byteable_crate_path() -> proc_macro2::TokenStream {
 /// | `#[byteable(little_endian)]` | All multi-byte fields use little-endian representation |
 /// | `#[byteable(big_endian)]` | All multi-byte fields use big-endian representation |
 /// | `#[byteable(io_only)]` | Generate `Readable`/`Writable` instead of fixed-size traits |
+/// | `#[byteable(bytemuck)]` | Also implement `bytemuck::Pod` / `Zeroable` for the raw struct (requires the `bytemuck` feature) |
+/// | `#[byteable(arbitrary)]` | Also implement `arbitrary::Arbitrary` by generating each field; enums only pick declared variants. Not allowed with `validate` or a `count` field (requires the `arbitrary` feature) |
+/// | `#[byteable(raw_derive(Hash, ...))]` | Forward the listed derives onto the generated raw struct; `Debug` is implemented by hand to show endian fields as native values |
+/// | `#[byteable(expose_raw)]` / `#[byteable(raw_name = Name)]` | Fixed-size structs only: give the raw struct a public, documented name (`{Struct}Raw` or `Name`; see below) |
+/// | `#[byteable(default = Variant)]` | Unit enums only: decode unknown discriminants as `Variant` |
+/// | `#[byteable(trailer = Type)]` | Append a `Type` after the fields, supplied and validated through `HasTrailer<Type>` |
+/// | `#[byteable(validate = path)]` | Fixed-size structs only: after decoding, call `path(&value) -> Result<(), DecodeError>` to reject bit-valid but meaningless values |
+/// | `#[byteable(crc32)]` / `#[byteable(crc32 = POLYNOMIAL)]` | Append a little-endian CRC-32 of all preceding bytes (IEEE polynomial by default), verified on decode |
+/// | `#[byteable(magic = b"...")]` / `#[byteable(magic = [..])]` | Prefix the struct with a fixed signature (up to 16 bytes), checked on decode (`DecodeError::BadMagic`) |
+/// | `#[byteable(assert_size = N)]` | Fixed-size structs only: fail to compile unless the serialized size is exactly `N` bytes |
+/// | `#[byteable(default)]` | Fixed-size structs only: implement `Default` as the value decoded from all-zero bytes (see below) |
+/// | `#[byteable(byte_eq)]` | Fixed-size structs only: implement `PartialEq`, `Eq` and `Hash` over the serialized bytes (see below) |
+/// | `#[byteable(c_union, tag_repr = TYPE, payload_size = N)]` | Enums only: lay out like a C tagged union (see above) |
+/// | `#[byteable(tagged)]` | Field enums only: fixed-size tag + largest-variant payload instead of `Readable`/`Writable` |
+///
+/// A struct-level endianness applies to the fields without a layout attribute of their own
+/// that are multi-byte integers or floats (`u16`–`u128`, `i16`–`i128`, `f32`, `f64`).
+/// `u8`, `i8`, `()` and `PhantomData<T>` fields have no byte order and are left as they are.
+/// Any other field must say how it is laid out, or the derive fails with an error naming it:
+/// fields marked `transparent` or `try_transparent` keep the byte layout of their type (a
+/// nested struct's fields are already fixed by its own derive, so the parent's default does
+/// not reach into it), and a field-level endianness overrides the default.
+/// Array fields such as `[u16; N]` take the endianness element by element, storing
+/// `[LittleEndian<u16>; N]` or `[BigEndian<u16>; N]`; this applies to field-level attributes too.
+/// Arrays may nest: a 4x4 matrix is either `[[f32; 4]; 4]` with an endianness or
+/// `[[LittleEndian<f32>; 4]; 4]` marked `transparent`, and is stored row by row.
+/// In `io_only` structs and field enums the default applies to the same multi-byte integer
+/// and float fields; other field types, such as `String` or `Vec<T>`, keep their own encoding.
 ///
 /// # Field-level attributes
 ///
@@ -91,7 +1112,130 @@ fn byteable_crate_path() -> proc_macro2::TokenStream {
 /// |-----------|--------|
 /// | `#[byteable(little_endian)]` | This field uses little-endian (overrides struct-level) |
 /// | `#[byteable(big_endian)]` | This field uses big-endian (overrides struct-level) |
+/// | `#[byteable(transparent)]` | Field uses its own type's layout (e.g. a nested `Byteable` struct) |
 /// | `#[byteable(try_transparent)]` | Field decode may fail; the struct impl becomes `TryFromRawRepr` |
+/// | `#[byteable(pad_before = N)]` | Insert `N` reserved bytes before this field: written as zeros, ignored when decoding |
+/// | `#[byteable(pad_after = N)]` | Same as `pad_before`, but after the field |
+/// | `#[byteable(magic = b"...")]` | Insert a fixed signature before this field, checked on decode |
+/// | `#[byteable(count = field)]` | `io_only` structs only: a `Vec<T>` holding exactly as many elements as the earlier integer field `field`, with no length prefix of its own |
+/// | `#[byteable(with = path)]` | Fixed-size structs only: store the field as `path::to_raw(value)` and decode it with `path::from_raw(raw)`, where the raw type is any `PlainOldData` |
+/// | `#[byteable(skip)]` | Fixed-size structs only: leave the field out of the bytes entirely; decoding sets it to `Default::default()` |
+///
+/// Combining an endianness with `transparent` or `try_transparent` on the same field is a
+/// compile error.
+///
+/// A `bool` or `char` field cannot take an endianness, because decoding it can fail. Give it
+/// the type `BigEndianTry<T>` or `LittleEndianTry<T>` instead, marked `try_transparent`.
+///
+/// Misused attributes are reported as ordinary compile errors that point at the attribute,
+/// field or type at fault, so they show up inline in an editor.
+///
+/// # Zeroed defaults
+///
+/// `#[byteable(default)]` makes `Default::default()` return the value whose serialized bytes
+/// are all zero, a convenient starting point when filling in a packet field by field. The
+/// impl only exists when decoding is infallible: if the struct has `try_transparent` fields,
+/// a `magic` signature, a `trailer` or a `crc32`, all-zero bytes may not be a valid value, so
+/// the derive fails to compile instead of falling back to anything else. Implement `Default`
+/// by hand in that case.
+///
+/// ```rust
+/// use byteable::{Byteable, IntoByteArray};
+///
+/// #[derive(Byteable, Debug, PartialEq)]
+/// #[byteable(big_endian, default)]
+/// struct Packet {
+///     id: u16,
+///     length: u32,
+///     flags: u8,
+/// }
+///
+/// let mut packet = Packet::default();
+/// assert_eq!(packet, Packet { id: 0, length: 0, flags: 0 });
+/// packet.id = 7;
+/// assert_eq!(packet.into_byte_array(), [0, 7, 0, 0, 0, 0, 0]);
+/// ```
+///
+/// # Byte equality
+///
+/// `#[byteable(byte_eq)]` implements `PartialEq`, `Eq` and `Hash` by comparing and hashing the
+/// serialized bytes instead of the fields. Float fields then compare by bit pattern: a `NaN`
+/// equals an identical `NaN`, and `0.0` differs from `-0.0`. That makes structs with float
+/// fields usable as `HashMap` keys, for example to cache results by packet contents. Do not
+/// also derive `PartialEq` or `Hash`; the impls would conflict.
+///
+/// ```rust
+/// use byteable::Byteable;
+/// use std::collections::HashSet;
+///
+/// #[derive(Byteable, Clone, Copy)]
+/// #[byteable(little_endian, byte_eq)]
+/// struct Reading {
+///     sensor: u8,
+///     value: f32,
+/// }
+///
+/// let missing = Reading { sensor: 1, value: f32::NAN };
+/// assert!(missing == missing);
+/// assert!(Reading { sensor: 1, value: 0.0 } != Reading { sensor: 1, value: -0.0 });
+///
+/// let seen: HashSet<Reading> = [missing, missing].into_iter().collect();
+/// assert_eq!(seen.len(), 1);
+/// ```
+///
+/// # Exposing the raw struct
+///
+/// The raw struct behind `RawRepr::Raw` is normally named `__byteable_raw_{Struct}` and hidden
+/// from the docs. `#[byteable(expose_raw)]` names it `{Struct}Raw` instead, and
+/// `#[byteable(raw_name = Name)]` picks the name outright; either way it is documented and has
+/// the struct's visibility, as do its fields. That lets the exact layout appear in a public
+/// API, for example in an `extern "C"` signature.
+///
+/// The raw struct is `#[repr(C, packed)]` (`#[repr(C)]` for unit structs), so code that uses
+/// it directly must take care:
+///
+/// - Its fields may be unaligned. Copy them out; never take a reference to one or hand one to
+///   C as a pointer.
+/// - Its fields are encoded values (`BigEndian<u32>`, padding, magic bytes, raw bytes of
+///   `try_transparent` fields) that have not been validated. Convert the whole struct with
+///   `FromRawRepr::from_raw` or `TryFromRawRepr::try_from_raw` instead of trusting them.
+/// - The field layout follows the attributes on the Rust struct; changing those changes the
+///   raw struct, which is a breaking change for anyone naming it.
+///
+/// ```rust
+/// use byteable::{BigEndian, Byteable, FromRawRepr, RawRepr};
+///
+/// #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+/// #[byteable(big_endian, expose_raw)]
+/// pub struct Header {
+///     pub id: u16,
+///     pub length: u32,
+/// }
+///
+/// let raw: HeaderRaw = Header { id: 1, length: 2 }.to_raw();
+/// let length: BigEndian<u32> = raw.length; // a copy; `&raw.length` would not compile
+/// assert_eq!(length.get(), 2);
+/// assert_eq!(Header::from_raw(raw), Header { id: 1, length: 2 });
+/// ```
+///
+/// `#[byteable(raw_derive(Debug))]` gives the raw struct a `Debug` impl that copies each field
+/// out before formatting it and shows endian fields as their native value. Generated fields
+/// (padding, magic, trailer, checksum) are left out and shown as `..`:
+///
+/// ```rust
+/// use byteable::{Byteable, RawRepr};
+///
+/// #[derive(Byteable, Clone, Copy)]
+/// #[byteable(big_endian, expose_raw, raw_derive(Debug))]
+/// pub struct Header {
+///     pub id: u16,
+///     #[byteable(pad_before = 2)]
+///     pub length: u32,
+/// }
+///
+/// let raw = Header { id: 1, length: 2 }.to_raw();
+/// assert_eq!(format!("{raw:?}"), "HeaderRaw { id: 1, length: 2, .. }");
+/// ```
 ///
 /// # Examples
 ///
@@ -148,6 +1292,112 @@ fn byteable_crate_path() -> proc_macro2::TokenStream {
 /// assert_eq!(msg.id, msg2.id);
 /// ```
 ///
+/// ## Custom field conversion
+///
+/// ```rust
+/// use byteable::{Byteable, IntoByteArray, TryFromByteArray};
+///
+/// /// Millimetres on the wire, metres in memory.
+/// mod millis {
+///     use byteable::LittleEndian;
+///
+///     pub fn to_raw(metres: f32) -> LittleEndian<i32> {
+///         LittleEndian::new((metres * 1000.0).round() as i32)
+///     }
+///
+///     pub fn from_raw(raw: LittleEndian<i32>) -> f32 {
+///         raw.get() as f32 / 1000.0
+///     }
+/// }
+///
+/// #[derive(Byteable, Debug, PartialEq)]
+/// struct Reading {
+///     sensor: u8,
+///     #[byteable(with = millis)]
+///     depth: f32,
+/// }
+///
+/// let bytes = Reading { sensor: 1, depth: 1.5 }.into_byte_array();
+/// assert_eq!(bytes, [1, 0xDC, 0x05, 0, 0]);
+/// assert_eq!(Reading::try_from_byte_array(bytes).unwrap().depth, 1.5);
+/// ```
+///
+/// ## Skipped field
+///
+/// Runtime-only state such as a cache can live next to wire fields. A `skip` field takes no
+/// bytes, is ignored when encoding and is rebuilt with `Default` when decoding, so its type
+/// must implement `Default`.
+///
+/// ```rust
+/// use byteable::{Byteable, FromByteArray, IntoByteArray};
+///
+/// #[derive(Byteable, Debug, PartialEq)]
+/// #[byteable(little_endian)]
+/// struct Entry {
+///     id: u16,
+///     #[byteable(skip)]
+///     hits: u32,
+/// }
+///
+/// assert_eq!(Entry::BYTE_SIZE, 2);
+/// let bytes = Entry { id: 7, hits: 99 }.into_byte_array();
+/// assert_eq!(bytes, [7, 0]);
+/// assert_eq!(Entry::from_byte_array(bytes), Entry { id: 7, hits: 0 });
+/// ```
+///
+/// ## Validated struct
+///
+/// A validator sees the decoded struct and can reject it, e.g. with
+/// `DecodeError::InvalidValue`. Decoding then becomes fallible, so only `TryFromByteArray`
+/// is implemented.
+///
+/// ```rust
+/// use byteable::{Byteable, DecodeError, IntoByteArray, TryFromByteArray};
+///
+/// #[derive(Byteable, Debug, PartialEq)]
+/// #[byteable(validate = Percent::check)]
+/// struct Percent {
+///     value: u8,
+/// }
+///
+/// impl Percent {
+///     fn check(&self) -> Result<(), DecodeError> {
+///         if self.value <= 100 {
+///             Ok(())
+///         } else {
+///             Err(DecodeError::InvalidValue { type_name: "Percent", reason: "above 100" })
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Percent::try_from_byte_array([42]), Ok(Percent { value: 42 }));
+/// assert!(Percent::try_from_byte_array([101]).is_err());
+/// ```
+///
+/// ## Count-delimited field
+///
+/// ```rust
+/// use byteable::Byteable;
+/// use byteable::io::{ReadValue, WriteValue};
+///
+/// #[derive(Byteable, Debug, PartialEq)]
+/// #[byteable(io_only)]
+/// struct Samples {
+///     #[byteable(big_endian)]
+///     n: u16,
+///     #[byteable(count = n)]
+///     values: Vec<u8>,
+/// }
+///
+/// let samples = Samples { n: 3, values: vec![7, 8, 9] };
+/// let mut buf = Vec::new();
+/// buf.write_value(&samples).unwrap();
+/// assert_eq!(buf, [0, 3, 7, 8, 9]);
+/// assert_eq!(std::io::Cursor::new(&buf).read_value::<Samples>().unwrap(), samples);
+/// ```
+///
+/// Writing fails with `InvalidInput` if the count field does not match the length.
+///
 /// ## Unit enum (auto-inferred repr)
 ///
 /// ```rust
@@ -184,83 +1434,269 @@ fn byteable_crate_path() -> proc_macro2::TokenStream {
 /// let s2 = std::io::Cursor::new(&buf).read_value::<Shape>().unwrap();
 /// assert_eq!(s, s2);
 /// ```
+///
+/// ## Fixed-size field enum
+///
+/// ```rust
+/// use byteable::{Byteable, IntoByteArray, LittleEndian, TryFromByteArray};
+///
+/// #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+/// #[repr(u8)]
+/// #[byteable(tagged)]
+/// enum Msg {
+///     Ping,
+///     Data(LittleEndian<u32>),
+///     Coords([LittleEndian<i16>; 2]),
+/// }
+///
+/// // 1 tag byte + 4 payload bytes (the largest variant), zero-padded.
+/// let bytes = Msg::Coords([LittleEndian::new(1), LittleEndian::new(-1)]).into_byte_array();
+/// assert_eq!(bytes, [2, 1, 0, 0xFF, 0xFF]);
+/// assert_eq!(Msg::Ping.into_byte_array(), [0, 0, 0, 0, 0]);
+/// assert!(Msg::try_from_byte_array([3, 0, 0, 0, 0]).is_err());
+/// ```
+///
+/// ## C tagged union
+///
+/// ```rust
+/// use byteable::{Byteable, IntoByteArray, TryFromByteArray};
+///
+/// // struct { uint32_t tag; union { int32_t integer; float real; } payload; }
+/// #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+/// #[repr(u32)]
+/// #[byteable(c_union, tag_repr = u32, payload_size = 4, little_endian)]
+/// enum Value {
+///     Integer(i32) = 1,
+///     Real(f32) = 2,
+/// }
+///
+/// let bytes = Value::Integer(-1).into_byte_array();
+/// assert_eq!(bytes, [1, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF]);
+/// assert_eq!(Value::try_from_byte_array(bytes).unwrap(), Value::Integer(-1));
+/// ```
 #[proc_macro_derive(Byteable, attributes(byteable))]
 pub fn byteable_derive_macro(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input: DeriveInput = parse_macro_input!(input);
-    match input.data {
-        Data::Struct(_) => return struct_derive(input),
-        Data::Enum(_) => return enum_derive(input),
-        Data::Union(_) => panic!("union structs are unsupported"),
-    }
+    byteable_derive_impl(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
-fn struct_derive(input: DeriveInput) -> proc_macro::TokenStream {
-    if parse_byteable_attr(&input.attrs) == AttributeType::IoOnly {
-        return io_struct_derive(input);
+/// Validates the attributes of `input` and dispatches to the struct or enum derive. Every
+/// problem with the input is returned as an error pointing at its source.
+fn byteable_derive_impl(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let attrs = ByteableAttrs::parse(&input.attrs)?;
+    check_attribute_conflicts(&input, &attrs)?;
+    let arbitrary = if attrs.arbitrary && !matches!(input.data, Data::Union(_)) {
+        arbitrary_impl(&input, &attrs, &byteable_crate_path()?)?
+    } else {
+        quote! {}
+    };
+    let derived = match &input.data {
+        Data::Struct(_) => struct_derive(input, &attrs)?,
+        Data::Enum(_) => enum_derive(input, &attrs)?,
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "union structs are unsupported",
+            ));
+        }
+    };
+    Ok(quote! {
+        #derived
+        #arbitrary
+    })
+}
+
+fn struct_derive(
+    input: DeriveInput,
+    attrs: &ByteableAttrs,
+) -> syn::Result<proc_macro2::TokenStream> {
+    if attrs.default_variant.is_some() {
+        return Err(attrs.error(
+            "default",
+            "#[byteable(default = ...)] is only supported on enums",
+        ));
+    }
+    if attrs.c_union.is_some() {
+        return Err(attrs.error("c_union", "#[byteable(c_union)] is only supported on enums"));
+    }
+    if attrs.tagged {
+        return Err(attrs.error("tagged", "#[byteable(tagged)] is only supported on enums"));
+    }
+    if attrs.is_io_only() {
+        if attrs.trailer.is_some() {
+            return Err(attrs.error(
+                "trailer",
+                "#[byteable(trailer = ...)] is only supported on fixed-size structs",
+            ));
+        }
+        if attrs.validate.is_some() {
+            return Err(attrs.error(
+                "validate",
+                "#[byteable(validate = ...)] is only supported on fixed-size structs",
+            ));
+        }
+        if attrs.crc32.is_some() {
+            return Err(attrs.error(
+                "crc32",
+                "#[byteable(crc32)] is only supported on fixed-size structs",
+            ));
+        }
+        if attrs.magic.is_some() {
+            return Err(attrs.error(
+                "magic",
+                "#[byteable(magic = ...)] is only supported on fixed-size structs",
+            ));
+        }
+        if attrs.assert_size.is_some() {
+            return Err(attrs.error(
+                "assert_size",
+                "#[byteable(assert_size = ...)] cannot be combined with #[byteable(io_only)]: \
+                 io_only structs have no fixed size",
+            ));
+        }
+        if attrs.zero_default.is_some() {
+            return Err(attrs.error(
+                "default",
+                "#[byteable(default)] cannot be combined with #[byteable(io_only)]: \
+                 io_only structs have no fixed byte layout to zero",
+            ));
+        }
+        if attrs.byte_eq {
+            return Err(attrs.error(
+                "byte_eq",
+                "#[byteable(byte_eq)] cannot be combined with #[byteable(io_only)]: \
+                 io_only structs have no fixed byte representation to compare",
+            ));
+        }
+        if !attrs.raw_derives.is_empty() {
+            return Err(attrs.error(
+                "raw_derive",
+                "#[byteable(raw_derive(...))] cannot be combined with #[byteable(io_only)]: \
+                 io_only structs have no raw struct",
+            ));
+        }
+        if attrs.raw_name.is_some() {
+            return Err(attrs.error(
+                attrs.raw_name_key(),
+                "#[byteable(expose_raw)] and #[byteable(raw_name = ...)] cannot be combined with \
+                 #[byteable(io_only)]: io_only structs have no raw struct",
+            ));
+        }
+        if attrs.is_bytemuck() {
+            return Err(attrs.error(
+                "bytemuck",
+                "#[byteable(bytemuck)] cannot be combined with #[byteable(io_only)]: \
+                 io_only structs have no raw struct to mark as Pod",
+            ));
+        }
+        return io_struct_derive(input, attrs);
     }
-    fixed_struct_derived(input)
+    fixed_struct_derived(input, attrs)
 }
 
 fn gen_struct_field_write(
     field_access: &proc_macro2::TokenStream,
     field_type: &Type,
-    attrs: &[syn::Attribute],
+    attrs: &ByteableAttrs,
+    container_attr: AttributeType,
     bc: &proc_macro2::TokenStream,
-) -> proc_macro2::TokenStream {
-    match parse_byteable_attr(attrs) {
+) -> syn::Result<proc_macro2::TokenStream> {
+    Ok(match io_field_attr(attrs, field_type, container_attr)? {
         AttributeType::LittleEndian => quote! {
             writer.write_value(&<#field_type as #bc::HasEndianRepr>::to_little_endian(#field_access))?;
         },
         AttributeType::BigEndian => quote! {
             writer.write_value(&<#field_type as #bc::HasEndianRepr>::to_big_endian(#field_access))?;
         },
-        AttributeType::None => quote! { writer.write_value(&#field_access)?; },
-        AttributeType::IoOnly => {
-            panic!("#[byteable(io_only)] is a struct-level attribute and cannot be used on a field")
+        AttributeType::None | AttributeType::Transparent => {
+            quote! { writer.write_value(&#field_access)?; }
         }
-        AttributeType::TryTransparent => panic!(
-            "#[byteable(try_transparent)] is not applicable in \
-             io_only mode; remove the annotation or use a plain field"
-        ),
-    }
+        attr @ (AttributeType::IoOnly | AttributeType::Bytemuck) => {
+            return Err(attrs.error(
+                attr.name(),
+                format!(
+                    "#[byteable({})] is a struct-level attribute and cannot be used on a field",
+                    attr.name()
+                ),
+            ));
+        }
+        AttributeType::TryTransparent => {
+            return Err(attrs.error(
+                "try_transparent",
+                "#[byteable(try_transparent)] is not applicable in \
+                 io_only mode; remove the annotation or use a plain field",
+            ));
+        }
+    })
 }
 
 fn gen_field_read(
     field_ident: &Ident,
     field_ty: &syn::Type,
-    attrs: &[syn::Attribute],
+    attrs: &ByteableAttrs,
+    container_attr: AttributeType,
     bc: &proc_macro2::TokenStream,
-) -> proc_macro2::TokenStream {
-    match parse_byteable_attr(attrs) {
+) -> syn::Result<proc_macro2::TokenStream> {
+    let attr = io_field_attr(attrs, field_ty, container_attr)?;
+    reject_endian_on_bool_char(
+        field_ident,
+        field_ty,
+        attr,
+        "remove the attribute, the value is validated when read",
+    )?;
+    Ok(match attr {
         AttributeType::LittleEndian => {
-            quote! { let #field_ident: #field_ty = reader.read_value::<<#field_ty as #bc::HasEndianRepr>::LE>()?.get(); }
+            quote! {
+                let #field_ident: #field_ty = <#field_ty as #bc::FromEndianRepr>::from_little_endian(
+                    reader.read_value::<<#field_ty as #bc::HasEndianRepr>::LE>()?
+                );
+            }
         }
         AttributeType::BigEndian => {
-            quote! { let #field_ident: #field_ty = reader.read_value::<<#field_ty as #bc::HasEndianRepr>::BE>()?.get(); }
+            quote! {
+                let #field_ident: #field_ty = <#field_ty as #bc::FromEndianRepr>::from_big_endian(
+                    reader.read_value::<<#field_ty as #bc::HasEndianRepr>::BE>()?
+                );
+            }
         }
-        AttributeType::None => quote! { let #field_ident: #field_ty = reader.read_value()?; },
-        other => panic!(
-            "unsupported #[byteable] attribute `{other:?}` on field `{field_ident}`; \
-             only little_endian and big_endian are supported here"
-        ),
-    }
+        AttributeType::None | AttributeType::Transparent => {
+            quote! { let #field_ident: #field_ty = reader.read_value()?; }
+        }
+        other => {
+            return Err(attrs.error(
+                other.name(),
+                format!(
+                    "unsupported #[byteable] attribute `{other:?}` on field `{field_ident}`; \
+                     only little_endian and big_endian are supported here"
+                ),
+            ));
+        }
+    })
 }
 
-fn io_struct_derive(input: DeriveInput) -> proc_macro::TokenStream {
-    let bc = byteable_crate_path();
+fn io_struct_derive(
+    input: DeriveInput,
+    attrs: &ByteableAttrs,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let bc = byteable_crate_path()?;
     let name = &input.ident;
 
     let fields_data = match &input.data {
         Data::Struct(data) => &data.fields,
         _ => unreachable!(),
     };
+    let field_attrs = parse_field_attrs(fields_data)?;
+    field_attrs.iter().try_for_each(reject_field_padding)?;
 
     let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let container_attr = attrs.container_layout()?;
 
     if let Fields::Unit = fields_data {
         let vis = &input.vis;
         let raw_name = format_ident!("__byteable_raw_{}", name);
-        return quote! {
+        return Ok(quote! {
             #[derive(Clone, Copy)]
             #[doc(hidden)]
             #[allow(non_camel_case_types)]
@@ -333,8 +1769,7 @@ fn io_struct_derive(input: DeriveInput) -> proc_macro::TokenStream {
                     <Self as #bc::FromRawRepr>::from_raw(raw)
                 }
             }
-        }
-        .into();
+        });
     }
 
     let (fields, is_tuple) = match fields_data {
@@ -343,10 +1778,73 @@ fn io_struct_derive(input: DeriveInput) -> proc_macro::TokenStream {
         syn::Fields::Unit => unreachable!(),
     };
 
+    // `#[byteable(count = n)]` fields: (field, count field) pairs, each naming an earlier field.
+    let mut counted = Vec::new();
+    if is_tuple {
+        if let Some(attrs) = field_attrs.iter().find(|attrs| attrs.count.is_some()) {
+            return Err(attrs.error(
+                "count",
+                "#[byteable(count = ...)] requires a struct with named fields",
+            ));
+        }
+    } else {
+        for (i, (field, attrs)) in fields.iter().zip(&field_attrs).enumerate() {
+            let Some(count) = attrs.count.clone() else {
+                continue;
+            };
+            let fname = field.ident.as_ref().unwrap();
+            if !fields
+                .iter()
+                .take(i)
+                .any(|f| f.ident.as_ref() == Some(&count))
+            {
+                return Err(syn::Error::new_spanned(
+                    &count,
+                    format!(
+                        "#[byteable(count = {count})] on `{fname}` must name a field declared \
+                         before it"
+                    ),
+                ));
+            }
+            let attr = attrs.layout()?;
+            if attr != AttributeType::None {
+                return Err(attrs.error(
+                    attr.name(),
+                    format!(
+                        "#[byteable(count = ...)] on `{fname}` cannot be combined with a layout \
+                         attribute; use an element type such as `BigEndian<T>` instead"
+                    ),
+                ));
+            }
+            counted.push((fname.clone(), count));
+        }
+    }
+    let counted_field = |field: &syn::Field| {
+        counted
+            .iter()
+            .find(|(fname, _)| field.ident.as_ref() == Some(fname))
+            .map(|(_, count)| count)
+    };
+
+    // The counts are checked before anything is written, so a mismatch leaves the writer
+    // untouched.
+    let count_checks = counted.iter().map(|(fname, count)| {
+        let message = format!("count field `{count}` is {{}} but `{fname}` has {{}} elements");
+        quote! {
+            if ::core::convert::TryFrom::try_from(self.#count).ok() != Some(self.#fname.len()) {
+                return Err(::std::io::Error::new(
+                    ::std::io::ErrorKind::InvalidInput,
+                    format!(#message, self.#count, self.#fname.len()),
+                ));
+            }
+        }
+    });
+
     let write_stmts: Vec<_> = fields
         .iter()
+        .zip(&field_attrs)
         .enumerate()
-        .map(|(i, field)| {
+        .map(|(i, (field, attrs))| {
             let field_access = if is_tuple {
                 let idx = syn::Index::from(i);
                 quote! { self.#idx }
@@ -354,9 +1852,16 @@ fn io_struct_derive(input: DeriveInput) -> proc_macro::TokenStream {
                 let fname = field.ident.as_ref().unwrap();
                 quote! { self.#fname }
             };
-            gen_struct_field_write(&field_access, &field.ty, &field.attrs, &bc)
+            if counted_field(field).is_some() {
+                return Ok(quote! {
+                    for __item in &#field_access {
+                        writer.write_value(__item)?;
+                    }
+                });
+            }
+            gen_struct_field_write(&field_access, &field.ty, attrs, container_attr, &bc)
         })
-        .collect();
+        .collect::<syn::Result<_>>()?;
 
     let (read_bindings, construct_expr): (Vec<_>, proc_macro2::TokenStream) = if is_tuple {
         let idents: Vec<_> = (0..fields.len())
@@ -364,20 +1869,42 @@ fn io_struct_derive(input: DeriveInput) -> proc_macro::TokenStream {
             .collect();
         let bindings = fields
             .iter()
+            .zip(&field_attrs)
             .zip(&idents)
-            .map(|(f, id)| gen_field_read(id, &f.ty, &f.attrs, &bc))
-            .collect();
+            .map(|((f, attrs), id)| gen_field_read(id, &f.ty, attrs, container_attr, &bc))
+            .collect::<syn::Result<_>>()?;
         (bindings, quote! { Ok(Self(#(#idents),*)) })
     } else {
         let field_idents: Vec<_> = fields.iter().map(|f| f.ident.as_ref().unwrap()).collect();
         let bindings = fields
             .iter()
-            .map(|f| gen_field_read(f.ident.as_ref().unwrap(), &f.ty, &f.attrs, &bc))
-            .collect();
+            .zip(&field_attrs)
+            .map(|(f, attrs)| {
+                let fname = f.ident.as_ref().unwrap();
+                let Some(count) = counted_field(f) else {
+                    return gen_field_read(fname, &f.ty, attrs, container_attr, &bc);
+                };
+                let ty = &f.ty;
+                let message = format!("count field `{count}` does not fit in usize");
+                Ok(quote! {
+                    let #fname: #ty = {
+                        let len: usize = ::core::convert::TryFrom::try_from(#count).map_err(|_| {
+                            #bc::ReadableError::Io(::std::io::Error::new(
+                                ::std::io::ErrorKind::InvalidData,
+                                #message,
+                            ))
+                        })?;
+                        (0..len)
+                            .map(|_| reader.read_value())
+                            .collect::<Result<#ty, _>>()?
+                    };
+                })
+            })
+            .collect::<syn::Result<_>>()?;
         (bindings, quote! { Ok(Self { #(#field_idents),* }) })
     };
 
-    quote! {
+    Ok(quote! {
         impl #impl_generics #bc::Readable for #name #type_generics #where_clause {
             fn read_from(mut reader: &mut (impl ::std::io::Read + ?Sized)) -> Result<Self, #bc::ReadableError> {
                 use #bc::ReadValue;
@@ -388,35 +1915,147 @@ fn io_struct_derive(input: DeriveInput) -> proc_macro::TokenStream {
         impl #impl_generics #bc::Writable for #name #type_generics #where_clause {
             fn write_to(&self, mut writer: &mut (impl ::std::io::Write + ?Sized)) -> ::std::io::Result<()> {
                 use #bc::WriteValue;
+                #( #count_checks )*
                 #( #write_stmts )*
                 Ok(())
             }
         }
-    }.into()
+    })
 }
 
-fn fixed_struct_derived(input: DeriveInput) -> proc_macro::TokenStream {
-    let bc = byteable_crate_path();
+fn fixed_struct_derived(
+    input: DeriveInput,
+    attrs: &ByteableAttrs,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let bc = byteable_crate_path()?;
     let original_name = &input.ident;
 
-    // let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
-
     let fields_data = match &input.data {
         Data::Struct(data) => &data.fields,
         _ => unreachable!(),
     };
+    let field_attrs = parse_field_attrs(fields_data)?;
+    field_attrs.iter().try_for_each(reject_count)?;
 
     let vis = &input.vis;
-    let raw_name = format_ident!("__byteable_raw_{}", original_name);
+    let exposed_raw_name = attrs.raw_name(original_name);
+    let raw_doc = raw_struct_doc(exposed_raw_name.is_some(), original_name);
+    let raw_name =
+        exposed_raw_name.unwrap_or_else(|| format_ident!("__byteable_raw_{}", original_name));
+
+    // Generic structs get a generic raw struct. Its size depends on the type parameters, so
+    // its byte array is a `ByteConcat` chain instead of a `[u8; N]` (see
+    // `raw_byte_array_impls` below).
+    let is_generic = !input.generics.params.is_empty();
+    if is_generic {
+        if matches!(fields_data, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                &input.generics,
+                "#[derive(Byteable)] does not support generic unit structs",
+            ));
+        }
+        if attrs.is_bytemuck() || !attrs.raw_derives.is_empty() {
+            let key = if attrs.is_bytemuck() {
+                "bytemuck"
+            } else {
+                "raw_derive"
+            };
+            return Err(attrs.error(
+                key,
+                "#[byteable(bytemuck)] and #[byteable(raw_derive(...))] are not supported on \
+                 generic structs",
+            ));
+        }
+    }
+
+    // The raw struct is `repr(C, packed)` with only `PlainOldData` fields, so it already
+    // satisfies every `Pod` requirement; the `bytemuck` feature re-exports the crate for us.
+    let bytemuck_impls = if attrs.is_bytemuck() {
+        quote! {
+            unsafe impl #bc::__bytemuck::Zeroable for #raw_name {}
+            unsafe impl #bc::__bytemuck::Pod for #raw_name {}
+        }
+    } else {
+        quote! {}
+    };
+    let raw_derives = &attrs.raw_derives;
+    let trailer = &attrs.trailer;
+    let validate = &attrs.validate;
+    let crc32 = &attrs.crc32;
+
+    // Checked against the raw struct, so magic, padding, trailer and checksum bytes count.
+    let size_assert = match &attrs.assert_size {
+        Some(size) if is_generic => {
+            return Err(syn::Error::new_spanned(
+                size,
+                "#[byteable(assert_size = ...)] is not supported on generic structs",
+            ));
+        }
+        Some(size) => {
+            let message = format!(
+                "the serialized size of `{original_name}` does not match \
+                 #[byteable(assert_size = {})]",
+                quote!(#size)
+            );
+            quote! {
+                const _: () = ::core::assert!(
+                    <#raw_name as #bc::PlainOldData>::BYTE_SIZE == #size,
+                    #message
+                );
+            }
+        }
+        None => quote! {},
+    };
 
     if let Fields::Unit = fields_data {
-        return quote! {
-            #[derive(Clone, Copy)]
-            #[doc(hidden)]
+        if trailer.is_some() {
+            return Err(attrs.error(
+                "trailer",
+                "#[byteable(trailer = ...)] requires a struct with fields",
+            ));
+        }
+        if validate.is_some() {
+            return Err(attrs.error(
+                "validate",
+                "#[byteable(validate = ...)] requires a struct with fields",
+            ));
+        }
+        if crc32.is_some() {
+            return Err(attrs.error("crc32", "#[byteable(crc32)] requires a struct with fields"));
+        }
+        if attrs.magic.is_some() {
+            return Err(attrs.error(
+                "magic",
+                "#[byteable(magic = ...)] requires a struct with fields",
+            ));
+        }
+        let byte_eq_impls = if attrs.byte_eq {
+            byte_eq_impls(&input, &bc)
+        } else {
+            quote! {}
+        };
+        let unit_default_impl = if attrs.zero_default.is_some() {
+            quote! {
+                impl ::core::default::Default for #original_name {
+                    #[inline]
+                    fn default() -> Self {
+                        Self
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        };
+        return Ok(quote! {
+            #[derive(Clone, Copy, #(#raw_derives),*)]
+            #[repr(C)]
+            #raw_doc
             #[allow(non_camel_case_types)]
             #vis struct #raw_name;
 
             unsafe impl #bc::PlainOldData for #raw_name {}
+            #bytemuck_impls
+            #size_assert
 
             impl #bc::RawRepr for #original_name {
                 type Raw = #raw_name;
@@ -441,6 +2080,14 @@ fn fixed_struct_derived(input: DeriveInput) -> proc_macro::TokenStream {
                 }
             }
 
+            impl #bc::ByteableLayout for #original_name {
+                const BYTE_OFFSETS: &'static [(&'static str, usize)] = &[];
+                const BYTE_RANGES: &'static [(&'static str, ::core::ops::Range<usize>)] = &[];
+            }
+
+            #unit_default_impl
+            #byte_eq_impls
+
             impl #bc::IntoByteArray for #raw_name
                 where #raw_name : #bc::PlainOldData
             {
@@ -482,8 +2129,7 @@ fn fixed_struct_derived(input: DeriveInput) -> proc_macro::TokenStream {
                     <Self as #bc::FromRawRepr>::from_raw(raw)
                 }
             }
-        }
-        .into();
+        });
     }
 
     let (fields, is_tuple) = match fields_data {
@@ -492,106 +2138,530 @@ fn fixed_struct_derived(input: DeriveInput) -> proc_macro::TokenStream {
         Fields::Unit => unreachable!(),
     };
 
+    // The raw struct is `repr(C, packed)`, so its fields may sit at any address. Generated
+    // code must never take a reference to one: it only reads fields by value (`value.field`,
+    // a copy), assigns to them, or views the whole struct as bytes with `as_bytes`. Taking
+    // `&value.field` is rejected by the compiler for this reason, but an unsafe pointer read
+    // would not be, so any such access must use `ptr::read_unaligned`.
     struct FieldInfo {
-        raw_field_def: proc_macro2::TokenStream,
+        // `vis name:` for named fields, empty for tuple fields.
+        raw_field_label: proc_macro2::TokenStream,
+        // `None` for `skip` fields, which have no raw counterpart.
+        raw_ty: Option<proc_macro2::TokenStream>,
         to_raw_expr: proc_macro2::TokenStream,
-        from_raw_expr: proc_macro2::TokenStream,
+        // Reads `value.field` by value; see above.
+        from_raw_expr: Option<proc_macro2::TokenStream>,
+        // Adds the field to the raw struct's `Debug` output; `None` for generated fields.
+        debug_field: Option<proc_macro2::TokenStream>,
     }
 
+    // Multi-byte integer and float fields without their own layout attribute inherit the
+    // container's endianness, if any; see `inherited_field_attr`.
+    let container_attr = attrs.container_layout()?;
+
     // Process each field: determine raw type and to/from conversion expressions
     let mut field_infos = Vec::new();
     let mut has_try = false;
+    // Bounds the field types must satisfy for the raw struct (`repr_bounds`) and for the
+    // conversion back (`from_bounds`). Only needed when the struct is generic.
+    let mut repr_bounds: Vec<syn::WherePredicate> = Vec::new();
+    let mut from_bounds: Vec<syn::WherePredicate> = Vec::new();
+    // Position in the raw struct, which differs from the field index once padding is added.
+    let mut raw_index = 0usize;
+    // `(name, raw member, raw type)` of every declared field, for `ByteableLayout::BYTE_OFFSETS`
+    // and `BYTE_RANGES`.
+    let mut layout_entries = Vec::new();
+
+    // A magic signature becomes a `[u8; N]` in the raw struct holding the literal. It is
+    // checked before any field is decoded and ignored afterwards.
+    let mut magic_checks = Vec::new();
+    let magic =
+        |name: proc_macro2::TokenStream, raw_index: usize, bytes: &[proc_macro2::TokenStream]| {
+            let len = bytes.len();
+            let (label, member) = if is_tuple {
+                let idx = syn::Index::from(raw_index);
+                (quote! {}, quote! { #idx })
+            } else {
+                (quote! { #name: }, name)
+            };
+            let check = quote! {{
+                const __BYTEABLE_MAGIC: [u8; #len] = [#(#bytes),*];
+                let __byteable_got: [u8; #len] = value.#member;
+                if __byteable_got != __BYTEABLE_MAGIC {
+                    return Err(#bc::DecodeError::BadMagic {
+                        expected: &__BYTEABLE_MAGIC,
+                        got: #bc::MagicBytes::new(&__byteable_got),
+                    });
+                }
+            }};
+            let info = FieldInfo {
+                raw_field_label: quote! { #label },
+                raw_ty: Some(quote! { [u8; #len] }),
+                to_raw_expr: quote! { #label [#(#bytes),*] },
+                from_raw_expr: None,
+                debug_field: None,
+            };
+            (info, check)
+        };
 
-    for (i, field) in fields.iter().enumerate() {
+    if let Some(bytes) = &attrs.magic {
+        let (info, check) = magic(quote! { __byteable_magic }, raw_index, bytes);
+        field_infos.push(info);
+        magic_checks.push(check);
+        raw_index += 1;
+    }
+
+    for (i, (field, field_attrs)) in fields.iter().zip(&field_attrs).enumerate() {
         let field_type = &field.ty;
-        let attr = parse_byteable_attr(&field.attrs);
+
+        // A skipped field is left out of the raw struct and rebuilt with `Default` on decode.
+        if field_attrs.skip {
+            reject_on_skipped_field(field_attrs)?;
+            let label = match &field.ident {
+                Some(name) => quote! { #name: },
+                None => quote! {},
+            };
+            from_bounds.push(syn::parse_quote! { #field_type: ::core::default::Default });
+            field_infos.push(FieldInfo {
+                raw_field_label: quote! {},
+                raw_ty: None,
+                to_raw_expr: quote! {},
+                from_raw_expr: Some(
+                    quote! { #label <#field_type as ::core::default::Default>::default() },
+                ),
+                debug_field: None,
+            });
+            continue;
+        }
+
+        let with = &field_attrs.with;
+        let field_name = match &field.ident {
+            Some(name) => name.to_string(),
+            None => i.to_string(),
+        };
+        let attr = match field_attrs.layout()? {
+            AttributeType::None if with.is_some() => AttributeType::None,
+            AttributeType::None => inherited_field_attr(&field_name, field_type, container_attr)?,
+            other if with.is_some() => {
+                return Err(field_attrs.error(
+                    other.name(),
+                    format!(
+                        "#[byteable(with = ...)] cannot be combined with #[byteable({})]: the \
+                         conversion functions decide the field's bytes",
+                        other.name()
+                    ),
+                ));
+            }
+            other => other,
+        };
+        reject_endian_on_bool_char(
+            &field_name,
+            field_type,
+            attr,
+            "mark it #[byteable(try_transparent)], or use `BigEndianTry<T>` / \
+             `LittleEndianTry<T>` for a byte order",
+        )?;
+        if is_zero_sized(field_type)
+            && matches!(attr, AttributeType::LittleEndian | AttributeType::BigEndian)
+        {
+            return Err(syn::Error::new_spanned(
+                field_type,
+                format!(
+                    "#[byteable({})] cannot be used on field `{field_name}`: a zero-sized field \
+                     has no bytes to order",
+                    attr.name()
+                ),
+            ));
+        }
         if attr == AttributeType::TryTransparent {
             has_try = true;
         }
+        // Padding becomes an anonymous zeroed `[u8; N]` in the raw struct; it is written as
+        // zeros and ignored when converting back.
+        let padding = |kind: &str, len: &syn::Expr| {
+            let label = match &field.ident {
+                Some(name) => {
+                    let pad_name = format_ident!("__byteable_{}_{}", kind, name);
+                    quote! { #pad_name: }
+                }
+                None => quote! {},
+            };
+            FieldInfo {
+                raw_field_label: quote! { #label },
+                raw_ty: Some(quote! { [u8; #len] }),
+                to_raw_expr: quote! { #label [0u8; #len] },
+                from_raw_expr: None,
+                debug_field: None,
+            }
+        };
 
-        let field_info = if is_tuple {
-            let idx = syn::Index::from(i);
-            match attr {
-                AttributeType::LittleEndian => FieldInfo {
-                    raw_field_def: quote! { #vis <#field_type as #bc::HasEndianRepr>::LE },
-                    to_raw_expr: quote! { <#field_type as #bc::HasEndianRepr>::to_little_endian(self.#idx) },
-                    from_raw_expr: quote! { <#field_type as #bc::FromEndianRepr>::from_little_endian(value.#idx) },
-                },
-                AttributeType::BigEndian => FieldInfo {
-                    raw_field_def: quote! { #vis <#field_type as #bc::HasEndianRepr>::BE },
-                    to_raw_expr: quote! { <#field_type as #bc::HasEndianRepr>::to_big_endian(self.#idx) },
-                    from_raw_expr: quote! { <#field_type as #bc::FromEndianRepr>::from_big_endian(value.#idx) },
-                },
-                AttributeType::TryTransparent => FieldInfo {
-                    raw_field_def: quote! { #vis <#field_type as #bc::RawRepr>::Raw },
-                    to_raw_expr: quote! { <#field_type as #bc::RawRepr>::to_raw(&self.#idx) },
-                    from_raw_expr: quote! { <#field_type as #bc::TryFromRawRepr>::try_from_raw(value.#idx)? },
-                },
-                AttributeType::IoOnly => panic!(
-                    "#[byteable(io_only)] is a struct-level attribute and cannot be used on individual fields"
+        if let Some(len) = &field_attrs.pad_before {
+            field_infos.push(padding("pad_before", len));
+            raw_index += 1;
+        }
+
+        if let Some(bytes) = &field_attrs.magic {
+            let name = match &field.ident {
+                Some(name) => format_ident!("__byteable_magic_{}", name),
+                None => format_ident!("__byteable_magic"),
+            };
+            let (info, check) = magic(quote! { #name }, raw_index, bytes);
+            field_infos.push(info);
+            magic_checks.push(check);
+            raw_index += 1;
+        }
+
+        // `name:` prefix for named fields, empty for tuple fields. `raw_member` addresses the
+        // field in the raw struct.
+        let (member, raw_member, label) = match &field.ident {
+            Some(name) => (quote! { #name }, quote! { #name }, quote! { #name: }),
+            None => {
+                let idx = syn::Index::from(i);
+                let raw_idx = syn::Index::from(raw_index);
+                (quote! { #idx }, quote! { #raw_idx }, quote! {})
+            }
+        };
+        raw_index += 1;
+
+        // The raw struct's `Debug` copies each field out (it may be unaligned) and shows endian
+        // fields as their native value rather than as the wrapper around it.
+        let debug_value = match attr {
+            _ if with.is_some() => quote! { __byteable_field },
+            AttributeType::LittleEndian => {
+                quote! { <#field_type as #bc::FromEndianRepr>::from_little_endian(__byteable_field) }
+            }
+            AttributeType::BigEndian => {
+                quote! { <#field_type as #bc::FromEndianRepr>::from_big_endian(__byteable_field) }
+            }
+            _ => quote! { __byteable_field },
+        };
+        let debug_label = if is_tuple {
+            quote! {}
+        } else {
+            quote! { #field_name, }
+        };
+        let debug_field = quote! {{
+            let __byteable_field = self.#raw_member;
+            __byteable_debug.field(#debug_label &#debug_value);
+        }};
+
+        // The converted value is stored as bytes, so the field type needs no bounds.
+        let field_info = if let Some(path) = &with {
+            FieldInfo {
+                raw_field_label: quote! { #vis #label },
+                raw_ty: Some(quote! { [u8; #bc::__with_raw_size(#path::to_raw)] }),
+                to_raw_expr: quote! { #label #bc::__with_to_bytes(#path::to_raw(self.#member)) },
+                from_raw_expr: Some(
+                    quote! { #label #path::from_raw(#bc::__with_from_bytes(value.#raw_member)) },
                 ),
-                AttributeType::None => FieldInfo {
-                    raw_field_def: quote! { #vis <#field_type as #bc::RawRepr>::Raw },
-                    to_raw_expr: quote! { <#field_type as #bc::RawRepr>::to_raw(&self.#idx) },
-                    from_raw_expr: quote! { <#field_type as #bc::FromRawRepr>::from_raw(value.#idx) },
-                },
+                debug_field: Some(debug_field),
             }
         } else {
-            let name = field.ident.as_ref().unwrap();
+            let (repr_bound, from_bound) = match attr {
+                AttributeType::LittleEndian | AttributeType::BigEndian => (
+                    quote! { #field_type: #bc::HasEndianRepr },
+                    quote! { #field_type: #bc::FromEndianRepr },
+                ),
+                AttributeType::TryTransparent => (
+                    quote! { #field_type: #bc::RawRepr },
+                    quote! { #field_type: #bc::TryFromRawRepr },
+                ),
+                _ => (
+                    quote! { #field_type: #bc::RawRepr },
+                    quote! { #field_type: #bc::FromRawRepr },
+                ),
+            };
+            repr_bounds.push(syn::parse_quote! { #repr_bound });
+            from_bounds.push(syn::parse_quote! { #from_bound });
+
             match attr {
                 AttributeType::LittleEndian => FieldInfo {
-                    raw_field_def: quote! { #vis #name: <#field_type as #bc::HasEndianRepr>::LE },
-                    to_raw_expr: quote! { #name: <#field_type as #bc::HasEndianRepr>::to_little_endian(self.#name) },
-                    from_raw_expr: quote! { #name: <#field_type as #bc::FromEndianRepr>::from_little_endian(value.#name) },
+                    raw_field_label: quote! { #vis #label },
+                    raw_ty: Some(quote! { <#field_type as #bc::HasEndianRepr>::LE }),
+                    to_raw_expr: quote! { #label <#field_type as #bc::HasEndianRepr>::to_little_endian(self.#member) },
+                    from_raw_expr: Some(
+                        quote! { #label <#field_type as #bc::FromEndianRepr>::from_little_endian(value.#raw_member) },
+                    ),
+                    debug_field: Some(debug_field),
                 },
                 AttributeType::BigEndian => FieldInfo {
-                    raw_field_def: quote! { #vis #name: <#field_type as #bc::HasEndianRepr>::BE },
-                    to_raw_expr: quote! { #name: <#field_type as #bc::HasEndianRepr>::to_big_endian(self.#name) },
-                    from_raw_expr: quote! { #name: <#field_type as #bc::FromEndianRepr>::from_big_endian(value.#name) },
+                    raw_field_label: quote! { #vis #label },
+                    raw_ty: Some(quote! { <#field_type as #bc::HasEndianRepr>::BE }),
+                    to_raw_expr: quote! { #label <#field_type as #bc::HasEndianRepr>::to_big_endian(self.#member) },
+                    from_raw_expr: Some(
+                        quote! { #label <#field_type as #bc::FromEndianRepr>::from_big_endian(value.#raw_member) },
+                    ),
+                    debug_field: Some(debug_field),
                 },
                 AttributeType::TryTransparent => FieldInfo {
-                    raw_field_def: quote! { #vis #name: <#field_type as #bc::RawRepr>::Raw },
-                    to_raw_expr: quote! { #name: <#field_type as #bc::RawRepr>::to_raw(&self.#name) },
-                    from_raw_expr: quote! { #name: <#field_type as #bc::TryFromRawRepr>::try_from_raw(value.#name)? },
+                    raw_field_label: quote! { #vis #label },
+                    raw_ty: Some(quote! { <#field_type as #bc::RawRepr>::Raw }),
+                    to_raw_expr: quote! { #label <#field_type as #bc::RawRepr>::to_raw(&self.#member) },
+                    from_raw_expr: Some(
+                        quote! { #label <#field_type as #bc::TryFromRawRepr>::try_from_raw(value.#raw_member)? },
+                    ),
+                    debug_field: Some(debug_field),
                 },
-                AttributeType::IoOnly => panic!(
-                    "#[byteable(io_only)] is a struct-level attribute and cannot be used on individual fields"
-                ),
-                AttributeType::None => FieldInfo {
-                    raw_field_def: quote! { #vis #name: <#field_type as #bc::RawRepr>::Raw },
-                    to_raw_expr: quote! { #name: <#field_type as #bc::RawRepr>::to_raw(&self.#name) },
-                    from_raw_expr: quote! { #name: <#field_type as #bc::FromRawRepr>::from_raw(value.#name) },
+                AttributeType::IoOnly | AttributeType::Bytemuck => {
+                    return Err(field_attrs.error(
+                        attr.name(),
+                        format!(
+                            "#[byteable({})] is a struct-level attribute and cannot be used on \
+                             individual fields",
+                            attr.name()
+                        ),
+                    ));
+                }
+                AttributeType::None | AttributeType::Transparent => FieldInfo {
+                    raw_field_label: quote! { #vis #label },
+                    raw_ty: Some(quote! { <#field_type as #bc::RawRepr>::Raw }),
+                    to_raw_expr: quote! { #label <#field_type as #bc::RawRepr>::to_raw(&self.#member) },
+                    from_raw_expr: Some(
+                        quote! { #label <#field_type as #bc::FromRawRepr>::from_raw(value.#raw_member) },
+                    ),
+                    debug_field: Some(debug_field),
                 },
             }
         };
+        layout_entries.push((field_name.clone(), raw_member, field_info.raw_ty.clone()));
         field_infos.push(field_info);
+
+        if let Some(len) = &field_attrs.pad_after {
+            field_infos.push(padding("pad_after", len));
+            raw_index += 1;
+        }
     }
 
-    let raw_struct_def = {
-        let field_defs = field_infos.iter().map(|v| &v.raw_field_def);
-        if is_tuple {
-            quote! {
-                #[derive(Clone, Copy)]
-                #[repr(C, packed)]
-                #[doc(hidden)]
-                #[allow(non_camel_case_types)]
-                #vis struct #raw_name( #(#field_defs),* );
-            }
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+
+    // The trailer is stored after the last field and decoded after the fields; the struct
+    // supplies and validates it through `HasTrailer`.
+    let mut trailer_bounds: Vec<syn::WherePredicate> = Vec::new();
+    let trailer_check = if let Some(trailer_ty) = &trailer {
+        has_try = true;
+        let (label, member) = if is_tuple {
+            let idx = syn::Index::from(raw_index);
+            (quote! {}, quote! { #idx })
+        } else {
+            (
+                quote! { __byteable_trailer: },
+                quote! { __byteable_trailer },
+            )
+        };
+        field_infos.push(FieldInfo {
+            raw_field_label: quote! { #label },
+            raw_ty: Some(quote! { <#trailer_ty as #bc::RawRepr>::Raw }),
+            to_raw_expr: quote! {
+                #label <#trailer_ty as #bc::RawRepr>::to_raw(
+                    &<Self as #bc::HasTrailer<#trailer_ty>>::trailer(self)
+                )
+            },
+            from_raw_expr: None,
+            debug_field: None,
+        });
+        raw_index += 1;
+        repr_bounds.push(syn::parse_quote! { #trailer_ty: #bc::RawRepr });
+        from_bounds.push(syn::parse_quote! { #trailer_ty: #bc::TryFromRawRepr });
+        trailer_bounds
+            .push(syn::parse_quote! { #original_name #ty_generics: #bc::HasTrailer<#trailer_ty> });
+        Some(quote! {
+            let __byteable_trailer =
+                <#trailer_ty as #bc::TryFromRawRepr>::try_from_raw(value.#member)?;
+            <Self as #bc::HasTrailer<#trailer_ty>>::check_trailer(
+                &__byteable_value,
+                &__byteable_trailer,
+            )?;
+        })
+    } else {
+        None
+    };
+
+    // The checksum is the last field of the raw struct and covers every byte before it. It is
+    // written as zero, computed over the finished raw struct and patched in, and verified
+    // before any field is decoded.
+    let crc32_parts = crc32.as_ref().map(|polynomial| {
+        has_try = true;
+        let polynomial = match polynomial {
+            Some(expr) => quote! { #expr },
+            None => quote! { #bc::checksum::CRC32_IEEE },
+        };
+        let (label, member) = if is_tuple {
+            let idx = syn::Index::from(raw_index);
+            (quote! {}, quote! { #idx })
         } else {
+            (quote! { __byteable_crc32: }, quote! { __byteable_crc32 })
+        };
+        field_infos.push(FieldInfo {
+            raw_field_label: quote! { #label },
+            raw_ty: Some(quote! { #bc::LittleEndian<u32> }),
+            to_raw_expr: quote! { #label #bc::LittleEndian::new(0) },
+            from_raw_expr: None,
+            debug_field: None,
+        });
+        let compute = |raw: proc_macro2::TokenStream| {
+            quote! {{
+                let __byteable_bytes = #bc::PlainOldData::as_bytes(&#raw);
+                #bc::checksum::crc32_with_polynomial(
+                    #polynomial,
+                    &__byteable_bytes[..__byteable_bytes.len() - 4],
+                )
+            }}
+        };
+        let compute_to = compute(quote! { __byteable_raw });
+        let compute_from = compute(quote! { value });
+        (
             quote! {
-                #[derive(Clone, Copy)]
-                #[repr(C, packed)]
-                #[doc(hidden)]
-                #[allow(non_camel_case_types)]
-                #vis struct #raw_name { #(#field_defs),* }
+                let __byteable_crc32 = #compute_to;
+                __byteable_raw.#member = #bc::LittleEndian::new(__byteable_crc32);
+            },
+            quote! {
+                let __byteable_expected = value.#member.get();
+                let __byteable_computed = #compute_from;
+                if __byteable_expected != __byteable_computed {
+                    return Err(#bc::DecodeError::ChecksumMismatch {
+                        expected: __byteable_expected,
+                        computed: __byteable_computed,
+                    });
+                }
+            },
+        )
+    });
+
+    let def_generics = &input.generics;
+    let mut repr_generics = input.generics.clone();
+    let mut to_raw_generics = input.generics.clone();
+    let mut from_generics = input.generics.clone();
+    if is_generic {
+        repr_generics
+            .make_where_clause()
+            .predicates
+            .extend(repr_bounds.iter().cloned());
+        let to_raw_where = to_raw_generics.make_where_clause();
+        to_raw_where.predicates.extend(repr_bounds.iter().cloned());
+        to_raw_where
+            .predicates
+            .extend(trailer_bounds.iter().cloned());
+        let from_where = from_generics.make_where_clause();
+        from_where.predicates.extend(repr_bounds);
+        from_where.predicates.extend(from_bounds);
+        from_where.predicates.extend(trailer_bounds);
+    }
+    let repr_where = &repr_generics.where_clause;
+    let to_raw_where = &to_raw_generics.where_clause;
+    let from_where = &from_generics.where_clause;
+
+    // `raw_derive(Debug)` gets a manual impl instead, so endian fields show their native value
+    // rather than the wrapper holding it. (`raw_derive` is rejected on generic structs.)
+    let (raw_derives, debug_requested): (Vec<_>, Vec<_>) = raw_derives
+        .iter()
+        .partition(|path| path.segments.last().is_none_or(|s| s.ident != "Debug"));
+    let raw_debug_impl = if debug_requested.is_empty() {
+        quote! {}
+    } else {
+        let raw_name_str = raw_name.to_string();
+        let builder = if is_tuple {
+            quote! { debug_tuple }
+        } else {
+            quote! { debug_struct }
+        };
+        // Padding, magic, trailer and checksum fields are left out and shown as `..`.
+        let debug_fields: Vec<_> = field_infos
+            .iter()
+            .filter_map(|v| v.debug_field.as_ref())
+            .collect();
+        let raw_field_count = field_infos.iter().filter(|v| v.raw_ty.is_some()).count();
+        let finish = if debug_fields.len() < raw_field_count {
+            quote! { finish_non_exhaustive }
+        } else {
+            quote! { finish }
+        };
+        quote! {
+            impl ::core::fmt::Debug for #raw_name {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    let mut __byteable_debug = f.#builder(#raw_name_str);
+                    #(#debug_fields)*
+                    __byteable_debug.#finish()
+                }
             }
         }
     };
 
-    let raw_impls = {
+    let raw_struct_def = {
+        let field_defs = field_infos.iter().filter_map(|v| {
+            let (label, ty) = (&v.raw_field_label, v.raw_ty.as_ref()?);
+            Some(quote! { #label #ty })
+        });
+        // `#[derive]` cannot be used on a generic packed struct, so those get manual impls.
+        let (derive_attr, clone_impls) = if is_generic {
+            (
+                quote! {},
+                quote! {
+                    impl #impl_generics ::core::clone::Clone for #raw_name #ty_generics #repr_where {
+                        #[inline]
+                        fn clone(&self) -> Self {
+                            *self
+                        }
+                    }
+
+                    impl #impl_generics ::core::marker::Copy for #raw_name #ty_generics #repr_where {}
+                },
+            )
+        } else {
+            (
+                quote! { #[derive(Clone, Copy, #(#raw_derives),*)] },
+                quote! {},
+            )
+        };
+        let def = if is_tuple {
+            quote! { #vis struct #raw_name #def_generics ( #(#field_defs),* ) #repr_where; }
+        } else {
+            quote! { #vis struct #raw_name #def_generics #repr_where { #(#field_defs),* } }
+        };
         quote! {
-            unsafe impl #bc::PlainOldData for #raw_name {}
+            #derive_attr
+            #[repr(C, packed)]
+            #raw_doc
+            #[allow(non_camel_case_types)]
+            #def
+
+            #clone_impls
+            #raw_debug_impl
+        }
+    };
+
+    // A generic raw struct has no nameable `[u8; N]`; its byte array is instead the
+    // `ByteConcat` chain of its fields' byte arrays, which has the same size and bytes.
+    let raw_byte_array_impls = if is_generic {
+        let mut byte_array_generics = repr_generics.clone();
+        let byte_array_where = byte_array_generics.make_where_clause();
+        let mut chain = quote! { [u8; 0] };
+        for raw_ty in field_infos.iter().rev().filter_map(|v| v.raw_ty.as_ref()) {
+            byte_array_where
+                .predicates
+                .push(syn::parse_quote! { #raw_ty: #bc::IntoByteArray });
+            chain = quote! {
+                #bc::ByteConcat<<#raw_ty as #bc::IntoByteArray>::ByteArray, #chain>
+            };
+        }
+        let byte_array_where = &byte_array_generics.where_clause;
+        quote! {
+            impl #impl_generics #bc::IntoByteArray for #raw_name #ty_generics #byte_array_where {
+                type ByteArray = #chain;
+                #[inline]
+                fn into_byte_array(&self) -> Self::ByteArray {
+                    #bc::__packed_to_byte_array(*self)
+                }
+            }
 
+            impl #impl_generics #bc::FromByteArray for #raw_name #ty_generics #byte_array_where {
+                #[inline]
+                fn from_byte_array(byte_array: Self::ByteArray) -> Self {
+                    #bc::__byte_array_to_packed(byte_array)
+                }
+            }
+        }
+    } else {
+        quote! {
             impl #bc::IntoByteArray for #raw_name
                 where #raw_name : #bc::PlainOldData
             {
@@ -614,8 +2684,17 @@ fn fixed_struct_derived(input: DeriveInput) -> proc_macro::TokenStream {
         }
     };
 
+    let raw_impls = {
+        quote! {
+            unsafe impl #impl_generics #bc::PlainOldData for #raw_name #ty_generics #repr_where {}
+            #bytemuck_impls
+
+            #raw_byte_array_impls
+        }
+    };
+
     let from_raw_body = {
-        let from_raw_exprs = field_infos.iter().map(|v| &v.from_raw_expr);
+        let from_raw_exprs = field_infos.iter().filter_map(|v| v.from_raw_expr.as_ref());
         if is_tuple {
             quote! { Self(#(#from_raw_exprs),*) }
         } else {
@@ -624,130 +2703,247 @@ fn fixed_struct_derived(input: DeriveInput) -> proc_macro::TokenStream {
     };
 
     let raw_repr = {
-        let to_raw_exprs = field_infos.iter().map(|v| &v.to_raw_expr);
-        if is_tuple {
+        let to_raw_exprs = field_infos
+            .iter()
+            .filter(|v| v.raw_ty.is_some())
+            .map(|v| &v.to_raw_expr);
+        let to_raw_body = if is_tuple {
+            quote! { #raw_name (#(#to_raw_exprs),*) }
+        } else {
+            quote! { #raw_name { #(#to_raw_exprs),* } }
+        };
+        let to_raw_fn_body = match &crc32_parts {
+            Some((patch, _)) => quote! {
+                let mut __byteable_raw = #to_raw_body;
+                #patch
+                __byteable_raw
+            },
+            None => to_raw_body,
+        };
+        let into_byte_array_impl = {
+            let mut generics = input.generics.clone();
+            generics
+                .make_where_clause()
+                .predicates
+                .extend::<[syn::WherePredicate; 2]>([
+                    syn::parse_quote! { #original_name #ty_generics: #bc::RawRepr },
+                    syn::parse_quote! {
+                        <#original_name #ty_generics as #bc::RawRepr>::Raw: #bc::IntoByteArray
+                    },
+                ]);
+            let where_clause = &generics.where_clause;
             quote! {
-                impl #bc::RawRepr for #original_name {
-                    type Raw = #raw_name;
-
-                    #[inline]
-                    fn to_raw(&self) -> Self::Raw {
-                        #raw_name (#(#to_raw_exprs),*)
-                    }
-                }
-
-                impl #bc::IntoByteArray for #original_name
-                where
-                    #original_name: #bc::RawRepr,
-                    <#original_name as #bc::RawRepr>::Raw: #bc::IntoByteArray,
-                {
+                impl #impl_generics #bc::IntoByteArray for #original_name #ty_generics #where_clause {
                     type ByteArray = <<Self as #bc::RawRepr>::Raw as #bc::IntoByteArray>::ByteArray;
                     fn into_byte_array(&self) -> Self::ByteArray {
                         <Self as #bc::RawRepr>::to_raw(self).into_byte_array()
                     }
                 }
+            }
+        };
+        quote! {
+            impl #impl_generics #bc::RawRepr for #original_name #ty_generics #to_raw_where {
+                type Raw = #raw_name #ty_generics;
 
+                #[inline]
+                fn to_raw(&self) -> Self::Raw {
+                    #to_raw_fn_body
+                }
             }
-        } else {
-            quote! {
-                impl #bc::RawRepr for #original_name {
-                    type Raw = #raw_name;
 
-                    #[inline]
-                    fn to_raw(&self) -> Self::Raw {
-                        #raw_name { #(#to_raw_exprs),* }
+            #into_byte_array_impl
+        }
+    };
+
+    // The validator runs last, on the fully decoded value.
+    let validate_check = validate.as_ref().map(|path| {
+        has_try = true;
+        quote! { #path(&__byteable_value)?; }
+    });
+    let try_from_raw_body = if trailer_check.is_some() || validate_check.is_some() {
+        quote! {
+            let __byteable_value = #from_raw_body;
+            #trailer_check
+            #validate_check
+            Ok(__byteable_value)
+        }
+    } else {
+        quote! { Ok(#from_raw_body) }
+    };
+    let try_from_raw_body = match &crc32_parts {
+        Some((_, verify)) => quote! {
+            #verify
+            #try_from_raw_body
+        },
+        None => try_from_raw_body,
+    };
+    let try_from_raw_body = quote! {
+        #(#magic_checks)*
+        #try_from_raw_body
+    };
+    if !magic_checks.is_empty() {
+        has_try = true;
+    }
+
+    let from_byte_array_where = |from_trait: proc_macro2::TokenStream| {
+        let mut generics = input.generics.clone();
+        generics
+            .make_where_clause()
+            .predicates
+            .extend::<[syn::WherePredicate; 2]>([
+                syn::parse_quote! { #original_name #ty_generics: #from_trait },
+                syn::parse_quote! {
+                    <#original_name #ty_generics as #bc::RawRepr>::Raw: #bc::FromByteArray
+                },
+            ]);
+        generics.where_clause
+    };
+
+    let original_impls = if has_try {
+        let try_from_byte_array_impl = {
+            let where_clause = from_byte_array_where(quote! { #bc::TryFromRawRepr });
+            quote! {
+                impl #impl_generics #bc::TryFromByteArray for #original_name #ty_generics #where_clause {
+                    fn try_from_byte_array(byte_array: Self::ByteArray) -> Result<Self, #bc::DecodeError> {
+                        let raw = <<Self as #bc::RawRepr>::Raw as #bc::FromByteArray>::from_byte_array(byte_array);
+                        <Self as #bc::TryFromRawRepr>::try_from_raw(raw)
                     }
                 }
-
-                impl #bc::IntoByteArray for #original_name
-                where
-                    #original_name: #bc::RawRepr,
-                    <#original_name as #bc::RawRepr>::Raw: #bc::IntoByteArray,
-                {
-                    type ByteArray = <<Self as #bc::RawRepr>::Raw as #bc::IntoByteArray>::ByteArray;
-                    fn into_byte_array(&self) -> Self::ByteArray {
-                        <Self as #bc::RawRepr>::to_raw(self).into_byte_array()
+            }
+        };
+        quote! {
+            impl #impl_generics #bc::TryFromRawRepr for #original_name #ty_generics #from_where {
+                #[inline]
+                fn try_from_raw(value: Self::Raw) -> Result<Self, #bc::DecodeError> { #try_from_raw_body }
+            }
+
+            #try_from_byte_array_impl
+        }
+    } else {
+        let from_byte_array_impl = {
+            let where_clause = from_byte_array_where(quote! { #bc::FromRawRepr });
+            quote! {
+                impl #impl_generics #bc::FromByteArray for #original_name #ty_generics #where_clause {
+                    fn from_byte_array(byte_array: Self::ByteArray) -> Self {
+                        let raw = <<Self as #bc::RawRepr>::Raw as #bc::FromByteArray>::from_byte_array(byte_array);
+                        <Self as #bc::FromRawRepr>::from_raw(raw)
                     }
                 }
             }
-        }
-    };
-
-    let original_impls = if has_try {
+        };
         quote! {
-            impl #bc::TryFromRawRepr for #original_name {
+            impl #impl_generics #bc::FromRawRepr for #original_name #ty_generics #from_where {
                 #[inline]
-                fn try_from_raw(value: #raw_name) -> Result<Self, #bc::DecodeError> { Ok(#from_raw_body) }
+                fn from_raw(value: Self::Raw) -> Self { #from_raw_body }
             }
 
-            impl #bc::TryFromByteArray for #original_name
-            where
-                #original_name: #bc::TryFromRawRepr,
-                <#original_name as #bc::RawRepr>::Raw: #bc::FromByteArray,
-            {
-                fn try_from_byte_array(byte_array: Self::ByteArray) -> Result<Self, #bc::DecodeError> {
-                    let raw = <<Self as #bc::RawRepr>::Raw as #bc::FromByteArray>::from_byte_array(byte_array);
-                    <Self as #bc::TryFromRawRepr>::try_from_raw(raw)
-                }
+            impl #impl_generics #bc::TryFromRawRepr for #original_name #ty_generics #from_where {
+                #[inline]
+                fn try_from_raw(value: Self::Raw) -> Result<Self, #bc::DecodeError> { Ok(<Self as #bc::FromRawRepr>::from_raw(value)) }
             }
 
+            #from_byte_array_impl
         }
+    };
+
+    let byte_eq_impls = if attrs.byte_eq {
+        byte_eq_impls(&input, &bc)
     } else {
+        quote! {}
+    };
+
+    // Zero bytes only make a valid value when nothing in the struct can fail to decode.
+    let default_impl = if attrs.zero_default.is_some() {
+        if has_try {
+            return Err(attrs.error(
+                "default",
+                format!(
+                    "#[byteable(default)] requires every field to decode from zero bytes without \
+                     failing, but `{original_name}` has try_transparent fields, a magic signature, \
+                     a trailer, a validator or a checksum; implement Default by hand instead"
+                ),
+            ));
+        }
         quote! {
-            impl #bc::FromRawRepr for #original_name {
+            impl #impl_generics ::core::default::Default for #original_name #ty_generics #from_where {
                 #[inline]
-                fn from_raw(value: #raw_name) -> Self { #from_raw_body }
+                fn default() -> Self {
+                    <Self as #bc::FromRawRepr>::from_raw(
+                        <<Self as #bc::RawRepr>::Raw as #bc::PlainOldData>::zeroed(),
+                    )
+                }
             }
+        }
+    } else {
+        quote! {}
+    };
 
-            impl #bc::TryFromRawRepr for #original_name {
-                #[inline]
-                fn try_from_raw(value: #raw_name) -> Result<Self, #bc::DecodeError> { Ok(<Self as #bc::FromRawRepr>::from_raw(value)) }
+    let layout_impl = {
+        let offsets = layout_entries.iter().map(|(name, raw_member, _)| {
+            quote! { (#name, ::core::mem::offset_of!(#raw_name #ty_generics, #raw_member)) }
+        });
+        let ranges = layout_entries.iter().map(|(name, raw_member, raw_ty)| {
+            quote! {
+                (#name, {
+                    let start = ::core::mem::offset_of!(#raw_name #ty_generics, #raw_member);
+                    start..start + ::core::mem::size_of::<#raw_ty>()
+                })
             }
-
-
-            impl #bc::FromByteArray for #original_name
-            where
-                #original_name: #bc::FromRawRepr,
-                <#original_name as #bc::RawRepr>::Raw: #bc::FromByteArray,
-            {
-                fn from_byte_array(byte_array: Self::ByteArray) -> Self {
-                    let raw = <<Self as #bc::RawRepr>::Raw as #bc::FromByteArray>::from_byte_array(byte_array);
-                    <Self as #bc::FromRawRepr>::from_raw(raw)
-                }
+        });
+        quote! {
+            impl #impl_generics #bc::ByteableLayout for #original_name #ty_generics #to_raw_where {
+                const BYTE_OFFSETS: &'static [(&'static str, usize)] = &[#(#offsets),*];
+                const BYTE_RANGES: &'static [(&'static str, ::core::ops::Range<usize>)] =
+                    &[#(#ranges),*];
             }
         }
     };
 
-    quote! {
+    Ok(quote! {
         #raw_struct_def
         #raw_impls
+        #size_assert
         #raw_repr
         #original_impls
-    }
-    .into()
+        #default_impl
+        #byte_eq_impls
+        #layout_impl
+    })
+}
+
+/// Returns the discriminant type of an enum: its `#[repr(...)]` if present, otherwise the
+/// smallest unsigned integer that can number every variant (`u8` → `u16` → `u32` → `u64`).
+fn discriminant_repr_type(
+    attrs: &[syn::Attribute],
+    variants: &Punctuated<syn::Variant, Token![,]>,
+    enum_name: &Ident,
+) -> Ident {
+    extract_repr_type(attrs).unwrap_or_else(|| {
+        let n = variants.len();
+        let ty_str = if n <= 256 {
+            "u8"
+        } else if n <= 65_536 {
+            "u16"
+        } else if n as u64 <= u32::MAX as u64 + 1 {
+            "u32"
+        } else {
+            "u64"
+        };
+        Ident::new(ty_str, enum_name.span())
+    })
 }
 
 fn extract_repr_type(attrs: &[syn::Attribute]) -> Option<syn::Ident> {
     for attr in attrs {
-        if attr.path().is_ident("repr") {
-            if let Meta::List(meta_list) = &attr.meta {
-                if let Ok(ident) = syn::parse2::<syn::Ident>(meta_list.tokens.clone()) {
-                    if matches!(
-                        ident.to_string().as_str(),
-                        "u8" | "i8"
-                            | "u16"
-                            | "i16"
-                            | "u32"
-                            | "i32"
-                            | "u64"
-                            | "i64"
-                            | "u128"
-                            | "i128"
-                    ) {
-                        return Some(ident);
-                    }
-                }
-            }
+        if attr.path().is_ident("repr")
+            && let Meta::List(meta_list) = &attr.meta
+            && let Ok(ident) = syn::parse2::<syn::Ident>(meta_list.tokens.clone())
+            && matches!(
+                ident.to_string().as_str(),
+                "u8" | "i8" | "u16" | "i16" | "u32" | "i32" | "u64" | "i64" | "u128" | "i128"
+            )
+        {
+            return Some(ident);
         }
     }
     None
@@ -756,61 +2952,153 @@ fn extract_repr_type(attrs: &[syn::Attribute]) -> Option<syn::Ident> {
 fn gen_enum_field_write(
     field_ident: &Ident,
     field_type: &Type,
-    attrs: &[syn::Attribute],
+    attrs: &ByteableAttrs,
+    container_attr: AttributeType,
     bc: &proc_macro2::TokenStream,
-) -> proc_macro2::TokenStream {
-    match parse_byteable_attr(attrs) {
+) -> syn::Result<proc_macro2::TokenStream> {
+    Ok(match io_field_attr(attrs, field_type, container_attr)? {
         AttributeType::LittleEndian => quote! {
             writer.write_value(&<#field_type as #bc::HasEndianRepr>::to_little_endian(*#field_ident))?;
         },
         AttributeType::BigEndian => quote! {
             writer.write_value(&<#field_type as #bc::HasEndianRepr>::to_big_endian(*#field_ident))?;
         },
-        AttributeType::None => quote! {
+        AttributeType::None | AttributeType::Transparent => quote! {
             writer.write_value(#field_ident)?;
         },
-        other => panic!(
-            "unsupported #[byteable] attribute `{other:?}` on field `{field_ident}`; \
-             only little_endian and big_endian are supported here"
-        ),
-    }
+        other => {
+            return Err(attrs.error(
+                other.name(),
+                format!(
+                    "unsupported #[byteable] attribute `{other:?}` on field `{field_ident}`; \
+                     only little_endian and big_endian are supported here"
+                ),
+            ));
+        }
+    })
 }
 
-fn enum_derive(input: DeriveInput) -> proc_macro::TokenStream {
+fn enum_derive(input: DeriveInput, attrs: &ByteableAttrs) -> syn::Result<proc_macro2::TokenStream> {
     let Data::Enum(enum_data) = &input.data else {
         unreachable!();
     };
+    if attrs.is_bytemuck() {
+        return Err(attrs.error(
+            "bytemuck",
+            "#[byteable(bytemuck)] is only supported on fixed-size structs",
+        ));
+    }
+    if !attrs.raw_derives.is_empty() {
+        return Err(attrs.error(
+            "raw_derive",
+            "#[byteable(raw_derive(...))] is only supported on fixed-size structs",
+        ));
+    }
+    if attrs.trailer.is_some() {
+        return Err(attrs.error(
+            "trailer",
+            "#[byteable(trailer = ...)] is only supported on fixed-size structs",
+        ));
+    }
+    if attrs.validate.is_some() {
+        return Err(attrs.error(
+            "validate",
+            "#[byteable(validate = ...)] is only supported on fixed-size structs",
+        ));
+    }
+    if attrs.crc32.is_some() {
+        return Err(attrs.error(
+            "crc32",
+            "#[byteable(crc32)] is only supported on fixed-size structs",
+        ));
+    }
+    if attrs.magic.is_some() {
+        return Err(attrs.error(
+            "magic",
+            "#[byteable(magic = ...)] is only supported on fixed-size structs",
+        ));
+    }
+    if attrs.zero_default.is_some() {
+        return Err(attrs.error(
+            "default",
+            "#[byteable(default)] is only supported on fixed-size structs; \
+             use #[byteable(default = Variant)] to choose a fallback variant",
+        ));
+    }
+    if attrs.raw_name.is_some() {
+        return Err(attrs.error(
+            attrs.raw_name_key(),
+            "#[byteable(expose_raw)] and #[byteable(raw_name = ...)] are only supported on \
+             fixed-size structs",
+        ));
+    }
+    if attrs.byte_eq {
+        return Err(attrs.error(
+            "byte_eq",
+            "#[byteable(byte_eq)] is only supported on fixed-size structs",
+        ));
+    }
+    if attrs.assert_size.is_some() {
+        return Err(attrs.error(
+            "assert_size",
+            "#[byteable(assert_size = ...)] is only supported on fixed-size structs",
+        ));
+    }
+    if let Some(c_union) = &attrs.c_union {
+        if attrs.tagged {
+            return Err(attrs.error(
+                "tagged",
+                "#[byteable(tagged)] cannot be combined with #[byteable(c_union)]",
+            ));
+        }
+        return tagged_enum_derive(
+            input,
+            attrs,
+            "c_union",
+            c_union.tag_repr.clone(),
+            Some(c_union.payload_size.clone()),
+        );
+    }
+    if attrs.tagged {
+        let tag_repr = discriminant_repr_type(&input.attrs, &enum_data.variants, &input.ident);
+        return tagged_enum_derive(input, attrs, "tagged", tag_repr, None);
+    }
     let has_field_variants = enum_data
         .variants
         .iter()
         .any(|v| !matches!(v.fields, Fields::Unit));
     if !has_field_variants {
-        return unit_enum_derive(input);
+        return unit_enum_derive(input, attrs);
+    }
+    if attrs.default_variant.is_some() {
+        return Err(attrs.error(
+            "default",
+            "#[byteable(default = ...)] is only supported on enums whose variants are all unit \
+             variants: the payload of an unknown variant cannot be skipped",
+        ));
+    }
+    let variant_field_attrs = enum_data
+        .variants
+        .iter()
+        .map(|variant| parse_field_attrs(&variant.fields))
+        .collect::<syn::Result<Vec<_>>>()?;
+    for field_attrs in variant_field_attrs.iter().flatten() {
+        reject_field_padding(field_attrs)?;
+        reject_count(field_attrs)?;
     }
     let name = input.ident;
-    let bc = byteable_crate_path();
+    let bc = byteable_crate_path()?;
 
     // generate io_only variant
 
     let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
 
     // Determine repr type — use explicit #[repr(...)] if present, otherwise auto-select.
-    let repr_ty = extract_repr_type(&input.attrs).unwrap_or_else(|| {
-        let n = enum_data.variants.len();
-        let ty_str = if n <= 256 {
-            "u8"
-        } else if n <= 65_536 {
-            "u16"
-        } else if n as u64 <= u32::MAX as u64 + 1 {
-            "u32"
-        } else {
-            "u64"
-        };
-        Ident::new(ty_str, name.span())
-    });
+    let repr_ty = discriminant_repr_type(&input.attrs, &enum_data.variants, &name);
 
-    let endian_attr = parse_byteable_attr(&input.attrs);
+    let endian_attr = attrs.container_layout()?;
     let discriminants = compute_discriminants(&enum_data.variants);
+    let valid_discriminants = valid_discriminants(&repr_ty, &discriminants, &bc);
 
     let read_disc = match endian_attr {
         AttributeType::LittleEndian => quote! {
@@ -832,7 +3120,8 @@ fn enum_derive(input: DeriveInput) -> proc_macro::TokenStream {
         .variants
         .iter()
         .zip(&discriminants)
-        .map(|(variant, disc_tokens)| {
+        .zip(&variant_field_attrs)
+        .map(|((variant, disc_tokens), field_attrs)| {
             let variant_name = &variant.ident;
             let write_disc = match endian_attr {
                 AttributeType::LittleEndian => quote! {
@@ -848,7 +3137,7 @@ fn enum_derive(input: DeriveInput) -> proc_macro::TokenStream {
                     writer.write_value(&disc_val)?;
                 },
             };
-            match &variant.fields {
+            Ok(match &variant.fields {
                 Fields::Unit => quote! {
                     #name::#variant_name => { #write_disc }
                 },
@@ -861,10 +3150,11 @@ fn enum_derive(input: DeriveInput) -> proc_macro::TokenStream {
                     let field_writes: Vec<_> = named
                         .named
                         .iter()
-                        .map(|f| {
-                            gen_enum_field_write(f.ident.as_ref().unwrap(), &f.ty, &f.attrs, &bc)
+                        .zip(field_attrs)
+                        .map(|(f, attrs)| {
+                            gen_enum_field_write(f.ident.as_ref().unwrap(), &f.ty, attrs, endian_attr, &bc)
                         })
-                        .collect();
+                        .collect::<syn::Result<_>>()?;
                     quote! {
                         #name::#variant_name { #(#field_names),* } => {
                             #write_disc
@@ -879,9 +3169,10 @@ fn enum_derive(input: DeriveInput) -> proc_macro::TokenStream {
                     let field_writes: Vec<_> = unnamed
                         .unnamed
                         .iter()
+                        .zip(field_attrs)
                         .zip(&field_idents)
-                        .map(|(f, ident)| gen_enum_field_write(ident, &f.ty, &f.attrs, &bc))
-                        .collect();
+                        .map(|((f, attrs), ident)| gen_enum_field_write(ident, &f.ty, attrs, endian_attr, &bc))
+                        .collect::<syn::Result<_>>()?;
                     quote! {
                         #name::#variant_name(#(#field_idents),*) => {
                             #write_disc
@@ -889,16 +3180,18 @@ fn enum_derive(input: DeriveInput) -> proc_macro::TokenStream {
                         }
                     }
                 }
-            }
-        });
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
     let read_arms = enum_data
         .variants
         .iter()
         .zip(&discriminants)
-        .map(|(variant, disc_tokens)| {
+        .zip(&variant_field_attrs)
+        .map(|((variant, disc_tokens), field_attrs)| {
             let variant_name = &variant.ident;
 
-            match &variant.fields {
+            Ok(match &variant.fields {
                 Fields::Unit => quote! {
                     #disc_tokens => Ok(#name::#variant_name),
                 },
@@ -911,8 +3204,17 @@ fn enum_derive(input: DeriveInput) -> proc_macro::TokenStream {
                     let field_reads: Vec<_> = named
                         .named
                         .iter()
-                        .map(|f| gen_field_read(f.ident.as_ref().unwrap(), &f.ty, &f.attrs, &bc))
-                        .collect();
+                        .zip(field_attrs)
+                        .map(|(f, attrs)| {
+                            gen_field_read(
+                                f.ident.as_ref().unwrap(),
+                                &f.ty,
+                                attrs,
+                                endian_attr,
+                                &bc,
+                            )
+                        })
+                        .collect::<syn::Result<_>>()?;
                     quote! {
                         #disc_tokens => {
                             #( #field_reads )*
@@ -927,9 +3229,12 @@ fn enum_derive(input: DeriveInput) -> proc_macro::TokenStream {
                     let field_reads: Vec<_> = unnamed
                         .unnamed
                         .iter()
+                        .zip(field_attrs)
                         .zip(&field_idents)
-                        .map(|(f, ident)| gen_field_read(ident, &f.ty, &f.attrs, &bc))
-                        .collect();
+                        .map(|((f, attrs), ident)| {
+                            gen_field_read(ident, &f.ty, attrs, endian_attr, &bc)
+                        })
+                        .collect::<syn::Result<_>>()?;
                     quote! {
                         #disc_tokens => {
                             #( #field_reads )*
@@ -937,9 +3242,10 @@ fn enum_derive(input: DeriveInput) -> proc_macro::TokenStream {
                         }
                     }
                 }
-            }
-        });
-    quote! {
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(quote! {
         impl #impl_generics #bc::Writable for #name #type_generics #where_clause {
             fn write_to(&self, mut writer: &mut (impl ::std::io::Write + ?Sized)) -> ::std::io::Result<()> {
                 use #bc::WriteValue;
@@ -957,11 +3263,314 @@ fn enum_derive(input: DeriveInput) -> proc_macro::TokenStream {
                 #read_disc
                 match disc {
                     #(#read_arms)*
-                    _ => Err(#bc::ReadableError::DecodeError(#bc::DecodeError::InvalidDiscriminant { raw: disc as u64, type_name: ::core::stringify!(#name) })),
+                    _ => Err(#bc::ReadableError::DecodeError(#bc::DecodeError::InvalidDiscriminant { raw: #bc::Discriminant::from(disc), type_name: ::core::stringify!(#name), valid_discriminants: #valid_discriminants })),
+                }
+            }
+        }
+    })
+}
+
+/// Derives the fixed-size traits for `#[byteable(c_union, tag_repr = TYPE, payload_size = N)]`
+/// and `#[byteable(tagged)]` enums.
+///
+/// The raw form mirrors a C tagged union, `struct { TYPE tag; union { ... } payload; }`: the
+/// tag, then a payload holding the active variant's fields back to back, zero-padded. Each
+/// variant's fields get their own packed raw struct, which is copied into and out of the
+/// payload bytes; `pad_before`/`pad_after` add the padding a C compiler would insert. `mode`
+/// names the attribute in error messages; without an explicit `payload_size` the payload is as
+/// large as the largest variant.
+fn tagged_enum_derive(
+    input: DeriveInput,
+    attrs: &ByteableAttrs,
+    mode: &str,
+    tag_repr: Ident,
+    payload_size: Option<syn::Expr>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Enum(enum_data) = &input.data else {
+        unreachable!();
+    };
+    let enum_name = &input.ident;
+    let vis = &input.vis;
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            format!("#[byteable({mode})] does not support generic enums"),
+        ));
+    }
+    if attrs.is_io_only() {
+        return Err(attrs.error(
+            "io_only",
+            format!("#[byteable({mode})] cannot be combined with #[byteable(io_only)]"),
+        ));
+    }
+    if attrs.default_variant.is_some() {
+        return Err(attrs.error(
+            "default",
+            format!(
+                "#[byteable(default = ...)] cannot be combined with #[byteable({mode})]: the \
+                 payload of an unknown variant has no meaning"
+            ),
+        ));
+    }
+
+    let bc = byteable_crate_path()?;
+    let raw_name = format_ident!("__byteable_raw_{}", enum_name);
+    let container_attr = attrs.container_layout()?;
+    let discriminants = compute_discriminants(&enum_data.variants);
+    let valid_discriminants = valid_discriminants(&tag_repr, &discriminants, &bc);
+    let variant_raw_names: Vec<_> = enum_data
+        .variants
+        .iter()
+        .map(|variant| format_ident!("__byteable_raw_{}_{}", enum_name, variant.ident))
+        .collect();
+    let payload_size = match &payload_size {
+        Some(size) => quote! { #size },
+        None => quote! {
+            {
+                let sizes = [#(::core::mem::size_of::<#variant_raw_names>()),*];
+                let mut max = 0;
+                let mut i = 0;
+                while i < sizes.len() {
+                    if sizes[i] > max {
+                        max = sizes[i];
+                    }
+                    i += 1;
+                }
+                max
+            }
+        },
+    };
+
+    let (tag_raw_ty, tag_to_raw, tag_from_raw) = match container_attr {
+        AttributeType::LittleEndian => (
+            quote! { <#tag_repr as #bc::HasEndianRepr>::LE },
+            quote! { <#tag_repr as #bc::HasEndianRepr>::to_little_endian(tag) },
+            quote! { <#tag_repr as #bc::FromEndianRepr>::from_little_endian(raw.tag) },
+        ),
+        AttributeType::BigEndian => (
+            quote! { <#tag_repr as #bc::HasEndianRepr>::BE },
+            quote! { <#tag_repr as #bc::HasEndianRepr>::to_big_endian(tag) },
+            quote! { <#tag_repr as #bc::FromEndianRepr>::from_big_endian(raw.tag) },
+        ),
+        _ => (
+            quote! { <#tag_repr as #bc::RawRepr>::Raw },
+            quote! { <#tag_repr as #bc::RawRepr>::to_raw(&tag) },
+            quote! { <#tag_repr as #bc::FromRawRepr>::from_raw(raw.tag) },
+        ),
+    };
+
+    let mut variant_raws = Vec::new();
+    let mut to_raw_arms = Vec::new();
+    let mut from_raw_arms = Vec::new();
+    for ((variant, disc), variant_raw) in enum_data
+        .variants
+        .iter()
+        .zip(&discriminants)
+        .zip(&variant_raw_names)
+    {
+        let variant_name = &variant.ident;
+
+        let mut raw_tys = Vec::new();
+        let mut to_raws = Vec::new();
+        let mut from_raws = Vec::new();
+        let mut bindings = Vec::new();
+        // Index of the next member of the variant's raw struct, which also holds padding.
+        let mut raw_index = 0;
+        for (i, field) in variant.fields.iter().enumerate() {
+            let field_type = &field.ty;
+            let field_name = match &field.ident {
+                Some(name) => format!("{variant_name}::{name}"),
+                None => format!("{variant_name}::{i}"),
+            };
+            let field_attrs = ByteableAttrs::parse(&field.attrs)?;
+            reject_struct_field_attrs(&field_attrs)?;
+            reject_count(&field_attrs)?;
+            let attr = match field_attrs.layout()? {
+                AttributeType::None => {
+                    inherited_field_attr(&field_name, field_type, container_attr)?
+                }
+                other => other,
+            };
+            reject_endian_on_bool_char(
+                &field_name,
+                field_type,
+                attr,
+                "mark it #[byteable(try_transparent)], or use `BigEndianTry<T>` / \
+                 `LittleEndianTry<T>` for a byte order",
+            )?;
+            let binding = format_ident!("__field_{}", i);
+            // Padding is an anonymous zeroed `[u8; N]` member, written as zeros and ignored
+            // on decode, as in fixed-size structs.
+            if let Some(len) = &field_attrs.pad_before {
+                raw_tys.push(quote! { [u8; #len] });
+                to_raws.push(quote! { [0u8; #len] });
+                raw_index += 1;
+            }
+            let idx = syn::Index::from(raw_index);
+            raw_index += 1;
+            let (raw_ty, to_raw, from_raw) = match attr {
+                AttributeType::LittleEndian => (
+                    quote! { <#field_type as #bc::HasEndianRepr>::LE },
+                    quote! { <#field_type as #bc::HasEndianRepr>::to_little_endian(*#binding) },
+                    quote! { <#field_type as #bc::FromEndianRepr>::from_little_endian(fields.#idx) },
+                ),
+                AttributeType::BigEndian => (
+                    quote! { <#field_type as #bc::HasEndianRepr>::BE },
+                    quote! { <#field_type as #bc::HasEndianRepr>::to_big_endian(*#binding) },
+                    quote! { <#field_type as #bc::FromEndianRepr>::from_big_endian(fields.#idx) },
+                ),
+                AttributeType::TryTransparent => (
+                    quote! { <#field_type as #bc::RawRepr>::Raw },
+                    quote! { <#field_type as #bc::RawRepr>::to_raw(#binding) },
+                    quote! { <#field_type as #bc::TryFromRawRepr>::try_from_raw(fields.#idx)? },
+                ),
+                AttributeType::IoOnly | AttributeType::Bytemuck => {
+                    return Err(field_attrs.error(
+                        attr.name(),
+                        format!(
+                            "#[byteable({})] is a struct-level attribute and cannot be used on \
+                             individual fields",
+                            attr.name()
+                        ),
+                    ));
+                }
+                AttributeType::None | AttributeType::Transparent => (
+                    quote! { <#field_type as #bc::RawRepr>::Raw },
+                    quote! { <#field_type as #bc::RawRepr>::to_raw(#binding) },
+                    quote! { <#field_type as #bc::FromRawRepr>::from_raw(fields.#idx) },
+                ),
+            };
+            raw_tys.push(raw_ty);
+            to_raws.push(to_raw);
+            if let Some(len) = &field_attrs.pad_after {
+                raw_tys.push(quote! { [u8; #len] });
+                to_raws.push(quote! { [0u8; #len] });
+                raw_index += 1;
+            }
+            from_raws.push(match &field.ident {
+                Some(name) => quote! { #name: #from_raw },
+                None => from_raw,
+            });
+            bindings.push(match &field.ident {
+                Some(name) => quote! { #name: #binding },
+                None => quote! { #binding },
+            });
+        }
+
+        let fit_message = format!(
+            "variant `{variant_name}` of `{enum_name}` does not fit in #[byteable(payload_size = ...)]"
+        );
+        variant_raws.push(quote! {
+            #[derive(Clone, Copy)]
+            #[repr(C, packed)]
+            #[doc(hidden)]
+            #[allow(non_camel_case_types)]
+            struct #variant_raw(#(#raw_tys),*);
+
+            unsafe impl #bc::PlainOldData for #variant_raw {}
+
+            const _: () = ::core::assert!(
+                ::core::mem::size_of::<#variant_raw>() <= #payload_size,
+                #fit_message
+            );
+        });
+
+        let (pattern, construct) = match &variant.fields {
+            Fields::Named(_) => (
+                quote! { #enum_name::#variant_name { #(#bindings),* } },
+                quote! { #enum_name::#variant_name { #(#from_raws),* } },
+            ),
+            Fields::Unnamed(_) => (
+                quote! { #enum_name::#variant_name(#(#bindings),*) },
+                quote! { #enum_name::#variant_name(#(#from_raws),*) },
+            ),
+            Fields::Unit => (
+                quote! { #enum_name::#variant_name },
+                quote! { #enum_name::#variant_name },
+            ),
+        };
+        to_raw_arms.push(quote! {
+            #pattern => {
+                let fields = #variant_raw(#(#to_raws),*);
+                let bytes = #bc::PlainOldData::as_bytes(&fields);
+                payload[..bytes.len()].copy_from_slice(bytes);
+                #disc
+            }
+        });
+        from_raw_arms.push(quote! {
+            #disc => {
+                let mut fields = <#variant_raw as #bc::PlainOldData>::zeroed();
+                let bytes = #bc::PlainOldData::as_bytes_mut(&mut fields);
+                let len = bytes.len();
+                bytes.copy_from_slice(&payload[..len]);
+                Ok(#construct)
+            }
+        });
+    }
+
+    Ok(quote! {
+        #[derive(Clone, Copy)]
+        #[repr(C, packed)]
+        #[doc(hidden)]
+        #[allow(non_camel_case_types)]
+        #vis struct #raw_name {
+            tag: #tag_raw_ty,
+            payload: [u8; #payload_size],
+        }
+
+        unsafe impl #bc::PlainOldData for #raw_name {}
+
+        #(#variant_raws)*
+
+        impl #bc::RawRepr for #enum_name {
+            type Raw = #raw_name;
+
+            fn to_raw(&self) -> #raw_name {
+                let mut payload = [0u8; #payload_size];
+                let tag: #tag_repr = match self {
+                    #(#to_raw_arms)*
+                };
+                #raw_name {
+                    tag: #tag_to_raw,
+                    payload,
+                }
+            }
+        }
+
+        impl #bc::TryFromRawRepr for #enum_name {
+            fn try_from_raw(raw: #raw_name) -> Result<Self, #bc::DecodeError> {
+                let tag: #tag_repr = #tag_from_raw;
+                let payload = raw.payload;
+                match tag {
+                    #(#from_raw_arms)*
+                    _ => Err(#bc::DecodeError::InvalidDiscriminant {
+                        raw: #bc::Discriminant::from(tag),
+                        type_name: ::core::stringify!(#enum_name),
+                        valid_discriminants: #valid_discriminants,
+                    }),
                 }
             }
         }
-    }.into()
+
+        impl #bc::IntoByteArray for #enum_name {
+            type ByteArray = [u8; ::core::mem::size_of::<#raw_name>()];
+
+            fn into_byte_array(&self) -> Self::ByteArray {
+                let raw = <Self as #bc::RawRepr>::to_raw(self);
+                let mut bytes = [0u8; ::core::mem::size_of::<#raw_name>()];
+                bytes.copy_from_slice(#bc::PlainOldData::as_bytes(&raw));
+                bytes
+            }
+        }
+
+        impl #bc::TryFromByteArray for #enum_name {
+            fn try_from_byte_array(byte_array: Self::ByteArray) -> Result<Self, #bc::DecodeError> {
+                let mut raw = <#raw_name as #bc::PlainOldData>::zeroed();
+                #bc::PlainOldData::as_bytes_mut(&mut raw).copy_from_slice(&byte_array);
+                <Self as #bc::TryFromRawRepr>::try_from_raw(raw)
+            }
+        }
+    })
 }
 
 fn try_eval_int_expr(expr: &syn::Expr) -> Option<u128> {
@@ -1030,39 +3639,52 @@ fn compute_discriminants(
         .collect()
 }
 
-fn unit_enum_derive(input: DeriveInput) -> proc_macro::TokenStream {
-    let bc = byteable_crate_path();
+/// Builds the `&'static [Discriminant]` listing every accepted discriminant, reported by
+/// `DecodeError::InvalidDiscriminant` when decoding fails.
+fn valid_discriminants(
+    repr_ty: &Ident,
+    discriminants: &[proc_macro2::TokenStream],
+    bc: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let variant = Ident::new(&repr_ty.to_string().to_uppercase(), repr_ty.span());
+    quote! { &[#(#bc::Discriminant::#variant(#discriminants)),*] }
+}
+
+fn unit_enum_derive(
+    input: DeriveInput,
+    attrs: &ByteableAttrs,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let bc = byteable_crate_path()?;
     let Data::Enum(enum_data) = &input.data else {
         unreachable!();
     };
     let enum_name = &input.ident;
 
-    let repr_ty = extract_repr_type(&input.attrs).unwrap_or_else(|| {
-        let n = enum_data.variants.len();
-        let ty_str = if n <= 256 {
-            "u8"
-        } else if n <= 65_536 {
-            "u16"
-        } else if n as u64 <= u32::MAX as u64 + 1 {
-            "u32"
-        } else {
-            "u64"
-        };
-        Ident::new(ty_str, enum_name.span())
-    });
+    let repr_ty = discriminant_repr_type(&input.attrs, &enum_data.variants, enum_name);
 
-    let endian_attr = parse_byteable_attr(&input.attrs);
+    let endian_attr = attrs.container_layout()?;
     let discriminants = compute_discriminants(&enum_data.variants);
+    let valid_discriminants = valid_discriminants(&repr_ty, &discriminants, &bc);
+
+    let default_variant = &attrs.default_variant;
+    if let Some(default) = &default_variant
+        && !enum_data.variants.iter().any(|v| v.ident == *default)
+    {
+        return Err(syn::Error::new_spanned(
+            default,
+            format!("#[byteable(default = {default})] does not name a variant of `{enum_name}`"),
+        ));
+    }
 
-    let from_discriminant_arms =
-        enum_data
-            .variants
-            .iter()
-            .zip(&discriminants)
-            .map(|(variant, disc)| {
-                let variant_name = &variant.ident;
-                quote! { #disc => Ok(#enum_name::#variant_name), }
-            });
+    let from_discriminant_arms: Vec<_> = enum_data
+        .variants
+        .iter()
+        .zip(&discriminants)
+        .map(|(variant, disc)| {
+            let variant_name = &variant.ident;
+            quote! { #disc => #enum_name::#variant_name, }
+        })
+        .collect();
 
     let into_byte_array_body = match endian_attr {
         AttributeType::LittleEndian => quote! {
@@ -1079,24 +3701,66 @@ fn unit_enum_derive(input: DeriveInput) -> proc_macro::TokenStream {
         },
     };
 
-    let try_from_byte_array_body = match endian_attr {
+    let decode_raw = match endian_attr {
         AttributeType::LittleEndian => quote! {
             let le = <<#repr_ty as #bc::HasEndianRepr>::LE as #bc::FromByteArray>::from_byte_array(byte_array);
             let raw = <#repr_ty as #bc::FromEndianRepr>::from_little_endian(le);
-            <Self as #bc::TryFromRawRepr>::try_from_raw(raw)
         },
         AttributeType::BigEndian => quote! {
             let be = <<#repr_ty as #bc::HasEndianRepr>::BE as #bc::FromByteArray>::from_byte_array(byte_array);
             let raw = <#repr_ty as #bc::FromEndianRepr>::from_big_endian(be);
-            <Self as #bc::TryFromRawRepr>::try_from_raw(raw)
         },
         _ => quote! {
             let raw = <#repr_ty as #bc::FromByteArray>::from_byte_array(byte_array);
-            <Self as #bc::TryFromRawRepr>::try_from_raw(raw)
         },
     };
 
-    quote! {
+    // With a default variant every discriminant decodes, so the infallible traits are
+    // generated and the fallible ones follow from them.
+    let from_impls = match &default_variant {
+        Some(default) => quote! {
+            impl #bc::FromRawRepr for #enum_name {
+                fn from_raw(raw: Self::Raw) -> Self {
+                    match raw {
+                        #(#from_discriminant_arms)*
+                        _ => #enum_name::#default,
+                    }
+                }
+            }
+
+            impl #bc::TryFromRawRepr for #enum_name {
+                fn try_from_raw(raw: Self::Raw) -> Result<Self, #bc::DecodeError> {
+                    Ok(<Self as #bc::FromRawRepr>::from_raw(raw))
+                }
+            }
+
+            impl #bc::FromByteArray for #enum_name {
+                fn from_byte_array(byte_array: Self::ByteArray) -> Self {
+                    #decode_raw
+                    <Self as #bc::FromRawRepr>::from_raw(raw)
+                }
+            }
+        },
+        None => quote! {
+            impl #bc::TryFromRawRepr for #enum_name {
+                fn try_from_raw(raw: Self::Raw) -> Result<Self, #bc::DecodeError> {
+                    Ok(match raw {
+                        #(#from_discriminant_arms)*
+                        _ => return Err(#bc::DecodeError::InvalidDiscriminant { raw: #bc::Discriminant::from(raw), type_name: ::core::stringify!(#enum_name), valid_discriminants: #valid_discriminants })
+                    })
+                }
+            }
+
+            impl #bc::TryFromByteArray for #enum_name {
+                fn try_from_byte_array(byte_array: Self::ByteArray) -> Result<Self, #bc::DecodeError> {
+                    #decode_raw
+                    <Self as #bc::TryFromRawRepr>::try_from_raw(raw)
+                }
+            }
+        },
+    };
+
+    Ok(quote! {
         impl #bc::RawRepr for #enum_name {
             type Raw = #repr_ty;
             fn to_raw(&self) -> #repr_ty {
@@ -1104,15 +3768,6 @@ fn unit_enum_derive(input: DeriveInput) -> proc_macro::TokenStream {
             }
         }
 
-        impl #bc::TryFromRawRepr for #enum_name {
-            fn try_from_raw(raw: Self::Raw) -> Result<Self, #bc::DecodeError> {
-                match raw {
-                    #(#from_discriminant_arms)*
-                    _ => Err(#bc::DecodeError::InvalidDiscriminant { raw: raw as u64, type_name: ::core::stringify!(#enum_name) })
-                }
-            }
-        }
-
         impl #bc::IntoByteArray for #enum_name {
             type ByteArray = [u8; ::core::mem::size_of::<#repr_ty>()];
             fn into_byte_array(&self) -> Self::ByteArray {
@@ -1120,12 +3775,6 @@ fn unit_enum_derive(input: DeriveInput) -> proc_macro::TokenStream {
             }
         }
 
-        impl #bc::TryFromByteArray for #enum_name {
-            fn try_from_byte_array(byte_array: Self::ByteArray) -> Result<Self, #bc::DecodeError> {
-                #try_from_byte_array_body
-            }
-        }
-
-    }
-    .into()
+        #from_impls
+    })
 }
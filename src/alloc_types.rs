@@ -0,0 +1,41 @@
+//! Fixed-size trait implementations for `alloc` pointer types (requires the `alloc` feature).
+//!
+//! `Box<T>`, `Rc<T>` and `Arc<T>` serialize exactly like the `T` they point to. `Arc<T>` is
+//! only available on targets with pointer-sized atomics.
+
+use crate::{DecodeError, FromRawRepr, RawRepr, TryFromRawRepr};
+use alloc::{boxed::Box, rc::Rc};
+
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+
+macro_rules! smart_pointer_passthrough {
+    ($($wrapper:ident),+) => {
+        $(
+            impl<T: RawRepr> RawRepr for $wrapper<T> {
+                type Raw = T::Raw;
+
+                fn to_raw(&self) -> Self::Raw {
+                    self.as_ref().to_raw()
+                }
+            }
+
+            impl<T: FromRawRepr> FromRawRepr for $wrapper<T> {
+                fn from_raw(raw: Self::Raw) -> Self {
+                    Self::new(T::from_raw(raw))
+                }
+            }
+
+            impl<T: TryFromRawRepr> TryFromRawRepr for $wrapper<T> {
+                fn try_from_raw(raw: Self::Raw) -> Result<Self, DecodeError> {
+                    Ok(Self::new(T::try_from_raw(raw)?))
+                }
+            }
+        )+
+    };
+}
+
+smart_pointer_passthrough!(Rc, Box);
+
+#[cfg(target_has_atomic = "ptr")]
+smart_pointer_passthrough!(Arc);
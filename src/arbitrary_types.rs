@@ -0,0 +1,27 @@
+//! [`arbitrary`] support for the endian wrappers (requires the `arbitrary` feature).
+//!
+//! [`BigEndian<T>`] and [`LittleEndian<T>`] generate an arbitrary native `T` and wrap it with
+//! [`new`](BigEndian::new), so fuzzers see the same values they would for a plain `T`.
+//! Derived types opt in with `#[byteable(arbitrary)]`, unless they have a `validate` function
+//! or a `count` field, whose invariants independently generated fields would break.
+
+use crate::{BigEndian, EndianConvert, LittleEndian};
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+macro_rules! impl_arbitrary_endian {
+    ($($name:ident),+) => {
+        $(
+            impl<'a, T: EndianConvert + Arbitrary<'a>> Arbitrary<'a> for $name<T> {
+                fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                    T::arbitrary(u).map(Self::new)
+                }
+
+                fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                    T::size_hint(depth)
+                }
+            }
+        )+
+    };
+}
+
+impl_arbitrary_endian!(BigEndian, LittleEndian);
@@ -4,10 +4,66 @@
 //! synchronous API in [`crate::io`] but uses [`tokio::io::AsyncReadExt`] /
 //! [`tokio::io::AsyncWriteExt`] and returns `impl Future` from each method.
 
+use core::fmt;
 use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+
+use futures_core::{FusedStream, Stream};
 
 use crate::{PlainOldData, RawRepr, ReadableError, TryFromRawRepr};
 
+struct CountingWriter<'a, W: tokio::io::AsyncWrite + ?Sized + Unpin> {
+    inner: &'a mut W,
+    count: usize,
+}
+
+impl<'a, W: tokio::io::AsyncWrite + ?Sized + Unpin> CountingWriter<'a, W> {
+    fn new(inner: &'a mut W) -> Self {
+        Self { inner, count: 0 }
+    }
+
+    fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl<W: tokio::io::AsyncWrite + ?Sized + Unpin> tokio::io::AsyncWrite for CountingWriter<'_, W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let n = ready!(Pin::new(&mut *this.inner).poll_write(cx, buf))?;
+        this.count += n;
+        Poll::Ready(Ok(n))
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[io::IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let n = ready!(Pin::new(&mut *this.inner).poll_write_vectored(cx, bufs))?;
+        this.count += n;
+        Poll::Ready(Ok(n))
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.inner.is_write_vectored()
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut *self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut *self.get_mut().inner).poll_shutdown(cx)
+    }
+}
+
 /// Async counterpart of [`crate::io::FixedReadable`].
 ///
 /// Deserializes a fixed-size value from an async reader by filling a zeroed raw buffer
@@ -152,10 +208,180 @@ pub trait AsyncReadFixed: tokio::io::AsyncReadExt + Unpin {
     ) -> impl Future<Output = Result<T, ReadableError>> {
         T::read_fixed_from(self)
     }
+
+    /// Turn this async reader into a [`Stream`] of its fixed-size records.
+    ///
+    /// Async counterpart of [`crate::io::ReadFixed::byteable_iter`]: end of input on a
+    /// record boundary ends the stream, and an I/O error, a record cut short by the end of
+    /// input or a record that fails to decode is yielded as `Some(Err(..))`, after which the
+    /// stream is exhausted. See [`ByteableStream`] for cancellation safety.
+    ///
+    /// ```rust
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// use byteable::{AsyncReadFixed, BigEndian};
+    /// use futures_util::StreamExt;
+    ///
+    /// let data: &[u8] = &[0, 1, 0, 2, 0, 3];
+    /// let values: Vec<u16> = data
+    ///     .byteable_stream::<BigEndian<u16>>()
+    ///     .map(|value| value.unwrap().get())
+    ///     .collect()
+    ///     .await;
+    /// assert_eq!(values, [1, 2, 3]);
+    /// # }
+    /// ```
+    #[inline]
+    fn byteable_stream<T: TryFromRawRepr>(self) -> ByteableStream<Self, T>
+    where
+        Self: Sized,
+    {
+        ByteableStream::new(self)
+    }
 }
 
 impl<T: tokio::io::AsyncReadExt + ?Sized + Unpin> AsyncReadFixed for T {}
 
+/// A [`Stream`] of the fixed-size records of an async reader, returned by
+/// [`AsyncReadFixed::byteable_stream`].
+///
+/// Yields `Ok(T)` for each record until the input ends on a record boundary. The first
+/// error, including input that ends mid-record, is yielded once and then the stream is
+/// exhausted.
+///
+/// # Cancellation safety
+///
+/// The bytes of a partly received record are kept in the stream itself, not in the future
+/// polling it. Dropping a pending `next()` (for example when it loses a `tokio::select!`)
+/// loses nothing: the next poll carries on where the previous one stopped.
+///
+/// ```rust
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// use byteable::{AsyncReadFixed, Byteable};
+/// use futures_util::StreamExt;
+///
+/// #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+/// #[byteable(big_endian)]
+/// struct Message {
+///     kind: u8,
+///     value: u32,
+/// }
+///
+/// let (client, server) = tokio::io::duplex(64);
+/// tokio::spawn(async move {
+///     use byteable::AsyncWriteFixed;
+///     let mut client = client;
+///     for value in 1..=3 {
+///         client.write_fixed(&Message { kind: 1, value }).await.unwrap();
+///     }
+/// });
+///
+/// let total: u32 = server
+///     .byteable_stream::<Message>()
+///     .map(|message| message.unwrap().value)
+///     .fold(0, |sum, value| async move { sum + value })
+///     .await;
+/// assert_eq!(total, 6);
+/// # }
+/// ```
+pub struct ByteableStream<R, T: TryFromRawRepr> {
+    reader: R,
+    raw: T::Raw,
+    filled: usize,
+    done: bool,
+}
+
+impl<R, T: TryFromRawRepr> ByteableStream<R, T> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            raw: T::Raw::zeroed(),
+            filled: 0,
+            done: false,
+        }
+    }
+
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Returns a mutable reference to the underlying reader. Reading from it directly while a
+    /// record is partly received corrupts the stream.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Unwraps the reader. The bytes of a partly received record are lost.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: fmt::Debug, T: TryFromRawRepr> fmt::Debug for ByteableStream<R, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ByteableStream")
+            .field("reader", &self.reader)
+            .field("buffered", &self.filled)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+// The raw record is plain bytes that are never pinned, so only the reader decides.
+impl<R: Unpin, T: TryFromRawRepr> Unpin for ByteableStream<R, T> {}
+
+impl<R: tokio::io::AsyncRead + Unpin, T: TryFromRawRepr> Stream for ByteableStream<R, T> {
+    type Item = Result<T, ReadableError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        // Zero-sized records would never consume input, so they end the stream immediately.
+        if this.done || size_of::<T::Raw>() == 0 {
+            return Poll::Ready(None);
+        }
+        let result = loop {
+            let buf = this.raw.as_bytes_mut();
+            if this.filled == buf.len() {
+                break T::try_from_raw(this.raw).map_err(ReadableError::from);
+            }
+            let mut read_buf = tokio::io::ReadBuf::new(&mut buf[this.filled..]);
+            if let Err(e) = ready!(Pin::new(&mut this.reader).poll_read(cx, &mut read_buf)) {
+                break Err(e.into());
+            }
+            let n = read_buf.filled().len();
+            if n == 0 {
+                this.done = true;
+                if this.filled == 0 {
+                    return Poll::Ready(None);
+                }
+                break Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    format!(
+                        "input ended {} bytes into a {}-byte record",
+                        this.filled,
+                        buf.len()
+                    ),
+                )
+                .into());
+            }
+            this.filled += n;
+        };
+        this.filled = 0;
+        if result.is_err() {
+            this.done = true;
+        }
+        Poll::Ready(Some(result))
+    }
+}
+
+impl<R: tokio::io::AsyncRead + Unpin, T: TryFromRawRepr> FusedStream for ByteableStream<R, T> {
+    fn is_terminated(&self) -> bool {
+        self.done
+    }
+}
+
 /// Extension trait that adds [`read_value`](AsyncReadValue::read_value) to any async reader.
 ///
 /// Automatically implemented for all `T: AsyncReadExt + Unpin`. Async counterpart of
@@ -191,6 +417,65 @@ pub trait AsyncWriteFixed: tokio::io::AsyncWriteExt + Unpin {
     ) -> impl Future<Output = io::Result<()>> {
         val.write_fixed_to(self)
     }
+
+    /// Write an [`AsyncFixedWritable`] value and return the number of bytes written.
+    ///
+    /// Async counterpart of [`crate::io::WriteFixed::write_fixed_counted`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::Error`] if writing fails.
+    #[inline]
+    fn write_fixed_counted(
+        &mut self,
+        val: &impl AsyncFixedWritable,
+    ) -> impl Future<Output = io::Result<usize>> {
+        async move {
+            let mut w = CountingWriter::new(self);
+            w.write_fixed(val).await?;
+            Ok(w.count())
+        }
+    }
+
+    /// Write every item of `items` with as few writes to the underlying writer as possible.
+    ///
+    /// Async counterpart of [`crate::io::WriteFixed::write_fixed_all`]: the items are
+    /// serialized into buffers of up to 8 KiB, each written with one `write_all`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::Error`] if writing fails. Earlier buffers may already have been written.
+    fn write_fixed_all<T: RawRepr>(&mut self, items: &[T]) -> impl Future<Output = io::Result<()>> {
+        async move {
+            let mut buf = Vec::new();
+            for chunk in items.chunks(crate::io::coalesced_chunk_len::<T>()) {
+                crate::io::fill_coalesced(&mut buf, chunk);
+                self.write_all(&buf).await?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Write every item of `iter` in turn and return the total number of bytes written.
+    ///
+    /// Async counterpart of [`crate::io::WriteFixed::write_fixed_iter`]. Writing stops at the
+    /// first error.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::Error`] if writing any item fails; earlier items have been written.
+    fn write_fixed_iter<T: AsyncFixedWritable>(
+        &mut self,
+        iter: impl IntoIterator<Item = T>,
+    ) -> impl Future<Output = io::Result<usize>> {
+        async move {
+            let mut w = CountingWriter::new(self);
+            for item in iter {
+                w.write_fixed(&item).await?;
+            }
+            Ok(w.count())
+        }
+    }
 }
 
 impl<T: tokio::io::AsyncWriteExt + ?Sized + Unpin> AsyncWriteFixed for T {}
@@ -212,6 +497,25 @@ pub trait AsyncWriteValue: tokio::io::AsyncWriteExt + Unpin {
     ) -> impl Future<Output = io::Result<()>> {
         data.write_to(self)
     }
+
+    /// Write an [`AsyncWritable`] value and return the number of bytes written.
+    ///
+    /// Async counterpart of [`crate::io::WriteValue::write_value_counted`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::Error`] if writing fails.
+    #[inline]
+    fn write_value_counted<T: AsyncWritable + ?Sized>(
+        &mut self,
+        data: &T,
+    ) -> impl Future<Output = io::Result<usize>> {
+        async move {
+            let mut w = CountingWriter::new(self);
+            w.write_value(data).await?;
+            Ok(w.count())
+        }
+    }
 }
 
 impl<T: tokio::io::AsyncWriteExt + ?Sized + Unpin> AsyncWriteValue for T {}
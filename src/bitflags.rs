@@ -0,0 +1,307 @@
+//! [`BitFlags8`], [`BitFlags16`] and [`BitFlags32`]: integers with per-bit access.
+
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign};
+
+use crate::{
+    BigEndian, DecodeError, FromByteArray, FromEndianRepr, FromRawRepr, HasEndianRepr,
+    IntoByteArray, LittleEndian, RawRepr, TryFromRawRepr,
+};
+
+macro_rules! bitflags_type {
+    ($(#[$meta:meta])* $name:ident($int:ty)) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub struct $name($int);
+
+        impl $name {
+            /// The number of bits.
+            pub const BITS: u32 = <$int>::BITS;
+
+            /// Creates a value with no bits set.
+            pub const EMPTY: Self = Self(0);
+
+            /// Wraps a raw bit pattern.
+            #[inline]
+            pub const fn new(bits: $int) -> Self {
+                Self(bits)
+            }
+
+            /// Returns the raw bit pattern.
+            #[inline]
+            pub const fn bits(self) -> $int {
+                self.0
+            }
+
+            /// Returns whether bit `bit` (0 = least significant) is set.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `bit` is not below [`BITS`](Self::BITS).
+            #[inline]
+            pub const fn get(self, bit: u32) -> bool {
+                self.0 & Self::mask(bit) != 0
+            }
+
+            /// Sets bit `bit` to `value`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `bit` is not below [`BITS`](Self::BITS).
+            #[inline]
+            pub fn set(&mut self, bit: u32, value: bool) {
+                if value {
+                    self.0 |= Self::mask(bit);
+                } else {
+                    self.0 &= !Self::mask(bit);
+                }
+            }
+
+            /// Flips bit `bit`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `bit` is not below [`BITS`](Self::BITS).
+            #[inline]
+            pub fn toggle(&mut self, bit: u32) {
+                self.0 ^= Self::mask(bit);
+            }
+
+            /// Returns the number of set bits.
+            #[inline]
+            pub const fn count_ones(self) -> u32 {
+                self.0.count_ones()
+            }
+
+            #[inline]
+            const fn mask(bit: u32) -> $int {
+                assert!(bit < Self::BITS, "bit index out of range");
+                1 << bit
+            }
+        }
+
+        impl From<$int> for $name {
+            #[inline]
+            fn from(bits: $int) -> Self {
+                Self(bits)
+            }
+        }
+
+        impl From<$name> for $int {
+            #[inline]
+            fn from(flags: $name) -> Self {
+                flags.0
+            }
+        }
+
+        impl core::fmt::Binary for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Binary::fmt(&self.0, f)
+            }
+        }
+
+        impl BitOr for $name {
+            type Output = Self;
+
+            #[inline]
+            fn bitor(self, rhs: Self) -> Self {
+                Self(self.0 | rhs.0)
+            }
+        }
+
+        impl BitAnd for $name {
+            type Output = Self;
+
+            #[inline]
+            fn bitand(self, rhs: Self) -> Self {
+                Self(self.0 & rhs.0)
+            }
+        }
+
+        impl BitOrAssign for $name {
+            #[inline]
+            fn bitor_assign(&mut self, rhs: Self) {
+                self.0 |= rhs.0;
+            }
+        }
+
+        impl BitAndAssign for $name {
+            #[inline]
+            fn bitand_assign(&mut self, rhs: Self) {
+                self.0 &= rhs.0;
+            }
+        }
+
+        impl IntoByteArray for $name {
+            type ByteArray = [u8; size_of::<$int>()];
+
+            #[inline]
+            fn into_byte_array(&self) -> Self::ByteArray {
+                self.to_raw().into_byte_array()
+            }
+        }
+
+        impl FromByteArray for $name {
+            #[inline]
+            fn from_byte_array(byte_array: Self::ByteArray) -> Self {
+                Self::from_raw(<Self as RawRepr>::Raw::from_byte_array(byte_array))
+            }
+        }
+
+        impl TryFromRawRepr for $name {
+            #[inline]
+            fn try_from_raw(raw: Self::Raw) -> Result<Self, DecodeError> {
+                Ok(Self::from_raw(raw))
+            }
+        }
+    };
+}
+
+bitflags_type! {
+    /// A `u8` whose individual bits are flags, stored as a single byte.
+    ///
+    /// Drop it into a derived struct in place of a bare `u8` flags field to get
+    /// [`get`](Self::get) / [`set`](Self::set) / [`toggle`](Self::toggle) access and `|` / `&`
+    /// operators:
+    ///
+    /// ```rust
+    /// use byteable::{BitFlags8, Byteable, IntoByteArray};
+    ///
+    /// #[derive(Byteable)]
+    /// struct Status {
+    ///     flags: BitFlags8,
+    ///     code: u8,
+    /// }
+    ///
+    /// let mut flags = BitFlags8::new(0b0000_0001);
+    /// flags.set(3, true);
+    /// assert!(flags.get(0) && flags.get(3) && !flags.get(1));
+    /// assert_eq!(Status { flags, code: 7 }.into_byte_array(), [0b0000_1001, 7]);
+    /// ```
+    BitFlags8(u8)
+}
+
+bitflags_type! {
+    /// A `u16` whose individual bits are flags.
+    ///
+    /// Stored little-endian by default; as a derived struct field it follows
+    /// `#[byteable(big_endian)]` / `#[byteable(little_endian)]` like a `u16` would.
+    ///
+    /// ```rust
+    /// use byteable::{BitFlags16, Byteable, IntoByteArray};
+    ///
+    /// #[derive(Byteable)]
+    /// struct Header {
+    ///     #[byteable(big_endian)]
+    ///     flags: BitFlags16,
+    /// }
+    ///
+    /// let flags = BitFlags16::new(1 << 15) | BitFlags16::new(1);
+    /// assert_eq!(flags.into_byte_array(), [0x01, 0x80]);
+    /// assert_eq!(Header { flags }.into_byte_array(), [0x80, 0x01]);
+    /// ```
+    BitFlags16(u16)
+}
+
+bitflags_type! {
+    /// A `u32` whose individual bits are flags.
+    ///
+    /// Stored little-endian by default; as a derived struct field it follows
+    /// `#[byteable(big_endian)]` / `#[byteable(little_endian)]` like a `u32` would.
+    BitFlags32(u32)
+}
+
+impl RawRepr for BitFlags8 {
+    type Raw = u8;
+
+    #[inline]
+    fn to_raw(&self) -> Self::Raw {
+        self.0
+    }
+}
+
+impl FromRawRepr for BitFlags8 {
+    #[inline]
+    fn from_raw(raw: Self::Raw) -> Self {
+        Self(raw)
+    }
+}
+
+// Byte order does not apply to a single byte; these impls let `#[byteable(big_endian)]` /
+// `#[byteable(little_endian)]` containers hold a `BitFlags8` field as-is.
+impl HasEndianRepr for BitFlags8 {
+    type LE = u8;
+    type BE = u8;
+
+    #[inline]
+    fn to_little_endian(self) -> Self::LE {
+        self.0
+    }
+
+    #[inline]
+    fn to_big_endian(self) -> Self::BE {
+        self.0
+    }
+}
+
+impl FromEndianRepr for BitFlags8 {
+    #[inline]
+    fn from_little_endian(le: Self::LE) -> Self {
+        Self(le)
+    }
+
+    #[inline]
+    fn from_big_endian(be: Self::BE) -> Self {
+        Self(be)
+    }
+}
+
+macro_rules! impl_multi_byte_bitflags {
+    ($($name:ident($int:ty)),+) => {
+        $(
+            impl RawRepr for $name {
+                type Raw = LittleEndian<$int>;
+
+                #[inline]
+                fn to_raw(&self) -> Self::Raw {
+                    LittleEndian::new(self.0)
+                }
+            }
+
+            impl FromRawRepr for $name {
+                #[inline]
+                fn from_raw(raw: Self::Raw) -> Self {
+                    Self(raw.get())
+                }
+            }
+
+            impl HasEndianRepr for $name {
+                type LE = LittleEndian<$int>;
+                type BE = BigEndian<$int>;
+
+                #[inline]
+                fn to_little_endian(self) -> Self::LE {
+                    LittleEndian::new(self.0)
+                }
+
+                #[inline]
+                fn to_big_endian(self) -> Self::BE {
+                    BigEndian::new(self.0)
+                }
+            }
+
+            impl FromEndianRepr for $name {
+                #[inline]
+                fn from_little_endian(le: Self::LE) -> Self {
+                    Self(le.get())
+                }
+
+                #[inline]
+                fn from_big_endian(be: Self::BE) -> Self {
+                    Self(be.get())
+                }
+            }
+        )+
+    };
+}
+
+impl_multi_byte_bitflags!(BitFlags16(u16), BitFlags32(u32));
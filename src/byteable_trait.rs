@@ -68,21 +68,176 @@ pub unsafe trait PlainOldData: Copy + Sized {
 
 unsafe impl<T: PlainOldData, const N: usize> PlainOldData for [T; N] {}
 
+/// Zero-copy views of byte slices as [`PlainOldData`] values.
+///
+/// Automatically implemented for every `PlainOldData` type. Because such types have no padding
+/// and accept every bit pattern, a byte slice of the right length and alignment can be
+/// reinterpreted in place, e.g. to read records straight out of a memory-mapped file.
+///
+/// The methods return `None` instead of invoking undefined behaviour when the slice has the
+/// wrong length or is not aligned for `Self`. Types whose alignment is 1 (`u8`, byte arrays,
+/// and the `#[repr(C, packed)]` raw structs generated by `#[derive(Byteable)]`) can never be
+/// misaligned; for anything else, such as `u32` or `LittleEndian<u64>`, the slice must start
+/// at a suitably aligned address.
+///
+/// # Packed structs
+///
+/// A reference to a whole packed raw struct is sound, but references to its *fields* are
+/// not: a field of a packed struct may sit at any address, so the compiler rejects `&raw.field`
+/// for multi-byte fields. Copy the value out (or convert it with
+/// [`FromRawRepr::from_raw`]) to work with the individual fields.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteable::{Byteable, ByteableRef, FromRawRepr, RawRepr};
+///
+/// #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+/// #[byteable(big_endian)]
+/// struct Record {
+///     id: u32,
+///     value: u16,
+/// }
+///
+/// // e.g. a region of a memory-mapped file
+/// let region = [0, 0, 0, 7, 0, 9, 0xFF];
+///
+/// let (raw, rest) = <Record as RawRepr>::Raw::ref_from_prefix(&region).unwrap();
+/// assert_eq!(Record::from_raw(*raw), Record { id: 7, value: 9 });
+/// assert_eq!(rest, [0xFF]);
+///
+/// // Wrong length: no view.
+/// assert!(<Record as RawRepr>::Raw::ref_from_bytes(&region).is_none());
+/// ```
+pub trait ByteableRef: PlainOldData {
+    /// Reinterprets `bytes` as a reference to `Self`.
+    ///
+    /// Returns `None` if `bytes.len()` is not `size_of::<Self>()` or `bytes` is not aligned
+    /// for `Self`.
+    #[inline]
+    fn ref_from_bytes(bytes: &[u8]) -> Option<&Self> {
+        let ptr = bytes.as_ptr().cast::<Self>();
+        if bytes.len() != core::mem::size_of::<Self>() || !ptr.is_aligned() {
+            return None;
+        }
+        // SAFETY: the pointer is valid for `size_of::<Self>()` reads, aligned, and any bit
+        // pattern is a valid `Self` (PlainOldData). The lifetime is tied to `bytes`.
+        Some(unsafe { &*ptr })
+    }
+
+    /// Reinterprets `bytes` as a mutable reference to `Self`.
+    ///
+    /// Returns `None` under the same conditions as [`ref_from_bytes`](ByteableRef::ref_from_bytes).
+    /// Writes through the reference change `bytes`, which stay valid because `Self` has no
+    /// padding.
+    #[inline]
+    fn mut_from_bytes(bytes: &mut [u8]) -> Option<&mut Self> {
+        let ptr = bytes.as_mut_ptr().cast::<Self>();
+        if bytes.len() != core::mem::size_of::<Self>() || !ptr.is_aligned() {
+            return None;
+        }
+        // SAFETY: as in `ref_from_bytes`; the exclusive borrow of `bytes` is moved into the
+        // returned reference.
+        Some(unsafe { &mut *ptr })
+    }
+
+    /// Reinterprets the first `size_of::<Self>()` bytes of `bytes` as a reference to `Self`
+    /// and returns it together with the remaining bytes.
+    ///
+    /// Returns `None` if `bytes` is too short or not aligned for `Self`.
+    #[inline]
+    fn ref_from_prefix(bytes: &[u8]) -> Option<(&Self, &[u8])> {
+        let (head, rest) = bytes.split_at_checked(core::mem::size_of::<Self>())?;
+        Some((Self::ref_from_bytes(head)?, rest))
+    }
+}
+
+impl<T: PlainOldData> ByteableRef for T {}
+
 /// Marker trait for types that are fixed-size byte arrays.
 ///
-/// Currently only implemented for `[u8; N]`. It is used as the associated `ByteArray` type
-/// in [`IntoByteArray`] to represent the serialized form of a value.
+/// Implemented for `[u8; N]` and, recursively, for arrays of byte arrays such as
+/// `[[u8; 2]; 3]`. It is used as the associated `ByteArray` type in [`IntoByteArray`] to
+/// represent the serialized form of a value.
+///
+/// A nested array has alignment 1 and no padding, so its memory is exactly its
+/// [`BYTE_SIZE`](ByteArray::BYTE_SIZE) bytes in row-major order;
+/// [`as_byte_slice`](ByteArray::as_byte_slice) exposes that flat view.
 ///
 /// # Safety
 ///
-/// The implementor must be a plain byte array with `BYTE_SIZE` equal to its actual size.
+/// The implementor must consist solely of `u8`s (directly, through nested arrays, or through
+/// `repr(C)` aggregates of them such as [`Tagged2Bytes`](crate::Tagged2Bytes) and
+/// [`ByteConcat`]), with `BYTE_SIZE` equal to its actual size.
+///
+/// A `BYTE_SIZE` that differs from the actual size fails to compile as soon as
+/// [`as_byte_slice`](ByteArray::as_byte_slice) or
+/// [`as_byte_slice_mut`](ByteArray::as_byte_slice_mut) is used:
+///
+/// ```compile_fail
+/// use byteable::ByteArray;
+///
+/// #[derive(Clone, Copy)]
+/// struct Short([u8; 2]);
+///
+/// unsafe impl ByteArray for Short {
+///     const BYTE_SIZE: usize = 4;
+/// }
+///
+/// let _ = Short([0; 2]).as_byte_slice();
+/// ```
 pub unsafe trait ByteArray: Copy {
     /// The number of bytes in this array.
     const BYTE_SIZE: usize;
+
+    /// Returns the bytes of this array as a flat slice of length
+    /// [`BYTE_SIZE`](ByteArray::BYTE_SIZE).
+    ///
+    /// ```
+    /// use byteable::ByteArray;
+    ///
+    /// let nested = [[1u8, 2], [3, 4], [5, 6]];
+    /// assert_eq!(nested.as_byte_slice(), &[1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[inline]
+    fn as_byte_slice(&self) -> &[u8] {
+        // A wrong `BYTE_SIZE` would read past the value. This is a compile error for every
+        // type the method is used with, in every build profile.
+        const {
+            assert!(
+                core::mem::size_of::<Self>() == Self::BYTE_SIZE,
+                "ByteArray::BYTE_SIZE does not match the size of the type"
+            )
+        };
+        // SAFETY: the trait contract guarantees `Self` is `BYTE_SIZE` initialized `u8`s, and
+        // the size was checked above.
+        unsafe {
+            core::slice::from_raw_parts(core::ptr::from_ref(self) as *const u8, Self::BYTE_SIZE)
+        }
+    }
+
+    /// Returns the bytes of this array as a flat mutable slice of length
+    /// [`BYTE_SIZE`](ByteArray::BYTE_SIZE).
+    #[inline]
+    fn as_byte_slice_mut(&mut self) -> &mut [u8] {
+        const {
+            assert!(
+                core::mem::size_of::<Self>() == Self::BYTE_SIZE,
+                "ByteArray::BYTE_SIZE does not match the size of the type"
+            )
+        };
+        // SAFETY: as in `as_byte_slice`; every bit pattern is a valid `u8`.
+        unsafe {
+            core::slice::from_raw_parts_mut(core::ptr::from_mut(self) as *mut u8, Self::BYTE_SIZE)
+        }
+    }
 }
 unsafe impl<const N: usize> ByteArray for [u8; N] {
     const BYTE_SIZE: usize = N;
 }
+unsafe impl<A: ByteArray, const N: usize> ByteArray for [A; N] {
+    const BYTE_SIZE: usize = A::BYTE_SIZE * N;
+}
 
 /// Conversion from a value into its fixed-size byte representation.
 ///
@@ -92,6 +247,10 @@ unsafe impl<const N: usize> ByteArray for [u8; N] {
 /// [`BYTE_SIZE`](IntoByteArray::BYTE_SIZE) is a compile-time constant equal to the number of
 /// bytes produced by [`into_byte_array`](IntoByteArray::into_byte_array).
 ///
+/// `into_byte_array` takes `&self`: serializing only reads the value, so it works through a
+/// shared reference and for types that are neither `Copy` nor `Clone`. Derived structs read
+/// each field through the reference.
+///
 /// # Examples
 ///
 /// ```rust
@@ -108,7 +267,8 @@ unsafe impl<const N: usize> ByteArray for [u8; N] {
 /// let bytes: [u8; 4] = p.into_byte_array();
 /// ```
 pub trait IntoByteArray: Sized {
-    /// The fixed-size byte array type that this value serializes to (always `[u8; N]`).
+    /// The fixed-size byte array type that this value serializes to: `[u8; N]`, a nested
+    /// array of byte arrays for array types, or a [`ByteConcat`] chain for tuples.
     type ByteArray: ByteArray;
 
     /// Compile-time byte size of the serialized form.
@@ -116,6 +276,72 @@ pub trait IntoByteArray: Sized {
 
     /// Serialize this value into a fixed-size byte array.
     fn into_byte_array(&self) -> Self::ByteArray;
+
+    /// Renders the serialized bytes as an offset / hex / ASCII dump, 16 bytes per row, for
+    /// debugging.
+    ///
+    /// For a derived struct, [`ByteableLayout::hexdump_annotated`] also labels the fields.
+    ///
+    /// ```
+    /// use byteable::{BigEndian, IntoByteArray};
+    ///
+    /// assert_eq!(
+    ///     BigEndian::new(0x4869_2100u32).hexdump(),
+    ///     "00000000  48 69 21 00                                       |Hi!.|\n"
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn hexdump(&self) -> alloc::string::String {
+        crate::hexdump::hexdump(self.into_byte_array().as_byte_slice())
+    }
+
+    /// Encodes the serialized bytes as a lowercase hex string, two digits per byte.
+    ///
+    /// [`TryFromByteArray::from_hex`] decodes it again.
+    ///
+    /// ```
+    /// use byteable::{IntoByteArray, LittleEndian};
+    ///
+    /// assert_eq!(LittleEndian::new(0xCAFEu16).to_hex(), "feca");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn to_hex(&self) -> alloc::string::String {
+        crate::hex::encode(self.into_byte_array().as_byte_slice())
+    }
+
+    /// Writes the serialized bytes to the start of `dst` and returns how many were written
+    /// ([`BYTE_SIZE`](IntoByteArray::BYTE_SIZE)).
+    ///
+    /// This builds a packet in a pre-sized buffer without a `Cursor` or a `Vec`; the returned
+    /// length says where the next value goes. Bytes past the value are left untouched.
+    ///
+    /// ```
+    /// use byteable::{BigEndian, IntoByteArray, SliceError};
+    ///
+    /// let mut packet = [0u8; 8];
+    /// let mut len = BigEndian::new(0x0102u16).write_to_slice(&mut packet).unwrap();
+    /// len += 7u8.write_to_slice(&mut packet[len..]).unwrap();
+    /// assert_eq!(&packet[..len], [1, 2, 7]);
+    ///
+    /// assert_eq!(
+    ///     0u32.write_to_slice(&mut packet[6..]),
+    ///     Err(SliceError::TooShort { needed: 4, available: 2 })
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SliceError::TooShort`] if `dst` is shorter than `BYTE_SIZE`; `dst` is then
+    /// left unchanged.
+    fn write_to_slice(&self, dst: &mut [u8]) -> Result<usize, SliceError> {
+        let available = dst.len();
+        let dst = dst.get_mut(..Self::BYTE_SIZE).ok_or(SliceError::TooShort {
+            needed: Self::BYTE_SIZE,
+            available,
+        })?;
+        dst.copy_from_slice(self.into_byte_array().as_byte_slice());
+        Ok(Self::BYTE_SIZE)
+    }
 }
 
 /// Infallible conversion from a fixed-size byte array back into a value.
@@ -138,6 +364,31 @@ pub trait FromByteArray: IntoByteArray {
 /// For types that implement [`FromByteArray`], a blanket impl provides `TryFromByteArray`
 /// automatically by wrapping the infallible conversion in `Ok(...)`.
 ///
+/// Since [`IntoByteArray`] is a supertrait and the error is always [`DecodeError`],
+/// `T: TryFromByteArray` is the one bound generic code needs to both encode and decode any
+/// fixed-size type, fallible (`bool`, `char`, derived enums) or not:
+///
+/// ```rust
+/// use byteable::{ByteArray, DecodeError, IntoByteArray, TryFromByteArray};
+///
+/// fn decode<T: TryFromByteArray>(bytes: &[u8]) -> Option<Result<T, DecodeError>> {
+///     // SAFETY: a `ByteArray` consists solely of `u8`s, so all-zeros is a valid value.
+///     let mut array: T::ByteArray = unsafe { core::mem::zeroed() };
+///     array
+///         .as_byte_slice_mut()
+///         .copy_from_slice(bytes.get(..T::BYTE_SIZE)?);
+///     Some(T::try_from_byte_array(array))
+/// }
+///
+/// fn roundtrip<T: TryFromByteArray>(value: T) -> Result<T, DecodeError> {
+///     T::try_from_byte_array(value.into_byte_array())
+/// }
+///
+/// assert_eq!(decode::<u16>(&[1, 0, 9]), Some(Ok(1)));
+/// assert_eq!(decode::<bool>(&[2]), Some(Err(DecodeError::InvalidBool(2))));
+/// assert_eq!(roundtrip('x'), Ok('x'));
+/// ```
+///
 /// # Errors
 ///
 /// Returns [`DecodeError`] if the byte array does not represent a valid value of `Self`.
@@ -148,6 +399,69 @@ pub trait TryFromByteArray: IntoByteArray {
     ///
     /// Returns [`DecodeError`] if the bytes are not a valid encoding of `Self`.
     fn try_from_byte_array(byte_array: Self::ByteArray) -> Result<Self, DecodeError>;
+
+    /// Decodes a hex string, as written by [`IntoByteArray::to_hex`], into a value.
+    ///
+    /// The string must hold exactly two hex digits (either case) per serialized byte.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FromHexError`](crate::hex::FromHexError) if the string has the wrong length,
+    /// contains a non-hex character, or its bytes are not a valid encoding of `Self`.
+    ///
+    /// ```
+    /// use byteable::{FromHexError, TryFromByteArray};
+    ///
+    /// assert_eq!(u32::from_hex("78563412"), Ok(0x1234_5678));
+    /// assert_eq!(
+    ///     u32::from_hex("785634"),
+    ///     Err(FromHexError::InvalidLength { expected: 8, found: 6 })
+    /// );
+    /// assert!(matches!(bool::from_hex("02"), Err(FromHexError::Decode(_))));
+    /// ```
+    fn from_hex(hex: &str) -> Result<Self, crate::hex::FromHexError> {
+        // SAFETY: a `ByteArray` consists solely of `u8`s, so all-zeros is a valid value.
+        let mut bytes: Self::ByteArray = unsafe { core::mem::zeroed() };
+        crate::hex::decode_into(hex, bytes.as_byte_slice_mut())?;
+        Ok(Self::try_from_byte_array(bytes)?)
+    }
+
+    /// Decodes a value from the start of `src` and returns it with the bytes after it.
+    ///
+    /// The counterpart of [`IntoByteArray::write_to_slice`]: parse a contiguous buffer one
+    /// value at a time by feeding each call the remainder of the last.
+    ///
+    /// ```
+    /// use byteable::{BigEndian, SliceError, TryFromByteArray};
+    ///
+    /// let packet = [1, 2, 7, 0xFF];
+    /// let (len, rest) = BigEndian::<u16>::read_from_slice(&packet).unwrap();
+    /// let (kind, rest) = u8::read_from_slice(rest).unwrap();
+    /// assert_eq!((len.get(), kind, rest), (0x0102, 7, &[0xFF][..]));
+    ///
+    /// assert_eq!(
+    ///     u16::read_from_slice(rest),
+    ///     Err(SliceError::TooShort { needed: 2, available: 1 })
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SliceError::TooShort`] if `src` is shorter than
+    /// [`BYTE_SIZE`](IntoByteArray::BYTE_SIZE), or [`SliceError::Decode`] if the bytes are not
+    /// a valid encoding of `Self`.
+    fn read_from_slice(src: &[u8]) -> Result<(Self, &[u8]), SliceError> {
+        let (head, rest) = src
+            .split_at_checked(Self::BYTE_SIZE)
+            .ok_or(SliceError::TooShort {
+                needed: Self::BYTE_SIZE,
+                available: src.len(),
+            })?;
+        // SAFETY: a `ByteArray` consists solely of `u8`s, so all-zeros is a valid value.
+        let mut bytes: Self::ByteArray = unsafe { core::mem::zeroed() };
+        bytes.as_byte_slice_mut().copy_from_slice(head);
+        Ok((Self::try_from_byte_array(bytes)?, rest))
+    }
 }
 
 impl<T: FromByteArray> TryFromByteArray for T {
@@ -156,6 +470,142 @@ impl<T: FromByteArray> TryFromByteArray for T {
     }
 }
 
+/// Moves the elements out of a fully initialized `[MaybeUninit<T>; N]`.
+///
+/// The array impls below fill a `MaybeUninit` array in place and finish with this instead of
+/// `<[_; N]>::map`, which keeps several copies of the array on the stack in debug builds: too
+/// much for a thread with a small stack once the array is a few KiB.
+///
+/// # Safety
+///
+/// Every element of `array` must be initialized, and the caller must not use them afterwards.
+#[inline]
+unsafe fn assume_init_array<T, const N: usize>(array: &[core::mem::MaybeUninit<T>; N]) -> [T; N] {
+    // SAFETY: `MaybeUninit<T>` has the layout of `T`, so both arrays have the same layout, and
+    // the caller guarantees every element is initialized. `MaybeUninit` never drops its
+    // contents, so the moved-out elements are not dropped twice.
+    unsafe { array.as_ptr().cast::<[T; N]>().read() }
+}
+
+/// Arrays serialize element by element, so `[T; N]` has `ByteArray = [T::ByteArray; N]`.
+/// Use [`ByteArray::as_byte_slice`] for the flat bytes, or [`FlatByteable::to_flat_bytes`] for
+/// an owned `[u8; N]`.
+impl<T: IntoByteArray, const N: usize> IntoByteArray for [T; N] {
+    type ByteArray = [T::ByteArray; N];
+
+    fn into_byte_array(&self) -> Self::ByteArray {
+        use core::mem::MaybeUninit;
+        let mut out: [MaybeUninit<T::ByteArray>; N] = [const { MaybeUninit::uninit() }; N];
+        for (slot, e) in out.iter_mut().zip(self) {
+            slot.write(e.into_byte_array());
+        }
+        // SAFETY: every element was written above.
+        unsafe { assume_init_array(&out) }
+    }
+}
+
+/// Decoding is fallible whenever the element's is; already-decoded elements are dropped if a
+/// later one fails. The error is a [`DecodeError::ArrayElement`] naming the first invalid
+/// element.
+impl<T: TryFromByteArray, const N: usize> TryFromByteArray for [T; N] {
+    fn try_from_byte_array(byte_array: Self::ByteArray) -> Result<Self, DecodeError> {
+        use core::mem::MaybeUninit;
+        let mut out: [MaybeUninit<T>; N] = [const { MaybeUninit::uninit() }; N];
+        let mut initialized = 0usize;
+        for (slot, el) in out.iter_mut().zip(&byte_array) {
+            match T::try_from_byte_array(*el) {
+                Ok(v) => {
+                    slot.write(v);
+                    initialized += 1;
+                }
+                Err(e) => {
+                    for s in &mut out[..initialized] {
+                        unsafe { s.assume_init_drop() };
+                    }
+                    return Err(DecodeError::ArrayElement {
+                        index: initialized,
+                        kind: e.kind(),
+                    });
+                }
+            }
+        }
+        // SAFETY: every element was written above.
+        Ok(unsafe { assume_init_array(&out) })
+    }
+}
+
+/// Conversion between a value and a single flat `[u8; N]`.
+///
+/// [`IntoByteArray::ByteArray`] is not always a flat array: `[T; N]` serializes to
+/// `[T::ByteArray; N]`, and tuples to a [`ByteConcat`] chain. This trait reinterprets that
+/// form as one contiguous `[u8; N]`, which is easier to index or hand to an API expecting a
+/// plain buffer. The bytes are exactly those of [`ByteArray::as_byte_slice`].
+///
+/// It is implemented for every [`IntoByteArray`] type and every `N`; `N` is usually inferred
+/// from the surrounding code. An `N` other than [`BYTE_SIZE`](IntoByteArray::BYTE_SIZE) is
+/// rejected at compile time.
+///
+/// ```rust
+/// use byteable::{BigEndian, FlatByteable};
+///
+/// let values = [BigEndian::new(1u16), BigEndian::new(0x0203)];
+/// let flat: [u8; 4] = values.to_flat_bytes();
+/// assert_eq!(flat, [0, 1, 2, 3]);
+/// assert_eq!(<[BigEndian<u16>; 2]>::try_from_flat_bytes(flat), Ok(values));
+/// ```
+///
+/// ```compile_fail
+/// use byteable::{BigEndian, FlatByteable};
+///
+/// let values = [BigEndian::new(1u16), BigEndian::new(0x0203)];
+/// let flat: [u8; 3] = values.to_flat_bytes(); // 4 bytes do not fit in 3
+/// ```
+pub trait FlatByteable<const N: usize>: IntoByteArray {
+    /// Evaluated on use: fails to compile unless `N` is the serialized size of `Self`.
+    #[doc(hidden)]
+    const FLAT_SIZE_MATCHES: () = assert!(
+        N == <Self as IntoByteArray>::BYTE_SIZE,
+        "the flat array length must equal the type's BYTE_SIZE"
+    );
+
+    /// Serializes this value into a flat byte array.
+    fn to_flat_bytes(&self) -> [u8; N] {
+        let () = Self::FLAT_SIZE_MATCHES;
+        let mut flat = [0u8; N];
+        flat.copy_from_slice(self.into_byte_array().as_byte_slice());
+        flat
+    }
+
+    /// Deserializes a value from a flat byte array. Infallible.
+    fn from_flat_bytes(flat: [u8; N]) -> Self
+    where
+        Self: FromByteArray,
+    {
+        Self::from_byte_array(flat_to_byte_array::<Self, N>(flat))
+    }
+
+    /// Attempts to deserialize a value from a flat byte array.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DecodeError`] if the bytes are not a valid encoding of `Self`.
+    fn try_from_flat_bytes(flat: [u8; N]) -> Result<Self, DecodeError>
+    where
+        Self: TryFromByteArray,
+    {
+        Self::try_from_byte_array(flat_to_byte_array::<Self, N>(flat))
+    }
+}
+
+impl<T: IntoByteArray, const N: usize> FlatByteable<N> for T {}
+
+fn flat_to_byte_array<T: FlatByteable<N>, const N: usize>(flat: [u8; N]) -> T::ByteArray {
+    let () = T::FLAT_SIZE_MATCHES;
+    // SAFETY: the assertion above makes both types `N` bytes long, and a `ByteArray` consists
+    // solely of `u8`s, so any `N` bytes are a valid value of it.
+    unsafe { core::mem::transmute_copy(&flat) }
+}
+
 macro_rules! unsafe_impl_plain_old_data {
     ($($ty:ty),+) => {
         $(
@@ -201,7 +651,16 @@ unsafe_impl_plain_old_data!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f3
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DecodeError {
     /// The raw discriminant value does not correspond to any variant of the enum.
-    InvalidDiscriminant { raw: u64, type_name: &'static str },
+    ///
+    /// `valid_discriminants` lists the values the enum accepts, in declaration order.
+    InvalidDiscriminant {
+        raw: Discriminant,
+        type_name: &'static str,
+        valid_discriminants: &'static [Discriminant],
+    },
+    /// Element `index` of an array failed to decode with an error of kind `kind`. In nested
+    /// arrays, `index` is the position in the outermost one.
+    ArrayElement { index: usize, kind: DecodeErrorKind },
     /// A `bool` field contained a byte other than `0` (false) or `1` (true).
     InvalidBool(u8),
     /// A `char` field contained a `u32` value that is not a valid Unicode scalar.
@@ -211,35 +670,484 @@ pub enum DecodeError {
     InvalidTag { raw: u8, type_name: &'static str },
     /// A `String` field contained bytes that are not valid UTF-8.
     InvalidUtf8,
+    /// Byte `index` of a [`FixedAscii`](crate::FixedAscii) was not ASCII (`0x80` or above).
+    InvalidAscii { index: usize, value: u8 },
     /// A `CString` field contained an interior null byte.
     InvalidCString,
     /// A `NonZero<T>` field decoded to zero, which is not allowed.
     InvalidZero,
     /// A `NotNan<T>` field decoded to NaN, which is not allowed.
     InvalidNaN,
+    /// A `Duration` field had a nanoseconds part of one second or more.
+    InvalidNanos(u32),
+    /// A trailer (see [`HasTrailer`]) did not match the decoded fields.
+    InvalidTrailer { type_name: &'static str },
+    /// The bytes decoded to a value that its type rejects, e.g. from a
+    /// `#[byteable(validate = ...)]` function. `reason` says which constraint failed.
+    InvalidValue {
+        type_name: &'static str,
+        reason: &'static str,
+    },
+    /// A checksum (see `#[byteable(crc32)]`) did not match the checksum computed over the
+    /// preceding bytes.
+    ChecksumMismatch { expected: u32, computed: u32 },
+    /// A magic signature (see `#[byteable(magic = ...)]`) did not match the expected bytes.
+    BadMagic {
+        expected: &'static [u8],
+        got: MagicBytes,
+    },
 }
 
 impl core::fmt::Display for DecodeError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
-            DecodeError::InvalidDiscriminant { raw, type_name } => {
-                write!(f, "invalid discriminant {raw} for type {type_name}")
+            DecodeError::InvalidDiscriminant {
+                raw,
+                type_name,
+                valid_discriminants,
+            } => {
+                write!(f, "invalid discriminant {raw} for type {type_name}")?;
+                fmt_valid_discriminants(f, valid_discriminants)
             }
             DecodeError::InvalidBool(v) => write!(f, "invalid value {v} for bool"),
             DecodeError::InvalidChar(v) => write!(f, "invalid value {v} for char"),
+            DecodeError::ArrayElement { index, kind } => {
+                write!(f, "{kind} at array index {index}")
+            }
             DecodeError::InvalidTag { raw, type_name } => {
                 write!(f, "invalid tag {raw} for {type_name}")
             }
             DecodeError::InvalidUtf8 => write!(f, "invalid UTF-8"),
+            DecodeError::InvalidAscii { index, value } => {
+                write!(
+                    f,
+                    "invalid value {value:#04x} for ASCII at byte index {index}"
+                )
+            }
             DecodeError::InvalidCString => write!(f, "invalid CString: interior null byte"),
             DecodeError::InvalidZero => write!(f, "invalid value: zero not allowed"),
             DecodeError::InvalidNaN => write!(f, "invalid value: NaN not allowed"),
+            DecodeError::InvalidNanos(v) => {
+                write!(
+                    f,
+                    "invalid value {v} for nanoseconds: must be below 1_000_000_000"
+                )
+            }
+            DecodeError::InvalidTrailer { type_name } => {
+                write!(f, "invalid trailer for {type_name}")
+            }
+            DecodeError::InvalidValue { type_name, reason } => {
+                write!(f, "invalid value for {type_name}: {reason}")
+            }
+            DecodeError::BadMagic { expected, got } => {
+                write!(
+                    f,
+                    "bad magic: expected {expected:02x?}, got {:02x?}",
+                    got.as_slice()
+                )
+            }
+            DecodeError::ChecksumMismatch { expected, computed } => {
+                write!(
+                    f,
+                    "checksum mismatch: expected {expected:#010x}, computed {computed:#010x}"
+                )
+            }
         }
     }
 }
 
+/// Appends the accepted discriminants to an invalid-discriminant message.
+fn fmt_valid_discriminants(
+    f: &mut core::fmt::Formatter<'_>,
+    valid_discriminants: &[Discriminant],
+) -> core::fmt::Result {
+    // Long lists are cut off so a wide enum does not flood the message.
+    const MAX_LISTED: usize = 8;
+    for (i, valid) in valid_discriminants.iter().take(MAX_LISTED).enumerate() {
+        f.write_str(if i == 0 { ", expected one of " } else { ", " })?;
+        valid.fmt_value(f)?;
+    }
+    if valid_discriminants.len() > MAX_LISTED {
+        write!(f, ", ... ({} total)", valid_discriminants.len())?;
+    }
+    Ok(())
+}
+
 impl core::error::Error for DecodeError {}
 
+impl DecodeError {
+    /// Returns the kind of this error. For [`DecodeError::ArrayElement`] it is the kind of the
+    /// element's error.
+    pub const fn kind(&self) -> DecodeErrorKind {
+        match self {
+            DecodeError::InvalidDiscriminant { .. } => DecodeErrorKind::InvalidDiscriminant,
+            DecodeError::ArrayElement { kind, .. } => *kind,
+            DecodeError::InvalidBool(_) => DecodeErrorKind::InvalidBool,
+            DecodeError::InvalidChar(_) => DecodeErrorKind::InvalidChar,
+            DecodeError::InvalidTag { .. } => DecodeErrorKind::InvalidTag,
+            DecodeError::InvalidUtf8 => DecodeErrorKind::InvalidUtf8,
+            DecodeError::InvalidAscii { .. } => DecodeErrorKind::InvalidAscii,
+            DecodeError::InvalidCString => DecodeErrorKind::InvalidCString,
+            DecodeError::InvalidZero => DecodeErrorKind::InvalidZero,
+            DecodeError::InvalidNaN => DecodeErrorKind::InvalidNaN,
+            DecodeError::InvalidNanos(_) => DecodeErrorKind::InvalidNanos,
+            DecodeError::InvalidTrailer { .. } => DecodeErrorKind::InvalidTrailer,
+            DecodeError::InvalidValue { .. } => DecodeErrorKind::InvalidValue,
+            DecodeError::ChecksumMismatch { .. } => DecodeErrorKind::ChecksumMismatch,
+            DecodeError::BadMagic { .. } => DecodeErrorKind::BadMagic,
+        }
+    }
+}
+
+/// The kind of a [`DecodeError`], without the values it carries. Returned by
+/// [`DecodeError::kind`] and carried by [`DecodeError::ArrayElement`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecodeErrorKind {
+    /// See [`DecodeError::InvalidDiscriminant`].
+    InvalidDiscriminant,
+    /// See [`DecodeError::InvalidBool`].
+    InvalidBool,
+    /// See [`DecodeError::InvalidChar`].
+    InvalidChar,
+    /// See [`DecodeError::InvalidTag`].
+    InvalidTag,
+    /// See [`DecodeError::InvalidUtf8`].
+    InvalidUtf8,
+    /// See [`DecodeError::InvalidAscii`].
+    InvalidAscii,
+    /// See [`DecodeError::InvalidCString`].
+    InvalidCString,
+    /// See [`DecodeError::InvalidZero`].
+    InvalidZero,
+    /// See [`DecodeError::InvalidNaN`].
+    InvalidNaN,
+    /// See [`DecodeError::InvalidNanos`].
+    InvalidNanos,
+    /// See [`DecodeError::InvalidTrailer`].
+    InvalidTrailer,
+    /// See [`DecodeError::InvalidValue`].
+    InvalidValue,
+    /// See [`DecodeError::ChecksumMismatch`].
+    ChecksumMismatch,
+    /// See [`DecodeError::BadMagic`].
+    BadMagic,
+}
+
+impl core::fmt::Display for DecodeErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            DecodeErrorKind::InvalidDiscriminant => "invalid discriminant",
+            DecodeErrorKind::InvalidBool => "invalid bool",
+            DecodeErrorKind::InvalidChar => "invalid char",
+            DecodeErrorKind::InvalidTag => "invalid tag",
+            DecodeErrorKind::InvalidUtf8 => "invalid UTF-8",
+            DecodeErrorKind::InvalidAscii => "invalid ASCII",
+            DecodeErrorKind::InvalidCString => "invalid CString",
+            DecodeErrorKind::InvalidZero => "invalid value: zero not allowed",
+            DecodeErrorKind::InvalidNaN => "invalid value: NaN not allowed",
+            DecodeErrorKind::InvalidNanos => "invalid nanoseconds",
+            DecodeErrorKind::InvalidTrailer => "invalid trailer",
+            DecodeErrorKind::InvalidValue => "invalid value",
+            DecodeErrorKind::ChecksumMismatch => "checksum mismatch",
+            DecodeErrorKind::BadMagic => "bad magic",
+        })
+    }
+}
+
+/// Error returned by [`IntoByteArray::write_to_slice`] and
+/// [`TryFromByteArray::read_from_slice`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliceError {
+    /// The slice is shorter than the value's serialized size.
+    TooShort {
+        /// The serialized size of the value, [`IntoByteArray::BYTE_SIZE`].
+        needed: usize,
+        /// The length of the slice.
+        available: usize,
+    },
+    /// The bytes do not decode to a value of the target type.
+    Decode(DecodeError),
+}
+
+impl core::fmt::Display for SliceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SliceError::TooShort { needed, available } => {
+                write!(
+                    f,
+                    "slice too short: need {needed} bytes, {available} available"
+                )
+            }
+            SliceError::Decode(error) => error.fmt(f),
+        }
+    }
+}
+
+impl core::error::Error for SliceError {}
+
+impl From<DecodeError> for SliceError {
+    #[inline]
+    fn from(value: DecodeError) -> Self {
+        Self::Decode(value)
+    }
+}
+
+/// A raw enum discriminant, carried by [`DecodeError::InvalidDiscriminant`].
+///
+/// Each variant corresponds to one `#[repr(...)]` integer type, so signed and 128-bit
+/// discriminants are reported exactly rather than truncated. Build one with `From`:
+///
+/// ```rust
+/// use byteable::Discriminant;
+///
+/// assert_eq!(Discriminant::from(-1i8), Discriminant::I8(-1));
+/// assert_eq!(Discriminant::from(u128::MAX).to_string(), format!("{}u128", u128::MAX));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Discriminant {
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+}
+
+macro_rules! impl_discriminant_from {
+    ($($ty:ident => $variant:ident),+ $(,)?) => {
+        $(
+            impl From<$ty> for Discriminant {
+                #[inline]
+                fn from(value: $ty) -> Self {
+                    Discriminant::$variant(value)
+                }
+            }
+        )+
+
+        impl Discriminant {
+            /// Formats the value without the type suffix.
+            fn fmt_value(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                match self {
+                    $(Discriminant::$variant(v) => write!(f, "{v}"),)+
+                }
+            }
+        }
+
+        impl core::fmt::Display for Discriminant {
+            /// Formats the value with its type as a suffix, like an integer literal (`-1i8`).
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                match self {
+                    $(Discriminant::$variant(v) => write!(f, "{v}{}", stringify!($ty)),)+
+                }
+            }
+        }
+    };
+}
+
+impl_discriminant_from!(
+    u8 => U8,
+    u16 => U16,
+    u32 => U32,
+    u64 => U64,
+    u128 => U128,
+    i8 => I8,
+    i16 => I16,
+    i32 => I32,
+    i64 => I64,
+    i128 => I128,
+);
+
+/// The bytes found in place of a magic signature, carried by [`DecodeError::BadMagic`].
+///
+/// `DecodeError` is `Copy` and does not allocate, so the bytes are stored inline; magic
+/// signatures are limited to [`MAX_LEN`](MagicBytes::MAX_LEN) bytes.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct MagicBytes {
+    bytes: [u8; MagicBytes::MAX_LEN],
+    len: u8,
+}
+
+impl MagicBytes {
+    /// The longest magic signature supported by `#[byteable(magic = ...)]`.
+    pub const MAX_LEN: usize = 16;
+
+    /// Copies `bytes`, truncated to [`MAX_LEN`](MagicBytes::MAX_LEN).
+    pub fn new(bytes: &[u8]) -> Self {
+        let len = bytes.len().min(Self::MAX_LEN);
+        let mut stored = [0; Self::MAX_LEN];
+        stored[..len].copy_from_slice(&bytes[..len]);
+        Self {
+            bytes: stored,
+            len: len as u8,
+        }
+    }
+
+    /// The stored bytes.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.bytes[..usize::from(self.len)]
+    }
+}
+
+impl core::fmt::Debug for MagicBytes {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl core::ops::Deref for MagicBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+/// Supplies and validates the trailer of a struct derived with `#[byteable(trailer = T)]`.
+///
+/// The derive appends a `T` after the struct's fields. When serializing, the trailer comes
+/// from [`trailer`](HasTrailer::trailer); when decoding, the trailer is decoded after the
+/// fields and passed to [`check_trailer`](HasTrailer::check_trailer) together with them. This
+/// suits structured footers such as checksums or end markers.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteable::{Byteable, DecodeError, HasTrailer, IntoByteArray, TryFromByteArray};
+///
+/// #[derive(Byteable, Debug, PartialEq)]
+/// struct Footer {
+///     sum: u8,
+/// }
+///
+/// #[derive(Byteable, Debug, PartialEq)]
+/// #[byteable(trailer = Footer)]
+/// struct Packet {
+///     a: u8,
+///     b: u8,
+/// }
+///
+/// impl HasTrailer<Footer> for Packet {
+///     fn trailer(&self) -> Footer {
+///         Footer { sum: self.a.wrapping_add(self.b) }
+///     }
+///
+///     fn check_trailer(&self, trailer: &Footer) -> Result<(), DecodeError> {
+///         if *trailer == self.trailer() {
+///             Ok(())
+///         } else {
+///             Err(DecodeError::InvalidTrailer { type_name: "Packet" })
+///         }
+///     }
+/// }
+///
+/// let packet = Packet { a: 2, b: 3 };
+/// assert_eq!(packet.into_byte_array(), [2, 3, 5]);
+/// assert_eq!(Packet::try_from_byte_array([2, 3, 5]).unwrap(), packet);
+/// assert!(Packet::try_from_byte_array([2, 3, 6]).is_err());
+/// ```
+pub trait HasTrailer<T> {
+    /// Builds the trailer written after the fields.
+    fn trailer(&self) -> T;
+
+    /// Validates a decoded trailer against the decoded fields.
+    ///
+    /// The default accepts every trailer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DecodeError`] (typically [`DecodeError::InvalidTrailer`]) to reject the value.
+    fn check_trailer(&self, trailer: &T) -> Result<(), DecodeError> {
+        let _ = trailer;
+        Ok(())
+    }
+}
+
+/// The byte offset and range of each field in a derived struct's serialized form.
+///
+/// `#[derive(Byteable)]` implements this for every fixed-size struct. The offsets are
+/// measured in the raw representation, which is the serialized byte layout, so they can be
+/// used to label a hex dump or to check a struct against a format specification. Tuple struct
+/// fields are named by their index (`"0"`, `"1"`, ...). Padding, magic signatures, trailers and
+/// checksums are not listed, but they do shift the offsets of the fields after them.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteable::{Byteable, ByteableLayout};
+///
+/// #[derive(Byteable, Clone, Copy)]
+/// #[byteable(big_endian)]
+/// struct Header {
+///     magic: u32,
+///     #[byteable(pad_before = 2)]
+///     version: u16,
+///     flags: u8,
+/// }
+///
+/// assert_eq!(
+///     Header::BYTE_OFFSETS,
+///     [("magic", 0), ("version", 6), ("flags", 8)]
+/// );
+/// assert_eq!(
+///     Header::BYTE_RANGES,
+///     [("magic", 0..4), ("version", 6..8), ("flags", 8..9)]
+/// );
+/// assert_eq!(Header::field_offset("version"), Some(6));
+/// assert_eq!(Header::field_offset("missing"), None);
+/// ```
+pub trait ByteableLayout {
+    /// `(field name, byte offset)` for every field, in declaration order.
+    const BYTE_OFFSETS: &'static [(&'static str, usize)];
+
+    /// `(field name, byte range)` for every field, in declaration order. A range starts at the
+    /// field's offset and covers only the field's own bytes, not padding or a trailer after it.
+    const BYTE_RANGES: &'static [(&'static str, core::ops::Range<usize>)];
+
+    /// Returns the byte offset of the field called `name`, or `None` if there is no such field.
+    fn field_offset(name: &str) -> Option<usize> {
+        Self::BYTE_OFFSETS
+            .iter()
+            .find(|(field, _)| *field == name)
+            .map(|&(_, offset)| offset)
+    }
+
+    /// Like [`IntoByteArray::hexdump`], but starts a new row at each field and labels it with
+    /// the field's name.
+    ///
+    /// Each field covers its [`BYTE_RANGES`](ByteableLayout::BYTE_RANGES) entry. Bytes outside
+    /// every field, such as padding, a magic prefix, a trailer or a checksum, are shown without
+    /// a label.
+    ///
+    /// ```
+    /// use byteable::{Byteable, ByteableLayout};
+    ///
+    /// #[derive(Byteable, Clone, Copy)]
+    /// struct Packet {
+    ///     #[byteable(big_endian)]
+    ///     id: u16,
+    ///     payload: [u8; 4],
+    /// }
+    ///
+    /// let dump = Packet { id: 123, payload: *b"ping" }.hexdump_annotated();
+    /// // 00000000  00 7b                                             |.{|                id
+    /// // 00000002  70 69 6e 67                                       |ping|              payload
+    /// let rows: Vec<&str> = dump.lines().collect();
+    /// assert!(rows[0].starts_with("00000000  00 7b ") && rows[0].ends_with("|.{|                id"));
+    /// assert!(rows[1].starts_with("00000002  70 69 6e 67 ") && rows[1].ends_with("  payload"));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn hexdump_annotated(&self) -> alloc::string::String
+    where
+        Self: IntoByteArray,
+    {
+        crate::hexdump::hexdump_annotated(self.into_byte_array().as_byte_slice(), Self::BYTE_RANGES)
+    }
+}
+
 /// Conversion of a value to its raw, [`PlainOldData`] representation.
 ///
 /// The "raw representation" is an intermediate type that:
@@ -266,7 +1174,13 @@ impl<T: RawRepr, const N: usize> RawRepr for [T; N] {
     type Raw = [T::Raw; N];
 
     fn to_raw(&self) -> Self::Raw {
-        self.each_ref().map(|e| e.to_raw())
+        use core::mem::MaybeUninit;
+        let mut out: [MaybeUninit<T::Raw>; N] = [const { MaybeUninit::uninit() }; N];
+        for (slot, e) in out.iter_mut().zip(self) {
+            slot.write(e.to_raw());
+        }
+        // SAFETY: every element was written above.
+        unsafe { assume_init_array(&out) }
     }
 }
 
@@ -283,7 +1197,13 @@ pub trait FromRawRepr: RawRepr {
 
 impl<T: FromRawRepr, const N: usize> FromRawRepr for [T; N] {
     fn from_raw(raw: Self::Raw) -> Self {
-        raw.map(|el| T::from_raw(el))
+        use core::mem::MaybeUninit;
+        let mut out: [MaybeUninit<T>; N] = [const { MaybeUninit::uninit() }; N];
+        for (slot, el) in out.iter_mut().zip(&raw) {
+            slot.write(T::from_raw(*el));
+        }
+        // SAFETY: every element was written above.
+        unsafe { assume_init_array(&out) }
     }
 }
 
@@ -295,6 +1215,39 @@ impl<T: FromRawRepr, const N: usize> FromRawRepr for [T; N] {
 /// A blanket impl provides `TryFromRawRepr` for `[T; N]` when `T: TryFromRawRepr`,
 /// properly dropping already-initialized elements on failure.
 ///
+/// # Hand-written impls
+///
+/// A type with its own validation implements [`RawRepr`] and this trait by hand, mapping its
+/// conversion error onto the closest [`DecodeError`] variant. That is enough for the I/O
+/// traits and for use as a `try_transparent` field of a derived struct:
+///
+/// ```rust
+/// use byteable::{DecodeError, LittleEndian, RawRepr, ReadFixed, TryFromRawRepr};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// struct Glyph(char);
+///
+/// impl RawRepr for Glyph {
+///     type Raw = LittleEndian<u32>;
+///
+///     fn to_raw(&self) -> Self::Raw {
+///         LittleEndian::new(u32::from(self.0))
+///     }
+/// }
+///
+/// impl TryFromRawRepr for Glyph {
+///     fn try_from_raw(raw: Self::Raw) -> Result<Self, DecodeError> {
+///         char::try_from(raw.get())
+///             .map(Glyph)
+///             .map_err(|_| DecodeError::InvalidChar(raw.get()))
+///     }
+/// }
+///
+/// let mut bytes: &[u8] = &[0x41, 0, 0, 0, 0x00, 0xD8, 0, 0];
+/// assert_eq!(bytes.read_fixed::<Glyph>().unwrap(), Glyph('A'));
+/// assert!(bytes.read_fixed::<Glyph>().is_err()); // a lone surrogate
+/// ```
+///
 /// # Errors
 ///
 /// Returns [`DecodeError`] if the raw bytes do not encode a valid `Self`.
@@ -312,8 +1265,8 @@ impl<T: TryFromRawRepr, const N: usize> TryFromRawRepr for [T; N] {
         use core::mem::MaybeUninit;
         let mut out: [MaybeUninit<T>; N] = [const { MaybeUninit::uninit() }; N];
         let mut initialized = 0usize;
-        for (slot, el) in out.iter_mut().zip(raw) {
-            match T::try_from_raw(el) {
+        for (slot, el) in out.iter_mut().zip(&raw) {
+            match T::try_from_raw(*el) {
                 Ok(v) => {
                     slot.write(v);
                     initialized += 1;
@@ -322,14 +1275,148 @@ impl<T: TryFromRawRepr, const N: usize> TryFromRawRepr for [T; N] {
                     for s in &mut out[..initialized] {
                         unsafe { s.assume_init_drop() };
                     }
-                    return Err(e);
+                    return Err(DecodeError::ArrayElement {
+                        index: initialized,
+                        kind: e.kind(),
+                    });
                 }
             }
         }
-        Ok(out.map(|e| unsafe { e.assume_init() }))
+        // SAFETY: every element was written above.
+        Ok(unsafe { assume_init_array(&out) })
     }
 }
 
+/// Two byte arrays laid out back to back: the serialized form of a tuple.
+///
+/// Rust does not guarantee the memory layout of tuples, so a tuple's bytes are never
+/// reinterpreted directly. Instead, `(T1, T2, ..., Tn)` serializes to a chain of
+/// `ByteConcat`s, one per element in field order and ending in `[u8; 0]`:
+///
+/// ```text
+/// ByteConcat<T1::ByteArray, ByteConcat<T2::ByteArray, ... ByteConcat<Tn::ByteArray, [u8; 0]>>>
+/// ```
+///
+/// Every part is a byte array, so the chain is a flat run of bytes: the encoding of `T1`,
+/// then `T2`, and so on. Use [`ByteArray::as_byte_slice`] to get them.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct ByteConcat<Head: ByteArray, Tail: ByteArray> {
+    head: Head,
+    tail: Tail,
+}
+
+// SAFETY: `repr(C)` over two byte arrays: alignment 1, no padding, and every byte belongs to
+// one of them.
+unsafe impl<Head: ByteArray, Tail: ByteArray> ByteArray for ByteConcat<Head, Tail> {
+    const BYTE_SIZE: usize = Head::BYTE_SIZE + Tail::BYTE_SIZE;
+}
+
+// SAFETY: as above; byte arrays accept every bit pattern.
+unsafe impl<Head: ByteArray, Tail: ByteArray> PlainOldData for ByteConcat<Head, Tail> {}
+
+impl<Head: ByteArray, Tail: ByteArray> core::fmt::Debug for ByteConcat<Head, Tail> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ByteConcat")
+            .field(&self.as_byte_slice())
+            .finish()
+    }
+}
+
+impl<Head: ByteArray, Tail: ByteArray> PartialEq for ByteConcat<Head, Tail> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_byte_slice() == other.as_byte_slice()
+    }
+}
+
+impl<Head: ByteArray, Tail: ByteArray> Eq for ByteConcat<Head, Tail> {}
+
+/// The `ByteConcat` chain type for a list of tuple element types.
+macro_rules! tuple_byte_array {
+    () => { [u8; 0] };
+    ($head:ident $(, $rest:ident)*) => {
+        ByteConcat<<$head as IntoByteArray>::ByteArray, tuple_byte_array!($($rest),*)>
+    };
+}
+
+/// A `ByteConcat` chain expression whose heads are the given byte-array expressions.
+macro_rules! tuple_byte_concat {
+    () => { [] };
+    ($head:expr $(, $rest:expr)*) => {
+        ByteConcat { head: $head, tail: tuple_byte_concat!($($rest),*) }
+    };
+}
+
+/// A pattern binding the heads of a `ByteConcat` chain to the given identifiers.
+macro_rules! tuple_byte_pattern {
+    () => { _ };
+    ($head:ident $(, $rest:ident)*) => {
+        ByteConcat { head: $head, tail: tuple_byte_pattern!($($rest),*) }
+    };
+}
+
+macro_rules! impl_tuple {
+    ($($ty:ident $var:ident),+) => {
+        /// Tuples serialize element by element in field order; the layout is defined by the
+        /// field order, not by Rust's in-memory tuple layout. See [`ByteConcat`].
+        impl<$($ty: IntoByteArray),+> IntoByteArray for ($($ty,)+) {
+            type ByteArray = tuple_byte_array!($($ty),+);
+
+            fn into_byte_array(&self) -> Self::ByteArray {
+                let ($($var,)+) = self;
+                tuple_byte_concat!($($var.into_byte_array()),+)
+            }
+        }
+
+        /// Elements are decoded in field order; the first failure is returned.
+        impl<$($ty: TryFromByteArray),+> TryFromByteArray for ($($ty,)+) {
+            fn try_from_byte_array(byte_array: Self::ByteArray) -> Result<Self, DecodeError> {
+                let tuple_byte_pattern!($($var),+) = byte_array;
+                Ok(($($ty::try_from_byte_array($var)?,)+))
+            }
+        }
+
+        // The raw form is the serialized form itself, so tuples can be used as derived
+        // struct fields and read or written through the I/O traits.
+
+        impl<$($ty: IntoByteArray),+> RawRepr for ($($ty,)+) {
+            type Raw = tuple_byte_array!($($ty),+);
+
+            fn to_raw(&self) -> Self::Raw {
+                self.into_byte_array()
+            }
+        }
+
+        impl<$($ty: FromByteArray),+> FromRawRepr for ($($ty,)+) {
+            fn from_raw(raw: Self::Raw) -> Self {
+                let tuple_byte_pattern!($($var),+) = raw;
+                ($($ty::from_byte_array($var),)+)
+            }
+        }
+
+        impl<$($ty: TryFromByteArray),+> TryFromRawRepr for ($($ty,)+) {
+            fn try_from_raw(raw: Self::Raw) -> Result<Self, DecodeError> {
+                Self::try_from_byte_array(raw)
+            }
+        }
+    };
+}
+
+impl_tuple!(T1 v1);
+impl_tuple!(T1 v1, T2 v2);
+impl_tuple!(T1 v1, T2 v2, T3 v3);
+impl_tuple!(T1 v1, T2 v2, T3 v3, T4 v4);
+impl_tuple!(T1 v1, T2 v2, T3 v3, T4 v4, T5 v5);
+impl_tuple!(T1 v1, T2 v2, T3 v3, T4 v4, T5 v5, T6 v6);
+impl_tuple!(T1 v1, T2 v2, T3 v3, T4 v4, T5 v5, T6 v6, T7 v7);
+impl_tuple!(T1 v1, T2 v2, T3 v3, T4 v4, T5 v5, T6 v6, T7 v7, T8 v8);
+impl_tuple!(T1 v1, T2 v2, T3 v3, T4 v4, T5 v5, T6 v6, T7 v7, T8 v8, T9 v9);
+impl_tuple!(T1 v1, T2 v2, T3 v3, T4 v4, T5 v5, T6 v6, T7 v7, T8 v8, T9 v9, T10 v10);
+impl_tuple!(T1 v1, T2 v2, T3 v3, T4 v4, T5 v5, T6 v6, T7 v7, T8 v8, T9 v9, T10 v10, T11 v11);
+impl_tuple!(
+    T1 v1, T2 v2, T3 v3, T4 v4, T5 v5, T6 v6, T7 v7, T8 v8, T9 v9, T10 v10, T11 v11, T12 v12
+);
+
 /// Marker trait for multi-byte primitive types that support byte-order conversion.
 ///
 /// Implemented for `u16`, `u32`, `u64`, `u128`, `i16`, `i32`, `i64`, `i128`, `f32`, `f64`.
@@ -423,6 +1510,11 @@ impl_endian_convert_float!(f32 => u32, f64 => u64);
 ///
 /// Use [`new`](BigEndian::new) to construct from a native-endian value, and
 /// [`get`](BigEndian::get) to retrieve the native-endian value.
+/// For primitive `T`, [`new_const`](BigEndian::<u32>::new_const) and
+/// [`get_const`](BigEndian::<u32>::get_const) do the same in `const` context.
+/// [`from_raw_bytes`](BigEndian::from_raw_bytes) / [`into_raw_bytes`](BigEndian::into_raw_bytes)
+/// move the stored bytes in and out as they are, e.g. to keep a field read off the wire
+/// without decoding and re-encoding it.
 ///
 /// # Examples
 ///
@@ -433,6 +1525,18 @@ impl_endian_convert_float!(f32 => u32, f64 => u64);
 /// assert_eq!(be.get(), 0x1234u16);
 /// // The internal bytes are stored in big-endian order:
 /// assert_eq!(be.into_byte_array(), [0x12, 0x34]);
+///
+/// // `AsRef<[u8]>` borrows those same bytes, e.g. for `io::Write::write_all`:
+/// assert_eq!(be.as_ref(), &[0x12, 0x34]);
+///
+/// // In const context, name the wrapped type explicitly:
+/// const HTTP_PORT: BigEndian<u16> = BigEndian::<u16>::new_const(80);
+/// assert_eq!(HTTP_PORT.into_byte_array(), [0, 80]);
+///
+/// // Bytes already in network order are stored as they are:
+/// let port = BigEndian::<u16>::from_raw_bytes([0x1F, 0x90]);
+/// assert_eq!(port.get(), 8080);
+/// assert_eq!(port.into_raw_bytes(), [0x1F, 0x90]);
 /// ```
 #[repr(transparent)]
 #[derive(Clone, Copy)]
@@ -445,6 +1549,11 @@ pub struct BigEndian<T: EndianConvert>(T);
 ///
 /// Use [`new`](LittleEndian::new) to construct from a native-endian value, and
 /// [`get`](LittleEndian::get) to retrieve the native-endian value.
+/// For primitive `T`, [`new_const`](LittleEndian::<u32>::new_const) and
+/// [`get_const`](LittleEndian::<u32>::get_const) do the same in `const` context.
+/// [`from_raw_bytes`](LittleEndian::from_raw_bytes) /
+/// [`into_raw_bytes`](LittleEndian::into_raw_bytes) move the stored bytes in and out as they
+/// are.
 ///
 /// # Examples
 ///
@@ -474,6 +1583,12 @@ macro_rules! impl_endian_wrapper {
             pub fn get(self) -> T {
                 T::$from_fn(self.0)
             }
+
+            /// Applies `f` to the native value and wraps the result in the same byte order.
+            #[inline]
+            pub fn map<U: EndianConvert>(self, f: impl FnOnce(T) -> U) -> $name<U> {
+                $name::new(f(self.get()))
+            }
         }
 
         impl<T: core::fmt::Debug + EndianConvert> core::fmt::Debug for $name<T> {
@@ -526,6 +1641,14 @@ macro_rules! impl_endian_wrapper {
             }
         }
 
+        /// Views the stored bytes, already in the wrapper's byte order.
+        impl<T: EndianConvert> AsRef<[u8]> for $name<T> {
+            #[inline]
+            fn as_ref(&self) -> &[u8] {
+                self.0.as_bytes()
+            }
+        }
+
         impl<T: EndianConvert> RawRepr for $name<T> {
             type Raw = Self;
 
@@ -551,6 +1674,66 @@ macro_rules! impl_endian_wrapper {
 impl_endian_wrapper!(BigEndian, to_be, from_be);
 impl_endian_wrapper!(LittleEndian, to_le, from_le);
 
+// Converting between the two wrappers keeps the native value and only reverses the stored
+// bytes: one of `from_*`/`to_*` is always a no-op on the host, so each direction is a single
+// byte swap.
+
+impl<T: EndianConvert> BigEndian<T> {
+    /// Returns the same value stored in little-endian byte order.
+    ///
+    /// This reverses the stored bytes directly; the native value is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteable::{BigEndian, IntoByteArray};
+    ///
+    /// let be = BigEndian::new(0x1234u16);
+    /// let le = be.to_little();
+    /// assert_eq!(le.get(), 0x1234);
+    /// assert_eq!(le.into_byte_array(), [0x34, 0x12]);
+    /// ```
+    #[inline]
+    pub fn to_little(self) -> LittleEndian<T> {
+        LittleEndian(T::from_be(self.0).to_le())
+    }
+}
+
+impl<T: EndianConvert> LittleEndian<T> {
+    /// Returns the same value stored in big-endian byte order.
+    ///
+    /// This reverses the stored bytes directly; the native value is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use byteable::{IntoByteArray, LittleEndian};
+    ///
+    /// let le = LittleEndian::new(0x1234u16);
+    /// let be = le.to_big();
+    /// assert_eq!(be.get(), 0x1234);
+    /// assert_eq!(be.into_byte_array(), [0x12, 0x34]);
+    /// ```
+    #[inline]
+    pub fn to_big(self) -> BigEndian<T> {
+        BigEndian(T::from_le(self.0).to_be())
+    }
+}
+
+impl<T: EndianConvert> From<BigEndian<T>> for LittleEndian<T> {
+    #[inline]
+    fn from(value: BigEndian<T>) -> Self {
+        value.to_little()
+    }
+}
+
+impl<T: EndianConvert> From<LittleEndian<T>> for BigEndian<T> {
+    #[inline]
+    fn from(value: LittleEndian<T>) -> Self {
+        value.to_big()
+    }
+}
+
 macro_rules! impl_from_endian_for_primitive {
     ($($ty:ty),+) => {
         $(
@@ -580,6 +1763,179 @@ macro_rules! impl_byte_array_endian {
 impl_byte_array_endian!(u16, u32, u64, u128, i16, i32, i64, i128, f32, f64);
 impl_from_endian_for_primitive!(u16, u32, u64, u128, i16, i32, i64, i128, f32, f64);
 
+// `new`/`get` go through the `EndianConvert` trait and so cannot be `const`. These inherent
+// per-type versions use the primitives' own const byte-swapping instead, which allows
+// `BigEndian`/`LittleEndian` values in `const` and `static` items.
+macro_rules! impl_const_endian_int {
+    ($($ty:ty),+) => {
+        $(
+            impl BigEndian<$ty> {
+                /// Const equivalent of [`new`](BigEndian::new).
+                #[inline]
+                pub const fn new_const(value: $ty) -> Self {
+                    Self(value.to_be())
+                }
+
+                /// Const equivalent of [`get`](BigEndian::get).
+                #[inline]
+                pub const fn get_const(self) -> $ty {
+                    <$ty>::from_be(self.0)
+                }
+            }
+
+            impl LittleEndian<$ty> {
+                /// Const equivalent of [`new`](LittleEndian::new).
+                #[inline]
+                pub const fn new_const(value: $ty) -> Self {
+                    Self(value.to_le())
+                }
+
+                /// Const equivalent of [`get`](LittleEndian::get).
+                #[inline]
+                pub const fn get_const(self) -> $ty {
+                    <$ty>::from_le(self.0)
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_const_endian_float {
+    ($($ty:ty => $bits:ty),+) => {
+        $(
+            impl BigEndian<$ty> {
+                /// Const equivalent of [`new`](BigEndian::new).
+                #[inline]
+                pub const fn new_const(value: $ty) -> Self {
+                    Self(<$ty>::from_bits(value.to_bits().to_be()))
+                }
+
+                /// Const equivalent of [`get`](BigEndian::get).
+                #[inline]
+                pub const fn get_const(self) -> $ty {
+                    <$ty>::from_bits(<$bits>::from_be(self.0.to_bits()))
+                }
+            }
+
+            impl LittleEndian<$ty> {
+                /// Const equivalent of [`new`](LittleEndian::new).
+                #[inline]
+                pub const fn new_const(value: $ty) -> Self {
+                    Self(<$ty>::from_bits(value.to_bits().to_le()))
+                }
+
+                /// Const equivalent of [`get`](LittleEndian::get).
+                #[inline]
+                pub const fn get_const(self) -> $ty {
+                    <$ty>::from_bits(<$bits>::from_le(self.0.to_bits()))
+                }
+            }
+        )+
+    };
+}
+
+impl_const_endian_int!(u16, u32, u64, u128, i16, i32, i64, i128);
+impl_const_endian_float!(f32 => u32, f64 => u64);
+
+// The stored value already holds the wire bytes, so wrapping or unwrapping them is a plain
+// copy with no byte swap.
+
+impl<T: EndianConvert> BigEndian<T>
+where
+    Self: FromByteArray,
+{
+    /// Wraps bytes that are already in big-endian order, without decoding them.
+    ///
+    /// The inverse of [`into_raw_bytes`](Self::into_raw_bytes).
+    #[inline]
+    pub fn from_raw_bytes(bytes: <Self as IntoByteArray>::ByteArray) -> Self {
+        Self::from_byte_array(bytes)
+    }
+
+    /// Returns the stored bytes, in big-endian order.
+    #[inline]
+    pub fn into_raw_bytes(self) -> <Self as IntoByteArray>::ByteArray {
+        self.into_byte_array()
+    }
+}
+
+impl<T: EndianConvert> LittleEndian<T>
+where
+    Self: FromByteArray,
+{
+    /// Wraps bytes that are already in little-endian order, without decoding them.
+    ///
+    /// The inverse of [`into_raw_bytes`](Self::into_raw_bytes).
+    #[inline]
+    pub fn from_raw_bytes(bytes: <Self as IntoByteArray>::ByteArray) -> Self {
+        Self::from_byte_array(bytes)
+    }
+
+    /// Returns the stored bytes, in little-endian order.
+    #[inline]
+    pub fn into_raw_bytes(self) -> <Self as IntoByteArray>::ByteArray {
+        self.into_byte_array()
+    }
+}
+
+/// Serializes an array of primitives in big-endian order without wrapping each element.
+///
+/// A `[u8; N * size_of::<T>()]` return type is not expressible on stable Rust, so the result
+/// is one byte array per element (e.g. `[[u8; 2]; 3]` for `[u16; 3]`). Its memory is the flat
+/// big-endian byte sequence; use [`ByteArray::as_byte_slice`] to view it as `&[u8]`.
+///
+/// ```
+/// use byteable::{ByteArray, to_be_array};
+///
+/// let bytes = to_be_array([0x0102u16, 0x0304]);
+/// assert_eq!(bytes, [[0x01, 0x02], [0x03, 0x04]]);
+/// assert_eq!(bytes.as_byte_slice(), &[0x01, 0x02, 0x03, 0x04]);
+/// ```
+#[inline]
+pub fn to_be_array<T: EndianConvert, const N: usize>(
+    arr: [T; N],
+) -> <[BigEndian<T>; N] as IntoByteArray>::ByteArray
+where
+    BigEndian<T>: IntoByteArray,
+{
+    arr.map(BigEndian::new).into_byte_array()
+}
+
+/// Serializes an array of primitives in little-endian order without wrapping each element.
+///
+/// See [`to_be_array`] for the shape of the result.
+#[inline]
+pub fn to_le_array<T: EndianConvert, const N: usize>(
+    arr: [T; N],
+) -> <[LittleEndian<T>; N] as IntoByteArray>::ByteArray
+where
+    LittleEndian<T>: IntoByteArray,
+{
+    arr.map(LittleEndian::new).into_byte_array()
+}
+
+/// Decodes an array of primitives from big-endian bytes produced by [`to_be_array`].
+#[inline]
+pub fn from_be_array<T: EndianConvert, const N: usize>(
+    bytes: <[BigEndian<T>; N] as IntoByteArray>::ByteArray,
+) -> [T; N]
+where
+    BigEndian<T>: FromByteArray,
+{
+    bytes.map(|el| BigEndian::<T>::from_byte_array(el).get())
+}
+
+/// Decodes an array of primitives from little-endian bytes produced by [`to_le_array`].
+#[inline]
+pub fn from_le_array<T: EndianConvert, const N: usize>(
+    bytes: <[LittleEndian<T>; N] as IntoByteArray>::ByteArray,
+) -> [T; N]
+where
+    LittleEndian<T>: FromByteArray,
+{
+    bytes.map(|el| LittleEndian::<T>::from_byte_array(el).get())
+}
+
 /// Provides typed little-endian and big-endian representations for a type.
 ///
 /// This trait allows the derive macro to express per-field endian constraints at the type
@@ -587,8 +1943,9 @@ impl_from_endian_for_primitive!(u16, u32, u64, u128, i16, i32, i64, i128, f32, f
 /// generated code to call `u32::to_little_endian()` and store a `<u32 as HasEndianRepr>::LE`
 /// (i.e. `LittleEndian<u32>`) in the raw struct.
 ///
-/// Implemented for all [`EndianConvert`] types (primitives and floats), and by the
-/// `ordered-float` feature for `OrderedFloat<T>` and `NotNan<T>`.
+/// Implemented for all [`EndianConvert`] types (primitives and floats), for `u8`/`i8` (where
+/// both representations are the value itself), for arrays of any of these (converted element
+/// by element), and by the `ordered-float` feature for `OrderedFloat<T>` and `NotNan<T>`.
 pub trait HasEndianRepr: Sized {
     /// The little-endian representation type (e.g. `LittleEndian<u32>` for `u32`).
     type LE: PlainOldData;
@@ -677,3 +2034,112 @@ impl<T: EndianConvert> FromEndianRepr for T {
         be.get()
     }
 }
+
+macro_rules! impl_endian_repr_single_byte {
+    ($($type:ty),+) => {
+        $(
+            impl HasEndianRepr for $type {
+                type LE = Self;
+                type BE = Self;
+
+                fn to_little_endian(self) -> Self::LE {
+                    self
+                }
+
+                fn to_big_endian(self) -> Self::BE {
+                    self
+                }
+            }
+
+            impl FromEndianRepr for $type {
+                fn from_little_endian(le: Self::LE) -> Self {
+                    le
+                }
+
+                fn from_big_endian(be: Self::BE) -> Self {
+                    be
+                }
+            }
+        )+
+    };
+}
+
+// Byte order has no effect on single-byte values; these impls let a struct-level
+// `#[byteable(big_endian)]` / `#[byteable(little_endian)]` apply to `u8`/`i8` fields as-is.
+impl_endian_repr_single_byte!(u8, i8);
+
+// Arrays convert element by element, so `#[byteable(little_endian)] samples: [u16; N]` stores
+// `[LittleEndian<u16>; N]` without spelling out the wrapper on every element type.
+impl<T: HasEndianRepr, const N: usize> HasEndianRepr for [T; N] {
+    type LE = [T::LE; N];
+    type BE = [T::BE; N];
+
+    fn to_little_endian(self) -> Self::LE {
+        self.map(T::to_little_endian)
+    }
+
+    fn to_big_endian(self) -> Self::BE {
+        self.map(T::to_big_endian)
+    }
+}
+
+impl<T: FromEndianRepr, const N: usize> FromEndianRepr for [T; N] {
+    fn from_little_endian(le: Self::LE) -> Self {
+        le.map(T::from_little_endian)
+    }
+
+    fn from_big_endian(be: Self::BE) -> Self {
+        be.map(T::from_big_endian)
+    }
+}
+
+// Support for `#[byteable(with = path)]`. The raw struct stores the converted value as a byte
+// array, since the type returned by `path::to_raw` cannot be named from the path alone; these
+// helpers recover its size and move it in and out of the array.
+
+/// The size of the value returned by a `#[byteable(with = path)]` conversion function.
+#[doc(hidden)]
+pub const fn __with_raw_size<T, R: PlainOldData>(_to_raw: fn(T) -> R) -> usize {
+    core::mem::size_of::<R>()
+}
+
+/// Copies the result of `path::to_raw` into the field's byte array.
+#[doc(hidden)]
+#[inline]
+pub fn __with_to_bytes<R: PlainOldData, const N: usize>(raw: R) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    bytes.copy_from_slice(raw.as_bytes());
+    bytes
+}
+
+/// Reads the value passed to `path::from_raw` back out of the field's byte array.
+#[doc(hidden)]
+#[inline]
+pub fn __with_from_bytes<R: PlainOldData, const N: usize>(bytes: [u8; N]) -> R {
+    let mut raw = R::zeroed();
+    raw.as_bytes_mut().copy_from_slice(&bytes);
+    raw
+}
+
+// Support for generic derived structs. The size of their raw struct depends on the type
+// parameters, so its byte array is a `ByteConcat` chain of the fields' byte arrays rather than
+// a `[u8; N]`; these helpers move the packed raw struct in and out of that chain.
+
+/// Copies the raw form of a generic derived struct into its byte array.
+#[doc(hidden)]
+#[inline]
+pub fn __packed_to_byte_array<R: PlainOldData, B: ByteArray>(raw: R) -> B {
+    const { assert!(core::mem::size_of::<R>() == core::mem::size_of::<B>()) };
+    // SAFETY: both types are the same size, `R` has no padding, and a `ByteArray` accepts
+    // every bit pattern.
+    unsafe { core::mem::transmute_copy(&raw) }
+}
+
+/// Reads the raw form of a generic derived struct back out of its byte array.
+#[doc(hidden)]
+#[inline]
+pub fn __byte_array_to_packed<B: ByteArray, R: PlainOldData>(bytes: B) -> R {
+    const { assert!(core::mem::size_of::<R>() == core::mem::size_of::<B>()) };
+    // SAFETY: both types are the same size, and `R` accepts every bit pattern.
+    unsafe { core::mem::transmute_copy(&bytes) }
+}
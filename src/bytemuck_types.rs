@@ -0,0 +1,99 @@
+//! Interop with [`bytemuck`] (requires the `bytemuck` feature).
+//!
+//! ## `PlainOldData` and `Pod`
+//!
+//! [`PlainOldData`] and [`bytemuck::Pod`] describe the same property: no padding bytes and
+//! every bit pattern valid. `Pod` additionally requires `'static` and a `repr(C)` or
+//! `repr(transparent)` layout, which all of this crate's `PlainOldData` types already have.
+//! Coherence rules out a blanket impl between the two traits, so this module provides:
+//!
+//! - `Pod` / `Zeroable` for [`BigEndian<T>`] and [`LittleEndian<T>`] when `T: Pod`.
+//! - [`as_pod_slice`] and [`try_cast_slice`], which cast slices of any `PlainOldData` type.
+//! - The derive option `#[byteable(bytemuck)]`, which implements `Pod` / `Zeroable` for the
+//!   generated raw struct so `<T as RawRepr>::Raw` can be used with `bytemuck::cast_slice`.
+//!
+//! ```
+//! # #[cfg(feature = "derive")] {
+//! use byteable::{Byteable, RawRepr};
+//!
+//! #[derive(Byteable)]
+//! #[byteable(bytemuck)]
+//! struct Sample {
+//!     #[byteable(big_endian)]
+//!     id: u16,
+//!     value: u8,
+//! }
+//!
+//! let raws = [Sample { id: 1, value: 2 }.to_raw(), Sample { id: 3, value: 4 }.to_raw()];
+//! assert_eq!(bytemuck::cast_slice::<_, u8>(&raws), &[0, 1, 2, 0, 3, 4]);
+//! # }
+//! ```
+
+use crate::{BigEndian, EndianConvert, LittleEndian, PlainOldData};
+use bytemuck::{Pod, PodCastError, Zeroable};
+
+// SAFETY: both wrappers are `repr(transparent)` over `T`, holding its bytes in swapped order.
+// Any bit pattern of a `Pod` `T` is valid, so the wrappers inherit both guarantees.
+unsafe impl<T: EndianConvert + Zeroable> Zeroable for BigEndian<T> {}
+unsafe impl<T: EndianConvert + Pod> Pod for BigEndian<T> {}
+unsafe impl<T: EndianConvert + Zeroable> Zeroable for LittleEndian<T> {}
+unsafe impl<T: EndianConvert + Pod> Pod for LittleEndian<T> {}
+
+/// Reinterprets a slice of [`PlainOldData`] values as its underlying bytes.
+///
+/// The returned slice has length `items.len() * T::BYTE_SIZE`.
+///
+/// ```
+/// use byteable::{LittleEndian, bytemuck_types::as_pod_slice};
+///
+/// let values = [LittleEndian::new(1u16), LittleEndian::new(0x0203)];
+/// assert_eq!(as_pod_slice(&values), &[1, 0, 3, 2]);
+/// ```
+#[inline]
+pub fn as_pod_slice<T: PlainOldData>(items: &[T]) -> &[u8] {
+    // SAFETY: PlainOldData guarantees T has no padding, so every byte of the slice is
+    // initialized. `u8` has alignment 1 and the length covers exactly the input.
+    unsafe {
+        core::slice::from_raw_parts(items.as_ptr() as *const u8, core::mem::size_of_val(items))
+    }
+}
+
+/// Reinterprets a byte slice as a slice of [`PlainOldData`] values without copying.
+///
+/// # Errors
+///
+/// Mirrors [`bytemuck::try_cast_slice`]:
+///
+/// - [`PodCastError::TargetAlignmentGreaterAndInputNotAligned`] if `bytes` is not aligned
+///   for `T`.
+/// - [`PodCastError::OutputSliceWouldHaveSlop`] if `bytes.len()` is not a multiple of
+///   `T::BYTE_SIZE`.
+/// - [`PodCastError::SizeMismatch`] if `T` is zero-sized and `bytes` is not empty.
+///
+/// ```
+/// use byteable::{BigEndian, bytemuck_types::try_cast_slice};
+///
+/// let values: &[BigEndian<u16>] = try_cast_slice(&[0, 1, 0, 2]).unwrap();
+/// assert_eq!(values[0].get(), 1);
+/// assert_eq!(values[1].get(), 2);
+/// ```
+#[inline]
+pub fn try_cast_slice<T: PlainOldData>(bytes: &[u8]) -> Result<&[T], PodCastError> {
+    let size = core::mem::size_of::<T>();
+    if size == 0 {
+        return if bytes.is_empty() {
+            Ok(&[])
+        } else {
+            Err(PodCastError::SizeMismatch)
+        };
+    }
+    if !(bytes.as_ptr() as usize).is_multiple_of(core::mem::align_of::<T>()) {
+        return Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned);
+    }
+    if !bytes.len().is_multiple_of(size) {
+        return Err(PodCastError::OutputSliceWouldHaveSlop);
+    }
+    // SAFETY: the pointer is aligned for T, the length is an exact multiple of T's size, and
+    // PlainOldData guarantees every bit pattern is a valid T.
+    Ok(unsafe { core::slice::from_raw_parts(bytes.as_ptr() as *const T, bytes.len() / size) })
+}
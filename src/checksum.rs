@@ -0,0 +1,50 @@
+//! CRC-32 checksums, as used by `#[byteable(crc32)]`.
+//!
+//! The functions compute the reflected CRC-32 family used by Ethernet, zlib and PNG: initial
+//! value `0xFFFF_FFFF`, input and output reflected, final XOR `0xFFFF_FFFF`. Only the
+//! generator polynomial varies; pass it in the usual (non-reflected) notation.
+
+/// Generator polynomial of CRC-32/ISO-HDLC ("IEEE"), used by Ethernet, zlib and PNG.
+pub const CRC32_IEEE: u32 = 0x04C1_1DB7;
+
+/// Generator polynomial of CRC-32C (Castagnoli), used by iSCSI, SCTP and ext4.
+pub const CRC32_CASTAGNOLI: u32 = 0x1EDC_6F41;
+
+/// Computes the IEEE CRC-32 of `bytes`.
+///
+/// ```
+/// use byteable::checksum::crc32;
+///
+/// assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+/// ```
+#[inline]
+pub const fn crc32(bytes: &[u8]) -> u32 {
+    crc32_with_polynomial(CRC32_IEEE, bytes)
+}
+
+/// Computes a CRC-32 of `bytes` with the given generator polynomial.
+///
+/// ```
+/// use byteable::checksum::{CRC32_CASTAGNOLI, crc32_with_polynomial};
+///
+/// assert_eq!(crc32_with_polynomial(CRC32_CASTAGNOLI, b"123456789"), 0xE306_9283);
+/// ```
+pub const fn crc32_with_polynomial(polynomial: u32, bytes: &[u8]) -> u32 {
+    let reflected = polynomial.reverse_bits();
+    let mut crc = 0xFFFF_FFFF_u32;
+    let mut i = 0;
+    while i < bytes.len() {
+        crc ^= bytes[i] as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ reflected
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        i += 1;
+    }
+    !crc
+}
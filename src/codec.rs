@@ -0,0 +1,150 @@
+//! Length-prefixed framing for `tokio_util::codec`.
+//!
+//! This module is only available when the `tokio-util` feature is enabled. It provides
+//! [`ByteableCodec`], a [`Decoder`] / [`Encoder`] pair that turns a byte stream into a stream
+//! of [`Readable`] / [`Writable`] values, so it can be plugged straight into
+//! [`tokio_util::codec::Framed`], `FramedRead` or `FramedWrite`.
+//!
+//! Each frame is a length prefix of type `L` (any [`LengthPrefix`], big-endian `u32` by
+//! default) counting the bytes of the body that follows, then the body itself.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use byteable::{BigEndian, ByteableCodec};
+//! use tokio_util::bytes::BytesMut;
+//! use tokio_util::codec::{Decoder, Encoder};
+//!
+//! let mut codec = ByteableCodec::<String, BigEndian<u16>>::new(1024);
+//!
+//! let mut buf = BytesMut::new();
+//! codec.encode("hi".to_string(), &mut buf).unwrap();
+//! assert_eq!(&buf[..2], &[0, 10]); // 8-byte string length + 2 bytes of text
+//!
+//! assert_eq!(codec.decode(&mut buf).unwrap().as_deref(), Some("hi"));
+//! assert!(buf.is_empty());
+//! ```
+
+use std::io;
+use std::marker::PhantomData;
+
+use tokio_util::bytes::{Buf, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::{BigEndian, LengthPrefix, Readable, Writable};
+
+/// A [`Decoder`] / [`Encoder`] for frames of a length prefix `L` followed by an encoded `T`.
+///
+/// Frames whose body is longer than the `max_frame_len` passed to
+/// [`new`](ByteableCodec::new) are rejected with [`io::ErrorKind::InvalidData`], both when
+/// decoding (before any of the body is buffered) and when encoding.
+pub struct ByteableCodec<T, L = BigEndian<u32>> {
+    max_frame_len: usize,
+    _marker: PhantomData<fn() -> (T, L)>,
+}
+
+impl<T, L> ByteableCodec<T, L> {
+    /// Creates a codec that accepts frame bodies of at most `max_frame_len` bytes.
+    pub fn new(max_frame_len: usize) -> Self {
+        Self {
+            max_frame_len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The largest frame body this codec accepts, in bytes.
+    pub fn max_frame_len(&self) -> usize {
+        self.max_frame_len
+    }
+
+    fn check_len(&self, len: usize) -> io::Result<()> {
+        if len > self.max_frame_len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "frame of {len} bytes exceeds the maximum of {} bytes",
+                    self.max_frame_len
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<T, L> Clone for ByteableCodec<T, L> {
+    fn clone(&self) -> Self {
+        Self::new(self.max_frame_len)
+    }
+}
+
+impl<T, L> core::fmt::Debug for ByteableCodec<T, L> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ByteableCodec")
+            .field("max_frame_len", &self.max_frame_len)
+            .finish()
+    }
+}
+
+impl<T: Readable, L: LengthPrefix> Decoder for ByteableCodec<T, L> {
+    type Item = T;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> io::Result<Option<T>> {
+        let mut rest = &src[..];
+        let prefix = match L::read_fixed_from(&mut rest) {
+            Ok(prefix) => prefix,
+            Err(crate::ReadableError::Io(e)) if e.kind() == io::ErrorKind::UnexpectedEof => {
+                return Ok(None);
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let header_len = src.len() - rest.len();
+        let len = prefix.to_len().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "frame length does not fit in usize",
+            )
+        })?;
+        self.check_len(len)?;
+
+        if rest.len() < len {
+            src.reserve(len - rest.len());
+            return Ok(None);
+        }
+
+        src.advance(header_len);
+        let frame = src.split_to(len);
+        let mut body = &frame[..];
+        let value = T::read_from(&mut body)?;
+        if !body.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{} trailing bytes after the decoded frame", body.len()),
+            ));
+        }
+        Ok(Some(value))
+    }
+}
+
+impl<T: Writable, L: LengthPrefix> Encoder<T> for ByteableCodec<T, L> {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: T, dst: &mut BytesMut) -> io::Result<()> {
+        let mut body = Vec::new();
+        item.write_to(&mut body)?;
+        self.check_len(body.len())?;
+        let prefix = L::from_len(body.len()).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "frame length does not fit in the length prefix",
+            )
+        })?;
+
+        let mut header = Vec::new();
+        prefix.write_fixed_to(&mut header)?;
+        dst.reserve(header.len() + body.len());
+        dst.extend_from_slice(&header);
+        dst.extend_from_slice(&body);
+        Ok(())
+    }
+}
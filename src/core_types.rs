@@ -3,28 +3,47 @@
 //!
 //! Covered types: `u8`/`i8` (identity repr), multi-byte integers and floats (little-endian
 //! by default), `bool` (1 byte, 0 or 1), `char` (4-byte little-endian Unicode scalar),
-//! [`PhantomData<T>`](core::marker::PhantomData) (0 bytes),
-//! [`NonZero<T>`](core::num::NonZero), network address types
+//! `()` and [`PhantomData<T>`](core::marker::PhantomData) (0 bytes),
+//! [`NonZero<T>`](core::num::NonZero), [`Wrapping<T>`](core::num::Wrapping) and
+//! [`Saturating<T>`](core::num::Saturating) (same bytes as the integer), network address types
 //! (`Ipv4Addr`, `Ipv6Addr`, `SocketAddrV4`, `SocketAddrV6`), all range variants, and
 //! [`Duration`](core::time::Duration) /
 //! [`SystemTime`](std::time::SystemTime) (`std` feature only).
 //!
+//! ## Socket address wire format
+//!
+//! `SocketAddrV4` is the 4 address octets followed by the `u16` port (6 bytes);
+//! `SocketAddrV6` is the 16 address octets, the port, then the `u32` flowinfo and scope ID
+//! (26 bytes). The octets are always in network order. The numeric fields are little-endian
+//! in the raw form, like every other multi-byte default; mark a field
+//! `#[byteable(big_endian)]` for the network byte order most protocols expect.
+//!
 //! ## `SystemTime` wire format
 //!
 //! `SystemTime` is encoded as a **signed** `i64` seconds offset from the Unix epoch
 //! followed by a `u32` sub-second nanoseconds field (always in `[0, 999_999_999]`).
 //! Negative seconds represent times before 1970-01-01 00:00:00 UTC, following the
 //! standard POSIX `timespec` convention.
+//!
+//! ## `Duration` wire format
+//!
+//! `Duration` is encoded as a `u64` seconds field followed by a `u32` sub-second nanoseconds
+//! field, 12 bytes in total. The raw form is little-endian; the [`HasEndianRepr`] /
+//! [`TryFromEndianRepr`] impls add a big-endian form. Decoding is fallible: a nanoseconds
+//! field of `1_000_000_000` or more is rejected with [`DecodeError::InvalidNanos`] instead of
+//! being carried into the seconds, so a derived struct needs `#[byteable(try_transparent)]`
+//! on a `Duration` field.
 
 use crate::{
-    DecodeError, FromByteArray, FromRawRepr, IntoByteArray, LittleEndian, PlainOldData, RawRepr,
-    TryFromByteArray, TryFromRawRepr, impl_byte_array,
+    BigEndian, DecodeError, EndianConvert, FromByteArray, FromEndianRepr, FromRawRepr,
+    HasEndianRepr, IntoByteArray, LittleEndian, PlainOldData, RawRepr, TryFromByteArray,
+    TryFromEndianRepr, TryFromRawRepr, impl_byte_array,
 };
 use core::{
     marker::PhantomData,
     net::Ipv4Addr,
     net::{Ipv6Addr, SocketAddrV4, SocketAddrV6},
-    num::NonZero,
+    num::{NonZero, Saturating, Wrapping},
     ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
     time::Duration,
 };
@@ -314,6 +333,36 @@ impl<T> FromByteArray for PhantomData<T> {
     }
 }
 
+impl RawRepr for () {
+    type Raw = UnitStructRaw;
+
+    fn to_raw(&self) -> Self::Raw {
+        UnitStructRaw
+    }
+}
+
+impl FromRawRepr for () {
+    fn from_raw(_: Self::Raw) -> Self {}
+}
+
+impl TryFromRawRepr for () {
+    fn try_from_raw(_: Self::Raw) -> Result<Self, DecodeError> {
+        Ok(())
+    }
+}
+
+impl IntoByteArray for () {
+    type ByteArray = [u8; 0];
+
+    fn into_byte_array(&self) -> Self::ByteArray {
+        []
+    }
+}
+
+impl FromByteArray for () {
+    fn from_byte_array(_: Self::ByteArray) -> Self {}
+}
+
 macro_rules! impl_nonzero {
     ($($type:ty),+) => {
         $(
@@ -338,6 +387,115 @@ macro_rules! impl_nonzero {
 
 impl_nonzero!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
 
+// `Wrapping<T>` and `Saturating<T>` are `repr(transparent)`, so they serialize exactly like
+// the integer they wrap and can stand in for it in existing formats.
+macro_rules! impl_transparent_num_wrapper {
+    ($wrapper:ident) => {
+        // SAFETY: `repr(transparent)` over `T`, which is itself `PlainOldData`.
+        unsafe impl<T: PlainOldData> PlainOldData for $wrapper<T> {}
+
+        // SAFETY: delegates to `T`, whose conversions round-trip.
+        unsafe impl<T: EndianConvert> EndianConvert for $wrapper<T> {
+            #[inline]
+            fn from_le(value: Self) -> Self {
+                $wrapper(T::from_le(value.0))
+            }
+
+            #[inline]
+            fn from_be(value: Self) -> Self {
+                $wrapper(T::from_be(value.0))
+            }
+
+            #[inline]
+            fn to_le(self) -> Self {
+                $wrapper(self.0.to_le())
+            }
+
+            #[inline]
+            fn to_be(self) -> Self {
+                $wrapper(self.0.to_be())
+            }
+        }
+    };
+    ($wrapper:ident: $($type:ty),+) => {
+        $(
+            impl RawRepr for $wrapper<$type> {
+                type Raw = <$type as RawRepr>::Raw;
+
+                fn to_raw(&self) -> Self::Raw {
+                    self.0.to_raw()
+                }
+            }
+
+            impl FromRawRepr for $wrapper<$type> {
+                fn from_raw(raw: Self::Raw) -> Self {
+                    $wrapper(<$type>::from_raw(raw))
+                }
+            }
+
+            impl_try_from_rawrepr!($wrapper<$type>);
+            impl_byte_array_via_raw!($wrapper<$type>);
+        )+
+    };
+}
+
+impl_transparent_num_wrapper!(Wrapping);
+impl_transparent_num_wrapper!(Saturating);
+impl_transparent_num_wrapper!(Wrapping: u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+impl_transparent_num_wrapper!(Saturating: u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+macro_rules! impl_byte_array_endian_num_wrapper {
+    ($($type:ty),+) => {
+        $(
+            impl_byte_array!(
+                LittleEndian<Wrapping<$type>>,
+                BigEndian<Wrapping<$type>>,
+                LittleEndian<Saturating<$type>>,
+                BigEndian<Saturating<$type>>
+            );
+        )+
+    };
+}
+
+impl_byte_array_endian_num_wrapper!(u16, u32, u64, u128, i16, i32, i64, i128);
+
+// Byte order has no effect on single-byte values, as for `u8`/`i8` themselves.
+macro_rules! impl_endian_repr_single_byte_num_wrapper {
+    ($($wrapper:ident<$type:ty>),+) => {
+        $(
+            impl HasEndianRepr for $wrapper<$type> {
+                type LE = $type;
+                type BE = $type;
+
+                fn to_little_endian(self) -> Self::LE {
+                    self.0
+                }
+
+                fn to_big_endian(self) -> Self::BE {
+                    self.0
+                }
+            }
+
+            impl FromEndianRepr for $wrapper<$type> {
+                fn from_little_endian(le: Self::LE) -> Self {
+                    $wrapper(le)
+                }
+
+                fn from_big_endian(be: Self::BE) -> Self {
+                    $wrapper(be)
+                }
+            }
+        )+
+    };
+}
+
+impl_endian_repr_single_byte_num_wrapper!(
+    Wrapping<u8>,
+    Wrapping<i8>,
+    Saturating<u8>,
+    Saturating<i8>
+);
+
 #[repr(C, packed)]
 #[derive(Clone, Copy)]
 #[doc(hidden)]
@@ -420,7 +578,7 @@ impl RawRepr for SocketAddrV4 {
 
 impl FromRawRepr for SocketAddrV4 {
     fn from_raw(raw: Self::Raw) -> Self {
-        Self::new(Ipv4Addr::from_raw(raw.ip), u16::from_raw(raw.port))
+        Self::from_little_endian(raw)
     }
 }
 
@@ -428,6 +586,45 @@ impl_try_from_rawrepr!(SocketAddrV4);
 
 impl_byte_array_via_raw!(SocketAddrV4);
 
+#[repr(C, packed)]
+#[derive(Clone, Copy)]
+#[doc(hidden)]
+pub struct SocketAddrV4BeRaw {
+    ip: <Ipv4Addr as RawRepr>::Raw,
+    port: BigEndian<u16>,
+}
+
+unsafe impl PlainOldData for SocketAddrV4BeRaw {}
+impl_byte_array!(SocketAddrV4BeRaw);
+
+// The address octets are always in network order; only the port follows the field's
+// endianness. `#[byteable(big_endian)]` gives the conventional all-network-order layout.
+impl HasEndianRepr for SocketAddrV4 {
+    type LE = SocketAddrV4Raw;
+    type BE = SocketAddrV4BeRaw;
+
+    fn to_little_endian(self) -> Self::LE {
+        self.to_raw()
+    }
+
+    fn to_big_endian(self) -> Self::BE {
+        SocketAddrV4BeRaw {
+            ip: self.ip().to_raw(),
+            port: BigEndian::new(self.port()),
+        }
+    }
+}
+
+impl FromEndianRepr for SocketAddrV4 {
+    fn from_little_endian(le: Self::LE) -> Self {
+        Self::new(Ipv4Addr::from_raw(le.ip), u16::from_raw(le.port))
+    }
+
+    fn from_big_endian(be: Self::BE) -> Self {
+        Self::new(Ipv4Addr::from_raw(be.ip), be.port.get())
+    }
+}
+
 #[repr(C, packed)]
 #[derive(Clone, Copy)]
 #[doc(hidden)]
@@ -456,17 +653,63 @@ impl RawRepr for SocketAddrV6 {
 
 impl FromRawRepr for SocketAddrV6 {
     fn from_raw(raw: Self::Raw) -> Self {
-        Self::new(
-            Ipv6Addr::from_raw(raw.ip),
-            u16::from_raw(raw.port),
-            u32::from_raw(raw.flowinfo),
-            u32::from_raw(raw.scope_id),
-        )
+        Self::from_little_endian(raw)
     }
 }
 
 impl_try_from_rawrepr!(SocketAddrV6);
 
+#[repr(C, packed)]
+#[derive(Clone, Copy)]
+#[doc(hidden)]
+pub struct SocketAddrV6BeRaw {
+    ip: <Ipv6Addr as RawRepr>::Raw,
+    port: BigEndian<u16>,
+    flowinfo: BigEndian<u32>,
+    scope_id: BigEndian<u32>,
+}
+
+unsafe impl PlainOldData for SocketAddrV6BeRaw {}
+impl_byte_array!(SocketAddrV6BeRaw);
+
+impl HasEndianRepr for SocketAddrV6 {
+    type LE = SocketAddrV6Raw;
+    type BE = SocketAddrV6BeRaw;
+
+    fn to_little_endian(self) -> Self::LE {
+        self.to_raw()
+    }
+
+    fn to_big_endian(self) -> Self::BE {
+        SocketAddrV6BeRaw {
+            ip: self.ip().to_raw(),
+            port: BigEndian::new(self.port()),
+            flowinfo: BigEndian::new(self.flowinfo()),
+            scope_id: BigEndian::new(self.scope_id()),
+        }
+    }
+}
+
+impl FromEndianRepr for SocketAddrV6 {
+    fn from_little_endian(le: Self::LE) -> Self {
+        Self::new(
+            Ipv6Addr::from_raw(le.ip),
+            u16::from_raw(le.port),
+            u32::from_raw(le.flowinfo),
+            u32::from_raw(le.scope_id),
+        )
+    }
+
+    fn from_big_endian(be: Self::BE) -> Self {
+        Self::new(
+            Ipv6Addr::from_raw(be.ip),
+            be.port.get(),
+            be.flowinfo.get(),
+            be.scope_id.get(),
+        )
+    }
+}
+
 impl_byte_array_via_raw!(SocketAddrV6);
 
 macro_rules! impl_range_byteable {
@@ -671,25 +914,70 @@ pub struct DurationRaw {
 unsafe impl PlainOldData for DurationRaw {}
 impl_byte_array!(DurationRaw);
 
+#[repr(C, packed)]
+#[derive(Clone, Copy)]
+#[doc(hidden)]
+pub struct DurationBeRaw {
+    secs: BigEndian<u64>,
+    nanos: BigEndian<u32>,
+}
+
+unsafe impl PlainOldData for DurationBeRaw {}
+impl_byte_array!(DurationBeRaw);
+
+/// Builds a `Duration`, rejecting a sub-second part that `Duration::new` would silently carry
+/// into the seconds (or overflow on).
+fn duration_from_parts(secs: u64, nanos: u32) -> Result<Duration, DecodeError> {
+    if nanos >= 1_000_000_000 {
+        return Err(DecodeError::InvalidNanos(nanos));
+    }
+    Ok(Duration::new(secs, nanos))
+}
+
 impl RawRepr for Duration {
     type Raw = DurationRaw;
 
     fn to_raw(&self) -> Self::Raw {
+        self.to_little_endian()
+    }
+}
+
+impl TryFromRawRepr for Duration {
+    fn try_from_raw(raw: Self::Raw) -> Result<Self, DecodeError> {
+        Self::try_from_little_endian(raw)
+    }
+}
+
+impl_try_byte_array_via_raw!(Duration);
+
+impl HasEndianRepr for Duration {
+    type LE = DurationRaw;
+    type BE = DurationBeRaw;
+
+    fn to_little_endian(self) -> Self::LE {
         DurationRaw {
             secs: self.as_secs().to_raw(),
             nanos: self.subsec_nanos().to_raw(),
         }
     }
-}
 
-impl FromRawRepr for Duration {
-    fn from_raw(raw: Self::Raw) -> Self {
-        Self::new(u64::from_raw(raw.secs), u32::from_raw(raw.nanos))
+    fn to_big_endian(self) -> Self::BE {
+        DurationBeRaw {
+            secs: BigEndian::new(self.as_secs()),
+            nanos: BigEndian::new(self.subsec_nanos()),
+        }
     }
 }
 
-impl_try_from_rawrepr!(Duration);
-impl_byte_array_via_raw!(Duration);
+impl TryFromEndianRepr for Duration {
+    fn try_from_little_endian(le: Self::LE) -> Result<Self, DecodeError> {
+        duration_from_parts(u64::from_raw(le.secs), u32::from_raw(le.nanos))
+    }
+
+    fn try_from_big_endian(be: Self::BE) -> Result<Self, DecodeError> {
+        duration_from_parts(be.secs.get(), be.nanos.get())
+    }
+}
 
 #[cfg(feature = "std")]
 #[repr(C, packed)]
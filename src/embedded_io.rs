@@ -0,0 +1,175 @@
+//! Blocking I/O traits for reading and writing byteable values via `embedded-io`.
+//!
+//! This module is only available when the `embedded-io` feature is enabled. It does not
+//! require `std`: the traits mirror the fixed-size half of the `io` module, but are built on
+//! [`embedded_io::Read`] / [`embedded_io::Write`], so they work with UARTs, flash drivers and
+//! any other `no_std` reader or writer implementing those traits.
+//!
+//! Errors keep the reader's or writer's own error type instead of converting to
+//! `std::io::Error`: reads return [`EmbeddedReadError`], writes return the writer's `Error`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use byteable::{BigEndian, EmbeddedReadFixed, EmbeddedWriteFixed, LittleEndian};
+//!
+//! let mut buf = [0u8; 6];
+//! let mut writer = &mut buf[..];
+//! writer.write_fixed_embedded(&BigEndian::new(0x0102_0304u32)).unwrap();
+//! writer.write_fixed_embedded(&LittleEndian::new(0x0506u16)).unwrap();
+//! assert_eq!(buf, [1, 2, 3, 4, 6, 5]);
+//!
+//! let mut reader = &buf[..];
+//! let value: BigEndian<u32> = reader.read_fixed_embedded().unwrap();
+//! assert_eq!(value.get(), 0x0102_0304);
+//! ```
+
+use core::fmt;
+
+use embedded_io::{Read, ReadExactError, Write};
+
+use crate::{DecodeError, PlainOldData, RawRepr, TryFromRawRepr};
+
+/// Error returned when reading a value from an [`embedded_io::Read`] source.
+///
+/// `E` is the reader's error type. This is the `no_std` counterpart of
+/// [`ReadableError`](crate::io::ReadableError) with the same two failure modes.
+#[derive(Debug)]
+pub enum EmbeddedReadError<E> {
+    /// The reader failed or hit end of input before the value was complete.
+    Io(ReadExactError<E>),
+    /// The bytes were read successfully but could not be decoded into the target type.
+    DecodeError(DecodeError),
+}
+
+impl<E: fmt::Debug> fmt::Display for EmbeddedReadError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EmbeddedReadError::Io(ReadExactError::UnexpectedEof) => {
+                f.write_str("unexpected end of input")
+            }
+            EmbeddedReadError::Io(ReadExactError::Other(error)) => {
+                write!(f, "read error: {error:?}")
+            }
+            EmbeddedReadError::DecodeError(decode_error) => decode_error.fmt(f),
+        }
+    }
+}
+
+impl<E: fmt::Debug> core::error::Error for EmbeddedReadError<E> {}
+
+impl<E> From<ReadExactError<E>> for EmbeddedReadError<E> {
+    #[inline]
+    fn from(value: ReadExactError<E>) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl<E> From<DecodeError> for EmbeddedReadError<E> {
+    #[inline]
+    fn from(value: DecodeError) -> Self {
+        Self::DecodeError(value)
+    }
+}
+
+/// `embedded-io` counterpart of [`crate::io::FixedReadable`].
+///
+/// Deserializes a fixed-size value by filling a zeroed raw buffer with `read_exact`, then
+/// validating and converting via [`TryFromRawRepr`].
+///
+/// A blanket impl covers all types that implement [`TryFromRawRepr`].
+///
+/// Prefer the extension method [`EmbeddedReadFixed::read_fixed_embedded`] over calling this
+/// trait directly.
+pub trait EmbeddedFixedReadable: Sized {
+    /// Read exactly `size_of::<Self::Raw>()` bytes from `reader` and decode them into `Self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmbeddedReadError::Io`] if the reader fails or runs out of input, or
+    /// [`EmbeddedReadError::DecodeError`] if the bytes do not encode a valid `Self`.
+    fn read_fixed_from<R: Read + ?Sized>(
+        reader: &mut R,
+    ) -> Result<Self, EmbeddedReadError<R::Error>>;
+}
+
+impl<T: TryFromRawRepr> EmbeddedFixedReadable for T {
+    #[inline]
+    fn read_fixed_from<R: Read + ?Sized>(
+        reader: &mut R,
+    ) -> Result<Self, EmbeddedReadError<R::Error>> {
+        let mut b = T::Raw::zeroed();
+        reader.read_exact(b.as_bytes_mut())?;
+        let r = T::try_from_raw(b)?;
+        Ok(r)
+    }
+}
+
+/// `embedded-io` counterpart of [`crate::io::FixedWritable`].
+///
+/// Serializes a fixed-size value by converting to a raw representation and calling
+/// `write_all`. A blanket impl covers all types that implement [`RawRepr`].
+///
+/// Prefer the extension method [`EmbeddedWriteFixed::write_fixed_embedded`] over calling
+/// this directly.
+pub trait EmbeddedFixedWritable {
+    /// Write the fixed-size byte representation of `self` to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the writer's error if writing fails.
+    fn write_fixed_to<W: Write + ?Sized>(&self, writer: &mut W) -> Result<(), W::Error>;
+}
+
+impl<T: RawRepr> EmbeddedFixedWritable for T {
+    #[inline]
+    fn write_fixed_to<W: Write + ?Sized>(&self, writer: &mut W) -> Result<(), W::Error> {
+        let raw = self.to_raw();
+        writer.write_all(raw.as_bytes())
+    }
+}
+
+/// Extension trait that adds [`read_fixed_embedded`](EmbeddedReadFixed::read_fixed_embedded)
+/// to any [`embedded_io::Read`] source.
+///
+/// Automatically implemented for all `T: embedded_io::Read`. Counterpart of
+/// [`crate::io::ReadFixed`]; the method is named apart from `read_fixed` because types such as
+/// `&[u8]` implement both `Read` traits and the two are re-exported side by side.
+pub trait EmbeddedReadFixed: Read {
+    /// Read an [`EmbeddedFixedReadable`] value from this reader.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EmbeddedReadError`] on read failure, end of input or decode error.
+    #[inline]
+    fn read_fixed_embedded<T: EmbeddedFixedReadable>(
+        &mut self,
+    ) -> Result<T, EmbeddedReadError<Self::Error>> {
+        T::read_fixed_from(self)
+    }
+}
+
+impl<T: Read + ?Sized> EmbeddedReadFixed for T {}
+
+/// Extension trait that adds
+/// [`write_fixed_embedded`](EmbeddedWriteFixed::write_fixed_embedded) to any
+/// [`embedded_io::Write`] sink.
+///
+/// Automatically implemented for all `T: embedded_io::Write`. Counterpart of
+/// [`crate::io::WriteFixed`].
+pub trait EmbeddedWriteFixed: Write {
+    /// Write an [`EmbeddedFixedWritable`] value to this writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns the writer's error if writing fails.
+    #[inline]
+    fn write_fixed_embedded(
+        &mut self,
+        val: &impl EmbeddedFixedWritable,
+    ) -> Result<(), Self::Error> {
+        val.write_fixed_to(self)
+    }
+}
+
+impl<T: Write + ?Sized> EmbeddedWriteFixed for T {}
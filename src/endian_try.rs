@@ -0,0 +1,179 @@
+//! [`BigEndianTry<T>`] / [`LittleEndianTry<T>`]: byte-order wrappers for types such as `bool`
+//! and `char` that are stored as an integer but reject some of its values.
+
+use crate::{
+    DecodeError, FromByteArray, FromEndianRepr, HasEndianRepr, IntoByteArray, RawRepr,
+    TryFromByteArray, TryFromRawRepr,
+};
+
+/// A type stored as an integer in a chosen byte order, where not every integer is a valid
+/// value.
+///
+/// This is the fallible counterpart of [`EndianConvert`](crate::EndianConvert): `char` is a
+/// `u32` on the wire, but a surrogate or out-of-range `u32` is not a `char`, so it cannot be
+/// [`PlainOldData`](crate::PlainOldData) or sit in a [`BigEndian`](crate::BigEndian). Wrap it in
+/// [`BigEndianTry`] or [`LittleEndianTry`] instead; decoding those validates the integer.
+///
+/// Implemented for `bool` (stored as a `u8`, `0` or `1`) and `char` (stored as a `u32`
+/// Unicode scalar value).
+pub trait TryEndianConvert: Copy {
+    /// The integer the value is stored as.
+    type Bits: FromEndianRepr;
+
+    /// Returns the integer that stores `self`.
+    fn to_bits(self) -> Self::Bits;
+
+    /// Converts a stored integer back to a value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DecodeError`] if `bits` does not encode a valid `Self`.
+    fn try_from_bits(bits: Self::Bits) -> Result<Self, DecodeError>;
+}
+
+impl TryEndianConvert for bool {
+    type Bits = u8;
+
+    #[inline]
+    fn to_bits(self) -> u8 {
+        self.to_raw()
+    }
+
+    #[inline]
+    fn try_from_bits(bits: u8) -> Result<Self, DecodeError> {
+        Self::try_from_raw(bits)
+    }
+}
+
+impl TryEndianConvert for char {
+    type Bits = u32;
+
+    #[inline]
+    fn to_bits(self) -> u32 {
+        self as u32
+    }
+
+    #[inline]
+    fn try_from_bits(bits: u32) -> Result<Self, DecodeError> {
+        char::from_u32(bits).ok_or(DecodeError::InvalidChar(bits))
+    }
+}
+
+/// Stores a [`TryEndianConvert`] value in **big-endian** byte order.
+///
+/// Encoding is infallible; decoding goes through [`TryFromByteArray`] /
+/// [`TryFromRawRepr`] and rejects bytes that do not encode a valid `T`. The wrapper itself
+/// always holds a valid value, so [`get`](BigEndianTry::get) cannot fail.
+///
+/// Like any field whose decoding can fail, a `BigEndianTry<T>` field of a derived struct is
+/// marked `#[byteable(try_transparent)]`; the byte order is part of the type.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteable::{BigEndianTry, Byteable, DecodeError, IntoByteArray, TryFromByteArray};
+///
+/// let c = BigEndianTry::new('é');
+/// assert_eq!(c.into_byte_array(), [0x00, 0x00, 0x00, 0xE9]);
+/// assert_eq!(BigEndianTry::<char>::try_from_byte_array([0, 0, 0, 0xE9]), Ok(c));
+/// assert_eq!(
+///     BigEndianTry::<char>::try_from_byte_array([0, 0, 0xD8, 0]),
+///     Err(DecodeError::InvalidChar(0xD800))
+/// );
+///
+/// #[derive(Byteable, Debug, PartialEq)]
+/// struct Key {
+///     #[byteable(try_transparent)]
+///     code: BigEndianTry<char>,
+///     #[byteable(try_transparent)]
+///     pressed: BigEndianTry<bool>,
+/// }
+///
+/// let key = Key::try_from_byte_array([0, 0, 0, b'q', 1]).unwrap();
+/// assert_eq!(key.code.get(), 'q');
+/// assert!(key.pressed.get());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct BigEndianTry<T: TryEndianConvert>(T);
+
+/// Stores a [`TryEndianConvert`] value in **little-endian** byte order.
+///
+/// See [`BigEndianTry`]; this is the same wrapper with the opposite byte order.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteable::{IntoByteArray, LittleEndianTry, TryFromByteArray};
+///
+/// let c = LittleEndianTry::new('é');
+/// assert_eq!(c.into_byte_array(), [0xE9, 0x00, 0x00, 0x00]);
+/// assert_eq!(LittleEndianTry::<char>::try_from_byte_array([0xE9, 0, 0, 0]), Ok(c));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct LittleEndianTry<T: TryEndianConvert>(T);
+
+macro_rules! impl_endian_try_wrapper {
+    ($name:ident, $repr:ident, $to_fn:ident, $from_fn:ident) => {
+        impl<T: TryEndianConvert> $name<T> {
+            /// Wraps `value`.
+            #[inline]
+            pub const fn new(value: T) -> Self {
+                Self(value)
+            }
+
+            /// Returns the wrapped value.
+            #[inline]
+            pub const fn get(self) -> T {
+                self.0
+            }
+        }
+
+        impl<T: TryEndianConvert> From<T> for $name<T> {
+            #[inline]
+            fn from(value: T) -> Self {
+                Self(value)
+            }
+        }
+
+        impl<T: TryEndianConvert> RawRepr for $name<T> {
+            type Raw = <T::Bits as HasEndianRepr>::$repr;
+
+            #[inline]
+            fn to_raw(&self) -> Self::Raw {
+                self.0.to_bits().$to_fn()
+            }
+        }
+
+        impl<T: TryEndianConvert> TryFromRawRepr for $name<T> {
+            #[inline]
+            fn try_from_raw(raw: Self::Raw) -> Result<Self, DecodeError> {
+                T::try_from_bits(T::Bits::$from_fn(raw)).map(Self)
+            }
+        }
+
+        impl<T: TryEndianConvert> IntoByteArray for $name<T>
+        where
+            <T::Bits as HasEndianRepr>::$repr: IntoByteArray,
+        {
+            type ByteArray = <<T::Bits as HasEndianRepr>::$repr as IntoByteArray>::ByteArray;
+
+            #[inline]
+            fn into_byte_array(&self) -> Self::ByteArray {
+                self.to_raw().into_byte_array()
+            }
+        }
+
+        impl<T: TryEndianConvert> TryFromByteArray for $name<T>
+        where
+            <T::Bits as HasEndianRepr>::$repr: FromByteArray,
+        {
+            #[inline]
+            fn try_from_byte_array(byte_array: Self::ByteArray) -> Result<Self, DecodeError> {
+                Self::try_from_raw(FromByteArray::from_byte_array(byte_array))
+            }
+        }
+    };
+}
+
+impl_endian_try_wrapper!(BigEndianTry, BE, to_big_endian, from_big_endian);
+impl_endian_try_wrapper!(LittleEndianTry, LE, to_little_endian, from_little_endian);
@@ -0,0 +1,178 @@
+//! [`FixedAscii<N>`]: a fixed-width, NUL-padded string field that only holds ASCII.
+
+use crate::fixed_str::{pad_nul, trim_nul};
+use crate::{DecodeError, IntoByteArray, RawRepr, TryFromByteArray, TryFromRawRepr};
+
+/// An ASCII string stored in exactly `N` bytes, padded with NULs.
+///
+/// Like [`FixedStr<N>`](crate::FixedStr), the string ends at the first NUL byte, or fills all
+/// `N` bytes if there is none. Unlike `FixedStr`, every one of the `N` bytes is guaranteed to
+/// be ASCII (below `0x80`): decoding fails with [`DecodeError::InvalidAscii`] otherwise. This
+/// suits protocols that mandate ASCII identifiers, where a high byte means the frame is
+/// corrupt rather than Latin-1 text.
+///
+/// Encoding is infallible; decoding goes through [`TryFromByteArray`], so mark the field
+/// `#[byteable(try_transparent)]` in a derived struct. Because the contents are ASCII,
+/// [`as_str`](FixedAscii::as_str) always succeeds.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteable::{DecodeError, FixedAscii, IntoByteArray, TryFromByteArray};
+///
+/// let name = FixedAscii::<8>::try_from("eth0").unwrap();
+/// assert_eq!(name.as_str(), "eth0");
+/// assert_eq!(name.into_byte_array(), *b"eth0\0\0\0\0");
+///
+/// assert!(FixedAscii::<8>::try_from("café").is_err());
+/// assert_eq!(
+///     FixedAscii::<4>::try_from_byte_array([b'a', 0xE9, 0, 0]),
+///     Err(DecodeError::InvalidAscii { index: 1, value: 0xE9 })
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct FixedAscii<const N: usize>([u8; N]);
+
+impl<const N: usize> FixedAscii<N> {
+    /// Wraps `bytes` after checking that every byte is ASCII.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DecodeError::InvalidAscii`] naming the first byte that is not.
+    pub fn from_bytes(bytes: [u8; N]) -> Result<Self, DecodeError> {
+        match bytes.iter().position(|b| !b.is_ascii()) {
+            Some(index) => Err(DecodeError::InvalidAscii {
+                index,
+                value: bytes[index],
+            }),
+            None => Ok(Self(bytes)),
+        }
+    }
+
+    /// Returns the stored array, ASCII text followed by its NUL padding.
+    #[inline]
+    pub const fn as_array(&self) -> &[u8; N] {
+        &self.0
+    }
+
+    /// Returns the ASCII text without its NUL padding; [`as_str`](Self::as_str) as bytes.
+    pub fn trimmed_bytes(&self) -> &[u8] {
+        trim_nul(&self.0)
+    }
+
+    /// Returns the string up to the first NUL.
+    pub fn as_str(&self) -> &str {
+        let bytes = self.trimmed_bytes();
+        // SAFETY: every byte is ASCII (checked on construction), and ASCII is valid UTF-8.
+        unsafe { core::str::from_utf8_unchecked(bytes) }
+    }
+}
+
+impl<const N: usize> Default for FixedAscii<N> {
+    /// The empty string: `N` NUL bytes, which are ASCII.
+    #[inline]
+    fn default() -> Self {
+        Self([0; N])
+    }
+}
+
+impl<const N: usize> core::fmt::Display for FixedAscii<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<const N: usize> core::fmt::Debug for FixedAscii<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("FixedAscii").field(&self.as_str()).finish()
+    }
+}
+
+/// Error returned when a string cannot be stored in a [`FixedAscii<N>`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixedAsciiError {
+    /// The string is longer than `N` bytes.
+    TooLong {
+        /// The length of the string in bytes.
+        len: usize,
+        /// The capacity `N` of the target `FixedAscii`.
+        capacity: usize,
+    },
+    /// The string contains a character outside ASCII, starting at byte `index`.
+    NonAscii {
+        /// The byte offset of the first non-ASCII character.
+        index: usize,
+    },
+}
+
+impl core::fmt::Display for FixedAsciiError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FixedAsciiError::TooLong { len, capacity } => write!(
+                f,
+                "string of {len} bytes does not fit in a FixedAscii<{capacity}>"
+            ),
+            FixedAsciiError::NonAscii { index } => {
+                write!(
+                    f,
+                    "string is not ASCII: non-ASCII character at byte {index}"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for FixedAsciiError {}
+
+impl<const N: usize> TryFrom<&str> for FixedAscii<N> {
+    type Error = FixedAsciiError;
+
+    /// Checks that `value` is ASCII and at most `N` bytes long, then NUL-pads it.
+    ///
+    /// The non-ASCII check runs first, so a long non-ASCII string reports
+    /// [`NonAscii`](FixedAsciiError::NonAscii).
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if let Some(index) = value.bytes().position(|b| !b.is_ascii()) {
+            return Err(FixedAsciiError::NonAscii { index });
+        }
+        pad_nul(value.as_bytes())
+            .map(Self)
+            .ok_or(FixedAsciiError::TooLong {
+                len: value.len(),
+                capacity: N,
+            })
+    }
+}
+
+impl<const N: usize> IntoByteArray for FixedAscii<N> {
+    type ByteArray = [u8; N];
+
+    #[inline]
+    fn into_byte_array(&self) -> Self::ByteArray {
+        self.0
+    }
+}
+
+impl<const N: usize> TryFromByteArray for FixedAscii<N> {
+    #[inline]
+    fn try_from_byte_array(byte_array: Self::ByteArray) -> Result<Self, DecodeError> {
+        Self::from_bytes(byte_array)
+    }
+}
+
+impl<const N: usize> RawRepr for FixedAscii<N> {
+    type Raw = [u8; N];
+
+    #[inline]
+    fn to_raw(&self) -> Self::Raw {
+        self.0
+    }
+}
+
+impl<const N: usize> TryFromRawRepr for FixedAscii<N> {
+    #[inline]
+    fn try_from_raw(raw: Self::Raw) -> Result<Self, DecodeError> {
+        Self::from_bytes(raw)
+    }
+}
@@ -0,0 +1,136 @@
+//! [`FixedDecimal<SCALE>`]: a decimal number stored as a scaled little-endian `i64`.
+
+use crate::{
+    DecodeError, FromByteArray, FromRawRepr, IntoByteArray, LittleEndian, RawRepr, TryFromRawRepr,
+};
+
+/// A decimal number stored as an integer count of `10^-SCALE` units.
+///
+/// Financial and measurement formats often avoid floating point by storing scaled integers,
+/// e.g. an amount in cents for `SCALE = 2`. `FixedDecimal<SCALE>` serializes as its unit count,
+/// a little-endian `i64` (8 bytes), and can be used directly as a derived struct field.
+///
+/// [`Display`](core::fmt::Display) prints exactly `SCALE` decimal places.
+///
+/// `SCALE` may be at most 18, so that `10^SCALE` fits in an `i64`; larger scales fail to
+/// compile when the scale factor is used.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteable::{FixedDecimal, IntoByteArray};
+///
+/// let price = FixedDecimal::<2>::from_f64(12.345);
+/// assert_eq!(price.units(), 1235); // rounded half away from zero
+/// assert_eq!(price.to_string(), "12.35");
+/// assert_eq!(price.into_byte_array(), 1235i64.to_le_bytes());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct FixedDecimal<const SCALE: u32>(LittleEndian<i64>);
+
+impl<const SCALE: u32> FixedDecimal<SCALE> {
+    /// The number of units in `1`: `10^SCALE`.
+    pub const FACTOR: i64 = 10i64.pow(SCALE);
+
+    /// Creates a value from a count of `10^-SCALE` units (e.g. cents for `SCALE = 2`).
+    #[inline]
+    pub fn from_units(units: i64) -> Self {
+        Self(LittleEndian::new(units))
+    }
+
+    /// Returns the value as a count of `10^-SCALE` units.
+    #[inline]
+    pub fn units(self) -> i64 {
+        self.0.get()
+    }
+
+    /// Converts `value` to the nearest representable decimal, rounding halfway cases away
+    /// from zero.
+    ///
+    /// Values outside the `i64` range saturate, and NaN becomes zero.
+    pub fn from_f64(value: f64) -> Self {
+        Self::from_units(round_half_away_from_zero(value * Self::FACTOR as f64) as i64)
+    }
+
+    /// Returns the value as an `f64`. Large unit counts may lose precision.
+    pub fn to_f64(self) -> f64 {
+        self.units() as f64 / Self::FACTOR as f64
+    }
+}
+
+/// Rounds `value` to an integer, halfway cases away from zero. NaN and infinities are returned
+/// unchanged, for the saturating `as` cast that follows.
+///
+/// `core` has no `f64::round`, and adding `±0.5` before truncating is inexact: it rounds the
+/// largest `f64` below one half up, and odd integers above `2^52` to the next even one.
+pub(crate) fn round_half_away_from_zero(value: f64) -> f64 {
+    // Every `f64` of magnitude `2^52` or more is already an integer.
+    if value.is_nan() || value.abs() >= 4_503_599_627_370_496.0 {
+        return value;
+    }
+    // Both conversions are exact below `2^52`, and so is the subtraction.
+    let truncated = value as i64 as f64;
+    let fraction = value - truncated;
+    if fraction >= 0.5 {
+        truncated + 1.0
+    } else if fraction <= -0.5 {
+        truncated - 1.0
+    } else {
+        truncated
+    }
+}
+
+impl<const SCALE: u32> core::fmt::Display for FixedDecimal<SCALE> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let units = self.units();
+        let factor = Self::FACTOR.unsigned_abs();
+        let magnitude = units.unsigned_abs();
+        if units < 0 {
+            f.write_str("-")?;
+        }
+        write!(f, "{}", magnitude / factor)?;
+        if SCALE > 0 {
+            write!(f, ".{:0width$}", magnitude % factor, width = SCALE as usize)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const SCALE: u32> IntoByteArray for FixedDecimal<SCALE> {
+    type ByteArray = [u8; 8];
+
+    #[inline]
+    fn into_byte_array(&self) -> Self::ByteArray {
+        self.0.into_byte_array()
+    }
+}
+
+impl<const SCALE: u32> FromByteArray for FixedDecimal<SCALE> {
+    #[inline]
+    fn from_byte_array(byte_array: Self::ByteArray) -> Self {
+        Self(LittleEndian::from_byte_array(byte_array))
+    }
+}
+
+impl<const SCALE: u32> RawRepr for FixedDecimal<SCALE> {
+    type Raw = LittleEndian<i64>;
+
+    #[inline]
+    fn to_raw(&self) -> Self::Raw {
+        self.0
+    }
+}
+
+impl<const SCALE: u32> FromRawRepr for FixedDecimal<SCALE> {
+    #[inline]
+    fn from_raw(raw: Self::Raw) -> Self {
+        Self(raw)
+    }
+}
+
+impl<const SCALE: u32> TryFromRawRepr for FixedDecimal<SCALE> {
+    #[inline]
+    fn try_from_raw(raw: Self::Raw) -> Result<Self, DecodeError> {
+        Ok(Self::from_raw(raw))
+    }
+}
@@ -0,0 +1,154 @@
+//! [`FixedOption<T>`]: an optional value with a fixed-size encoding.
+
+use crate::{
+    ByteArray, DecodeError, IntoByteArray, PlainOldData, RawRepr, TryFromByteArray, TryFromRawRepr,
+};
+
+/// An `Option<T>` that always serializes to the same number of bytes: a presence byte
+/// followed by room for a `T`.
+///
+/// The variable-length `Option<T>` encoding of the I/O traits writes nothing after a `0` tag,
+/// so its size depends on the value. `FixedOption<T>` instead reserves the full payload even
+/// when empty, trading `T::BYTE_SIZE` bytes of space for a fixed layout: it can be a field of
+/// a derived fixed-size struct, an array element, or a record in a file read by index.
+///
+/// # Wire format
+///
+/// | Offset | Size | Content |
+/// |--------|------|---------|
+/// | 0 | 1 | tag (`0` = `None`, `1` = `Some`) |
+/// | 1 | `T::BYTE_SIZE` | the value, or zeros for `None` |
+///
+/// Any other tag fails to decode with [`DecodeError::InvalidTag`], and a `None` whose payload
+/// is not all zeros with [`DecodeError::InvalidValue`], so every value has exactly one
+/// encoding.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteable::{BigEndian, ByteArray, FixedOption, IntoByteArray, TryFromByteArray};
+///
+/// let some = FixedOption(Some(BigEndian::new(0x0102u16)));
+/// assert_eq!(some.into_byte_array().as_byte_slice(), &[1, 1, 2]);
+///
+/// let none = FixedOption::<BigEndian<u16>>(None);
+/// let bytes = none.into_byte_array();
+/// assert_eq!(bytes.as_byte_slice(), &[0, 0, 0]);
+/// assert_eq!(FixedOption::try_from_byte_array(bytes), Ok(none));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedOption<T>(pub Option<T>);
+
+impl<T> FixedOption<T> {
+    /// Returns the wrapped `Option`.
+    pub fn into_inner(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T> Default for FixedOption<T> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
+
+impl<T> From<Option<T>> for FixedOption<T> {
+    fn from(value: Option<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> From<FixedOption<T>> for Option<T> {
+    fn from(value: FixedOption<T>) -> Self {
+        value.0
+    }
+}
+
+/// The serialized form of a [`FixedOption`]: a tag byte followed by a payload byte array.
+///
+/// Both fields have alignment 1, so the type is a plain run of `1 + X::BYTE_SIZE` bytes.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct FixedOptionBytes<X: ByteArray> {
+    tag: u8,
+    payload: X,
+}
+
+impl<X: ByteArray> FixedOptionBytes<X> {
+    fn zeroed() -> Self {
+        // SAFETY: the struct consists solely of `u8`s, so all-zero is a valid value.
+        unsafe { core::mem::zeroed() }
+    }
+}
+
+// SAFETY: `repr(C)` over a `u8` and a byte array: alignment 1, no padding, and any bit
+// pattern is valid.
+unsafe impl<X: ByteArray> ByteArray for FixedOptionBytes<X> {
+    const BYTE_SIZE: usize = core::mem::size_of::<Self>();
+}
+
+// SAFETY: as above.
+unsafe impl<X: ByteArray> PlainOldData for FixedOptionBytes<X> {}
+
+impl<X: ByteArray> core::fmt::Debug for FixedOptionBytes<X> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("FixedOptionBytes")
+            .field(&self.as_byte_slice())
+            .finish()
+    }
+}
+
+impl<X: ByteArray> PartialEq for FixedOptionBytes<X> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_byte_slice() == other.as_byte_slice()
+    }
+}
+
+impl<X: ByteArray> Eq for FixedOptionBytes<X> {}
+
+impl<T: IntoByteArray> IntoByteArray for FixedOption<T> {
+    type ByteArray = FixedOptionBytes<T::ByteArray>;
+
+    fn into_byte_array(&self) -> Self::ByteArray {
+        let mut bytes = FixedOptionBytes::zeroed();
+        if let Some(value) = &self.0 {
+            bytes.tag = 1;
+            bytes.payload = value.into_byte_array();
+        }
+        bytes
+    }
+}
+
+impl<T: TryFromByteArray> TryFromByteArray for FixedOption<T> {
+    fn try_from_byte_array(byte_array: Self::ByteArray) -> Result<Self, DecodeError> {
+        match byte_array.tag {
+            0 if byte_array.payload.as_byte_slice().iter().all(|&b| b == 0) => Ok(Self(None)),
+            0 => Err(DecodeError::InvalidValue {
+                type_name: "FixedOption",
+                reason: "the payload of None is not zeroed",
+            }),
+            1 => T::try_from_byte_array(byte_array.payload).map(|value| Self(Some(value))),
+            raw => Err(DecodeError::InvalidTag {
+                raw,
+                type_name: "FixedOption",
+            }),
+        }
+    }
+}
+
+// The raw form is the serialized form itself, so `FixedOption<T>` can be used as a derived
+// struct field and read or written through the I/O traits.
+
+impl<T: IntoByteArray> RawRepr for FixedOption<T> {
+    type Raw = FixedOptionBytes<T::ByteArray>;
+
+    fn to_raw(&self) -> Self::Raw {
+        self.into_byte_array()
+    }
+}
+
+impl<T: TryFromByteArray> TryFromRawRepr for FixedOption<T> {
+    fn try_from_raw(raw: Self::Raw) -> Result<Self, DecodeError> {
+        Self::try_from_byte_array(raw)
+    }
+}
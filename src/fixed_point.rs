@@ -0,0 +1,306 @@
+//! [`Fixed<I, FRAC_BITS>`]: a binary fixed-point number stored as a plain integer.
+
+use crate::fixed_decimal::round_half_away_from_zero;
+use crate::{
+    BigEndian, DecodeError, EndianConvert, FromByteArray, FromRawRepr, IntoByteArray, LittleEndian,
+    PlainOldData, RawRepr, TryFromRawRepr,
+};
+use core::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
+
+/// A binary fixed-point number: an integer `I` that represents `bits / 2^FRAC_BITS`.
+///
+/// Sensor, audio, and graphics formats often store fractional values this way, e.g. Q16.16
+/// (`Fixed<i32, 16>`) or Q8.8 (`Fixed<i16, 8>`). `Fixed` serializes exactly as its integer,
+/// so it can be used as a derived struct field, with `#[byteable(big_endian)]` /
+/// `#[byteable(little_endian)]` (little-endian by default), or inside
+/// [`BigEndian`] / [`LittleEndian`].
+///
+/// The stored integer is the only state: reading and writing never go through a float, so
+/// every bit pattern round-trips unchanged. [`from_f64`](Self::from_f64) and
+/// [`to_f64`](Self::to_f64) are only for converting at the edges.
+///
+/// Implemented for `i16`, `i32`, `i64`, `u16`, `u32`, and `u64`. `FRAC_BITS` may be at most
+/// the integer's bit width; larger values fail to compile when a float conversion, `*`, or `/`
+/// is used.
+///
+/// # Arithmetic
+///
+/// `+` and `-` behave like the underlying integer operators. `*` and `/` compute in a type
+/// twice as wide and round toward negative infinity; they panic if the result does not fit,
+/// and `/` panics on division by zero. The `checked_*` methods return `None` instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteable::{Byteable, Fixed, IntoByteArray};
+///
+/// type Q16 = Fixed<i32, 16>;
+///
+/// let half = Q16::from_f64(0.5);
+/// assert_eq!(half.to_bits(), 0x8000);
+/// assert_eq!((half + half).to_f64(), 1.0);
+/// assert_eq!((Q16::from_f64(1.5) * Q16::from_f64(-2.0)).to_f64(), -3.0);
+///
+/// #[derive(Byteable)]
+/// struct Reading {
+///     #[byteable(big_endian)]
+///     temperature: Q16,
+/// }
+///
+/// let reading = Reading { temperature: Q16::from_f64(21.25) };
+/// assert_eq!(reading.into_byte_array(), [0x00, 0x15, 0x40, 0x00]);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Fixed<I, const FRAC_BITS: u32>(I);
+
+macro_rules! impl_fixed {
+    ($($int:ty => $wide:ty),+ $(,)?) => {
+        $(
+            impl<const FRAC_BITS: u32> Fixed<$int, FRAC_BITS> {
+                /// Fails to compile when `FRAC_BITS` exceeds the width of the integer.
+                const VALID: () = assert!(
+                    FRAC_BITS <= <$int>::BITS,
+                    "FRAC_BITS exceeds the width of the integer"
+                );
+
+                /// `2^FRAC_BITS`: the stored value of `1`.
+                const SCALE: f64 = {
+                    let () = Self::VALID;
+                    (1u128 << FRAC_BITS) as f64
+                };
+
+                /// Wraps a stored integer as-is.
+                #[inline]
+                pub const fn from_bits(bits: $int) -> Self {
+                    Self(bits)
+                }
+
+                /// Returns the stored integer.
+                #[inline]
+                pub const fn to_bits(self) -> $int {
+                    self.0
+                }
+
+                /// Converts `value` to the nearest representable number, rounding halfway
+                /// cases away from zero.
+                ///
+                /// Values outside the representable range saturate, and NaN becomes zero.
+                pub fn from_f64(value: f64) -> Self {
+                    Self(round_half_away_from_zero(value * Self::SCALE) as $int)
+                }
+
+                /// Returns the value as an `f64`. Wide integers may lose precision.
+                pub fn to_f64(self) -> f64 {
+                    self.0 as f64 / Self::SCALE
+                }
+
+                /// Returns `self + rhs`, or `None` on overflow.
+                #[inline]
+                pub fn checked_add(self, rhs: Self) -> Option<Self> {
+                    self.0.checked_add(rhs.0).map(Self)
+                }
+
+                /// Returns `self - rhs`, or `None` on overflow.
+                #[inline]
+                pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    self.0.checked_sub(rhs.0).map(Self)
+                }
+
+                /// Returns `self * rhs` rounded toward negative infinity, or `None` if it
+                /// does not fit.
+                #[inline]
+                pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    let () = Self::VALID;
+                    let product = <$wide>::from(self.0) * <$wide>::from(rhs.0);
+                    <$int>::try_from(product >> FRAC_BITS).ok().map(Self)
+                }
+
+                /// Returns `self / rhs` rounded toward negative infinity, or `None` if `rhs`
+                /// is zero or the result does not fit.
+                #[inline]
+                pub fn checked_div(self, rhs: Self) -> Option<Self> {
+                    let () = Self::VALID;
+                    let dividend = <$wide>::from(self.0) << FRAC_BITS;
+                    let quotient = dividend.checked_div_euclid(<$wide>::from(rhs.0))?;
+                    // Euclidean division rounds toward negative infinity only for a
+                    // positive divisor; adjust the other case to match.
+                    let quotient = if rhs.0 < <$int>::default()
+                        && quotient * <$wide>::from(rhs.0) != dividend
+                    {
+                        quotient - 1
+                    } else {
+                        quotient
+                    };
+                    <$int>::try_from(quotient).ok().map(Self)
+                }
+            }
+
+            impl<const FRAC_BITS: u32> Add for Fixed<$int, FRAC_BITS> {
+                type Output = Self;
+
+                #[inline]
+                fn add(self, rhs: Self) -> Self {
+                    Self(self.0 + rhs.0)
+                }
+            }
+
+            impl<const FRAC_BITS: u32> Sub for Fixed<$int, FRAC_BITS> {
+                type Output = Self;
+
+                #[inline]
+                fn sub(self, rhs: Self) -> Self {
+                    Self(self.0 - rhs.0)
+                }
+            }
+
+            impl<const FRAC_BITS: u32> Mul for Fixed<$int, FRAC_BITS> {
+                type Output = Self;
+
+                #[inline]
+                fn mul(self, rhs: Self) -> Self {
+                    self.checked_mul(rhs)
+                        .expect("fixed-point multiplication overflowed")
+                }
+            }
+
+            impl<const FRAC_BITS: u32> Div for Fixed<$int, FRAC_BITS> {
+                type Output = Self;
+
+                #[inline]
+                fn div(self, rhs: Self) -> Self {
+                    assert!(rhs.0 != 0, "fixed-point division by zero");
+                    self.checked_div(rhs)
+                        .expect("fixed-point division overflowed")
+                }
+            }
+
+            impl<const FRAC_BITS: u32> AddAssign for Fixed<$int, FRAC_BITS> {
+                #[inline]
+                fn add_assign(&mut self, rhs: Self) {
+                    *self = *self + rhs;
+                }
+            }
+
+            impl<const FRAC_BITS: u32> SubAssign for Fixed<$int, FRAC_BITS> {
+                #[inline]
+                fn sub_assign(&mut self, rhs: Self) {
+                    *self = *self - rhs;
+                }
+            }
+
+            impl<const FRAC_BITS: u32> core::fmt::Display for Fixed<$int, FRAC_BITS> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    core::fmt::Display::fmt(&self.to_f64(), f)
+                }
+            }
+
+            // SAFETY: `repr(transparent)` over a primitive integer: no padding and every bit
+            // pattern is a valid value.
+            unsafe impl<const FRAC_BITS: u32> PlainOldData for Fixed<$int, FRAC_BITS> {}
+
+            // SAFETY: delegates to the wrapped integer's conversions, which round-trip.
+            unsafe impl<const FRAC_BITS: u32> EndianConvert for Fixed<$int, FRAC_BITS> {
+                #[inline]
+                fn from_le(value: Self) -> Self {
+                    Self(<$int>::from_le(value.0))
+                }
+
+                #[inline]
+                fn from_be(value: Self) -> Self {
+                    Self(<$int>::from_be(value.0))
+                }
+
+                #[inline]
+                fn to_le(self) -> Self {
+                    Self(self.0.to_le())
+                }
+
+                #[inline]
+                fn to_be(self) -> Self {
+                    Self(self.0.to_be())
+                }
+            }
+
+            impl<const FRAC_BITS: u32> RawRepr for Fixed<$int, FRAC_BITS> {
+                type Raw = LittleEndian<Self>;
+
+                #[inline]
+                fn to_raw(&self) -> Self::Raw {
+                    LittleEndian::new(*self)
+                }
+            }
+
+            impl<const FRAC_BITS: u32> FromRawRepr for Fixed<$int, FRAC_BITS> {
+                #[inline]
+                fn from_raw(raw: Self::Raw) -> Self {
+                    raw.get()
+                }
+            }
+
+            impl<const FRAC_BITS: u32> TryFromRawRepr for Fixed<$int, FRAC_BITS> {
+                #[inline]
+                fn try_from_raw(raw: Self::Raw) -> Result<Self, DecodeError> {
+                    Ok(Self::from_raw(raw))
+                }
+            }
+
+            impl<const FRAC_BITS: u32> IntoByteArray for Fixed<$int, FRAC_BITS> {
+                type ByteArray = [u8; size_of::<$int>()];
+
+                #[inline]
+                fn into_byte_array(&self) -> Self::ByteArray {
+                    self.0.to_le_bytes()
+                }
+            }
+
+            impl<const FRAC_BITS: u32> FromByteArray for Fixed<$int, FRAC_BITS> {
+                #[inline]
+                fn from_byte_array(byte_array: Self::ByteArray) -> Self {
+                    Self(<$int>::from_le_bytes(byte_array))
+                }
+            }
+
+            impl<const FRAC_BITS: u32> IntoByteArray for LittleEndian<Fixed<$int, FRAC_BITS>> {
+                type ByteArray = [u8; size_of::<$int>()];
+
+                #[inline]
+                fn into_byte_array(&self) -> Self::ByteArray {
+                    self.get().0.to_le_bytes()
+                }
+            }
+
+            impl<const FRAC_BITS: u32> FromByteArray for LittleEndian<Fixed<$int, FRAC_BITS>> {
+                #[inline]
+                fn from_byte_array(byte_array: Self::ByteArray) -> Self {
+                    Self::new(Fixed(<$int>::from_le_bytes(byte_array)))
+                }
+            }
+
+            impl<const FRAC_BITS: u32> IntoByteArray for BigEndian<Fixed<$int, FRAC_BITS>> {
+                type ByteArray = [u8; size_of::<$int>()];
+
+                #[inline]
+                fn into_byte_array(&self) -> Self::ByteArray {
+                    self.get().0.to_be_bytes()
+                }
+            }
+
+            impl<const FRAC_BITS: u32> FromByteArray for BigEndian<Fixed<$int, FRAC_BITS>> {
+                #[inline]
+                fn from_byte_array(byte_array: Self::ByteArray) -> Self {
+                    Self::new(Fixed(<$int>::from_be_bytes(byte_array)))
+                }
+            }
+        )+
+    };
+}
+
+impl_fixed!(
+    i16 => i32,
+    i32 => i64,
+    i64 => i128,
+    u16 => u32,
+    u32 => u64,
+    u64 => u128,
+);
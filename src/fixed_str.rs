@@ -0,0 +1,205 @@
+//! [`FixedStr<N>`]: a fixed-width, NUL-padded string field.
+
+use crate::{
+    DecodeError, FromByteArray, FromRawRepr, IntoByteArray, PlainOldData, RawRepr, TryFromRawRepr,
+};
+
+/// A string stored in exactly `N` bytes, padded with NULs.
+///
+/// This is the classic fixed-width name field found in binary formats (device names, tar
+/// headers, ...). The string ends at the first NUL byte, or fills all `N` bytes if there is
+/// none. `FixedStr<N>` is [`PlainOldData`] and serializes as its `N` bytes, so it can be used
+/// directly as a derived struct field.
+///
+/// Because any byte pattern can be decoded, the contents are not guaranteed to be UTF-8:
+/// [`as_str`](FixedStr::as_str) reports invalid UTF-8, and [`Display`](core::fmt::Display)
+/// substitutes `U+FFFD` for invalid sequences.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteable::{FixedStr, IntoByteArray};
+///
+/// let name = FixedStr::<8>::try_from("eth0").unwrap();
+/// assert_eq!(name.as_str(), Ok("eth0"));
+/// assert_eq!(name.into_byte_array(), *b"eth0\0\0\0\0");
+/// assert_eq!(name.to_string(), "eth0");
+///
+/// assert!(FixedStr::<4>::try_from("too long").is_err());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct FixedStr<const N: usize>([u8; N]);
+
+impl<const N: usize> FixedStr<N> {
+    /// Wraps `bytes` as they are.
+    #[inline]
+    pub const fn from_bytes(bytes: [u8; N]) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns all `N` bytes, including the NUL padding.
+    #[inline]
+    pub const fn as_array(&self) -> &[u8; N] {
+        &self.0
+    }
+
+    /// Returns the bytes up to (not including) the first NUL.
+    pub fn trimmed_bytes(&self) -> &[u8] {
+        trim_nul(&self.0)
+    }
+
+    /// Returns the string up to the first NUL.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Utf8Error`](core::str::Utf8Error) if those bytes are not valid UTF-8.
+    pub fn as_str(&self) -> Result<&str, core::str::Utf8Error> {
+        core::str::from_utf8(self.trimmed_bytes())
+    }
+}
+
+impl<const N: usize> Default for FixedStr<N> {
+    #[inline]
+    fn default() -> Self {
+        Self([0; N])
+    }
+}
+
+impl<const N: usize> core::fmt::Display for FixedStr<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for chunk in self.trimmed_bytes().utf8_chunks() {
+            f.write_str(chunk.valid())?;
+            if !chunk.invalid().is_empty() {
+                f.write_str("\u{FFFD}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> core::fmt::Debug for FixedStr<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("FixedStr")
+            .field(&DebugText(self.trimmed_bytes()))
+            .finish()
+    }
+}
+
+/// Returns `bytes` up to (not including) the first NUL, or all of them if there is none.
+///
+/// Shared with [`FixedAscii`](crate::FixedAscii), which uses the same padding.
+pub(crate) fn trim_nul(bytes: &[u8]) -> &[u8] {
+    let len = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    &bytes[..len]
+}
+
+/// Copies `bytes` to the front of an `N`-byte array of NULs, or returns `None` if they do
+/// not fit.
+pub(crate) fn pad_nul<const N: usize>(bytes: &[u8]) -> Option<[u8; N]> {
+    let mut out = [0; N];
+    out.get_mut(..bytes.len())?.copy_from_slice(bytes);
+    Some(out)
+}
+
+/// Formats bytes like a `str` literal, escaping invalid UTF-8 as `\xNN`, without allocating.
+struct DebugText<'a>(&'a [u8]);
+
+impl core::fmt::Debug for DebugText<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("\"")?;
+        for chunk in self.0.utf8_chunks() {
+            for c in chunk.valid().chars() {
+                write!(f, "{}", c.escape_debug())?;
+            }
+            for b in chunk.invalid() {
+                write!(f, "\\x{b:02x}")?;
+            }
+        }
+        f.write_str("\"")
+    }
+}
+
+/// Error returned when a string does not fit in a [`FixedStr<N>`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedStrTooLong {
+    /// The length of the string in bytes.
+    pub len: usize,
+    /// The capacity `N` of the target `FixedStr`.
+    pub capacity: usize,
+}
+
+impl core::fmt::Display for FixedStrTooLong {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "string of {} bytes does not fit in a FixedStr<{}>",
+            self.len, self.capacity
+        )
+    }
+}
+
+impl core::error::Error for FixedStrTooLong {}
+
+impl<const N: usize> TryFrom<&str> for FixedStr<N> {
+    type Error = FixedStrTooLong;
+
+    /// Copies `value` and pads it with NULs. A string of exactly `N` bytes has no terminator.
+    ///
+    /// `value` should not contain NUL bytes: the stored string ends at the first one.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        pad_nul(value.as_bytes()).map(Self).ok_or(FixedStrTooLong {
+            len: value.len(),
+            capacity: N,
+        })
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for FixedStr<N> {
+    #[inline]
+    fn from(bytes: [u8; N]) -> Self {
+        Self(bytes)
+    }
+}
+
+// SAFETY: `repr(transparent)` over `[u8; N]`: no padding and every bit pattern is valid.
+unsafe impl<const N: usize> PlainOldData for FixedStr<N> {}
+
+impl<const N: usize> IntoByteArray for FixedStr<N> {
+    type ByteArray = [u8; N];
+
+    #[inline]
+    fn into_byte_array(&self) -> Self::ByteArray {
+        self.0
+    }
+}
+
+impl<const N: usize> FromByteArray for FixedStr<N> {
+    #[inline]
+    fn from_byte_array(byte_array: Self::ByteArray) -> Self {
+        Self(byte_array)
+    }
+}
+
+impl<const N: usize> RawRepr for FixedStr<N> {
+    type Raw = Self;
+
+    #[inline]
+    fn to_raw(&self) -> Self::Raw {
+        *self
+    }
+}
+
+impl<const N: usize> FromRawRepr for FixedStr<N> {
+    #[inline]
+    fn from_raw(raw: Self::Raw) -> Self {
+        raw
+    }
+}
+
+impl<const N: usize> TryFromRawRepr for FixedStr<N> {
+    #[inline]
+    fn try_from_raw(raw: Self::Raw) -> Result<Self, DecodeError> {
+        Ok(raw)
+    }
+}
@@ -0,0 +1,221 @@
+//! [`Usize32`] and [`Usize64`]: `usize` values stored at a fixed width.
+//!
+//! `usize` is deliberately not serializable: its width depends on the platform, so a file
+//! written on a 64-bit machine would not read back on a 32-bit one. These types pin the width
+//! down and check for overflow whenever a value is narrowed.
+
+use crate::{
+    BigEndian, DecodeError, EndianConvert, FromByteArray, FromRawRepr, IntoByteArray, LittleEndian,
+    PlainOldData, RawRepr, TryFromRawRepr, impl_byte_array,
+};
+
+/// Error returned when a `usize` does not fit in a [`Usize32`], or a stored value does not fit
+/// in this platform's `usize`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UsizeOutOfRange {
+    /// The value that was out of range.
+    pub value: u64,
+}
+
+impl core::fmt::Display for UsizeOutOfRange {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "value {} does not fit in the target width", self.value)
+    }
+}
+
+impl core::error::Error for UsizeOutOfRange {}
+
+macro_rules! fixed_usize_type {
+    ($(#[$meta:meta])* $name:ident($int:ty)) => {
+        $(#[$meta])*
+        #[repr(transparent)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        pub struct $name($int);
+
+        impl $name {
+            /// The number of bits.
+            pub const BITS: u32 = <$int>::BITS;
+
+            /// Stores `value`, or returns `None` if it does not fit in
+            /// [`BITS`](Self::BITS) bits.
+            #[inline]
+            pub fn new(value: usize) -> Option<Self> {
+                <$int>::try_from(value).ok().map(Self)
+            }
+
+            /// Returns the value, or `None` if it does not fit in this platform's `usize`.
+            #[inline]
+            pub fn get(self) -> Option<usize> {
+                usize::try_from(self.0).ok()
+            }
+
+            /// Wraps a stored integer as-is.
+            #[inline]
+            pub const fn from_bits(bits: $int) -> Self {
+                Self(bits)
+            }
+
+            /// Returns the stored integer.
+            #[inline]
+            pub const fn to_bits(self) -> $int {
+                self.0
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl TryFrom<usize> for $name {
+            type Error = UsizeOutOfRange;
+
+            #[inline]
+            fn try_from(value: usize) -> Result<Self, Self::Error> {
+                Self::new(value).ok_or(UsizeOutOfRange {
+                    value: value as u64,
+                })
+            }
+        }
+
+        impl TryFrom<$name> for usize {
+            type Error = UsizeOutOfRange;
+
+            #[inline]
+            fn try_from(value: $name) -> Result<Self, Self::Error> {
+                value.get().ok_or(UsizeOutOfRange {
+                    value: value.0.into(),
+                })
+            }
+        }
+
+        impl From<$int> for $name {
+            #[inline]
+            fn from(bits: $int) -> Self {
+                Self(bits)
+            }
+        }
+
+        impl From<$name> for $int {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        // SAFETY: `repr(transparent)` over a `u32`/`u64`, so the layout is that fixed-width
+        // integer whatever the platform's `usize` is. Any bit pattern is a valid value: one
+        // that does not fit in `usize` is only rejected when `get` narrows it, never by
+        // constructing the wrapper.
+        unsafe impl PlainOldData for $name {}
+
+        // SAFETY: swaps the bytes of the stored fixed-width integer, never a `usize`, so the
+        // conversions round-trip at the same width on every platform.
+        unsafe impl EndianConvert for $name {
+            #[inline]
+            fn from_le(value: Self) -> Self {
+                Self(<$int>::from_le(value.0))
+            }
+
+            #[inline]
+            fn from_be(value: Self) -> Self {
+                Self(<$int>::from_be(value.0))
+            }
+
+            #[inline]
+            fn to_le(self) -> Self {
+                Self(self.0.to_le())
+            }
+
+            #[inline]
+            fn to_be(self) -> Self {
+                Self(self.0.to_be())
+            }
+        }
+
+        impl_byte_array!(LittleEndian<$name>, BigEndian<$name>);
+
+        impl RawRepr for $name {
+            type Raw = LittleEndian<Self>;
+
+            #[inline]
+            fn to_raw(&self) -> Self::Raw {
+                LittleEndian::new(*self)
+            }
+        }
+
+        impl FromRawRepr for $name {
+            #[inline]
+            fn from_raw(raw: Self::Raw) -> Self {
+                raw.get()
+            }
+        }
+
+        impl TryFromRawRepr for $name {
+            #[inline]
+            fn try_from_raw(raw: Self::Raw) -> Result<Self, DecodeError> {
+                Ok(Self::from_raw(raw))
+            }
+        }
+
+        impl IntoByteArray for $name {
+            type ByteArray = [u8; size_of::<$int>()];
+
+            #[inline]
+            fn into_byte_array(&self) -> Self::ByteArray {
+                self.to_raw().into_byte_array()
+            }
+        }
+
+        impl FromByteArray for $name {
+            #[inline]
+            fn from_byte_array(byte_array: Self::ByteArray) -> Self {
+                Self::from_raw(LittleEndian::from_byte_array(byte_array))
+            }
+        }
+    };
+}
+
+fixed_usize_type! {
+    /// A `usize` stored in 4 bytes.
+    ///
+    /// Construction fails for values above `u32::MAX`, so a 64-bit writer cannot silently
+    /// truncate a length. Serializes little-endian by default and composes with
+    /// [`BigEndian`] / [`LittleEndian`] like the primitive integers.
+    ///
+    /// ```rust
+    /// use byteable::{BigEndian, IntoByteArray, Usize32};
+    ///
+    /// let len = Usize32::new(0x0102).unwrap();
+    /// assert_eq!(len.into_byte_array(), [0x02, 0x01, 0, 0]);
+    /// assert_eq!(BigEndian::new(len).into_byte_array(), [0, 0, 0x01, 0x02]);
+    /// assert_eq!(len.get(), Some(0x0102));
+    ///
+    /// # #[cfg(target_pointer_width = "64")]
+    /// assert!(Usize32::new(1 << 32).is_none());
+    /// ```
+    Usize32(u32)
+}
+
+fixed_usize_type! {
+    /// A `usize` stored in 8 bytes.
+    ///
+    /// Every `usize` fits, but reading one back with [`get`](Self::get) fails on platforms
+    /// where the stored value exceeds `usize::MAX`. Serializes little-endian by default and
+    /// composes with [`BigEndian`] / [`LittleEndian`] like the primitive integers.
+    ///
+    /// ```rust
+    /// use byteable::{Byteable, IntoByteArray, Usize64};
+    ///
+    /// #[derive(Byteable)]
+    /// struct Header {
+    ///     #[byteable(big_endian)]
+    ///     entries: Usize64,
+    /// }
+    ///
+    /// let entries = Usize64::new(3).unwrap();
+    /// assert_eq!(Header { entries }.into_byte_array(), [0, 0, 0, 0, 0, 0, 0, 3]);
+    /// ```
+    Usize64(u64)
+}
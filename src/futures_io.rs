@@ -0,0 +1,218 @@
+//! Async I/O traits for reading and writing byteable values via `futures-io`.
+//!
+//! This module is only available when the `futures` feature is enabled. It is the
+//! runtime-agnostic twin of the tokio-based `async_io` module: the traits and method
+//! signatures are the same, but they are built on [`futures_util::AsyncReadExt`] /
+//! [`futures_util::AsyncWriteExt`], so they work with `smol`, `async-std` and any other reader
+//! implementing `futures_io::AsyncRead` / `AsyncWrite`.
+//!
+//! The standard collections, `String`, `CString`, `PathBuf`, `Option` and `Result` implement
+//! [`FuturesReadable`] / [`FuturesWritable`] with the same wire format as their `std` impls.
+
+use std::io;
+
+use crate::{PlainOldData, RawRepr, ReadableError, TryFromRawRepr};
+
+/// Async counterpart of [`crate::io::FixedReadable`].
+///
+/// Deserializes a fixed-size value from an async reader by filling a zeroed raw buffer
+/// with `read_exact`, then validating and converting via [`TryFromRawRepr`].
+///
+/// A blanket impl covers all types that implement [`TryFromRawRepr`].
+///
+/// Prefer the extension method [`FuturesReadFixed::read_fixed`] over calling this trait directly.
+///
+/// # Errors
+///
+/// Returns [`ReadableError`] if the read fails or the bytes are not a valid encoding.
+pub trait FuturesFixedReadable: Sized {
+    /// Read exactly `size_of::<Self::Raw>()` bytes from `reader` and decode them into `Self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReadableError::Io`] on I/O failure or [`ReadableError::DecodeError`]
+    /// if the bytes do not encode a valid `Self`.
+    fn read_fixed_from(
+        reader: &mut (impl futures_util::AsyncReadExt + ?Sized + Unpin),
+    ) -> impl Future<Output = Result<Self, ReadableError>>;
+}
+
+impl<T: TryFromRawRepr> FuturesFixedReadable for T {
+    #[inline]
+    async fn read_fixed_from(
+        reader: &mut (impl futures_util::AsyncReadExt + ?Sized + Unpin),
+    ) -> Result<Self, ReadableError> {
+        let mut b = T::Raw::zeroed();
+        reader.read_exact(b.as_bytes_mut()).await?;
+        let r = T::try_from_raw(b)?;
+        Ok(r)
+    }
+}
+
+/// Async counterpart of [`crate::io::Readable`].
+///
+/// Deserializes a value (possibly variable-length) from an async reader. Variable-length
+/// types implement this directly; fixed-size types get a blanket impl via
+/// [`FuturesFixedReadable`].
+///
+/// Prefer the extension method [`FuturesReadValue::read_value`] over calling this directly.
+///
+/// # Errors
+///
+/// Returns [`ReadableError`] if the read fails or the bytes are not a valid encoding.
+pub trait FuturesReadable: Sized {
+    /// Read a value from `reader`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReadableError`] on I/O failure or decode error.
+    fn read_from(
+        reader: &mut (impl futures_util::AsyncReadExt + ?Sized + Unpin),
+    ) -> impl Future<Output = Result<Self, ReadableError>>;
+}
+
+impl<T: FuturesFixedReadable> FuturesReadable for T {
+    #[inline]
+    fn read_from(
+        reader: &mut (impl futures_util::AsyncReadExt + ?Sized + Unpin),
+    ) -> impl Future<Output = Result<Self, ReadableError>> {
+        T::read_fixed_from(reader)
+    }
+}
+
+/// Async counterpart of [`crate::io::FixedWritable`].
+///
+/// Serializes a fixed-size value to an async writer by converting to a raw representation
+/// and calling `write_all`. A blanket impl covers all types that implement [`RawRepr`].
+///
+/// Prefer the extension method [`FuturesWriteFixed::write_fixed`] over calling this directly.
+pub trait FuturesFixedWritable {
+    /// Write the fixed-size byte representation of `self` to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::Error`] if writing fails.
+    fn write_fixed_to(
+        &self,
+        writer: &mut (impl futures_util::AsyncWriteExt + ?Sized + Unpin),
+    ) -> impl Future<Output = io::Result<()>>;
+}
+
+impl<T: RawRepr> FuturesFixedWritable for T {
+    #[inline]
+    async fn write_fixed_to(
+        &self,
+        writer: &mut (impl futures_util::AsyncWriteExt + ?Sized + Unpin),
+    ) -> io::Result<()> {
+        let raw = self.to_raw();
+        writer.write_all(raw.as_bytes()).await
+    }
+}
+
+/// Async counterpart of [`crate::io::Writable`].
+///
+/// Serializes a value (possibly variable-length) to an async writer. Variable-length types
+/// implement this directly; fixed-size types get a blanket impl via [`FuturesFixedWritable`].
+///
+/// Prefer the extension method [`FuturesWriteValue::write_value`] over calling this directly.
+pub trait FuturesWritable {
+    /// Write `self` to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::Error`] if writing fails.
+    fn write_to(
+        &self,
+        writer: &mut (impl futures_util::AsyncWriteExt + ?Sized + Unpin),
+    ) -> impl Future<Output = io::Result<()>>;
+}
+
+impl<T: FuturesFixedWritable> FuturesWritable for T {
+    #[inline]
+    fn write_to(
+        &self,
+        writer: &mut (impl futures_util::AsyncWriteExt + ?Sized + Unpin),
+    ) -> impl Future<Output = io::Result<()>> {
+        self.write_fixed_to(writer)
+    }
+}
+
+/// Extension trait that adds [`read_fixed`](FuturesReadFixed::read_fixed) to any async reader.
+///
+/// Automatically implemented for all `T: AsyncReadExt + Unpin`. Async counterpart of
+/// [`crate::io::ReadFixed`].
+pub trait FuturesReadFixed: futures_util::AsyncReadExt + Unpin {
+    /// Read a [`FuturesFixedReadable`] value from this async reader.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReadableError`] on I/O failure or decode error.
+    #[inline]
+    fn read_fixed<T: FuturesFixedReadable>(
+        &mut self,
+    ) -> impl Future<Output = Result<T, ReadableError>> {
+        T::read_fixed_from(self)
+    }
+}
+
+impl<T: futures_util::AsyncReadExt + ?Sized + Unpin> FuturesReadFixed for T {}
+
+/// Extension trait that adds [`read_value`](FuturesReadValue::read_value) to any async reader.
+///
+/// Automatically implemented for all `T: AsyncReadExt + Unpin`. Async counterpart of
+/// [`crate::io::ReadValue`].
+pub trait FuturesReadValue: futures_util::AsyncReadExt + Unpin {
+    /// Read a [`FuturesReadable`] value from this async reader.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReadableError`] on I/O failure or decode error.
+    #[inline]
+    fn read_value<T: FuturesReadable>(&mut self) -> impl Future<Output = Result<T, ReadableError>> {
+        T::read_from(self)
+    }
+}
+
+impl<T: futures_util::AsyncReadExt + ?Sized + Unpin> FuturesReadValue for T {}
+
+/// Extension trait that adds [`write_fixed`](FuturesWriteFixed::write_fixed) to any async writer.
+///
+/// Automatically implemented for all `T: AsyncWriteExt + Unpin`. Async counterpart of
+/// [`crate::io::WriteFixed`].
+pub trait FuturesWriteFixed: futures_util::AsyncWriteExt + Unpin {
+    /// Write a [`FuturesFixedWritable`] value to this async writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::Error`] if writing fails.
+    #[inline]
+    fn write_fixed(
+        &mut self,
+        val: &impl FuturesFixedWritable,
+    ) -> impl Future<Output = io::Result<()>> {
+        val.write_fixed_to(self)
+    }
+}
+
+impl<T: futures_util::AsyncWriteExt + ?Sized + Unpin> FuturesWriteFixed for T {}
+
+/// Extension trait that adds [`write_value`](FuturesWriteValue::write_value) to any async writer.
+///
+/// Automatically implemented for all `T: AsyncWriteExt + Unpin`. Async counterpart of
+/// [`crate::io::WriteValue`].
+pub trait FuturesWriteValue: futures_util::AsyncWriteExt + Unpin {
+    /// Write a [`FuturesWritable`] value to this async writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::Error`] if writing fails.
+    #[inline]
+    fn write_value<T: FuturesWritable + ?Sized>(
+        &mut self,
+        data: &T,
+    ) -> impl Future<Output = io::Result<()>> {
+        data.write_to(self)
+    }
+}
+
+impl<T: futures_util::AsyncWriteExt + ?Sized + Unpin> FuturesWriteValue for T {}
@@ -0,0 +1,108 @@
+//! Hex strings for serialized values: [`IntoByteArray::to_hex`](crate::IntoByteArray::to_hex)
+//! and [`TryFromByteArray::from_hex`](crate::TryFromByteArray::from_hex).
+//!
+//! The encoding is two hex digits per byte, in serialized order, with no prefix or separators.
+//! `to_hex` writes lowercase digits; `from_hex` accepts either case.
+//!
+//! ```rust
+//! use byteable::{BigEndian, IntoByteArray, TryFromByteArray};
+//!
+//! let port = BigEndian::new(8080u16);
+//! assert_eq!(port.to_hex(), "1f90");
+//! assert_eq!(BigEndian::<u16>::from_hex("1F90"), Ok(port));
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::DecodeError;
+
+/// Error returned by [`TryFromByteArray::from_hex`](crate::TryFromByteArray::from_hex).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FromHexError {
+    /// The string is not exactly two hex digits per serialized byte.
+    InvalidLength {
+        /// The expected length of the string, `2 * BYTE_SIZE`.
+        expected: usize,
+        /// The actual length of the string in bytes.
+        found: usize,
+    },
+    /// The string contains something other than a hex digit at byte `index`.
+    InvalidChar {
+        /// The byte offset of the character in the string.
+        index: usize,
+        /// The offending character.
+        found: char,
+    },
+    /// The string is valid hex, but the bytes do not decode to a value of the target type.
+    Decode(DecodeError),
+}
+
+impl core::fmt::Display for FromHexError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            FromHexError::InvalidLength { expected, found } => {
+                write!(f, "expected {expected} hex digits, found {found}")
+            }
+            FromHexError::InvalidChar { index, found } => {
+                write!(f, "invalid hex digit {found:?} at index {index}")
+            }
+            FromHexError::Decode(error) => error.fmt(f),
+        }
+    }
+}
+
+impl core::error::Error for FromHexError {}
+
+impl From<DecodeError> for FromHexError {
+    #[inline]
+    fn from(value: DecodeError) -> Self {
+        Self::Decode(value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn encode(bytes: &[u8]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for &byte in bytes {
+        out.push(DIGITS[usize::from(byte >> 4)] as char);
+        out.push(DIGITS[usize::from(byte & 0xF)] as char);
+    }
+    out
+}
+
+/// Decodes `hex` into `out`, which must be filled exactly.
+pub(crate) fn decode_into(hex: &str, out: &mut [u8]) -> Result<(), FromHexError> {
+    if hex.len() != out.len() * 2 {
+        return Err(FromHexError::InvalidLength {
+            expected: out.len() * 2,
+            found: hex.len(),
+        });
+    }
+    let digit = |index: usize| {
+        let value = match hex.as_bytes()[index] {
+            c @ b'0'..=b'9' => c - b'0',
+            c @ b'a'..=b'f' => c - b'a' + 10,
+            c @ b'A'..=b'F' => c - b'A' + 10,
+            _ => {
+                // Non-ASCII bytes are part of a multi-byte character; report the whole
+                // character, starting from its first byte.
+                let start = (0..=index)
+                    .rev()
+                    .find(|&i| hex.is_char_boundary(i))
+                    .unwrap_or(0);
+                let found = hex[start..].chars().next().unwrap_or_default();
+                return Err(FromHexError::InvalidChar {
+                    index: start,
+                    found,
+                });
+            }
+        };
+        Ok(value)
+    };
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = (digit(2 * i)? << 4) | digit(2 * i + 1)?;
+    }
+    Ok(())
+}
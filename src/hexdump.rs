@@ -0,0 +1,70 @@
+//! Offset / hex / ASCII dumps behind [`IntoByteArray::hexdump`](crate::IntoByteArray::hexdump)
+//! and [`ByteableLayout::hexdump_annotated`](crate::ByteableLayout::hexdump_annotated).
+
+use alloc::string::String;
+use core::fmt::Write;
+use core::ops::Range;
+
+/// Bytes shown per row.
+const ROW_LEN: usize = 16;
+
+/// Formats `bytes` in rows of up to 16, like `hexdump -C`.
+pub(crate) fn hexdump(bytes: &[u8]) -> String {
+    let mut out = String::new();
+    write_rows(&mut out, bytes, 0, None);
+    out
+}
+
+/// Formats `bytes` with a row break at every field boundary, labelling each field's first row.
+///
+/// Bytes outside every range, such as padding, a magic prefix or a checksum, are dumped
+/// without a label; zero-sized fields are skipped.
+pub(crate) fn hexdump_annotated(bytes: &[u8], ranges: &[(&str, Range<usize>)]) -> String {
+    let mut out = String::new();
+    let mut pos = 0;
+    for (name, range) in ranges {
+        let start = range.start.min(bytes.len());
+        let end = range.end.min(bytes.len());
+        if pos < start {
+            write_rows(&mut out, &bytes[pos..start], pos, None);
+        }
+        if start < end {
+            write_rows(&mut out, &bytes[start..end], start, Some(name));
+        }
+        pos = pos.max(end);
+    }
+    if pos < bytes.len() {
+        write_rows(&mut out, &bytes[pos..], pos, None);
+    }
+    out
+}
+
+fn write_rows(out: &mut String, bytes: &[u8], base: usize, mut label: Option<&str>) {
+    for (row, chunk) in bytes.chunks(ROW_LEN).enumerate() {
+        let _ = write!(out, "{:08x} ", base + row * ROW_LEN);
+        for i in 0..ROW_LEN {
+            if i % 8 == 0 {
+                out.push(' ');
+            }
+            match chunk.get(i) {
+                Some(byte) => {
+                    let _ = write!(out, "{byte:02x} ");
+                }
+                None => out.push_str("   "),
+            }
+        }
+        out.push_str(" |");
+        out.extend(chunk.iter().map(|&byte| {
+            if byte.is_ascii_graphic() || byte == b' ' {
+                byte as char
+            } else {
+                '.'
+            }
+        }));
+        out.push('|');
+        if let Some(name) = label.take() {
+            let _ = write!(out, "{:pad$}  {name}", "", pad = ROW_LEN - chunk.len());
+        }
+        out.push('\n');
+    }
+}
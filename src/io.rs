@@ -1,92 +1,9 @@
-use crate::{PlainOldData, RawRepr, TryFromRawRepr, byteable_trait::DecodeError};
-use core::{error, fmt};
-use std::io::{self, Read, Write};
-
-struct CountingReader<'a, R: Read + ?Sized> {
-    inner: &'a mut R,
-    count: usize,
-}
-
-impl<'a, R: Read + ?Sized> CountingReader<'a, R> {
-    fn new(inner: &'a mut R) -> Self {
-        Self { inner, count: 0 }
-    }
-
-    fn count(&self) -> usize {
-        self.count
-    }
-}
-
-impl<R: Read + ?Sized> Read for CountingReader<'_, R> {
-    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
-        let n = self.inner.read(buf)?;
-        self.count += n;
-        Ok(n)
-    }
-
-    fn read_vectored(&mut self, bufs: &mut [io::IoSliceMut<'_>]) -> io::Result<usize> {
-        let n = self.inner.read_vectored(bufs)?;
-        self.count += n;
-        Ok(n)
-    }
-
-    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
-        let n = self.inner.read_to_end(buf)?;
-        self.count += n;
-        Ok(n)
-    }
-
-    fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
-        let n = self.inner.read_to_string(buf)?;
-        self.count += n;
-        Ok(n)
-    }
-
-    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
-        self.inner.read_exact(buf)?;
-        self.count += buf.len();
-        Ok(())
-    }
-}
-
-struct CountingWriter<'a, W: Write + ?Sized> {
-    inner: &'a mut W,
-    count: usize,
-}
-
-impl<'a, W: Write + ?Sized> CountingWriter<'a, W> {
-    fn new(inner: &'a mut W) -> Self {
-        Self { inner, count: 0 }
-    }
-
-    fn count(&self) -> usize {
-        self.count
-    }
-}
-
-impl<W: Write + ?Sized> Write for CountingWriter<'_, W> {
-    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        let n = self.inner.write(buf)?;
-        self.count += n;
-        Ok(n)
-    }
-
-    fn flush(&mut self) -> io::Result<()> {
-        self.inner.flush()
-    }
-
-    fn write_vectored(&mut self, bufs: &[io::IoSlice<'_>]) -> io::Result<usize> {
-        let n = self.inner.write_vectored(bufs)?;
-        self.count += n;
-        Ok(n)
-    }
-
-    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
-        self.inner.write_all(buf)?;
-        self.count += buf.len();
-        Ok(())
-    }
-}
+use crate::{
+    BigEndian, ByteArray, EndianConvert, LittleEndian, PlainOldData, RawRepr, TryFromByteArray,
+    TryFromRawRepr, Usize32, Usize64, byteable_trait::DecodeError,
+};
+use core::{error, fmt, iter::FusedIterator, marker::PhantomData};
+use std::io::{self, Read, Seek, Write};
 
 /// Error returned when reading a value from a [`Read`] source fails.
 ///
@@ -125,6 +42,125 @@ impl From<DecodeError> for ReadableError {
     }
 }
 
+/// Converts to an [`io::Error`] so that reads can be propagated with `?` from functions
+/// returning [`io::Result`].
+///
+/// I/O errors are passed through unchanged; decode errors become
+/// [`io::ErrorKind::InvalidData`] with the [`DecodeError`] as the source.
+///
+/// ```rust
+/// use byteable::io::ReadFixed;
+/// use std::io::{self, Cursor};
+///
+/// fn read_flag(cursor: &mut Cursor<&[u8]>) -> io::Result<bool> {
+///     Ok(cursor.read_fixed()?)
+/// }
+///
+/// assert!(read_flag(&mut Cursor::new(&[1])).unwrap());
+/// assert_eq!(
+///     read_flag(&mut Cursor::new(&[2])).unwrap_err().kind(),
+///     io::ErrorKind::InvalidData
+/// );
+/// assert_eq!(
+///     read_flag(&mut Cursor::new(&[])).unwrap_err().kind(),
+///     io::ErrorKind::UnexpectedEof
+/// );
+/// ```
+impl From<ReadableError> for io::Error {
+    fn from(value: ReadableError) -> Self {
+        match value {
+            ReadableError::Io(error) => error,
+            ReadableError::DecodeError(error) => io::Error::new(io::ErrorKind::InvalidData, error),
+        }
+    }
+}
+
+/// An integer type usable as the element-count prefix of
+/// [`write_fixed_prefixed`](WriteFixed::write_fixed_prefixed) /
+/// [`read_fixed_prefixed`](ReadFixed::read_fixed_prefixed).
+///
+/// Implemented for the unsigned primitives `u8`–`u64` (native byte order encoding, i.e.
+/// little-endian for multi-byte types), for [`Usize32`] / [`Usize64`], and for [`BigEndian`] /
+/// [`LittleEndian`] wrappers of all of those except `u8`.
+pub trait LengthPrefix: FixedReadable + FixedWritable {
+    /// Converts an element count into a prefix, or `None` if it does not fit.
+    fn from_len(len: usize) -> Option<Self>;
+
+    /// Converts the prefix back into an element count, or `None` if it does not fit in `usize`.
+    fn to_len(&self) -> Option<usize>;
+}
+
+macro_rules! impl_length_prefix {
+    ($($ty:ty),+) => {
+        $(
+            impl LengthPrefix for $ty {
+                #[inline]
+                fn from_len(len: usize) -> Option<Self> {
+                    <$ty>::try_from(len).ok()
+                }
+
+                #[inline]
+                fn to_len(&self) -> Option<usize> {
+                    usize::try_from(*self).ok()
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_length_prefix_endian {
+    ($($ty:ty),+) => {
+        $(
+            impl LengthPrefix for BigEndian<$ty> {
+                #[inline]
+                fn from_len(len: usize) -> Option<Self> {
+                    <$ty>::from_len(len).map(BigEndian::new)
+                }
+
+                #[inline]
+                fn to_len(&self) -> Option<usize> {
+                    self.get().to_len()
+                }
+            }
+
+            impl LengthPrefix for LittleEndian<$ty> {
+                #[inline]
+                fn from_len(len: usize) -> Option<Self> {
+                    <$ty>::from_len(len).map(LittleEndian::new)
+                }
+
+                #[inline]
+                fn to_len(&self) -> Option<usize> {
+                    self.get().to_len()
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_length_prefix_fixed_usize {
+    ($($ty:ty),+) => {
+        $(
+            impl LengthPrefix for $ty {
+                #[inline]
+                fn from_len(len: usize) -> Option<Self> {
+                    <$ty>::new(len)
+                }
+
+                #[inline]
+                fn to_len(&self) -> Option<usize> {
+                    self.get()
+                }
+            }
+        )+
+    };
+}
+
+impl_length_prefix!(u8, u16, u32, u64);
+impl_length_prefix_endian!(u16, u32, u64);
+impl_length_prefix_fixed_usize!(Usize32, Usize64);
+impl_length_prefix_endian!(Usize32, Usize64);
+
 /// Deserialize a fixed-size value from a [`Read`] source.
 ///
 /// A blanket impl covers all types that implement [`TryFromRawRepr`]: it allocates a
@@ -149,6 +185,12 @@ pub trait FixedReadable: Sized {
 impl<T: TryFromRawRepr> FixedReadable for T {
     #[inline]
     fn read_fixed_from(reader: &mut (impl Read + ?Sized)) -> Result<Self, ReadableError> {
+        // The buffer must be initialized: `Read` implementations may read from the slice they
+        // are given, so handing them uninitialized memory is undefined behaviour. Reading into
+        // a `MaybeUninit` needs `Read::read_buf`, which is not yet stable. The zeroing is a
+        // single `memset` that the `read_fixed_array_4096` benchmark already covers.
+        // The buffer stays on the stack even for large arrays: the decoded value is returned
+        // there anyway, so a heap buffer would add an allocation to save a single copy.
         let mut b = T::Raw::zeroed();
         reader.read_exact(b.as_bytes_mut())?;
         let r = T::try_from_raw(b)?;
@@ -253,16 +295,449 @@ pub trait ReadFixed: Read {
         T::read_fixed_from(self)
     }
 
+    /// Read a [`FixedReadable`] value into an existing `dst`, overwriting it.
+    ///
+    /// Useful in receive loops that reuse one value instead of binding a fresh one each
+    /// iteration. The bytes are read into a stack buffer and decoded before `dst` is touched,
+    /// so on any error (including a partial read) `dst` is left unchanged.
+    ///
+    /// ```rust
+    /// use byteable::io::ReadFixed;
+    /// use std::io::Cursor;
+    ///
+    /// let mut cursor = Cursor::new([1u8, 0, 2, 0, 3]);
+    /// let mut value = 0u16;
+    /// cursor.read_fixed_into(&mut value).unwrap();
+    /// assert_eq!(value, 1);
+    /// cursor.read_fixed_into(&mut value).unwrap();
+    /// assert_eq!(value, 2);
+    ///
+    /// // Only one byte left: the read fails and `value` keeps its last contents.
+    /// assert!(cursor.read_fixed_into(&mut value).is_err());
+    /// assert_eq!(value, 2);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReadableError`] on I/O failure or decode error.
+    #[inline]
+    fn read_fixed_into<T: FixedReadable>(&mut self, dst: &mut T) -> Result<(), ReadableError> {
+        *dst = T::read_fixed_from(self)?;
+        Ok(())
+    }
+
+    /// Read a little-endian `T` without naming the [`LittleEndian`] wrapper.
+    ///
+    /// Equivalent to `read_fixed::<LittleEndian<T>>()` followed by
+    /// [`get`](LittleEndian::get), for quick ad-hoc parsing of integers and floats.
+    ///
+    /// ```rust
+    /// use byteable::io::ReadFixed;
+    /// use std::io::Cursor;
+    ///
+    /// let mut cursor = Cursor::new([0x34, 0x12, 0x00, 0x00, 0x80, 0x3F]);
+    /// assert_eq!(cursor.read_le::<u16>().unwrap(), 0x1234);
+    /// assert_eq!(cursor.read_le::<f32>().unwrap(), 1.0);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`io::Error`] if the reader fails or ends early.
+    #[inline]
+    fn read_le<T: EndianConvert>(&mut self) -> io::Result<T> {
+        Ok(self.read_fixed::<LittleEndian<T>>()?.get())
+    }
+
+    /// Read a big-endian `T` without naming the [`BigEndian`] wrapper.
+    ///
+    /// Equivalent to `read_fixed::<BigEndian<T>>()` followed by [`get`](BigEndian::get).
+    ///
+    /// ```rust
+    /// use byteable::io::ReadFixed;
+    /// use std::io::Cursor;
+    ///
+    /// let mut cursor = Cursor::new([0x12, 0x34, 0xFF, 0xFF, 0xFF, 0xFE]);
+    /// assert_eq!(cursor.read_be::<u16>().unwrap(), 0x1234);
+    /// assert_eq!(cursor.read_be::<i32>().unwrap(), -2);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`io::Error`] if the reader fails or ends early.
+    #[inline]
+    fn read_be<T: EndianConvert>(&mut self) -> io::Result<T> {
+        Ok(self.read_fixed::<BigEndian<T>>()?.get())
+    }
+
     #[inline]
     fn read_fixed_counted<T: FixedReadable>(&mut self) -> Result<(T, usize), ReadableError> {
         let mut reader = CountingReader::new(self);
         let v = reader.read_fixed()?;
-        Ok((v, reader.count()))
+        Ok((v, reader.position() as usize))
+    }
+
+    /// Read a fixed-size value and also return the exact bytes it was decoded from.
+    ///
+    /// The bytes are read once and kept, so there is no need to re-serialize the value to
+    /// recover them (e.g. for audit logs or checksums).
+    ///
+    /// ```rust
+    /// use byteable::{ByteArray, io::ReadFixed};
+    /// use std::io::Cursor;
+    ///
+    /// let mut cursor = Cursor::new([0x2A, 0, 0, 0]);
+    /// let (value, bytes) = cursor.read_fixed_with_bytes::<u32>().unwrap();
+    /// assert_eq!(value, 42);
+    /// assert_eq!(bytes.as_byte_slice(), &[0x2A, 0, 0, 0]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReadableError`] on I/O failure or decode error.
+    #[inline]
+    fn read_fixed_with_bytes<T: TryFromByteArray>(
+        &mut self,
+    ) -> Result<(T, T::ByteArray), ReadableError> {
+        // SAFETY: a `ByteArray` consists solely of `u8`s, so all-zeros is a valid value.
+        let mut bytes: T::ByteArray = unsafe { core::mem::zeroed() };
+        self.read_exact(bytes.as_byte_slice_mut())?;
+        let value = T::try_from_byte_array(bytes)?;
+        Ok((value, bytes))
+    }
+
+    /// Read fixed-size values until the reader is exhausted.
+    ///
+    /// End of input on a record boundary ends the stream. End of input in the middle of a
+    /// record is an error: the trailing bytes do not form a complete value. Zero-sized types
+    /// yield an empty `Vec`.
+    ///
+    /// ```rust
+    /// use byteable::io::ReadFixed;
+    /// use std::io::Cursor;
+    ///
+    /// let values: Vec<u16> = Cursor::new([1u8, 0, 2, 0]).read_fixed_until_eof().unwrap();
+    /// assert_eq!(values, [1, 2]);
+    ///
+    /// // Three bytes are one and a half `u16`s.
+    /// assert!(Cursor::new([1u8, 0, 2]).read_fixed_until_eof::<u16>().is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReadableError::Io`] with [`io::ErrorKind::UnexpectedEof`] if the input ends
+    /// mid-record, any other I/O error from the reader, or [`ReadableError::DecodeError`] if a
+    /// record fails to decode.
+    fn read_fixed_until_eof<T: TryFromRawRepr>(&mut self) -> Result<Vec<T>, ReadableError> {
+        let mut values = Vec::new();
+        while let Some(value) = read_record(self)? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+
+    /// Turn this reader into an iterator over its fixed-size records.
+    ///
+    /// The lazy counterpart of [`read_fixed_until_eof`](ReadFixed::read_fixed_until_eof):
+    /// each `next` reads one record, so a large file can be filtered or folded without
+    /// holding it in memory. End of input on a record boundary ends the iteration; an I/O
+    /// error, a record cut short by the end of input or a record that fails to decode is
+    /// yielded as `Some(Err(..))`, after which the iterator is exhausted.
+    ///
+    /// ```rust
+    /// use byteable::{BigEndian, ReadFixed};
+    /// use std::io::Cursor;
+    ///
+    /// let reader = Cursor::new([0, 1, 0, 2, 0, 3]);
+    /// let sum: u16 = reader
+    ///     .byteable_iter::<BigEndian<u16>>()
+    ///     .map(|value| value.map(BigEndian::get))
+    ///     .sum::<Result<_, _>>()
+    ///     .unwrap();
+    /// assert_eq!(sum, 6);
+    /// ```
+    #[inline]
+    fn byteable_iter<T: TryFromRawRepr>(self) -> ByteableIter<Self, T>
+    where
+        Self: Sized,
+    {
+        ByteableIter::new(self)
+    }
+
+    /// Read `n` fixed-size values, refusing up front if they would take more than
+    /// `max_total_bytes`.
+    ///
+    /// Use this when `n` comes from untrusted input, such as a length field parsed earlier:
+    /// the size is checked before anything is allocated or read, so a corrupt or malicious
+    /// count cannot trigger a huge allocation.
+    ///
+    /// ```rust
+    /// use byteable::{BigEndian, io::ReadFixed};
+    /// use std::io::{Cursor, ErrorKind};
+    ///
+    /// let mut cursor = Cursor::new([0, 1, 0, 2]);
+    /// let values: Vec<BigEndian<u16>> = cursor.read_fixed_n_limited(2, 4).unwrap();
+    /// assert_eq!(values, [BigEndian::new(1), BigEndian::new(2)]);
+    ///
+    /// let err = Cursor::new([]).read_fixed_n_limited::<u32>(1 << 40, 4096).unwrap_err();
+    /// assert_eq!(std::io::Error::from(err).kind(), ErrorKind::InvalidData);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReadableError::Io`] with [`io::ErrorKind::InvalidData`] if `n` values would
+    /// exceed `max_total_bytes`, and otherwise [`ReadableError`] on I/O failure or decode
+    /// error.
+    fn read_fixed_n_limited<T: TryFromRawRepr>(
+        &mut self,
+        n: usize,
+        max_total_bytes: usize,
+    ) -> Result<Vec<T>, ReadableError> {
+        match n.checked_mul(size_of::<T::Raw>()) {
+            Some(total) if total <= max_total_bytes => {}
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "{n} records of {} bytes exceed the limit of {max_total_bytes} bytes",
+                        size_of::<T::Raw>()
+                    ),
+                )
+                .into());
+            }
+        }
+        let mut values = Vec::with_capacity(n);
+        for _ in 0..n {
+            values.push(self.read_fixed()?);
+        }
+        Ok(values)
+    }
+
+    /// Read an element count of type `L`, then that many fixed-size values.
+    ///
+    /// The count is checked against `max` before anything is allocated, so a corrupt or
+    /// malicious prefix cannot trigger a huge allocation.
+    ///
+    /// ```rust
+    /// use byteable::{BigEndian, io::ReadFixed};
+    /// use std::io::Cursor;
+    ///
+    /// let mut cursor = Cursor::new([0, 0, 0, 2, 7, 9]);
+    /// let values: Vec<u8> = cursor.read_fixed_prefixed::<BigEndian<u32>, _>(16).unwrap();
+    /// assert_eq!(values, [7, 9]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReadableError::Io`] with [`io::ErrorKind::InvalidData`] if the count exceeds
+    /// `max` (or `usize`), and otherwise [`ReadableError`] on I/O failure or decode error.
+    fn read_fixed_prefixed<L: LengthPrefix, T: FixedReadable>(
+        &mut self,
+        max: usize,
+    ) -> Result<Vec<T>, ReadableError> {
+        let len = match self.read_fixed::<L>()?.to_len() {
+            Some(len) if len <= max => len,
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("length prefix exceeds the maximum of {max} elements"),
+                )
+                .into());
+            }
+        };
+        let mut values = Vec::with_capacity(len);
+        for _ in 0..len {
+            values.push(self.read_fixed()?);
+        }
+        Ok(values)
     }
 }
 
 impl<T: Read + ?Sized> ReadFixed for T {}
 
+/// Reads one record, or returns `None` if the input ends before its first byte.
+///
+/// Zero-sized records would never consume input, so they end the stream immediately.
+fn read_record<T: TryFromRawRepr>(
+    reader: &mut (impl Read + ?Sized),
+) -> Result<Option<T>, ReadableError> {
+    if size_of::<T::Raw>() == 0 {
+        return Ok(None);
+    }
+    let mut raw = T::Raw::zeroed();
+    let buf = raw.as_bytes_mut();
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    if filled == 0 {
+        return Ok(None);
+    }
+    if filled < buf.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            format!(
+                "input ended {filled} bytes into a {}-byte record",
+                buf.len()
+            ),
+        )
+        .into());
+    }
+    Ok(Some(T::try_from_raw(raw)?))
+}
+
+/// An iterator over the fixed-size records of a reader, returned by
+/// [`ReadFixed::byteable_iter`].
+///
+/// Yields `Ok(T)` for each record until the input ends on a record boundary. The first
+/// error, including input that ends mid-record, is yielded once and then the iterator is
+/// exhausted, so it never loops on a reader that keeps failing.
+///
+/// ```rust
+/// use byteable::{Byteable, ReadFixed, WriteFixed};
+///
+/// #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+/// #[byteable(little_endian)]
+/// struct Record {
+///     id: u16,
+///     level: u8,
+/// }
+///
+/// let mut log = Vec::new();
+/// for (id, level) in [(1, 3), (2, 9), (3, 7)] {
+///     log.write_fixed(&Record { id, level }).unwrap();
+/// }
+///
+/// let loud: Vec<u16> = log
+///     .as_slice()
+///     .byteable_iter::<Record>()
+///     .filter_map(Result::ok)
+///     .filter(|record| record.level > 5)
+///     .map(|record| record.id)
+///     .collect();
+/// assert_eq!(loud, [2, 3]);
+/// ```
+pub struct ByteableIter<R, T> {
+    reader: R,
+    done: bool,
+    _record: PhantomData<fn() -> T>,
+}
+
+impl<R, T> ByteableIter<R, T> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            done: false,
+            _record: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Returns a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    /// Unwraps the reader, positioned after the last record read.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: fmt::Debug, T> fmt::Debug for ByteableIter<R, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ByteableIter")
+            .field("reader", &self.reader)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<R: Read, T: TryFromRawRepr> Iterator for ByteableIter<R, T> {
+    type Item = Result<T, ReadableError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match read_record(&mut self.reader) {
+            Ok(Some(value)) => Some(Ok(value)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl<R: Read, T: TryFromRawRepr> FusedIterator for ByteableIter<R, T> {}
+
+/// Extension trait for random access to files of fixed-size records.
+///
+/// Automatically implemented for every `R: Read + Seek`. Both methods seek to an absolute
+/// position and leave the reader just past the record they read, so a sequential
+/// [`read_fixed`](ReadFixed::read_fixed) continues with the next record.
+///
+/// ```rust
+/// use byteable::{BigEndian, SeekReadFixed, WriteFixed};
+/// use std::io::Cursor;
+///
+/// let mut file = Cursor::new(Vec::new());
+/// for id in 0..10u32 {
+///     file.write_fixed(&BigEndian::new(id * 100)).unwrap();
+/// }
+/// let seventh: BigEndian<u32> = file.read_fixed_index(7).unwrap();
+/// assert_eq!(seventh.get(), 700);
+/// let second: BigEndian<u32> = file.read_fixed_at(8).unwrap();
+/// assert_eq!(second.get(), 200);
+/// ```
+pub trait SeekReadFixed: Read + Seek {
+    /// Seek to the absolute byte `offset`, then read a fixed-size value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReadableError`] if seeking or reading fails, or the bytes do not encode a
+    /// valid `T`.
+    fn read_fixed_at<T: FixedReadable>(&mut self, offset: u64) -> Result<T, ReadableError> {
+        self.seek(io::SeekFrom::Start(offset))?;
+        self.read_fixed()
+    }
+
+    /// Read record number `index` of a file that is a plain array of `T`, i.e. the value at
+    /// byte offset `index * T::BYTE_SIZE`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReadableError::Io`] with [`io::ErrorKind::InvalidInput`] if the offset
+    /// overflows `u64`, and otherwise [`ReadableError`] like
+    /// [`read_fixed_at`](Self::read_fixed_at).
+    fn read_fixed_index<T: TryFromRawRepr>(&mut self, index: u64) -> Result<T, ReadableError> {
+        let offset = index
+            .checked_mul(size_of::<T::Raw>() as u64)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("record index {index} is past the end of any file"),
+                )
+            })?;
+        self.read_fixed_at(offset)
+    }
+}
+
+impl<T: Read + Seek + ?Sized> SeekReadFixed for T {}
+
 /// Extension trait that adds [`read_value`](ReadValue::read_value) to any [`Read`] impl.
 ///
 /// Automatically implemented for all `T: Read`. Use this to read variable-length or
@@ -294,7 +769,7 @@ pub trait ReadValue: Read {
     fn read_value_counted<T: Readable>(&mut self) -> Result<(T, usize), ReadableError> {
         let mut reader = CountingReader::new(self);
         let v = reader.read_value()?;
-        Ok((v, reader.count()))
+        Ok((v, reader.position() as usize))
     }
 }
 
@@ -314,16 +789,122 @@ pub trait WriteFixed: Write {
         val.write_fixed_to(self)
     }
 
+    /// Write a [`FixedWritable`] value and return the number of bytes written.
+    ///
+    /// For types using the blanket impl this is always `size_of::<T::Raw>()`, which makes it
+    /// convenient for tracking record offsets while building a seekable file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::Error`] if writing fails.
     #[inline]
     fn write_fixed_counted(&mut self, val: &impl FixedWritable) -> io::Result<usize> {
         let mut w = CountingWriter::new(self);
         w.write_fixed(val)?;
-        Ok(w.count())
+        Ok(w.position() as usize)
+    }
+
+    /// Write every item of `iter` in turn and return the total number of bytes written.
+    ///
+    /// Items are written as they are produced, so a large export never has to be collected
+    /// into memory first. Writing stops at the first error.
+    ///
+    /// ```rust
+    /// use byteable::{BigEndian, io::WriteFixed};
+    ///
+    /// let mut buf = Vec::new();
+    /// let written = buf.write_fixed_iter((1..=3u16).map(BigEndian::new)).unwrap();
+    /// assert_eq!(written, 6);
+    /// assert_eq!(buf, [0, 1, 0, 2, 0, 3]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::Error`] if writing any item fails; earlier items have been written.
+    fn write_fixed_iter<T: FixedWritable>(
+        &mut self,
+        iter: impl IntoIterator<Item = T>,
+    ) -> io::Result<usize> {
+        let mut w = CountingWriter::new(self);
+        for item in iter {
+            w.write_fixed(&item)?;
+        }
+        Ok(w.position() as usize)
+    }
+
+    /// Write every item of `items` with as few calls to the underlying writer as possible.
+    ///
+    /// The items are serialized into a buffer of up to 8 KiB, which is then written in one
+    /// `write_all`. Writing many small values to an unbuffered socket or file this way costs
+    /// one system call per buffer instead of one per value.
+    ///
+    /// ```rust
+    /// use byteable::{BigEndian, io::WriteFixed};
+    ///
+    /// let mut buf = Vec::new();
+    /// buf.write_fixed_all(&[BigEndian::new(1u16), BigEndian::new(2)]).unwrap();
+    /// assert_eq!(buf, [0, 1, 0, 2]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::Error`] if writing fails. Earlier buffers may already have been written.
+    fn write_fixed_all<T: RawRepr>(&mut self, items: &[T]) -> io::Result<()> {
+        let mut buf = Vec::new();
+        for chunk in items.chunks(coalesced_chunk_len::<T>()) {
+            fill_coalesced(&mut buf, chunk);
+            self.write_all(&buf)?;
+        }
+        Ok(())
+    }
+
+    /// Write `items.len()` as an element count of type `L`, then each item.
+    ///
+    /// ```rust
+    /// use byteable::{BigEndian, io::WriteFixed};
+    ///
+    /// let mut buf = Vec::new();
+    /// buf.write_fixed_prefixed::<BigEndian<u32>, u8>(&[7, 9]).unwrap();
+    /// assert_eq!(buf, [0, 0, 0, 2, 7, 9]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::ErrorKind::InvalidInput`] if `items.len()` does not fit in `L`, and
+    /// otherwise any error from writing.
+    fn write_fixed_prefixed<L: LengthPrefix, T: FixedWritable>(
+        &mut self,
+        items: &[T],
+    ) -> io::Result<()> {
+        let len = L::from_len(items.len()).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} elements do not fit in the length prefix", items.len()),
+            )
+        })?;
+        self.write_fixed(&len)?;
+        for item in items {
+            self.write_fixed(item)?;
+        }
+        Ok(())
     }
 }
 
 impl<T: Write> WriteFixed for T {}
 
+/// The number of values of `T` that [`WriteFixed::write_fixed_all`] serializes per write.
+pub(crate) fn coalesced_chunk_len<T: RawRepr>() -> usize {
+    (8 * 1024 / size_of::<T::Raw>().max(1)).max(1)
+}
+
+/// Replaces the contents of `buf` with the serialized `items`.
+pub(crate) fn fill_coalesced<T: RawRepr>(buf: &mut Vec<u8>, items: &[T]) {
+    buf.clear();
+    for item in items {
+        buf.extend_from_slice(item.to_raw().as_bytes());
+    }
+}
+
 /// Extension trait that adds [`write_value`](WriteValue::write_value) to any [`Write`] impl.
 ///
 /// Automatically implemented for all `T: Write`. Use this to write variable-length or
@@ -349,12 +930,596 @@ pub trait WriteValue: Write {
         data.write_to(self)
     }
 
+    /// Write a [`Writable`] value and return the number of bytes written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::Error`] if writing fails.
     #[inline]
     fn write_value_counted<T: Writable + ?Sized>(&mut self, data: &T) -> io::Result<usize> {
         let mut w = CountingWriter::new(self);
         w.write_value(data)?;
-        Ok(w.count())
+        Ok(w.position() as usize)
     }
 }
 
 impl<T: Write> WriteValue for T {}
+
+/// A writer that records the start offset of every value written through it.
+///
+/// Each call to [`write_fixed`](IndexingWriter::write_fixed) or
+/// [`write_value`](IndexingWriter::write_value) first records the current stream position,
+/// so after writing a sequence of records [`offsets`](IndexingWriter::offsets) is a sidecar
+/// index for random access (e.g. with [`Seek::seek`] and [`ReadFixed::read_fixed`]).
+///
+/// `IndexingWriter` deliberately does not implement [`Write`]: bytes written around it would
+/// not be indexed. Use [`get_mut`](IndexingWriter::get_mut) for unindexed writes.
+///
+/// ```rust
+/// use byteable::{BigEndian, IndexingWriter};
+/// use std::io::Cursor;
+///
+/// let mut writer = IndexingWriter::new(Cursor::new(Vec::new()));
+/// writer.write_fixed(&BigEndian::new(1u32)).unwrap();
+/// writer.write_value("two").unwrap();
+/// writer.write_fixed(&3u8).unwrap();
+/// // `"two"` is an 8-byte length followed by 3 bytes.
+/// assert_eq!(writer.offsets(), &[0, 4, 15]);
+/// ```
+#[derive(Debug)]
+pub struct IndexingWriter<W: Write + Seek> {
+    inner: W,
+    offsets: Vec<u64>,
+}
+
+impl<W: Write + Seek> IndexingWriter<W> {
+    /// Wraps `inner`. Offsets are absolute stream positions, so writing may start anywhere.
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            offsets: Vec::new(),
+        }
+    }
+
+    /// Records the current position, then writes a [`FixedWritable`] value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::Error`] if querying the position or writing fails. The offset is only
+    /// recorded if the write succeeds.
+    pub fn write_fixed(&mut self, val: &impl FixedWritable) -> io::Result<()> {
+        let offset = self.inner.stream_position()?;
+        self.inner.write_fixed(val)?;
+        self.offsets.push(offset);
+        Ok(())
+    }
+
+    /// Records the current position, then writes a [`Writable`] value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::Error`] if querying the position or writing fails. The offset is only
+    /// recorded if the write succeeds.
+    pub fn write_value<T: Writable + ?Sized>(&mut self, data: &T) -> io::Result<()> {
+        let offset = self.inner.stream_position()?;
+        self.inner.write_value(data)?;
+        self.offsets.push(offset);
+        Ok(())
+    }
+
+    /// The start offsets of the values written so far, in write order.
+    pub fn offsets(&self) -> &[u64] {
+        &self.offsets
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying writer. Writes made through it are not
+    /// indexed.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwraps the writer, returning it together with the recorded offsets.
+    pub fn into_inner(self) -> (W, Vec<u64>) {
+        (self.inner, self.offsets)
+    }
+}
+
+/// A reader that counts the bytes read through it.
+///
+/// Formats with offset fields, or error messages that should name where decoding failed, need
+/// the current position in the stream. [`Seek::stream_position`] only works on seekable
+/// sources; `CountingReader` works on any [`Read`], such as a socket or a pipe.
+///
+/// ```rust
+/// use byteable::{BigEndian, CountingReader, ReadFixed, ReadValue};
+///
+/// let data = [0u8, 7, 2, 0, 0, 0, 0, 0, 0, 0, b'h', b'i'];
+/// let mut reader = CountingReader::new(&data[..]);
+/// assert_eq!(reader.read_fixed::<BigEndian<u16>>().unwrap().get(), 7);
+/// assert_eq!(reader.position(), 2);
+/// assert_eq!(reader.read_value::<String>().unwrap(), "hi");
+/// assert_eq!(reader.position(), 12);
+/// ```
+#[derive(Debug)]
+pub struct CountingReader<R> {
+    inner: R,
+    position: u64,
+}
+
+impl<R> CountingReader<R> {
+    /// Wraps `inner`, starting the count at zero.
+    pub fn new(inner: R) -> Self {
+        Self { inner, position: 0 }
+    }
+
+    /// The number of bytes read so far.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying reader. Bytes read through it are not
+    /// counted.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps the reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    fn advance(&mut self, n: usize) {
+        self.position += n as u64;
+    }
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.advance(n);
+        Ok(n)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [io::IoSliceMut<'_>]) -> io::Result<usize> {
+        let n = self.inner.read_vectored(bufs)?;
+        self.advance(n);
+        Ok(n)
+    }
+
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        let n = self.inner.read_to_end(buf)?;
+        self.advance(n);
+        Ok(n)
+    }
+
+    fn read_to_string(&mut self, buf: &mut String) -> io::Result<usize> {
+        let n = self.inner.read_to_string(buf)?;
+        self.advance(n);
+        Ok(n)
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        self.inner.read_exact(buf)?;
+        self.advance(buf.len());
+        Ok(())
+    }
+}
+
+/// A writer that counts the bytes written through it.
+///
+/// When a format stores offsets to data written later, the writer needs to know where each
+/// piece lands. [`Seek::stream_position`] only works on seekable sinks; `CountingWriter`
+/// works on any [`Write`], such as a socket or a compressor.
+///
+/// ```rust
+/// use byteable::{BigEndian, CountingWriter, WriteFixed, WriteValue};
+///
+/// let mut writer = CountingWriter::new(Vec::new());
+/// writer.write_fixed(&BigEndian::new(1u32)).unwrap();
+/// let name_offset = writer.position();
+/// writer.write_value("name").unwrap();
+/// assert_eq!(name_offset, 4);
+/// assert_eq!(writer.position(), 16);
+/// assert_eq!(writer.into_inner().len(), 16);
+/// ```
+#[derive(Debug)]
+pub struct CountingWriter<W> {
+    inner: W,
+    position: u64,
+}
+
+impl<W> CountingWriter<W> {
+    /// Wraps `inner`, starting the count at zero.
+    pub fn new(inner: W) -> Self {
+        Self { inner, position: 0 }
+    }
+
+    /// The number of bytes written so far.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying writer. Bytes written through it are not
+    /// counted.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwraps the writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    fn advance(&mut self, n: usize) {
+        self.position += n as u64;
+    }
+}
+
+impl<W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.advance(n);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+
+    fn write_vectored(&mut self, bufs: &[io::IoSlice<'_>]) -> io::Result<usize> {
+        let n = self.inner.write_vectored(bufs)?;
+        self.advance(n);
+        Ok(n)
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.inner.write_all(buf)?;
+        self.advance(buf.len());
+        Ok(())
+    }
+}
+
+/// A reader over an in-memory buffer that chops each value off the front.
+///
+/// `&[u8]` already implements [`Read`] by advancing itself, but once it is threaded through a
+/// parser there is no cheap way to ask what is left. `SliceReader` keeps the unconsumed tail
+/// in view: [`remaining`](SliceReader::remaining) borrows it for the buffer's lifetime, so a
+/// header can be parsed here and the rest handed to another subsystem without
+/// [`Cursor::position`](std::io::Cursor::position) arithmetic.
+///
+/// `SliceReader` implements [`Read`], [`BufRead`](io::BufRead) and [`PeekFixed`], so
+/// [`ReadFixed`] and [`ReadValue`] work on it as well. A failed read may consume part of the
+/// value it was decoding, like any other reader.
+///
+/// ```rust
+/// use byteable::{BigEndian, ReadValue, SliceReader};
+///
+/// let packet = [0, 2, 0xAB, 0xCD, 0xEF];
+/// let mut reader = SliceReader::new(&packet);
+/// let kind: BigEndian<u16> = reader.read_value().unwrap();
+/// assert_eq!(kind.get(), 2);
+///
+/// let payload: &[u8] = reader.remaining();
+/// assert_eq!(payload, [0xAB, 0xCD, 0xEF]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SliceReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> SliceReader<'a> {
+    /// Reads from the start of `data`.
+    #[inline]
+    pub const fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// Returns the bytes that have not been read yet.
+    #[inline]
+    pub const fn remaining(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Returns the number of bytes that have not been read yet.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` once every byte has been read.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+impl<'a> From<&'a [u8]> for SliceReader<'a> {
+    #[inline]
+    fn from(data: &'a [u8]) -> Self {
+        Self::new(data)
+    }
+}
+
+impl Read for SliceReader<'_> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.data.read(buf)
+    }
+
+    #[inline]
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        self.data.read_exact(buf)
+    }
+
+    #[inline]
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        self.data.read_to_end(buf)
+    }
+}
+
+impl io::BufRead for SliceReader<'_> {
+    #[inline]
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        Ok(self.data)
+    }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) {
+        self.data = &self.data[amt.min(self.data.len())..];
+    }
+}
+
+/// A buffering reader tuned for decoding many small values.
+///
+/// Every [`read_fixed`](ReadFixed::read_fixed) on an unbuffered reader such as
+/// [`std::fs::File`] ends in its own `read_exact`, which is one system call per field.
+/// `ByteableReader` keeps a refill buffer in front of the inner reader: small reads are served
+/// straight from it, and the inner reader is only touched when the buffer runs dry. Reads
+/// larger than the buffer bypass it.
+///
+/// `ByteableReader` implements [`Read`] and [`BufRead`](io::BufRead) (and [`Seek`] when `R`
+/// does), so [`ReadFixed`], [`ReadValue`] and every [`Readable`] type work on it unchanged.
+/// Unlike [`std::io::BufReader`], it also implements [`PeekFixed`], topping up its buffer so a
+/// value can be inspected before it is consumed. Readers that are already in memory (`&[u8]`,
+/// [`Cursor`](std::io::Cursor)) gain nothing from it.
+///
+/// ```rust
+/// use byteable::{BigEndian, ByteableReader, ReadFixed, ReadValue};
+///
+/// let data = [0u8, 0, 0, 7, 2, 0, 0, 0, 0, 0, 0, 0, b'h', b'i'];
+/// let mut reader = ByteableReader::new(&data[..]);
+/// assert_eq!(reader.read_fixed::<BigEndian<u32>>().unwrap().get(), 7);
+/// assert_eq!(reader.read_value::<String>().unwrap(), "hi");
+/// ```
+pub struct ByteableReader<R> {
+    inner: R,
+    buf: Vec<u8>,
+    pos: usize,
+    filled: usize,
+}
+
+impl<R: Read> ByteableReader<R> {
+    /// Wraps `inner` with a buffer of the default capacity (currently 8 KiB).
+    pub fn new(inner: R) -> Self {
+        Self::with_capacity(8 * 1024, inner)
+    }
+
+    /// Wraps `inner` with a buffer of `capacity` bytes. A `capacity` of `0` is raised to `1`,
+    /// since [`fill_buf`](io::BufRead::fill_buf) would otherwise always report end of input.
+    pub fn with_capacity(capacity: usize, inner: R) -> Self {
+        Self {
+            inner,
+            buf: vec![0; capacity.max(1)],
+            pos: 0,
+            filled: 0,
+        }
+    }
+}
+
+impl<R> ByteableReader<R> {
+    /// The bytes read from the inner reader but not yet consumed.
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf[self.pos..self.filled]
+    }
+
+    /// The capacity of the internal buffer.
+    ///
+    /// Peeking a value larger than the buffer grows it to fit.
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying reader. Reading from it directly skips
+    /// any bytes still held in the buffer.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps the reader. Any buffered bytes are lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    fn discard_buffer(&mut self) {
+        self.pos = 0;
+        self.filled = 0;
+    }
+}
+
+impl<R: fmt::Debug> fmt::Debug for ByteableReader<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ByteableReader")
+            .field("reader", &self.inner)
+            .field(
+                "buffer",
+                &format_args!("{}/{}", self.filled - self.pos, self.buf.len()),
+            )
+            .finish()
+    }
+}
+
+impl<R: Read> Read for ByteableReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        // Nothing buffered and a large request: skip the copy through our buffer.
+        if self.pos == self.filled && buf.len() >= self.buf.len() {
+            self.discard_buffer();
+            return self.inner.read(buf);
+        }
+        let available = io::BufRead::fill_buf(self)?;
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        io::BufRead::consume(self, n);
+        Ok(n)
+    }
+}
+
+impl<R: Read> io::BufRead for ByteableReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.pos >= self.filled {
+            self.filled = self.inner.read(&mut self.buf)?;
+            self.pos = 0;
+        }
+        Ok(self.buffer())
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos = (self.pos + amt).min(self.filled);
+    }
+}
+
+impl<R: Read + Seek> Seek for ByteableReader<R> {
+    /// Seeks the underlying reader, discarding the buffer. Positions are relative to the
+    /// logical stream, i.e. they account for bytes that were buffered but not yet consumed.
+    fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+        let remainder = (self.filled - self.pos) as i64;
+        let result = match pos {
+            io::SeekFrom::Current(offset) => match offset.checked_sub(remainder) {
+                Some(offset) => self.inner.seek(io::SeekFrom::Current(offset))?,
+                None => {
+                    self.inner.seek(io::SeekFrom::Current(-remainder))?;
+                    self.discard_buffer();
+                    self.inner.seek(io::SeekFrom::Current(offset))?
+                }
+            },
+            pos => self.inner.seek(pos)?,
+        };
+        self.discard_buffer();
+        Ok(result)
+    }
+
+    fn stream_position(&mut self) -> io::Result<u64> {
+        let remainder = (self.filled - self.pos) as u64;
+        Ok(self.inner.stream_position()? - remainder)
+    }
+}
+
+/// Inspect the next fixed-size value of a reader without consuming it.
+///
+/// Useful for dispatchers that read a message tag, pick a message type, then read the whole
+/// message including the tag. Implemented for in-memory readers (`&[u8]`, [`SliceReader`] and
+/// [`Cursor`](std::io::Cursor)) and for [`ByteableReader`], which refills its buffer as
+/// needed; other readers can be wrapped in a `ByteableReader` to gain it.
+///
+/// ```rust
+/// use byteable::{BigEndian, PeekFixed, ReadFixed};
+/// use std::io::Cursor;
+///
+/// let mut cursor = Cursor::new([0, 2, 0xAB, 0xCD]);
+/// let tag: BigEndian<u16> = cursor.peek_fixed().unwrap();
+/// assert_eq!(tag.get(), 2);
+/// // The tag is still there.
+/// assert_eq!(cursor.read_fixed::<[u8; 4]>().unwrap(), [0, 2, 0xAB, 0xCD]);
+/// ```
+pub trait PeekFixed {
+    /// Returns the next `len` bytes without consuming them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::ErrorKind::UnexpectedEof`] if fewer than `len` bytes remain, or any I/O
+    /// error from the underlying reader.
+    fn peek_bytes(&mut self, len: usize) -> io::Result<&[u8]>;
+
+    /// Decodes the next fixed-size value without consuming it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ReadableError::Io`] if not enough bytes are available, or
+    /// [`ReadableError::DecodeError`] if the bytes do not encode a valid `T`.
+    fn peek_fixed<T: TryFromRawRepr>(&mut self) -> Result<T, ReadableError> {
+        let mut raw = T::Raw::zeroed();
+        raw.as_bytes_mut()
+            .copy_from_slice(self.peek_bytes(size_of::<T::Raw>())?);
+        Ok(T::try_from_raw(raw)?)
+    }
+}
+
+fn peek_eof(len: usize, available: usize) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::UnexpectedEof,
+        format!("cannot peek {len} bytes, only {available} remain"),
+    )
+}
+
+impl PeekFixed for &[u8] {
+    fn peek_bytes(&mut self, len: usize) -> io::Result<&[u8]> {
+        self.get(..len).ok_or_else(|| peek_eof(len, self.len()))
+    }
+}
+
+impl PeekFixed for SliceReader<'_> {
+    fn peek_bytes(&mut self, len: usize) -> io::Result<&[u8]> {
+        self.data.peek_bytes(len)
+    }
+}
+
+impl<T: AsRef<[u8]>> PeekFixed for io::Cursor<T> {
+    fn peek_bytes(&mut self, len: usize) -> io::Result<&[u8]> {
+        let data = self.get_ref().as_ref();
+        let start = usize::try_from(self.position()).map_or(data.len(), |p| p.min(data.len()));
+        let rest = &data[start..];
+        rest.get(..len).ok_or_else(|| peek_eof(len, rest.len()))
+    }
+}
+
+impl<R: Read> PeekFixed for ByteableReader<R> {
+    fn peek_bytes(&mut self, len: usize) -> io::Result<&[u8]> {
+        if self.filled - self.pos < len {
+            // Move the unread bytes to the front, then top up from the inner reader.
+            self.buf.copy_within(self.pos..self.filled, 0);
+            self.filled -= self.pos;
+            self.pos = 0;
+            if self.buf.len() < len {
+                self.buf.resize(len, 0);
+            }
+            while self.filled < len {
+                match self.inner.read(&mut self.buf[self.filled..]) {
+                    Ok(0) => return Err(peek_eof(len, self.filled)),
+                    Ok(n) => self.filled += n,
+                    Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+        Ok(&self.buf[self.pos..self.pos + len])
+    }
+}
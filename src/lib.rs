@@ -58,19 +58,91 @@
 //! | Feature | Default | Description |
 //! |---------|---------|-------------|
 //! | `derive` | yes | `#[derive(Byteable)]` proc-macro |
-//! | `std` | yes | [`Readable`] / [`Writable`] I/O traits and `std` type impls |
+//! | `std` | yes | [`Readable`] / [`Writable`] I/O traits and `std` type impls (implies `alloc`) |
+//! | `alloc` | yes | Impls for `Box<T>`, `Rc<T>` and `Arc<T>` without `std` |
 //! | `tokio` | no | Async I/O traits via tokio |
+//! | `tokio-util` | no | Length-prefixed `Decoder` / `Encoder` for `tokio_util::codec` (see `codec`) |
+//! | `futures` | no | Async I/O traits via `futures-io` (`smol`, `async-std`, ...) |
+//! | `embedded-io` | no | `no_std` blocking I/O traits via `embedded-io` (see `embedded_io`) |
 //! | `ordered-float` | no | Impls for `OrderedFloat<T>` and `NotNan<T>` |
+//! | `uuid` | no | Impls for `uuid::Uuid`, stored as its 16 RFC 4122 bytes (see `uuid_types`) |
+//! | `serde` | no | `Serialize` / `Deserialize` for [`BigEndian<T>`] and [`LittleEndian<T>`] |
+//! | `bytemuck` | no | `Pod` interop, slice casts, and `#[byteable(bytemuck)]` (see `bytemuck_types`) |
+//! | `test-util` | no | Round-trip assertions for tests (see `testing`) |
+//! | `arbitrary` | no | `Arbitrary` for [`BigEndian<T>`] and [`LittleEndian<T>`], and `#[byteable(arbitrary)]` for fuzzing |
 //! | `all` | no | All of the above |
+//!
+//! # `no_std`
+//!
+//! With default features disabled the crate is `#![no_std]`: the byte-array, raw-repr and
+//! endian types only depend on `core`. Enable `alloc` for the pointer impls on targets with a
+//! global allocator:
+//!
+//! ```toml
+//! byteable = { version = "0.33", default-features = false, features = ["derive", "alloc"] }
+//! ```
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate self as byteable; // used to resolve derive macros in examples etc.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod byteable_trait;
 
 pub use byteable_trait::*;
 
+pub mod endian_try;
+
+pub use endian_try::{BigEndianTry, LittleEndianTry, TryEndianConvert};
+
+pub mod odd_int;
+
+pub use odd_int::{I24, Int24OutOfRange, U24};
+
+pub mod fixed_usize;
+
+pub use fixed_usize::{Usize32, Usize64, UsizeOutOfRange};
+
+pub mod open_enum;
+
+pub use open_enum::OpenEnum;
+
+pub mod bitflags;
+
+pub use bitflags::{BitFlags8, BitFlags16, BitFlags32};
+
+pub mod checksum;
+
+pub mod hex;
+
+pub use hex::FromHexError;
+
+pub mod fixed_decimal;
+
+pub use fixed_decimal::FixedDecimal;
+
+pub mod fixed_point;
+
+pub use fixed_point::Fixed;
+
+pub mod fixed_str;
+
+pub use fixed_str::{FixedStr, FixedStrTooLong};
+
+pub mod fixed_ascii;
+
+pub use fixed_ascii::{FixedAscii, FixedAsciiError};
+
+pub mod tagged;
+
+pub use tagged::{Tagged2, Tagged2Bytes};
+
+pub mod fixed_option;
+
+pub use fixed_option::{FixedOption, FixedOptionBytes};
+
 #[cfg(feature = "derive")]
 pub use byteable_derive::Byteable;
 
@@ -81,6 +153,23 @@ mod std_types_async;
 #[cfg(feature = "tokio")]
 pub use async_io::*;
 
+#[cfg(feature = "tokio-util")]
+pub mod codec;
+#[cfg(feature = "tokio-util")]
+pub use codec::ByteableCodec;
+
+#[cfg(feature = "futures")]
+pub mod futures_io;
+#[cfg(feature = "futures")]
+mod std_types_futures;
+#[cfg(feature = "futures")]
+pub use futures_io::*;
+
+#[cfg(feature = "embedded-io")]
+pub mod embedded_io;
+#[cfg(feature = "embedded-io")]
+pub use embedded_io::*;
+
 #[cfg(feature = "std")]
 pub mod io;
 
@@ -89,8 +178,37 @@ pub use io::*;
 
 mod core_types;
 
+#[cfg(feature = "alloc")]
+mod alloc_types;
+
+#[cfg(feature = "alloc")]
+mod hexdump;
+
+#[cfg(feature = "test-util")]
+pub mod testing;
+
 #[cfg(feature = "std")]
 mod std_types;
 
 #[cfg(feature = "ordered-float")]
 pub mod ordered_float_types;
+
+#[cfg(feature = "uuid")]
+pub mod uuid_types;
+
+#[cfg(feature = "serde")]
+mod serde_types;
+
+#[cfg(feature = "bytemuck")]
+pub mod bytemuck_types;
+
+#[cfg(feature = "bytemuck")]
+#[doc(hidden)]
+pub use bytemuck as __bytemuck;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_types;
+
+#[cfg(feature = "arbitrary")]
+#[doc(hidden)]
+pub use arbitrary as __arbitrary;
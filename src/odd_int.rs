@@ -0,0 +1,277 @@
+//! [`U24`] and [`I24`]: three-byte integers, as used by 24-bit PCM audio and some MIDI fields.
+
+use crate::{
+    BigEndian, DecodeError, EndianConvert, FromByteArray, FromRawRepr, IntoByteArray, LittleEndian,
+    PlainOldData, RawRepr, TryFromRawRepr, impl_byte_array,
+};
+
+/// Error returned when converting an integer that does not fit in a [`U24`] or [`I24`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Int24OutOfRange {
+    /// The value that was out of range.
+    pub value: i64,
+}
+
+impl core::fmt::Display for Int24OutOfRange {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "value {} does not fit in 24 bits", self.value)
+    }
+}
+
+impl core::error::Error for Int24OutOfRange {}
+
+macro_rules! int24_type {
+    (
+        $(#[$meta:meta])*
+        $name:ident($int:ty, $wide:ty),
+        min = $min:expr,
+        max = $max:expr,
+        decode = |$bytes:ident| $decode:expr
+    ) => {
+        $(#[$meta])*
+        // The three bytes are always stored least significant first, whatever the host's
+        // byte order; the `EndianConvert` impl below treats that as the "native" form.
+        #[repr(transparent)]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+        pub struct $name([u8; 3]);
+
+        impl $name {
+            /// The number of bits.
+            pub const BITS: u32 = 24;
+
+            /// The smallest representable value.
+            pub const MIN: Self = Self::new_wrapping($min);
+
+            /// The largest representable value.
+            pub const MAX: Self = Self::new_wrapping($max);
+
+            /// Creates a value, or returns `None` if `value` is outside
+            /// [`MIN`](Self::MIN)`..=`[`MAX`](Self::MAX).
+            #[inline]
+            pub const fn new(value: $int) -> Option<Self> {
+                // In range exactly when no bits are lost by the round trip.
+                let wrapped = Self::new_wrapping(value);
+                if wrapped.get() == value {
+                    Some(wrapped)
+                } else {
+                    None
+                }
+            }
+
+            /// Creates a value from the low 24 bits of `value`, discarding the rest.
+            #[inline]
+            pub const fn new_wrapping(value: $int) -> Self {
+                let [b0, b1, b2, _] = value.to_le_bytes();
+                Self([b0, b1, b2])
+            }
+
+            /// Returns the value.
+            #[inline]
+            pub const fn get(self) -> $int {
+                let $bytes = self.0;
+                $decode
+            }
+        }
+
+        impl core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_tuple(stringify!($name)).field(&self.get()).finish()
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.get(), f)
+            }
+        }
+
+        impl PartialOrd for $name {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            #[inline]
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.get().cmp(&other.get())
+            }
+        }
+
+        impl TryFrom<$int> for $name {
+            type Error = Int24OutOfRange;
+
+            #[inline]
+            fn try_from(value: $int) -> Result<Self, Self::Error> {
+                Self::new(value).ok_or(Int24OutOfRange {
+                    value: value as i64,
+                })
+            }
+        }
+
+        impl From<$name> for $int {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.get()
+            }
+        }
+
+        impl From<$name> for $wide {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.get().into()
+            }
+        }
+
+        // SAFETY: `repr(transparent)` over `[u8; 3]`: no padding and every bit pattern is a
+        // valid value.
+        unsafe impl PlainOldData for $name {}
+
+        // SAFETY: `to_*`/`from_*` either keep the bytes or reverse all three, so each pair
+        // round-trips.
+        unsafe impl EndianConvert for $name {
+            #[inline]
+            fn from_le(value: Self) -> Self {
+                value
+            }
+
+            #[inline]
+            fn from_be(value: Self) -> Self {
+                let [b0, b1, b2] = value.0;
+                Self([b2, b1, b0])
+            }
+
+            #[inline]
+            fn to_le(self) -> Self {
+                self
+            }
+
+            #[inline]
+            fn to_be(self) -> Self {
+                Self::from_be(self)
+            }
+        }
+
+        impl_byte_array!(LittleEndian<$name>, BigEndian<$name>);
+
+        impl RawRepr for $name {
+            type Raw = LittleEndian<Self>;
+
+            #[inline]
+            fn to_raw(&self) -> Self::Raw {
+                LittleEndian::new(*self)
+            }
+        }
+
+        impl FromRawRepr for $name {
+            #[inline]
+            fn from_raw(raw: Self::Raw) -> Self {
+                raw.get()
+            }
+        }
+
+        impl TryFromRawRepr for $name {
+            #[inline]
+            fn try_from_raw(raw: Self::Raw) -> Result<Self, DecodeError> {
+                Ok(Self::from_raw(raw))
+            }
+        }
+
+        impl IntoByteArray for $name {
+            type ByteArray = [u8; 3];
+
+            #[inline]
+            fn into_byte_array(&self) -> Self::ByteArray {
+                self.to_raw().into_byte_array()
+            }
+        }
+
+        impl FromByteArray for $name {
+            #[inline]
+            fn from_byte_array(byte_array: Self::ByteArray) -> Self {
+                Self::from_raw(LittleEndian::from_byte_array(byte_array))
+            }
+        }
+    };
+}
+
+int24_type! {
+    /// An unsigned 24-bit integer, `0..=16_777_215`, stored in 3 bytes.
+    ///
+    /// Serializes little-endian by default. It implements [`EndianConvert`], so
+    /// [`BigEndian<U24>`](BigEndian) and [`LittleEndian<U24>`](LittleEndian) work like they do
+    /// for the primitive integers, and a derived struct field honours
+    /// `#[byteable(big_endian)]` / `#[byteable(little_endian)]`.
+    ///
+    /// ```rust
+    /// use byteable::{BigEndian, IntoByteArray, U24};
+    ///
+    /// let sample = U24::new(0x12_3456).unwrap();
+    /// assert_eq!(sample.into_byte_array(), [0x56, 0x34, 0x12]);
+    /// assert_eq!(BigEndian::new(sample).into_byte_array(), [0x12, 0x34, 0x56]);
+    ///
+    /// assert!(U24::new(1 << 24).is_none());
+    /// assert_eq!(U24::new_wrapping(0x0112_3456), sample);
+    /// ```
+    U24(u32, u64),
+    min = 0,
+    max = (1 << 24) - 1,
+    decode = |b| u32::from_le_bytes([b[0], b[1], b[2], 0])
+}
+
+int24_type! {
+    /// A signed two's-complement 24-bit integer, `-8_388_608..=8_388_607`, stored in 3 bytes.
+    ///
+    /// Serializes little-endian by default and composes with [`BigEndian`] / [`LittleEndian`]
+    /// like [`U24`].
+    ///
+    /// ```rust
+    /// use byteable::{BigEndian, I24, IntoByteArray};
+    ///
+    /// let sample = I24::new(-2).unwrap();
+    /// assert_eq!(sample.into_byte_array(), [0xFE, 0xFF, 0xFF]);
+    /// assert_eq!(BigEndian::new(sample).get().get(), -2);
+    /// assert_eq!(I24::MIN.get(), -(1 << 23));
+    /// ```
+    I24(i32, i64),
+    min = -(1 << 23),
+    max = (1 << 23) - 1,
+    // Place the three bytes at the top of an `i32`, then shift back down to sign-extend.
+    decode = |b| i32::from_le_bytes([0, b[0], b[1], b[2]]) >> 8
+}
+
+impl From<u8> for U24 {
+    #[inline]
+    fn from(value: u8) -> Self {
+        Self::new_wrapping(value.into())
+    }
+}
+
+impl From<u16> for U24 {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self::new_wrapping(value.into())
+    }
+}
+
+impl From<i8> for I24 {
+    #[inline]
+    fn from(value: i8) -> Self {
+        Self::new_wrapping(value.into())
+    }
+}
+
+impl From<i16> for I24 {
+    #[inline]
+    fn from(value: i16) -> Self {
+        Self::new_wrapping(value.into())
+    }
+}
+
+impl From<u16> for I24 {
+    #[inline]
+    fn from(value: u16) -> Self {
+        Self::new_wrapping(value.into())
+    }
+}
@@ -0,0 +1,134 @@
+//! [`OpenEnum<E>`]: a forward-compatible wrapper that keeps unrecognized enum values.
+
+use crate::{
+    DecodeError, FromByteArray, FromRawRepr, IntoByteArray, PlainOldData, RawRepr,
+    TryFromByteArray, TryFromRawRepr,
+};
+
+/// Either a known variant of `E` or the raw bytes of a discriminant `E` does not recognize.
+///
+/// Wire formats often grow new enum values over time. Decoding a field as `OpenEnum<E>`
+/// instead of `E` never fails on an unknown discriminant: the original bytes are kept in
+/// [`Unknown`](OpenEnum::Unknown) and written back unchanged by
+/// [`into_byte_array`](IntoByteArray::into_byte_array), so a message can be decoded and
+/// re-encoded without losing data the program cannot interpret yet.
+///
+/// Unlike `#[byteable(default = Variant)]`, which maps every unknown value to one variant,
+/// `OpenEnum` preserves the exact value.
+///
+/// # Examples
+///
+/// ```rust
+/// use byteable::{Byteable, FromByteArray, IntoByteArray, OpenEnum};
+///
+/// #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+/// #[repr(u16)]
+/// #[byteable(big_endian)]
+/// enum Kind {
+///     Ping = 1,
+///     Pong = 2,
+/// }
+///
+/// let known = OpenEnum::<Kind>::from_byte_array([0, 1]);
+/// assert_eq!(known.get(), Ok(Kind::Ping));
+///
+/// let unknown = OpenEnum::<Kind>::from_byte_array([0, 7]);
+/// assert!(!unknown.is_known());
+/// assert_eq!(unknown.raw(), Some([0, 7]));
+/// assert_eq!(unknown.get(), Err([0, 7]));
+/// assert_eq!(unknown.into_byte_array(), [0, 7]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OpenEnum<E: IntoByteArray> {
+    /// A discriminant that decoded to a variant of `E`.
+    Known(E),
+    /// The encoded bytes of a discriminant that `E` does not recognize.
+    Unknown(E::ByteArray),
+}
+
+impl<E: IntoByteArray> OpenEnum<E> {
+    /// Returns the known variant, or the encoded bytes of an [`Unknown`](OpenEnum::Unknown)
+    /// value.
+    ///
+    /// # Errors
+    ///
+    /// Returns the stored bytes if the value is [`Unknown`](OpenEnum::Unknown).
+    pub fn get(self) -> Result<E, E::ByteArray> {
+        match self {
+            OpenEnum::Known(value) => Ok(value),
+            OpenEnum::Unknown(bytes) => Err(bytes),
+        }
+    }
+
+    /// Returns `true` if the value is a known variant of `E`.
+    pub fn is_known(&self) -> bool {
+        matches!(self, OpenEnum::Known(_))
+    }
+
+    /// Returns the encoded bytes of an [`Unknown`](OpenEnum::Unknown) value, or `None` for a
+    /// known variant.
+    pub fn raw(&self) -> Option<E::ByteArray> {
+        match self {
+            OpenEnum::Known(_) => None,
+            OpenEnum::Unknown(bytes) => Some(*bytes),
+        }
+    }
+}
+
+impl<E: IntoByteArray> From<E> for OpenEnum<E> {
+    fn from(value: E) -> Self {
+        OpenEnum::Known(value)
+    }
+}
+
+impl<E: IntoByteArray> IntoByteArray for OpenEnum<E> {
+    type ByteArray = E::ByteArray;
+
+    fn into_byte_array(&self) -> Self::ByteArray {
+        match self {
+            OpenEnum::Known(value) => value.into_byte_array(),
+            OpenEnum::Unknown(bytes) => *bytes,
+        }
+    }
+}
+
+impl<E: IntoByteArray + TryFromByteArray> FromByteArray for OpenEnum<E> {
+    fn from_byte_array(byte_array: Self::ByteArray) -> Self {
+        match E::try_from_byte_array(byte_array) {
+            Ok(value) => OpenEnum::Known(value),
+            Err(_) => OpenEnum::Unknown(byte_array),
+        }
+    }
+}
+
+// The raw form is the encoded byte array itself, so `OpenEnum<E>` can be used as a derived
+// struct field and read or written through the I/O traits.
+
+impl<E: IntoByteArray> RawRepr for OpenEnum<E>
+where
+    E::ByteArray: PlainOldData,
+{
+    type Raw = E::ByteArray;
+
+    fn to_raw(&self) -> Self::Raw {
+        self.into_byte_array()
+    }
+}
+
+impl<E: IntoByteArray + TryFromByteArray> FromRawRepr for OpenEnum<E>
+where
+    E::ByteArray: PlainOldData,
+{
+    fn from_raw(raw: Self::Raw) -> Self {
+        Self::from_byte_array(raw)
+    }
+}
+
+impl<E: IntoByteArray + TryFromByteArray> TryFromRawRepr for OpenEnum<E>
+where
+    E::ByteArray: PlainOldData,
+{
+    fn try_from_raw(raw: Self::Raw) -> Result<Self, DecodeError> {
+        Ok(Self::from_byte_array(raw))
+    }
+}
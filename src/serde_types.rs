@@ -0,0 +1,28 @@
+//! [`serde`] support for the endian wrappers (requires the `serde` feature).
+//!
+//! [`BigEndian<T>`] and [`LittleEndian<T>`] serialize as their native value, so a
+//! `LittleEndian<u16>` holding `1000` appears as `1000` in JSON rather than as a byte array.
+//! Deserializing goes through [`new`](BigEndian::new), which restores the stored byte order.
+
+use crate::{BigEndian, EndianConvert, LittleEndian};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+macro_rules! impl_serde_endian {
+    ($($name:ident),+) => {
+        $(
+            impl<T: EndianConvert + Serialize> Serialize for $name<T> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    self.get().serialize(serializer)
+                }
+            }
+
+            impl<'de, T: EndianConvert + Deserialize<'de>> Deserialize<'de> for $name<T> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    T::deserialize(deserializer).map(Self::new)
+                }
+            }
+        )+
+    };
+}
+
+impl_serde_endian!(BigEndian, LittleEndian);
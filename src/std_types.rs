@@ -5,7 +5,6 @@
 //!
 //! | Type | Encoding |
 //! |------|----------|
-//! | `Arc<T>` / `Rc<T>` / `Box<T>` | transparent passthrough to inner `T` |
 //! | `Vec<T>` / `VecDeque<T>` / `LinkedList<T>` | `u64` element count + elements in order |
 //! | `HashMap<K,V>` / `HashSet<T>` | `u64` count + alternating key/value (or element) pairs |
 //! | `BTreeMap<K,V>` / `BTreeSet<T>` | same as `HashMap` / `HashSet`, iteration order is sorted |
@@ -21,7 +20,7 @@
 //! All multi-byte length prefixes are little-endian `u64`.
 
 use crate::{
-    DecodeError, FromRawRepr, RawRepr, TryFromRawRepr,
+    DecodeError,
     io::{ReadFixed, ReadValue, Readable, ReadableError, Writable, WriteFixed, WriteValue},
 };
 use core::{
@@ -33,38 +32,8 @@ use std::{
     ffi::CString,
     io::{self, Read, Write},
     path::{Path, PathBuf},
-    rc::Rc,
-    sync::Arc,
 };
 
-macro_rules! smart_pointer_passthrough {
-    ($($wrapper:ident),+) => {
-        $(
-            impl<T: RawRepr> RawRepr for $wrapper<T> {
-                type Raw = T::Raw;
-
-                fn to_raw(&self) -> Self::Raw {
-                    self.as_ref().to_raw()
-                }
-            }
-
-            impl<T: FromRawRepr> FromRawRepr for $wrapper<T> {
-                fn from_raw(raw: Self::Raw) -> Self {
-                    Self::new(T::from_raw(raw))
-                }
-            }
-
-            impl<T: TryFromRawRepr> TryFromRawRepr for $wrapper<T> {
-                fn try_from_raw(raw: Self::Raw) -> Result<Self, DecodeError> {
-                    Ok(Self::new(T::try_from_raw(raw)?))
-                }
-            }
-        )+
-    };
-}
-
-smart_pointer_passthrough!(Arc, Rc, Box);
-
 // Wire format: `u64` element count (LE), then each element serialized in order.
 impl<T: Readable> Readable for Vec<T> {
     fn read_from(mut reader: &mut (impl Read + ?Sized)) -> Result<Self, ReadableError> {
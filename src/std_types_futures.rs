@@ -0,0 +1,400 @@
+//! Async [`FuturesReadable`] and [`FuturesWritable`] implementations for standard-library
+//! collection and pointer types (futures feature).
+//!
+//! Wire formats are identical to those in [`crate::std_types`]; see that module for the
+//! encoding reference table. This is the `futures-io` twin of the tokio impls: reads and
+//! writes go through [`futures_util::AsyncReadExt`] / [`futures_util::AsyncWriteExt`].
+
+use futures_util::{AsyncReadExt, AsyncWriteExt};
+
+use crate::{
+    DecodeError, FuturesReadFixed, FuturesReadValue, FuturesReadable, FuturesWritable,
+    FuturesWriteFixed, FuturesWriteValue, io::ReadableError,
+};
+use core::{
+    ffi::CStr,
+    hash::{BuildHasher, Hash},
+};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, LinkedList, VecDeque},
+    ffi::CString,
+    io::{self},
+    path::{Path, PathBuf},
+};
+
+impl<T: FuturesReadable> FuturesReadable for Vec<T> {
+    async fn read_from(
+        reader: &mut (impl AsyncReadExt + ?Sized + Unpin),
+    ) -> Result<Self, ReadableError> {
+        let len: u64 = reader.read_fixed().await?;
+        let len: usize = len.try_into().expect("could not convert u64 to usize");
+        let mut result = Vec::with_capacity(len);
+        for _ in 0..len {
+            result.push(reader.read_value().await?);
+        }
+        Ok(result)
+    }
+}
+
+impl<T: FuturesReadable> FuturesReadable for VecDeque<T> {
+    async fn read_from(
+        reader: &mut (impl AsyncReadExt + ?Sized + Unpin),
+    ) -> Result<Self, ReadableError> {
+        let len: u64 = reader.read_fixed().await?;
+        let len: usize = len.try_into().expect("could not convert u64 to usize");
+        let mut result = VecDeque::with_capacity(len);
+        for _ in 0..len {
+            result.push_back(reader.read_value().await?);
+        }
+        Ok(result)
+    }
+}
+
+impl<T: FuturesReadable> FuturesReadable for LinkedList<T> {
+    async fn read_from(
+        reader: &mut (impl AsyncReadExt + ?Sized + Unpin),
+    ) -> Result<Self, ReadableError> {
+        let len: u64 = reader.read_fixed().await?;
+        let len: usize = len.try_into().expect("could not convert u64 to usize");
+        let mut result = LinkedList::new();
+        for _ in 0..len {
+            result.push_back(reader.read_value().await?);
+        }
+        Ok(result)
+    }
+}
+
+impl<K, V, S> FuturesReadable for HashMap<K, V, S>
+where
+    K: FuturesReadable + Eq + std::hash::Hash,
+    V: FuturesReadable,
+    S: BuildHasher + Default,
+{
+    async fn read_from(
+        reader: &mut (impl AsyncReadExt + ?Sized + Unpin),
+    ) -> Result<Self, ReadableError> {
+        let len: u64 = reader.read_fixed().await?;
+        let len: usize = len.try_into().expect("could not convert u64 to usize");
+        let mut map = HashMap::with_capacity_and_hasher(len, S::default());
+        for _ in 0..len {
+            let key = reader.read_value().await?;
+            let val = reader.read_value().await?;
+            map.insert(key, val);
+        }
+        Ok(map)
+    }
+}
+
+impl<T, S> FuturesReadable for HashSet<T, S>
+where
+    T: FuturesReadable + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    async fn read_from(
+        reader: &mut (impl AsyncReadExt + ?Sized + Unpin),
+    ) -> Result<Self, ReadableError> {
+        let len: u64 = reader.read_fixed().await?;
+        let len: usize = len.try_into().expect("could not convert u64 to usize");
+        let mut set = HashSet::with_capacity_and_hasher(len, S::default());
+        for _ in 0..len {
+            set.insert(reader.read_value().await?);
+        }
+        Ok(set)
+    }
+}
+
+impl<K: FuturesReadable + Ord, V: FuturesReadable> FuturesReadable for BTreeMap<K, V> {
+    async fn read_from(
+        reader: &mut (impl AsyncReadExt + ?Sized + Unpin),
+    ) -> Result<Self, ReadableError> {
+        let len: u64 = reader.read_fixed().await?;
+        let len: usize = len.try_into().expect("could not convert u64 to usize");
+        let mut map = BTreeMap::new();
+        for _ in 0..len {
+            let key = reader.read_value().await?;
+            let val = reader.read_value().await?;
+            map.insert(key, val);
+        }
+        Ok(map)
+    }
+}
+
+impl<T: FuturesReadable + Ord> FuturesReadable for BTreeSet<T> {
+    async fn read_from(
+        reader: &mut (impl AsyncReadExt + ?Sized + Unpin),
+    ) -> Result<Self, ReadableError> {
+        let len: u64 = reader.read_fixed().await?;
+        let len: usize = len.try_into().expect("could not convert u64 to usize");
+        let mut set = BTreeSet::new();
+        for _ in 0..len {
+            set.insert(reader.read_value().await?);
+        }
+        Ok(set)
+    }
+}
+
+impl<T: FuturesReadable> FuturesReadable for Option<T> {
+    async fn read_from(
+        reader: &mut (impl AsyncReadExt + ?Sized + Unpin),
+    ) -> Result<Self, ReadableError> {
+        let tag: u8 = reader.read_fixed().await?;
+        match tag {
+            0 => Ok(None),
+            1 => Ok(Some(reader.read_value().await?)),
+            _ => Err(ReadableError::DecodeError(DecodeError::InvalidTag {
+                raw: tag,
+                type_name: "Option",
+            })),
+        }
+    }
+}
+
+impl<V: FuturesReadable, E: FuturesReadable> FuturesReadable for Result<V, E> {
+    async fn read_from(
+        reader: &mut (impl AsyncReadExt + ?Sized + Unpin),
+    ) -> Result<Self, ReadableError> {
+        let discriminator: u8 = reader.read_fixed().await?;
+        match discriminator {
+            0 => Ok(Ok(reader.read_value().await?)),
+            1 => Ok(Err(reader.read_value().await?)),
+            _ => Err(ReadableError::DecodeError(DecodeError::InvalidTag {
+                raw: discriminator,
+                type_name: "Result",
+            })),
+        }
+    }
+}
+
+impl FuturesReadable for String {
+    async fn read_from(
+        reader: &mut (impl AsyncReadExt + ?Sized + Unpin),
+    ) -> Result<Self, ReadableError> {
+        let len: u64 = reader.read_fixed().await?;
+        let len: usize = len.try_into().expect("could not convert u64 to usize");
+        let mut bytes = vec![0u8; len];
+        reader.read_exact(&mut bytes).await?;
+        String::from_utf8(bytes).map_err(|_| ReadableError::DecodeError(DecodeError::InvalidUtf8))
+    }
+}
+
+impl FuturesReadable for PathBuf {
+    async fn read_from(
+        reader: &mut (impl AsyncReadExt + ?Sized + Unpin),
+    ) -> Result<Self, ReadableError> {
+        let s = String::read_from(reader).await?;
+        Ok(PathBuf::from(s))
+    }
+}
+
+impl FuturesReadable for CString {
+    async fn read_from(
+        reader: &mut (impl AsyncReadExt + ?Sized + Unpin),
+    ) -> Result<Self, ReadableError> {
+        let v = Vec::read_from(reader).await?;
+        CString::new(v).map_err(|_| ReadableError::DecodeError(DecodeError::InvalidCString))
+    }
+}
+
+impl<T: FuturesWritable> FuturesWritable for [T] {
+    async fn write_to(&self, writer: &mut (impl AsyncWriteExt + ?Sized + Unpin)) -> io::Result<()> {
+        let len: u64 = self
+            .len()
+            .try_into()
+            .expect("could not convert usize to u64");
+        writer.write_fixed(&len).await?;
+        for el in self {
+            writer.write_value(el).await?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: FuturesWritable> FuturesWritable for Vec<T> {
+    fn write_to(
+        &self,
+        writer: &mut (impl AsyncWriteExt + ?Sized + Unpin),
+    ) -> impl Future<Output = io::Result<()>> {
+        self.as_slice().write_to(writer)
+    }
+}
+
+impl<T: FuturesWritable> FuturesWritable for VecDeque<T> {
+    async fn write_to(&self, writer: &mut (impl AsyncWriteExt + ?Sized + Unpin)) -> io::Result<()> {
+        let len: u64 = self
+            .len()
+            .try_into()
+            .expect("could not convert usize to u64");
+        writer.write_fixed(&len).await?;
+        for el in self {
+            writer.write_value(el).await?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: FuturesWritable> FuturesWritable for LinkedList<T> {
+    async fn write_to(&self, writer: &mut (impl AsyncWriteExt + ?Sized + Unpin)) -> io::Result<()> {
+        let len: u64 = self
+            .len()
+            .try_into()
+            .expect("could not convert usize to u64");
+        writer.write_fixed(&len).await?;
+        for el in self {
+            writer.write_value(el).await?;
+        }
+        Ok(())
+    }
+}
+
+impl<K, V, S> FuturesWritable for HashMap<K, V, S>
+where
+    K: FuturesWritable,
+    V: FuturesWritable,
+    S: BuildHasher,
+{
+    async fn write_to(&self, writer: &mut (impl AsyncWriteExt + ?Sized + Unpin)) -> io::Result<()> {
+        let len: u64 = self
+            .len()
+            .try_into()
+            .expect("could not convert usize to u64");
+        writer.write_fixed(&len).await?;
+        for (k, v) in self {
+            writer.write_value(k).await?;
+            writer.write_value(v).await?;
+        }
+        Ok(())
+    }
+}
+
+impl<T, S> FuturesWritable for HashSet<T, S>
+where
+    T: FuturesWritable,
+    S: BuildHasher,
+{
+    async fn write_to(&self, writer: &mut (impl AsyncWriteExt + ?Sized + Unpin)) -> io::Result<()> {
+        let len: u64 = self
+            .len()
+            .try_into()
+            .expect("could not convert usize to u64");
+        writer.write_fixed(&len).await?;
+        for el in self {
+            writer.write_value(el).await?;
+        }
+        Ok(())
+    }
+}
+
+impl<K: FuturesWritable, V: FuturesWritable> FuturesWritable for BTreeMap<K, V> {
+    async fn write_to(&self, writer: &mut (impl AsyncWriteExt + ?Sized + Unpin)) -> io::Result<()> {
+        let len: u64 = self
+            .len()
+            .try_into()
+            .expect("could not convert usize to u64");
+        writer.write_fixed(&len).await?;
+        for (k, v) in self {
+            writer.write_value(k).await?;
+            writer.write_value(v).await?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: FuturesWritable> FuturesWritable for BTreeSet<T> {
+    async fn write_to(&self, writer: &mut (impl AsyncWriteExt + ?Sized + Unpin)) -> io::Result<()> {
+        let len: u64 = self
+            .len()
+            .try_into()
+            .expect("could not convert usize to u64");
+        writer.write_fixed(&len).await?;
+        for el in self {
+            writer.write_value(el).await?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: FuturesWritable> FuturesWritable for Option<T> {
+    async fn write_to(&self, writer: &mut (impl AsyncWriteExt + ?Sized + Unpin)) -> io::Result<()> {
+        match self {
+            None => writer.write_fixed(&0u8).await,
+            Some(val) => {
+                writer.write_fixed(&1u8).await?;
+                writer.write_value(val).await
+            }
+        }
+    }
+}
+
+impl<V: FuturesWritable, E: FuturesWritable> FuturesWritable for Result<V, E> {
+    async fn write_to(&self, writer: &mut (impl AsyncWriteExt + ?Sized + Unpin)) -> io::Result<()> {
+        match self {
+            Ok(val) => {
+                writer.write_fixed(&0u8).await?;
+                writer.write_value(val).await
+            }
+            Err(err) => {
+                writer.write_fixed(&1u8).await?;
+                writer.write_value(err).await
+            }
+        }
+    }
+}
+
+impl FuturesWritable for str {
+    async fn write_to(&self, writer: &mut (impl AsyncWriteExt + ?Sized + Unpin)) -> io::Result<()> {
+        let len: u64 = self
+            .len()
+            .try_into()
+            .expect("could not convert usize to u64");
+        writer.write_fixed(&len).await?;
+        writer.write_all(self.as_bytes()).await
+    }
+}
+
+impl FuturesWritable for Path {
+    async fn write_to(&self, writer: &mut (impl AsyncWriteExt + ?Sized + Unpin)) -> io::Result<()> {
+        match self.to_str() {
+            Some(s) => s.write_to(writer).await,
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "path contains invalid UTF-8 characters",
+            )),
+        }
+    }
+}
+
+impl FuturesWritable for CStr {
+    fn write_to(
+        &self,
+        writer: &mut (impl AsyncWriteExt + ?Sized + Unpin),
+    ) -> impl Future<Output = io::Result<()>> {
+        self.to_bytes().write_to(writer)
+    }
+}
+
+impl FuturesWritable for String {
+    fn write_to(
+        &self,
+        writer: &mut (impl AsyncWriteExt + ?Sized + Unpin),
+    ) -> impl Future<Output = io::Result<()>> {
+        self.as_str().write_to(writer)
+    }
+}
+
+impl FuturesWritable for CString {
+    fn write_to(
+        &self,
+        writer: &mut (impl AsyncWriteExt + ?Sized + Unpin),
+    ) -> impl Future<Output = io::Result<()>> {
+        self.as_bytes().write_to(writer)
+    }
+}
+
+impl FuturesWritable for PathBuf {
+    fn write_to(
+        &self,
+        writer: &mut (impl AsyncWriteExt + ?Sized + Unpin),
+    ) -> impl Future<Output = io::Result<()>> {
+        self.as_path().write_to(writer)
+    }
+}
@@ -0,0 +1,197 @@
+//! [`Tagged2<A, B>`]: a fixed-size, tag-prefixed value of one of two types.
+
+use crate::{
+    ByteArray, DecodeError, IntoByteArray, PlainOldData, RawRepr, TryFromByteArray, TryFromRawRepr,
+};
+
+/// A value that is either an `A` or a `B`, encoded as a tag byte followed by the payload.
+///
+/// This is a lightweight alternative to defining a two-variant field enum for a one-off
+/// union of two fixed-size types. The tag is `0` for [`First`](Tagged2::First) and `1` for
+/// [`Second`](Tagged2::Second). The payload area is as large as the larger of the two types;
+/// when the smaller one is stored, the remaining bytes are zero, and decoding rejects them
+/// with [`DecodeError::InvalidValue`] otherwise.
+///
+/// The serialized form is a [`Tagged2Bytes`] rather than a `[u8; N]`, because its size
+/// depends on both type parameters. Use [`ByteArray::as_byte_slice`] to get the flat bytes.
+///
+/// # Wire format
+///
+/// | Offset | Size | Content |
+/// |--------|------|---------|
+/// | 0 | 1 | tag (`0` = `A`, `1` = `B`) |
+/// | 1 | `max(A::BYTE_SIZE, B::BYTE_SIZE)` | payload, zero-padded |
+///
+/// # Examples
+///
+/// ```rust
+/// use byteable::{BigEndian, ByteArray, IntoByteArray, Tagged2, TryFromByteArray};
+///
+/// type Value = Tagged2<u8, BigEndian<u32>>;
+///
+/// let small = Value::first(7);
+/// assert_eq!(small.into_byte_array().as_byte_slice(), &[0, 7, 0, 0, 0]);
+///
+/// let large = Value::second(BigEndian::new(0x0102_0304));
+/// let bytes = large.into_byte_array();
+/// assert_eq!(bytes.as_byte_slice(), &[1, 1, 2, 3, 4]);
+/// assert_eq!(Value::try_from_byte_array(bytes).unwrap(), large);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tagged2<A, B> {
+    /// Tag `0`: a value of the first type.
+    First(A),
+    /// Tag `1`: a value of the second type.
+    Second(B),
+}
+
+impl<A, B> Tagged2<A, B> {
+    /// Creates a [`First`](Tagged2::First) value.
+    pub const fn first(value: A) -> Self {
+        Tagged2::First(value)
+    }
+
+    /// Creates a [`Second`](Tagged2::Second) value.
+    pub const fn second(value: B) -> Self {
+        Tagged2::Second(value)
+    }
+
+    /// Returns the tag byte: `0` for [`First`](Tagged2::First), `1` for
+    /// [`Second`](Tagged2::Second).
+    pub const fn tag(&self) -> u8 {
+        match self {
+            Tagged2::First(_) => 0,
+            Tagged2::Second(_) => 1,
+        }
+    }
+
+    /// Returns the first value, or `None` if this is a [`Second`](Tagged2::Second).
+    pub fn as_first(&self) -> Option<&A> {
+        match self {
+            Tagged2::First(value) => Some(value),
+            Tagged2::Second(_) => None,
+        }
+    }
+
+    /// Returns the second value, or `None` if this is a [`First`](Tagged2::First).
+    pub fn as_second(&self) -> Option<&B> {
+        match self {
+            Tagged2::First(_) => None,
+            Tagged2::Second(value) => Some(value),
+        }
+    }
+}
+
+/// The serialized form of a [`Tagged2`]: a tag byte followed by the larger of two byte arrays.
+///
+/// All fields have alignment 1 and every byte belongs to the tag or to a payload array, so
+/// the type is a plain run of `1 + max(X::BYTE_SIZE, Y::BYTE_SIZE)` bytes.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct Tagged2Bytes<X: ByteArray, Y: ByteArray> {
+    tag: u8,
+    payload: Tagged2Payload<X, Y>,
+}
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+union Tagged2Payload<X: ByteArray, Y: ByteArray> {
+    first: X,
+    second: Y,
+}
+
+impl<X: ByteArray, Y: ByteArray> Tagged2Bytes<X, Y> {
+    fn zeroed() -> Self {
+        // SAFETY: `repr(C)` over a `u8` and a `repr(C)` union of byte arrays, so all-zero is a
+        // valid value.
+        unsafe { core::mem::zeroed() }
+    }
+
+    /// Returns `true` if the payload bytes after the first `len` are all zero.
+    fn is_zero_after(&self, len: usize) -> bool {
+        self.as_byte_slice()[1 + len..].iter().all(|&b| b == 0)
+    }
+}
+
+// SAFETY: `repr(C)` over a `u8` and a `repr(C)` union of byte arrays: alignment 1, no
+// padding, and every byte is covered by a `u8` field, so any bit pattern is valid.
+unsafe impl<X: ByteArray, Y: ByteArray> ByteArray for Tagged2Bytes<X, Y> {
+    const BYTE_SIZE: usize = core::mem::size_of::<Self>();
+}
+
+// SAFETY: as above.
+unsafe impl<X: ByteArray, Y: ByteArray> PlainOldData for Tagged2Bytes<X, Y> {}
+
+impl<X: ByteArray, Y: ByteArray> core::fmt::Debug for Tagged2Bytes<X, Y> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Tagged2Bytes")
+            .field(&self.as_byte_slice())
+            .finish()
+    }
+}
+
+impl<X: ByteArray, Y: ByteArray> PartialEq for Tagged2Bytes<X, Y> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_byte_slice() == other.as_byte_slice()
+    }
+}
+
+impl<X: ByteArray, Y: ByteArray> Eq for Tagged2Bytes<X, Y> {}
+
+impl<A: IntoByteArray, B: IntoByteArray> IntoByteArray for Tagged2<A, B> {
+    type ByteArray = Tagged2Bytes<A::ByteArray, B::ByteArray>;
+
+    fn into_byte_array(&self) -> Self::ByteArray {
+        let mut bytes = Tagged2Bytes::zeroed();
+        bytes.tag = self.tag();
+        match self {
+            Tagged2::First(value) => bytes.payload.first = value.into_byte_array(),
+            Tagged2::Second(value) => bytes.payload.second = value.into_byte_array(),
+        }
+        bytes
+    }
+}
+
+impl<A: TryFromByteArray, B: TryFromByteArray> TryFromByteArray for Tagged2<A, B> {
+    fn try_from_byte_array(byte_array: Self::ByteArray) -> Result<Self, DecodeError> {
+        let len = match byte_array.tag {
+            0 => A::ByteArray::BYTE_SIZE,
+            1 => B::ByteArray::BYTE_SIZE,
+            raw => {
+                return Err(DecodeError::InvalidTag {
+                    raw,
+                    type_name: "Tagged2",
+                });
+            }
+        };
+        if !byte_array.is_zero_after(len) {
+            return Err(DecodeError::InvalidValue {
+                type_name: "Tagged2",
+                reason: "the bytes after the payload are not zeroed",
+            });
+        }
+        // SAFETY: both union fields are byte arrays and every byte of the union is initialized.
+        if byte_array.tag == 0 {
+            A::try_from_byte_array(unsafe { byte_array.payload.first }).map(Tagged2::First)
+        } else {
+            B::try_from_byte_array(unsafe { byte_array.payload.second }).map(Tagged2::Second)
+        }
+    }
+}
+
+// The raw form is the serialized form itself, so `Tagged2<A, B>` can be used as a derived
+// struct field and read or written through the I/O traits.
+
+impl<A: IntoByteArray, B: IntoByteArray> RawRepr for Tagged2<A, B> {
+    type Raw = Tagged2Bytes<A::ByteArray, B::ByteArray>;
+
+    fn to_raw(&self) -> Self::Raw {
+        self.into_byte_array()
+    }
+}
+
+impl<A: TryFromByteArray, B: TryFromByteArray> TryFromRawRepr for Tagged2<A, B> {
+    fn try_from_raw(raw: Self::Raw) -> Result<Self, DecodeError> {
+        Self::try_from_byte_array(raw)
+    }
+}
@@ -0,0 +1,86 @@
+//! Round-trip assertions for tests (requires the `test-util` feature).
+//!
+//! [`assert_roundtrip`] and [`assert_try_roundtrip`] encode a value, decode the bytes again and
+//! check that the same value comes back. On failure they panic with both values and hex dumps
+//! of the original and re-encoded bytes, so the differing field is easy to spot.
+//!
+//! ```
+//! use byteable::testing::{assert_roundtrip, assert_try_roundtrip};
+//! use byteable::{BigEndian, LittleEndian};
+//!
+//! assert_roundtrip(BigEndian::new(0x1234u16));
+//! // Arrays of endian wrappers only implement `TryFromByteArray`:
+//! assert_try_roundtrip([LittleEndian::new(1.5f32), LittleEndian::new(-0.0)]);
+//! ```
+//!
+//! With the `arbitrary` feature and `#[byteable(arbitrary)]`, running the same assertion over
+//! generated values turns it into a property test.
+
+use crate::{ByteArray, FromByteArray, IntoByteArray, TryFromByteArray};
+use alloc::format;
+use alloc::string::String;
+use core::fmt::Debug;
+
+/// Asserts that decoding `value`'s bytes with [`FromByteArray`] gives back `value`.
+///
+/// # Panics
+///
+/// Panics if the decoded value is not equal to `value`, or if it re-encodes to different
+/// bytes.
+#[track_caller]
+pub fn assert_roundtrip<T>(value: T)
+where
+    T: IntoByteArray + FromByteArray + PartialEq + Debug,
+{
+    let bytes = value.into_byte_array();
+    let decoded = T::from_byte_array(bytes);
+    check(&value, bytes, &decoded);
+}
+
+/// Asserts that decoding `value`'s bytes with [`TryFromByteArray`] succeeds and gives back
+/// `value`.
+///
+/// ```
+/// use byteable::testing::assert_try_roundtrip;
+///
+/// assert_try_roundtrip('ß');
+/// assert_try_roundtrip([true, false]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if decoding fails, if the decoded value is not equal to `value`, or if it
+/// re-encodes to different bytes.
+#[track_caller]
+pub fn assert_try_roundtrip<T>(value: T)
+where
+    T: IntoByteArray + TryFromByteArray + PartialEq + Debug,
+{
+    let bytes = value.into_byte_array();
+    match T::try_from_byte_array(bytes) {
+        Ok(decoded) => check(&value, bytes, &decoded),
+        Err(error) => panic!(
+            "round trip failed: {value:?} did not decode: {error}\nencoded bytes:\n{}",
+            value.hexdump()
+        ),
+    }
+}
+
+#[track_caller]
+fn check<T: IntoByteArray + PartialEq + Debug>(value: &T, bytes: T::ByteArray, decoded: &T) {
+    let reencoded = decoded.into_byte_array();
+    let (original, again) = (bytes.as_byte_slice(), reencoded.as_byte_slice());
+    if decoded == value && original == again {
+        return;
+    }
+    let difference: String = match original.iter().zip(again).position(|(a, b)| a != b) {
+        Some(offset) => format!("first differing byte at offset {offset:#x}"),
+        None => "the bytes are identical".into(),
+    };
+    panic!(
+        "round trip changed the value\n original: {value:?}\n  decoded: {decoded:?}\n\
+         encoded bytes:\n{}re-encoded bytes:\n{}{difference}",
+        value.hexdump(),
+        decoded.hexdump(),
+    );
+}
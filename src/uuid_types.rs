@@ -0,0 +1,77 @@
+//! [`RawRepr`] and [`IntoByteArray`] impls for [`uuid::Uuid`] (requires the `uuid` feature).
+//!
+//! A `Uuid` is stored as its 16 bytes in the RFC 4122 (RFC 9562) canonical order, as returned
+//! by [`Uuid::as_bytes`] and accepted by [`Uuid::from_bytes`]: the most significant byte of
+//! the textual form comes first. This is the big-endian layout used by network protocols and
+//! databases, so encoded UUIDs are portable across systems. It is *not* the mixed-endian
+//! layout of Microsoft GUIDs (see [`Uuid::to_bytes_le`]).
+//!
+//! Every 16-byte pattern is a valid `Uuid`, so decoding is infallible and `Uuid` is
+//! [`PlainOldData`]. Its byte order is fixed, so inside a `#[byteable(big_endian)]` or
+//! `#[byteable(little_endian)]` struct a `Uuid` field is marked `#[byteable(transparent)]`.
+//!
+//! ```rust
+//! use byteable::{Byteable, FromByteArray, IntoByteArray};
+//! use uuid::Uuid;
+//!
+//! #[derive(Byteable, Debug, PartialEq)]
+//! #[byteable(little_endian)]
+//! struct Session {
+//!     #[byteable(transparent)]
+//!     id: Uuid,
+//!     seq: u32,
+//! }
+//!
+//! let id = Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+//! let bytes = Session { id, seq: 1 }.into_byte_array();
+//! assert_eq!(bytes[..4], [0x67, 0xE5, 0x50, 0x44]);
+//! assert_eq!(Session::from_byte_array(bytes), Session { id, seq: 1 });
+//! ```
+
+use crate::{
+    DecodeError, FromByteArray, FromRawRepr, IntoByteArray, PlainOldData, RawRepr, TryFromRawRepr,
+};
+use uuid::Uuid;
+
+// SAFETY: `Uuid` is `repr(transparent)` over `[u8; 16]` and documented to have the same ABI,
+// so it has no padding and every bit pattern is a valid value.
+unsafe impl PlainOldData for Uuid {}
+
+impl RawRepr for Uuid {
+    type Raw = [u8; 16];
+
+    #[inline]
+    fn to_raw(&self) -> Self::Raw {
+        *self.as_bytes()
+    }
+}
+
+impl FromRawRepr for Uuid {
+    #[inline]
+    fn from_raw(raw: Self::Raw) -> Self {
+        Uuid::from_bytes(raw)
+    }
+}
+
+impl TryFromRawRepr for Uuid {
+    #[inline]
+    fn try_from_raw(raw: Self::Raw) -> Result<Self, DecodeError> {
+        Ok(Self::from_raw(raw))
+    }
+}
+
+impl IntoByteArray for Uuid {
+    type ByteArray = [u8; 16];
+
+    #[inline]
+    fn into_byte_array(&self) -> Self::ByteArray {
+        *self.as_bytes()
+    }
+}
+
+impl FromByteArray for Uuid {
+    #[inline]
+    fn from_byte_array(byte_array: Self::ByteArray) -> Self {
+        Uuid::from_bytes(byte_array)
+    }
+}
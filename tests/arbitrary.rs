@@ -0,0 +1,132 @@
+#![cfg(all(feature = "arbitrary", feature = "derive"))]
+//! Tests for the `arbitrary` feature: endian-wrapper `Arbitrary` impls and
+//! `#[byteable(arbitrary)]`.
+
+use arbitrary::{Arbitrary, Unstructured};
+use byteable::{
+    BigEndian, Byteable, FromByteArray, IntoByteArray, LittleEndian, ReadValue, TryFromByteArray,
+    WriteValue,
+};
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use std::io::Cursor;
+
+#[derive(Byteable, Debug, PartialEq, Clone, Copy)]
+#[byteable(arbitrary)]
+struct Header {
+    #[byteable(big_endian)]
+    id: u16,
+    #[byteable(little_endian)]
+    length: u32,
+    flag: u8,
+    #[byteable(try_transparent)]
+    enabled: bool,
+}
+
+#[derive(Byteable, Debug, PartialEq, Clone, Copy)]
+#[byteable(arbitrary, big_endian)]
+struct Pair(u16, i64);
+
+#[derive(Byteable, Debug, PartialEq, Clone, Copy)]
+#[byteable(arbitrary)]
+#[repr(u8)]
+enum Kind {
+    Ping = 1,
+    Data = 7,
+    Close = 200,
+}
+
+#[derive(Byteable, Debug, PartialEq, Clone, Copy)]
+#[byteable(arbitrary)]
+struct Framed<T> {
+    #[byteable(try_transparent)]
+    kind: Kind,
+    #[byteable(transparent)]
+    payload: T,
+    marker: PhantomData<T>,
+}
+
+#[derive(Byteable, Debug, PartialEq)]
+#[byteable(arbitrary)]
+#[repr(u8)]
+enum Message {
+    Ping,
+    Text { body: String },
+    Point(#[byteable(big_endian)] i32, #[byteable(big_endian)] i32),
+}
+
+/// Builds `count` values from a fixed byte pattern, so every run sees the same inputs.
+fn generate<T: for<'a> Arbitrary<'a>>(count: usize) -> Vec<T> {
+    let bytes: Vec<u8> = (0..count * 64)
+        .map(|i| (i as u32).wrapping_mul(2_654_435_761).to_le_bytes()[3])
+        .collect();
+    let mut u = Unstructured::new(&bytes);
+    (0..count).map(|_| T::arbitrary(&mut u).unwrap()).collect()
+}
+
+fn assert_fixed_roundtrip<T>(count: usize)
+where
+    T: for<'a> Arbitrary<'a> + IntoByteArray + TryFromByteArray + PartialEq + Debug,
+{
+    for value in generate::<T>(count) {
+        let bytes = value.into_byte_array();
+        assert_eq!(T::try_from_byte_array(bytes), Ok(value));
+    }
+}
+
+// ── Endian wrappers ─────────────────────────────────────────────────────────
+
+#[test]
+fn endian_wrappers_generate_native_values() {
+    let bytes = [0x12, 0x34, 0x56, 0x78];
+    let be = BigEndian::<u32>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+    let native = u32::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+    assert_eq!(be.get(), native);
+    let le = LittleEndian::<u32>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+    assert_eq!(le.get(), native);
+    assert_eq!(
+        <BigEndian<u64> as Arbitrary>::size_hint(0),
+        <u64 as Arbitrary>::size_hint(0)
+    );
+}
+
+#[test]
+fn endian_wrappers_roundtrip() {
+    assert_fixed_roundtrip::<BigEndian<u64>>(200);
+    assert_fixed_roundtrip::<LittleEndian<i16>>(200);
+}
+
+// ── #[byteable(arbitrary)] ──────────────────────────────────────────────────
+
+#[test]
+fn structs_roundtrip() {
+    assert_fixed_roundtrip::<Header>(500);
+    for value in generate::<Pair>(500) {
+        assert_eq!(Pair::from_byte_array(value.into_byte_array()), value);
+    }
+}
+
+#[test]
+fn enums_only_generate_declared_variants() {
+    let kinds = generate::<Kind>(300);
+    for kind in [Kind::Ping, Kind::Data, Kind::Close] {
+        assert!(kinds.contains(&kind), "{kind:?} was never generated");
+    }
+    assert_fixed_roundtrip::<Kind>(300);
+}
+
+#[test]
+fn generic_structs_roundtrip() {
+    assert_fixed_roundtrip::<Framed<Pair>>(300);
+}
+
+#[test]
+fn io_only_enums_roundtrip() {
+    let messages = generate::<Message>(300);
+    assert!(messages.iter().any(|m| matches!(m, Message::Text { .. })));
+    for message in messages {
+        let mut buf = Vec::new();
+        buf.write_value(&message).unwrap();
+        assert_eq!(Cursor::new(buf).read_value::<Message>().unwrap(), message);
+    }
+}
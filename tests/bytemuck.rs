@@ -0,0 +1,95 @@
+#![cfg(all(feature = "bytemuck", feature = "derive"))]
+//! Tests for the `bytemuck` interop: endian-wrapper `Pod` impls, slice casts, and
+//! `#[byteable(bytemuck)]`.
+
+use byteable::bytemuck_types::{as_pod_slice, try_cast_slice};
+use byteable::{BigEndian, Byteable, FromRawRepr, LittleEndian, RawRepr};
+use bytemuck::PodCastError;
+
+#[derive(Byteable, Debug, PartialEq, Clone, Copy)]
+#[byteable(bytemuck)]
+struct Record {
+    #[byteable(big_endian)]
+    id: u16,
+    #[byteable(little_endian)]
+    value: u32,
+    flag: u8,
+}
+
+#[derive(Byteable, Debug, PartialEq, Clone, Copy)]
+#[byteable(bytemuck, big_endian)]
+struct Pair(u16, u16);
+
+// ── Endian wrappers ─────────────────────────────────────────────────────────
+
+#[test]
+fn endian_wrappers_are_pod() {
+    let values = [BigEndian::new(0x0102u16), BigEndian::new(0x0304)];
+    assert_eq!(bytemuck::cast_slice::<_, u8>(&values), &[1, 2, 3, 4]);
+
+    let le: LittleEndian<u32> = bytemuck::cast([0x78u8, 0x56, 0x34, 0x12]);
+    assert_eq!(le.get(), 0x1234_5678);
+    assert_eq!(
+        <BigEndian<u64> as bytemuck::Zeroable>::zeroed(),
+        BigEndian::new(0)
+    );
+}
+
+// ── Slice helpers ───────────────────────────────────────────────────────────
+
+#[test]
+fn as_pod_slice_exposes_raw_bytes() {
+    let raws = [Pair(1, 2).to_raw(), Pair(3, 4).to_raw()];
+    assert_eq!(as_pod_slice(&raws), &[0, 1, 0, 2, 0, 3, 0, 4]);
+}
+
+#[test]
+fn try_cast_slice_roundtrips() {
+    let bytes = [0u8, 1, 0xAA, 0, 0, 0, 7, 0, 2, 0xBB, 0, 0, 0, 8];
+    let raws: &[<Record as RawRepr>::Raw] = try_cast_slice(&bytes).unwrap();
+    assert_eq!(raws.len(), 2);
+    assert_eq!(
+        Record::from_raw(raws[1]),
+        Record {
+            id: 2,
+            value: 0xBB,
+            flag: 8
+        }
+    );
+}
+
+#[test]
+fn try_cast_slice_rejects_slop() {
+    assert_eq!(
+        try_cast_slice::<BigEndian<u16>>(&[1, 2, 3]).unwrap_err(),
+        PodCastError::OutputSliceWouldHaveSlop
+    );
+}
+
+#[test]
+fn try_cast_slice_rejects_misaligned() {
+    let storage = [0u32; 3];
+    let bytes = &bytemuck::cast_slice::<_, u8>(&storage)[1..9];
+    assert_eq!(
+        try_cast_slice::<u32>(bytes).unwrap_err(),
+        PodCastError::TargetAlignmentGreaterAndInputNotAligned
+    );
+}
+
+// ── #[byteable(bytemuck)] ───────────────────────────────────────────────────
+
+#[test]
+fn derived_raw_struct_is_pod() {
+    let record = Record {
+        id: 0x0102,
+        value: 0x0A0B_0C0D,
+        flag: 0xFF,
+    };
+    let raw = record.to_raw();
+    assert_eq!(
+        bytemuck::bytes_of(&raw),
+        &[0x01, 0x02, 0x0D, 0x0C, 0x0B, 0x0A, 0xFF]
+    );
+    let back: <Record as RawRepr>::Raw = bytemuck::pod_read_unaligned(bytemuck::bytes_of(&raw));
+    assert_eq!(Record::from_raw(back), record);
+}
@@ -0,0 +1,173 @@
+//! Integration tests for the `tokio_util::codec` framing layer.
+//!
+//! Covers `ByteableCodec` encoding and decoding, partial frames, oversized and trailing
+//! data, and a round trip through `Framed` over an in-memory duplex stream.
+#![cfg(all(feature = "tokio-util", feature = "derive"))]
+
+use byteable::{BigEndian, Byteable, ByteableCodec, LittleEndian};
+use futures_util::{SinkExt, StreamExt};
+use std::io;
+use tokio_util::bytes::BytesMut;
+use tokio_util::codec::{Decoder, Encoder, Framed};
+
+#[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+struct Header {
+    #[byteable(big_endian)]
+    magic: u32,
+    #[byteable(little_endian)]
+    version: u16,
+}
+
+#[derive(Byteable, Debug, Clone, PartialEq)]
+#[byteable(io_only)]
+struct Chat {
+    #[byteable(little_endian)]
+    id: u32,
+    text: String,
+}
+
+const HEADER: Header = Header {
+    magic: 0xCAFE_BABE,
+    version: 3,
+};
+
+// ── Encoding ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn encode_writes_length_then_body() {
+    let mut codec = ByteableCodec::<Header>::new(64);
+    let mut buf = BytesMut::new();
+    codec.encode(HEADER, &mut buf).unwrap();
+    assert_eq!(&buf[..], &[0, 0, 0, 6, 0xCA, 0xFE, 0xBA, 0xBE, 3, 0]);
+}
+
+#[test]
+fn encode_uses_the_chosen_prefix_type() {
+    let mut codec = ByteableCodec::<u8, LittleEndian<u16>>::new(64);
+    let mut buf = BytesMut::new();
+    codec.encode(7, &mut buf).unwrap();
+    assert_eq!(&buf[..], &[1, 0, 7]);
+}
+
+#[test]
+fn encode_rejects_oversized_frame() {
+    let mut codec = ByteableCodec::<Header>::new(4);
+    let mut buf = BytesMut::new();
+    let err = codec.encode(HEADER, &mut buf).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn encode_rejects_length_that_overflows_prefix() {
+    let mut codec = ByteableCodec::<Vec<u8>, u8>::new(usize::MAX);
+    let mut buf = BytesMut::new();
+    let err = codec.encode(vec![0; 300], &mut buf).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    assert!(buf.is_empty());
+}
+
+// ── Decoding ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn decode_roundtrip_variable_length() {
+    let mut codec = ByteableCodec::<Chat>::new(1024);
+    let chat = Chat {
+        id: 9,
+        text: "hello".into(),
+    };
+    let mut buf = BytesMut::new();
+    codec.encode(chat.clone(), &mut buf).unwrap();
+    assert_eq!(codec.decode(&mut buf).unwrap(), Some(chat));
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn decode_waits_for_complete_frame() {
+    let mut codec = ByteableCodec::<Header>::new(64);
+    let mut encoded = BytesMut::new();
+    codec.encode(HEADER, &mut encoded).unwrap();
+
+    let mut buf = BytesMut::new();
+    for (i, byte) in encoded.iter().enumerate() {
+        assert_eq!(codec.decode(&mut buf).unwrap(), None, "after {i} bytes");
+        buf.extend_from_slice(&[*byte]);
+    }
+    assert_eq!(codec.decode(&mut buf).unwrap(), Some(HEADER));
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn decode_leaves_following_frames_buffered() {
+    let mut codec = ByteableCodec::<u8, u8>::new(64);
+    let mut buf = BytesMut::from(&[1, 10, 1, 20, 1][..]);
+    assert_eq!(codec.decode(&mut buf).unwrap(), Some(10));
+    assert_eq!(codec.decode(&mut buf).unwrap(), Some(20));
+    assert_eq!(codec.decode(&mut buf).unwrap(), None);
+    assert_eq!(&buf[..], &[1]);
+}
+
+#[test]
+fn decode_rejects_oversized_frame_before_body_arrives() {
+    let mut codec = ByteableCodec::<Vec<u8>, BigEndian<u32>>::new(16);
+    let mut buf = BytesMut::from(&[0, 0, 1, 0][..]);
+    let err = codec.decode(&mut buf).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn decode_rejects_trailing_bytes_in_frame() {
+    let mut codec = ByteableCodec::<u8, u8>::new(64);
+    let mut buf = BytesMut::from(&[2, 10, 11][..]);
+    let err = codec.decode(&mut buf).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn decode_error_is_invalid_data() {
+    let mut codec = ByteableCodec::<bool, u8>::new(64);
+    let mut buf = BytesMut::from(&[1, 2][..]);
+    let err = codec.decode(&mut buf).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn frame_shorter_than_value_is_unexpected_eof() {
+    let mut codec = ByteableCodec::<Header, u8>::new(64);
+    let mut buf = BytesMut::from(&[2, 0xCA, 0xFE][..]);
+    let err = codec.decode(&mut buf).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+}
+
+// ── Framed ───────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn framed_roundtrip_over_duplex() {
+    let (client, server) = tokio::io::duplex(8);
+    let mut client = Framed::new(client, ByteableCodec::<Chat>::new(1024));
+    let mut server = Framed::new(server, ByteableCodec::<Chat>::new(1024));
+
+    let messages = vec![
+        Chat {
+            id: 1,
+            text: "first".into(),
+        },
+        Chat {
+            id: 2,
+            text: "a somewhat longer second message".into(),
+        },
+    ];
+
+    let sent = messages.clone();
+    let writer = tokio::spawn(async move {
+        for message in sent {
+            client.send(message).await.unwrap();
+        }
+    });
+
+    for expected in messages {
+        assert_eq!(server.next().await.unwrap().unwrap(), expected);
+    }
+    writer.await.unwrap();
+    assert!(server.next().await.is_none());
+}
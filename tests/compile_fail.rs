@@ -0,0 +1,21 @@
+//! Derive inputs and API uses that must be rejected at compile time.
+//!
+//! Each case lives in `tests/ui`, next to the compiler output it is expected to produce.
+//! Run with `TRYBUILD=overwrite` to regenerate the `.stderr` files after changing a message.
+#![cfg(feature = "derive")]
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+    #[cfg(feature = "std")]
+    t.compile_fail("tests/ui/std/*.rs");
+    #[cfg(feature = "bytemuck")]
+    t.compile_fail("tests/ui/bytemuck/*.rs");
+    #[cfg(feature = "tokio")]
+    t.compile_fail("tests/ui/tokio/*.rs");
+    #[cfg(feature = "arbitrary")]
+    t.compile_fail("tests/ui/arbitrary/*.rs");
+    #[cfg(not(feature = "arbitrary"))]
+    t.compile_fail("tests/ui/no_arbitrary/*.rs");
+}
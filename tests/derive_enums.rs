@@ -1,11 +1,15 @@
 //! Tests for `#[derive(Byteable)]` on C-like enums.
 //!
-//! Covers all supported `#[repr]` types (`u8`–`u128`, `i8`–`i64`), explicit
+//! Covers all supported `#[repr]` types (`u8`–`u128`, `i8`–`i128`), explicit
 //! and auto-inferred endianness, auto-inferred repr and discriminants, sparse
-//! discriminants, and invalid-discriminant error reporting.
+//! discriminants, invalid-discriminant error reporting, and `c_union` enums laid out
+//! like C tagged unions.
 #![cfg(feature = "derive")]
 
-use byteable::{Byteable, DecodeError, IntoByteArray, PlainOldData, RawRepr, TryFromByteArray};
+use byteable::{
+    Byteable, DecodeError, DecodeErrorKind, Discriminant, FromByteArray, FromRawRepr,
+    IntoByteArray, PlainOldData, RawRepr, TryFromByteArray,
+};
 
 // ── u8 repr ───────────────────────────────────────────────────────────────────
 
@@ -172,8 +176,9 @@ fn discriminant_value_u32() {
     assert!(matches!(
         err,
         DecodeError::InvalidDiscriminant {
-            raw: _,
-            type_name: _
+            raw: Discriminant::U32(0xFFFF_FFFF),
+            type_name: _,
+            ..
         }
     ))
 }
@@ -254,8 +259,9 @@ fn discriminant_value_u64() {
     assert!(matches!(
         err,
         DecodeError::InvalidDiscriminant {
-            raw: _,
-            type_name: _
+            raw: Discriminant::U64(42),
+            type_name: _,
+            ..
         }
     ))
 }
@@ -289,12 +295,35 @@ fn discriminant_value_i8() {
     assert!(matches!(
         err,
         DecodeError::InvalidDiscriminant {
-            raw: _,
-            type_name: _
+            raw: Discriminant::I8(5),
+            type_name: _,
+            ..
         }
     ))
 }
 
+#[test]
+fn negative_discriminant_value_i8() {
+    let err = Temperature::try_from_byte_array([(-5i8) as u8]).unwrap_err();
+    assert!(matches!(
+        err,
+        DecodeError::InvalidDiscriminant {
+            raw: Discriminant::I8(-5),
+            type_name: _,
+            ..
+        }
+    ))
+}
+
+#[test]
+fn invalid_discriminant_message_keeps_sign_and_type() {
+    let err = Temperature::try_from_byte_array([0xFF]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid discriminant -1i8 for type Temperature, expected one of -10, 0, 10, 30"
+    );
+}
+
 // ── i16 repr ──────────────────────────────────────────────────────────────────
 
 #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
@@ -384,8 +413,9 @@ fn i32_le_enum_invalid_discriminant() {
     assert!(matches!(
         err,
         DecodeError::InvalidDiscriminant {
-            raw: _,
-            type_name: _
+            raw: Discriminant::I32(42),
+            type_name: _,
+            ..
         }
     ))
 }
@@ -446,8 +476,9 @@ fn i64_le_enum_invalid_discriminant() {
     assert!(matches!(
         err,
         DecodeError::InvalidDiscriminant {
-            raw: _,
-            type_name: _
+            raw: Discriminant::I64(1),
+            type_name: _,
+            ..
         }
     ))
 }
@@ -504,6 +535,51 @@ fn sparse_enum_gaps_are_invalid() {
     }
 }
 
+#[test]
+fn sparse_enum_error_lists_valid_discriminants() {
+    let err = SparseEnum::try_from_byte_array([6]).unwrap_err();
+    assert_eq!(
+        err,
+        DecodeError::InvalidDiscriminant {
+            raw: Discriminant::U8(6),
+            type_name: "SparseEnum",
+            valid_discriminants: &[
+                Discriminant::U8(1),
+                Discriminant::U8(5),
+                Discriminant::U8(10),
+                Discriminant::U8(100),
+            ],
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "invalid discriminant 6u8 for type SparseEnum, expected one of 1, 5, 10, 100"
+    );
+}
+
+#[test]
+fn long_valid_discriminant_list_is_truncated() {
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    enum Digit {
+        D0,
+        D1,
+        D2,
+        D3,
+        D4,
+        D5,
+        D6,
+        D7,
+        D8,
+        D9,
+    }
+    let err = Digit::try_from_byte_array([10]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid discriminant 10u8 for type Digit, expected one of 0, 1, 2, 3, 4, 5, 6, 7, \
+         ... (10 total)"
+    );
+}
+
 #[test]
 fn endianness_irrelevant_for_u8() {
     // Single-byte enums behave identically regardless of endian annotation.
@@ -588,10 +664,215 @@ fn mixed_explicit_and_auto_discriminants() {
     assert!(MixedDiscEnum::try_from_byte_array([12u8]).is_err());
 }
 
+// ── Default variant for unknown discriminants ────────────────────────────────
+
+#[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+#[repr(u16)]
+#[byteable(big_endian, default = Unknown)]
+enum MessageKind {
+    Hello = 1,
+    Data = 2,
+    Unknown = 0xFFFF,
+}
+
+#[test]
+fn default_variant_known_discriminants() {
+    assert_eq!(MessageKind::from_byte_array([0, 1]), MessageKind::Hello);
+    assert_eq!(MessageKind::from_byte_array([0, 2]), MessageKind::Data);
+    assert_eq!(
+        MessageKind::from_byte_array([0xFF, 0xFF]),
+        MessageKind::Unknown
+    );
+}
+
+#[test]
+fn default_variant_absorbs_unknown_discriminants() {
+    assert_eq!(MessageKind::from_byte_array([0, 3]), MessageKind::Unknown);
+    assert_eq!(MessageKind::from_raw(0x1234), MessageKind::Unknown);
+    assert_eq!(
+        MessageKind::try_from_byte_array([0x12, 0x34]).unwrap(),
+        MessageKind::Unknown
+    );
+}
+
+#[test]
+fn default_variant_roundtrip_is_lossy() {
+    // The original discriminant is not preserved: re-encoding writes the default's own value.
+    let decoded = MessageKind::from_byte_array([0, 42]);
+    assert_eq!(decoded.into_byte_array(), [0xFF, 0xFF]);
+    assert_eq!(
+        MessageKind::from_byte_array(decoded.into_byte_array()),
+        decoded
+    );
+}
+
+#[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+#[byteable(default = Other)]
+enum Color {
+    Red,
+    Green,
+    Other,
+}
+
+#[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+struct Pixel {
+    color: Color,
+    #[byteable(little_endian)]
+    x: u16,
+}
+
+#[test]
+fn default_variant_enum_is_plain_struct_field() {
+    // No `try_transparent` needed: decoding the enum can no longer fail.
+    let pixel = Pixel::from_byte_array([9, 5, 0]);
+    assert_eq!(
+        pixel,
+        Pixel {
+            color: Color::Other,
+            x: 5
+        }
+    );
+    assert_eq!(Pixel::from_byte_array([1, 5, 0]).color, Color::Green);
+}
+
+// ── Arrays of enums ──────────────────────────────────────────────────────────
+
+#[test]
+fn enum_array_roundtrip() {
+    let statuses = [
+        Status::Idle,
+        Status::Failed,
+        Status::Running,
+        Status::Completed,
+    ];
+    assert_eq!(statuses.into_byte_array(), [[0], [3], [1], [2]]);
+    assert_eq!(
+        <[Status; 4]>::try_from_byte_array([[0], [3], [1], [2]]),
+        Ok(statuses)
+    );
+
+    let commands = [Command::Stop, Command::Start];
+    assert_eq!(commands.into_byte_array(), [[0x00, 0x20], [0x00, 0x10]]);
+    assert_eq!(
+        <[Command; 2]>::try_from_byte_array([[0x00, 0x20], [0x00, 0x10]]),
+        Ok(commands)
+    );
+}
+
+#[test]
+fn enum_array_names_first_invalid_element() {
+    let err = <[Status; 4]>::try_from_byte_array([[0], [1], [7], [9]]).unwrap_err();
+    assert_eq!(
+        err,
+        DecodeError::ArrayElement {
+            index: 2,
+            kind: DecodeErrorKind::InvalidDiscriminant,
+        }
+    );
+    assert_eq!(err.to_string(), "invalid discriminant at array index 2");
+
+    // A single enum still reports the plain variant.
+    assert!(matches!(
+        Status::try_from_byte_array([7]),
+        Err(DecodeError::InvalidDiscriminant { .. })
+    ));
+}
+
+#[test]
+fn nested_enum_array_names_the_outer_element() {
+    let mut bytes = [[Status::Idle; 2]; 3].into_byte_array();
+    bytes[2][1] = [9];
+    assert_eq!(
+        <[[Status; 2]; 3]>::try_from_byte_array(bytes),
+        Err(DecodeError::ArrayElement {
+            index: 2,
+            kind: DecodeErrorKind::InvalidDiscriminant,
+        })
+    );
+}
+
+#[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+#[byteable(big_endian)]
+struct StatusLog {
+    seq: u16,
+    #[byteable(try_transparent)]
+    entries: [Status; 3],
+}
+
+#[test]
+fn enum_array_as_struct_field() {
+    let log = StatusLog::try_from_byte_array([0, 5, 1, 1, 2]).unwrap();
+    assert_eq!(
+        log,
+        StatusLog {
+            seq: 5,
+            entries: [Status::Running, Status::Running, Status::Completed],
+        }
+    );
+    assert!(matches!(
+        StatusLog::try_from_byte_array([0, 5, 1, 4, 2]),
+        Err(DecodeError::ArrayElement { index: 1, .. })
+    ));
+}
+
+// ── OpenEnum ─────────────────────────────────────────────────────────────────
+
+mod open_enum {
+    use byteable::{Byteable, FromByteArray, IntoByteArray, OpenEnum};
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[repr(u16)]
+    #[byteable(big_endian)]
+    enum Opcode {
+        Read = 1,
+        Write = 2,
+    }
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct Request {
+        op: OpenEnum<Opcode>,
+        len: u8,
+    }
+
+    #[test]
+    fn known_discriminant_decodes_to_variant() {
+        let value = OpenEnum::<Opcode>::from_byte_array([0, 2]);
+        assert_eq!(value, OpenEnum::Known(Opcode::Write));
+        assert!(value.is_known());
+        assert_eq!(value.get(), Ok(Opcode::Write));
+        assert_eq!(value.raw(), None);
+        assert_eq!(value.into_byte_array(), [0, 2]);
+    }
+
+    #[test]
+    fn unknown_discriminant_is_retained() {
+        let value = OpenEnum::<Opcode>::from_byte_array([0x12, 0x34]);
+        assert_eq!(value, OpenEnum::Unknown([0x12, 0x34]));
+        assert!(!value.is_known());
+        assert_eq!(value.get(), Err([0x12, 0x34]));
+        assert_eq!(value.raw(), Some([0x12, 0x34]));
+        assert_eq!(value.into_byte_array(), [0x12, 0x34]);
+    }
+
+    #[test]
+    fn from_known_variant() {
+        let value: OpenEnum<Opcode> = Opcode::Read.into();
+        assert_eq!(value.into_byte_array(), [0, 1]);
+    }
+
+    #[test]
+    fn struct_field_roundtrips_unknown_bytes() {
+        let request = Request::from_byte_array([0xAB, 0xCD, 9]);
+        assert_eq!(request.op, OpenEnum::Unknown([0xAB, 0xCD]));
+        assert_eq!(request.len, 9);
+        assert_eq!(request.into_byte_array(), [0xAB, 0xCD, 9]);
+    }
+}
+
 // ── u128 / i128 repr ──────────────────────────────────────────────────────────
 
 mod u128_enums {
-    use byteable::{Byteable, DecodeError, IntoByteArray, TryFromByteArray};
+    use byteable::{Byteable, DecodeError, Discriminant, IntoByteArray, TryFromByteArray};
 
     #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
     #[repr(u128)]
@@ -623,14 +904,20 @@ mod u128_enums {
 
     #[test]
     fn u128_enum_invalid_discriminant() {
-        let err = LargeU128::try_from_byte_array(1u128.to_le_bytes()).unwrap_err();
-        assert!(matches!(
+        // Above u64::MAX, so a truncating conversion would report the wrong value.
+        let err = LargeU128::try_from_byte_array((1u128 << 100).to_le_bytes()).unwrap_err();
+        assert_eq!(
             err,
             DecodeError::InvalidDiscriminant {
-                raw: _,
-                type_name: _
+                raw: Discriminant::U128(1 << 100),
+                type_name: "LargeU128",
+                valid_discriminants: &[
+                    Discriminant::U128(0),
+                    Discriminant::U128(1 << 127),
+                    Discriminant::U128(u128::MAX),
+                ],
             }
-        ))
+        );
     }
 
     #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
@@ -674,8 +961,22 @@ mod u128_enums {
         assert!(matches!(
             err,
             DecodeError::InvalidDiscriminant {
-                raw: _,
-                type_name: _
+                raw: Discriminant::I128(1),
+                type_name: _,
+                ..
+            }
+        ))
+    }
+
+    #[test]
+    fn i128_enum_negative_invalid_discriminant() {
+        let err = SignedI128::try_from_byte_array((-1i128).to_le_bytes()).unwrap_err();
+        assert!(matches!(
+            err,
+            DecodeError::InvalidDiscriminant {
+                raw: Discriminant::I128(-1),
+                type_name: _,
+                ..
             }
         ))
     }
@@ -704,3 +1005,401 @@ mod u128_enums {
         );
     }
 }
+
+// ── C tagged unions ───────────────────────────────────────────────────────────
+
+mod c_union {
+    use byteable::{Byteable, DecodeError, Discriminant, IntoByteArray, TryFromByteArray};
+
+    /// Mirrors this C declaration:
+    ///
+    /// ```c
+    /// struct value {
+    ///     uint32_t tag;
+    ///     union {
+    ///         int32_t integer;                          /* tag 1 */
+    ///         struct { uint16_t x, y; uint8_t z; } point; /* tag 2 */
+    ///         struct { uint8_t a; uint32_t b; } padded; /* tag 3 */
+    ///     } payload;
+    /// };
+    /// ```
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[repr(u32)]
+    #[byteable(c_union, tag_repr = u32, payload_size = 8, little_endian)]
+    enum Value {
+        Empty = 0,
+        Integer(i32) = 1,
+        Point {
+            x: u16,
+            y: u16,
+            z: u8,
+        } = 2,
+        // C aligns `b` to 4, leaving three bytes after `a`.
+        Padded {
+            a: u8,
+            #[byteable(pad_before = 3)]
+            b: u32,
+        } = 3,
+    }
+
+    // The hand-written C layout. The union has alignment 4, so there is no padding after
+    // the tag, and size 8 (`sizeof` rounds the 6-byte `point` up), which is `payload_size`.
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    struct CPoint {
+        x: u16,
+        y: u16,
+        z: u8,
+    }
+
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    struct CPadded {
+        a: u8,
+        b: u32,
+    }
+
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    union CPayload {
+        integer: i32,
+        point: CPoint,
+        padded: CPadded,
+        bytes: [u8; 8],
+    }
+
+    #[repr(C)]
+    struct CValue {
+        tag: u32,
+        payload: CPayload,
+    }
+
+    fn c_bytes(value: CValue) -> [u8; 12] {
+        assert_eq!(core::mem::size_of::<CValue>(), 12);
+        unsafe { core::mem::transmute(value) }
+    }
+
+    #[test]
+    fn byte_size_is_tag_plus_payload() {
+        assert_eq!(Value::BYTE_SIZE, 12);
+    }
+
+    #[cfg(target_endian = "little")]
+    #[test]
+    fn integer_variant_matches_c_layout() {
+        let mut payload = CPayload { bytes: [0; 8] };
+        payload.integer = -2;
+        let expected = c_bytes(CValue { tag: 1, payload });
+
+        assert_eq!(Value::Integer(-2).into_byte_array(), expected);
+        assert_eq!(
+            Value::try_from_byte_array(expected).unwrap(),
+            Value::Integer(-2)
+        );
+    }
+
+    #[cfg(target_endian = "little")]
+    #[test]
+    fn struct_variant_matches_c_layout() {
+        let mut payload = CPayload { bytes: [0; 8] };
+        payload.point = CPoint {
+            x: 0x0102,
+            y: 0x0304,
+            z: 5,
+        };
+        let mut expected = c_bytes(CValue { tag: 2, payload });
+        // The C compiler may leave `CPoint`'s padding byte unspecified; the derive writes 0.
+        expected[9] = 0;
+
+        let value = Value::Point {
+            x: 0x0102,
+            y: 0x0304,
+            z: 5,
+        };
+        assert_eq!(value.into_byte_array(), expected);
+        assert_eq!(Value::try_from_byte_array(expected).unwrap(), value);
+    }
+
+    #[cfg(target_endian = "little")]
+    #[test]
+    fn padded_struct_variant_matches_c_layout() {
+        let mut payload = CPayload { bytes: [0; 8] };
+        payload.padded = CPadded {
+            a: 7,
+            b: 0x0102_0304,
+        };
+        let mut expected = c_bytes(CValue { tag: 3, payload });
+        // The padding after `a` is unspecified in C; the derive writes zeros.
+        expected[5..8].fill(0);
+
+        let value = Value::Padded {
+            a: 7,
+            b: 0x0102_0304,
+        };
+        assert_eq!(value.into_byte_array(), expected);
+        assert_eq!(Value::try_from_byte_array(expected).unwrap(), value);
+    }
+
+    #[test]
+    fn variant_padding_is_ignored_on_decode() {
+        let bytes = [3, 0, 0, 0, 7, 0xAA, 0xBB, 0xCC, 4, 3, 2, 1];
+        assert_eq!(
+            Value::try_from_byte_array(bytes).unwrap(),
+            Value::Padded {
+                a: 7,
+                b: 0x0102_0304
+            }
+        );
+    }
+
+    #[test]
+    fn payload_is_zero_padded() {
+        assert_eq!(
+            Value::Integer(0x0A0B_0C0D).into_byte_array(),
+            [1, 0, 0, 0, 0x0D, 0x0C, 0x0B, 0x0A, 0, 0, 0, 0]
+        );
+        assert_eq!(Value::Empty.into_byte_array(), [0; 12]);
+    }
+
+    #[test]
+    fn unknown_tag_is_err() {
+        let mut bytes = Value::Empty.into_byte_array();
+        bytes[0] = 9;
+        assert_eq!(
+            Value::try_from_byte_array(bytes),
+            Err(DecodeError::InvalidDiscriminant {
+                raw: Discriminant::U32(9),
+                type_name: "Value",
+                valid_discriminants: &[
+                    Discriminant::U32(0),
+                    Discriminant::U32(1),
+                    Discriminant::U32(2),
+                    Discriminant::U32(3),
+                ],
+            })
+        );
+    }
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[repr(u16)]
+    #[byteable(c_union, tag_repr = u16, payload_size = 4, big_endian)]
+    enum Event {
+        Click(u16, u16) = 0x0100,
+        Key {
+            #[byteable(try_transparent)]
+            pressed: bool,
+            #[byteable(little_endian)]
+            code: u16,
+        } = 0x0200,
+    }
+
+    #[test]
+    fn container_and_field_endianness() {
+        assert_eq!(
+            Event::Click(1, 2).into_byte_array(),
+            [0x01, 0x00, 0, 1, 0, 2]
+        );
+        assert_eq!(
+            Event::Key {
+                pressed: true,
+                code: 0x0304
+            }
+            .into_byte_array(),
+            [0x02, 0x00, 1, 0x04, 0x03, 0]
+        );
+    }
+
+    #[test]
+    fn field_errors_propagate() {
+        assert_eq!(
+            Event::try_from_byte_array([0x02, 0x00, 7, 0, 0, 0]),
+            Err(DecodeError::InvalidBool(7))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn read_write_fixed() {
+        use byteable::{ReadFixed, WriteFixed};
+
+        let events = [
+            Event::Click(10, 20),
+            Event::Key {
+                pressed: false,
+                code: 65,
+            },
+        ];
+        let mut buf = Vec::new();
+        for event in &events {
+            buf.write_fixed(event).unwrap();
+        }
+        assert_eq!(buf.len(), 12);
+
+        let mut cursor = std::io::Cursor::new(buf);
+        for event in &events {
+            assert_eq!(cursor.read_fixed::<Event>().unwrap(), *event);
+        }
+    }
+}
+
+// ── Fixed-size field enums ────────────────────────────────────────────────────
+
+mod tagged {
+    use byteable::{
+        Byteable, DecodeError, DecodeErrorKind, Discriminant, IntoByteArray, LittleEndian,
+        TryFromByteArray,
+    };
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[repr(u8)]
+    #[byteable(tagged)]
+    enum Msg {
+        Ping,
+        Data(LittleEndian<u32>),
+        Coords([LittleEndian<i16>; 2]),
+    }
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[repr(u16)]
+    #[byteable(tagged, big_endian)]
+    enum Command {
+        Reset = 0x10,
+        Move { x: i16, y: i16, fast: u8 } = 0x20,
+        Wait(#[byteable(little_endian)] u32) = 0x30,
+        Toggle(#[byteable(try_transparent)] bool) = 0x40,
+    }
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[byteable(big_endian)]
+    struct Frame {
+        seq: u16,
+        #[byteable(try_transparent)]
+        msg: Msg,
+    }
+
+    #[test]
+    fn payload_is_sized_to_largest_variant() {
+        assert_eq!(Msg::BYTE_SIZE, 1 + 4);
+        assert_eq!(Command::BYTE_SIZE, 2 + 5);
+    }
+
+    #[test]
+    fn tag_then_fields_then_zero_padding() {
+        assert_eq!(Msg::Ping.into_byte_array(), [0, 0, 0, 0, 0]);
+        assert_eq!(
+            Msg::Data(LittleEndian::new(0x0403_0201)).into_byte_array(),
+            [1, 1, 2, 3, 4]
+        );
+        assert_eq!(
+            Msg::Coords([LittleEndian::new(1), LittleEndian::new(-2)]).into_byte_array(),
+            [2, 1, 0, 0xFE, 0xFF]
+        );
+    }
+
+    #[test]
+    fn container_and_variant_endianness() {
+        assert_eq!(
+            Command::Reset.into_byte_array(),
+            [0x00, 0x10, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            Command::Move {
+                x: 1,
+                y: -1,
+                fast: 1
+            }
+            .into_byte_array(),
+            [0x00, 0x20, 0x00, 0x01, 0xFF, 0xFF, 0x01]
+        );
+        assert_eq!(
+            Command::Wait(0x0102_0304).into_byte_array(),
+            [0x00, 0x30, 0x04, 0x03, 0x02, 0x01, 0]
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        for msg in [
+            Msg::Ping,
+            Msg::Data(LittleEndian::new(u32::MAX)),
+            Msg::Coords([LittleEndian::new(-7), LittleEndian::new(7)]),
+        ] {
+            assert_eq!(Msg::try_from_byte_array(msg.into_byte_array()), Ok(msg));
+        }
+        for command in [
+            Command::Reset,
+            Command::Move {
+                x: -300,
+                y: 300,
+                fast: 0,
+            },
+            Command::Wait(5),
+            Command::Toggle(true),
+        ] {
+            assert_eq!(
+                Command::try_from_byte_array(command.into_byte_array()),
+                Ok(command)
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_tag_and_invalid_field_are_errors() {
+        assert_eq!(
+            Msg::try_from_byte_array([3, 0, 0, 0, 0]),
+            Err(DecodeError::InvalidDiscriminant {
+                raw: Discriminant::U8(3),
+                type_name: "Msg",
+                valid_discriminants: &[
+                    Discriminant::U8(0),
+                    Discriminant::U8(1),
+                    Discriminant::U8(2),
+                ],
+            })
+        );
+        assert_eq!(
+            Command::try_from_byte_array([0x00, 0x40, 2, 0, 0, 0, 0]),
+            Err(DecodeError::InvalidBool(2))
+        );
+    }
+
+    #[test]
+    fn usable_as_struct_field() {
+        let frame = Frame {
+            seq: 9,
+            msg: Msg::Data(LittleEndian::new(1)),
+        };
+        let bytes = frame.into_byte_array();
+        assert_eq!(bytes, [0, 9, 1, 1, 0, 0, 0]);
+        assert_eq!(Frame::try_from_byte_array(bytes), Ok(frame));
+    }
+
+    #[test]
+    fn arrays_of_tagged_enums_name_the_bad_element() {
+        let msgs = [Msg::Ping, Msg::Data(LittleEndian::new(7))];
+        let bytes = msgs.into_byte_array();
+        assert_eq!(<[Msg; 2]>::try_from_byte_array(bytes), Ok(msgs));
+
+        let mut bad = bytes;
+        bad[1][0] = 9;
+        assert_eq!(
+            <[Msg; 2]>::try_from_byte_array(bad),
+            Err(DecodeError::ArrayElement {
+                index: 1,
+                kind: DecodeErrorKind::InvalidDiscriminant,
+            })
+        );
+
+        // An invalid payload field keeps the position of the enum in the array.
+        let cmds = [Command::Reset, Command::Wait(1), Command::Toggle(true)];
+        let mut bad = cmds.into_byte_array();
+        bad[2][2] = 2;
+        assert_eq!(
+            <[Command; 3]>::try_from_byte_array(bad),
+            Err(DecodeError::ArrayElement {
+                index: 2,
+                kind: DecodeErrorKind::InvalidBool,
+            })
+        );
+    }
+}
@@ -222,6 +222,45 @@ fn mixed_field_annotations_roundtrip() {
     }
 }
 
+// ── Enum-level default endianness ────────────────────────────────────────────
+
+#[derive(Byteable, Debug, PartialEq)]
+#[repr(u16)]
+#[byteable(big_endian)]
+enum Frame {
+    Data { id: u32, flags: u8, #[byteable(little_endian)] crc: u16 } = 1,
+    Text(u16, String) = 2,
+}
+
+#[test]
+fn enum_endianness_applies_to_multi_byte_fields() {
+    let original = Frame::Data {
+        id: 0x0102_0304,
+        flags: 0xAA,
+        crc: 0x0506,
+    };
+    let mut buf = Vec::new();
+    buf.write_value(&original).unwrap();
+    // discriminant and id big-endian, flags untouched, crc keeps its own little-endian
+    assert_eq!(buf, [0x00, 0x01, 0x01, 0x02, 0x03, 0x04, 0xAA, 0x06, 0x05]);
+
+    let decoded: Frame = Cursor::new(&buf).read_value().unwrap();
+    assert_eq!(decoded, original);
+}
+
+#[test]
+fn enum_endianness_leaves_non_primitive_fields_alone() {
+    let original = Frame::Text(0x0102, "hi".into());
+    let mut buf = Vec::new();
+    buf.write_value(&original).unwrap();
+    assert_eq!(&buf[..4], &[0x00, 0x02, 0x01, 0x02]);
+    // the String keeps its usual little-endian u64 length prefix
+    assert_eq!(&buf[4..12], &2u64.to_le_bytes());
+
+    let decoded: Frame = Cursor::new(&buf).read_value().unwrap();
+    assert_eq!(decoded, original);
+}
+
 // ── C-like enum as a field (uses newly-added Readable impl) ──────────────────
 
 #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
@@ -17,6 +17,7 @@ mod named_structs {
         d: f64,
     }
 
+    #[allow(clippy::approx_constant)]
     fn make_test() -> TestStruct {
         TestStruct {
             a: 42,
@@ -55,6 +56,7 @@ mod named_structs {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn le_f64_field_layout() {
         let bytes = make_test().into_byte_array();
         let d_bytes: [u8; 8] = bytes[11..19].try_into().unwrap();
@@ -226,6 +228,156 @@ mod unit_structs {
     }
 }
 
+// ── Zero-sized fields ────────────────────────────────────────────────────────
+
+mod zero_sized_fields {
+    use byteable::{Byteable, ByteableLayout, IntoByteArray, ReadFixed, TryFromByteArray};
+    use core::marker::PhantomData;
+    use std::io::Cursor;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Byteable)]
+    #[byteable(big_endian)]
+    struct Tagged<T> {
+        id: u16,
+        unit: (),
+        marker: PhantomData<T>,
+        len: u16,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Byteable)]
+    struct Handle(u8, PhantomData<String>, ());
+
+    #[test]
+    fn zero_sized_fields_add_no_bytes() {
+        let tagged = Tagged::<String> {
+            id: 0x0102,
+            unit: (),
+            marker: PhantomData,
+            len: 0x0304,
+        };
+        let bytes = tagged.into_byte_array();
+        assert_eq!(size_of_val(&bytes), 4);
+        assert_eq!(
+            Tagged::<String>::try_from_byte_array(bytes).unwrap(),
+            tagged
+        );
+        assert_eq!(
+            Cursor::new([1, 2, 3, 4])
+                .read_fixed::<Tagged<String>>()
+                .unwrap(),
+            tagged
+        );
+        assert_eq!(
+            Tagged::<String>::BYTE_OFFSETS,
+            &[("id", 0), ("unit", 2), ("marker", 2), ("len", 2)]
+        );
+    }
+
+    #[test]
+    fn zero_sized_tuple_fields() {
+        let handle = Handle(7, PhantomData, ());
+        assert_eq!(handle.into_byte_array(), [7]);
+        assert_eq!(Handle::try_from_byte_array([7]).unwrap(), handle);
+    }
+}
+
+// ── Structs without Copy ─────────────────────────────────────────────────────
+
+mod non_copy {
+    use byteable::{
+        Byteable, FromByteArray, IntoByteArray, ReadFixed, TryFromByteArray, WriteFixed,
+    };
+    use std::cell::Cell;
+    use std::io::Cursor;
+
+    // Deliberately neither `Clone` nor `Copy`: a 1 KiB record that should only ever move.
+    #[derive(Debug, PartialEq, Byteable)]
+    #[byteable(big_endian)]
+    struct Frame {
+        sequence: u32,
+        samples: [u16; 510],
+        #[byteable(try_transparent)]
+        last: bool,
+    }
+
+    #[derive(Debug, PartialEq, Byteable)]
+    struct Outer {
+        #[byteable(try_transparent)]
+        frame: Frame,
+        checksum: u32,
+    }
+
+    thread_local! {
+        static DROPS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[derive(Byteable)]
+    struct Guard {
+        id: u8,
+    }
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            DROPS.with(|drops| drops.set(drops.get() + 1));
+        }
+    }
+
+    fn frame() -> Frame {
+        let mut samples = [0u16; 510];
+        for (i, sample) in samples.iter_mut().enumerate() {
+            *sample = i as u16;
+        }
+        Frame {
+            sequence: 0x0102_0304,
+            samples,
+            last: true,
+        }
+    }
+
+    #[test]
+    fn roundtrip_without_copy() {
+        let bytes = frame().into_byte_array();
+        assert_eq!(bytes.len(), 1025);
+        assert_eq!(bytes[..6], [1, 2, 3, 4, 0, 0]);
+        assert_eq!(bytes[1024], 1);
+        assert_eq!(Frame::try_from_byte_array(bytes).unwrap(), frame());
+    }
+
+    #[test]
+    fn nested_non_copy_field() {
+        let outer = Outer {
+            frame: frame(),
+            checksum: 7,
+        };
+        let mut buf = Vec::new();
+        buf.write_fixed(&outer).unwrap();
+        assert_eq!(buf.len(), 1029);
+        assert_eq!(Cursor::new(buf).read_fixed::<Outer>().unwrap(), outer);
+    }
+
+    #[test]
+    fn serialized_through_shared_references() {
+        let shared = std::rc::Rc::new(frame());
+        let view = std::rc::Rc::clone(&shared);
+        assert_eq!(view.into_byte_array(), shared.into_byte_array());
+
+        let frames = [frame(), frame()];
+        let total: usize = frames.iter().map(|f| f.into_byte_array().len()).sum();
+        assert_eq!(total, 2 * Frame::BYTE_SIZE);
+    }
+
+    #[test]
+    fn drop_types_are_converted_by_reference() {
+        let guard = Guard { id: 9 };
+        assert_eq!(guard.into_byte_array(), [9]);
+        let decoded = Guard::from_byte_array([3]);
+        assert_eq!(decoded.id, 3);
+        drop(guard);
+        drop(decoded);
+        assert_eq!(DROPS.with(Cell::get), 2);
+    }
+}
+
 // ── Visibility modifiers ──────────────────────────────────────────────────────
 
 mod visibility {
@@ -408,6 +560,7 @@ mod transparent {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn outer_field_layout() {
         let outer = TestStruct {
             member: MemberStruct { a: 10, b: 0x1234 },
@@ -428,6 +581,7 @@ mod transparent {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn roundtrip() {
         let original = TestStruct {
             member: MemberStruct { a: 10, b: 0x1234 },
@@ -447,125 +601,1859 @@ mod transparent {
     }
 }
 
-// ── Compile-time safety validation (PlainOldData) ────────────────────────────
+// ── Struct-level endianness and transparent children ─────────────────────────
 
-mod safety {
+mod container_endian {
     use byteable::{Byteable, FromByteArray, IntoByteArray};
 
-    #[derive(Clone, Copy, Byteable)]
-    pub struct SafePacket {
-        id: u8,
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    struct Child {
         #[byteable(little_endian)]
-        length: u16,
-        #[byteable(big_endian)]
-        checksum: u32,
-        data: [u8; 4],
+        a: u16,
+        b: u32,
     }
 
-    #[derive(Clone, Copy, Byteable)]
-    struct Point {
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    #[byteable(big_endian)]
+    struct Parent {
+        tag: u8,
+        #[byteable(transparent)]
+        child: Child,
+        value: u32,
         #[byteable(little_endian)]
-        x: i32,
+        overridden: u16,
+    }
+
+    fn make_parent() -> Parent {
+        Parent {
+            tag: 7,
+            child: Child {
+                a: 0x0102,
+                b: 0x03040506,
+            },
+            value: 0x0A0B0C0D,
+            overridden: 0x1122,
+        }
+    }
+
+    #[test]
+    fn byte_size() {
+        // u8(1) + child(6) + u32(4) + u16(2) = 13
+        assert_eq!(Parent::BYTE_SIZE, 13);
+    }
+
+    #[test]
+    fn container_endian_applies_to_unannotated_fields() {
+        let bytes = make_parent().into_byte_array();
+        assert_eq!(bytes[0], 7);
+        assert_eq!(&bytes[7..11], &[0x0A, 0x0B, 0x0C, 0x0D]);
+    }
+
+    #[test]
+    fn field_attribute_overrides_container_endian() {
+        let bytes = make_parent().into_byte_array();
+        assert_eq!(&bytes[11..13], &[0x22, 0x11]);
+    }
+
+    #[test]
+    fn container_endian_does_not_reach_transparent_child() {
+        let parent = make_parent();
+        let bytes = parent.into_byte_array();
+        // The child's fields keep their own (little-endian) layout.
+        assert_eq!(&bytes[1..7], &parent.child.into_byte_array());
+        assert_eq!(&bytes[1..7], &[0x02, 0x01, 0x06, 0x05, 0x04, 0x03]);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let original = make_parent();
+        assert_eq!(
+            Parent::from_byte_array(original.into_byte_array()),
+            original
+        );
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    struct Repeated {
+        #[byteable(big_endian)]
+        #[byteable(big_endian)]
+        value: u16,
+    }
+
+    #[test]
+    fn repeating_an_attribute_is_not_a_conflict() {
+        assert_eq!(Repeated { value: 0x0102 }.into_byte_array(), [1, 2]);
+    }
+}
+
+// ── Endian array fields ──────────────────────────────────────────────────────
+
+mod endian_arrays {
+    use byteable::{
+        Byteable, FromByteArray, IntoByteArray, LittleEndian, PlainOldData, RawRepr, ReadValue,
+        WriteValue,
+    };
+    use std::io::Cursor;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    struct Samples {
         #[byteable(little_endian)]
-        y: i32,
+        samples: [u16; 4],
+        #[byteable(big_endian)]
+        grid: [[i32; 2]; 2],
     }
 
-    #[derive(Clone, Copy, Byteable)]
-    struct Shape {
-        id: u8,
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    #[byteable(big_endian)]
+    struct Frame {
+        id: [u8; 2],
+        levels: [f32; 2],
+    }
+
+    #[derive(Debug, PartialEq, Byteable)]
+    #[byteable(io_only)]
+    struct Dynamic {
+        name: String,
+        #[byteable(big_endian)]
+        points: [u16; 3],
+    }
+
+    #[test]
+    fn raw_field_uses_per_element_wrappers() {
+        let value = Samples {
+            samples: [1, 2, 3, 4],
+            grid: [[5, -6], [7, 8]],
+        };
+        let raw = value.to_raw();
+        let samples = raw.samples;
+        assert_eq!(samples, [1, 2, 3, 4].map(LittleEndian::<u16>::new));
+        assert_eq!(
+            <<Samples as RawRepr>::Raw as PlainOldData>::BYTE_SIZE,
+            4 * 2 + 4 * 4
+        );
+    }
+
+    #[test]
+    fn each_element_is_byte_swapped() {
+        let value = Samples {
+            samples: [0x0102, 0x0304, 0x0506, 0x0708],
+            grid: [[1, -1], [0x0A0B_0C0D, 0]],
+        };
+        let bytes = value.into_byte_array();
+        assert_eq!(&bytes[..8], &[2, 1, 4, 3, 6, 5, 8, 7]);
+        assert_eq!(&bytes[8..12], &[0, 0, 0, 1]);
+        assert_eq!(&bytes[12..16], &[0xFF; 4]);
+        assert_eq!(&bytes[16..20], &[0x0A, 0x0B, 0x0C, 0x0D]);
+        assert_eq!(Samples::from_byte_array(bytes), value);
+    }
+
+    #[test]
+    fn container_endian_reaches_array_elements() {
+        let frame = Frame {
+            id: [9, 8],
+            levels: [1.0, -2.5],
+        };
+        let bytes = frame.into_byte_array();
+        assert_eq!(&bytes[..2], &[9, 8]);
+        assert_eq!(&bytes[2..6], &1.0f32.to_be_bytes());
+        assert_eq!(&bytes[6..10], &(-2.5f32).to_be_bytes());
+        assert_eq!(Frame::from_byte_array(bytes), frame);
+    }
+
+    #[test]
+    fn io_only_array_field() {
+        let value = Dynamic {
+            name: "p".into(),
+            points: [1, 2, 0x0304],
+        };
+        let mut buf = Vec::new();
+        buf.write_value(&value).unwrap();
+        assert_eq!(&buf[buf.len() - 6..], &[0, 1, 0, 2, 3, 4]);
+        assert_eq!(Cursor::new(buf).read_value::<Dynamic>().unwrap(), value);
+    }
+}
+
+// ── Nested array fields ──────────────────────────────────────────────────────
+
+mod nested_arrays {
+    use byteable::{
+        Byteable, DecodeError, DecodeErrorKind, FromByteArray, IntoByteArray, LittleEndian,
+        ReadFixed, TryFromByteArray, WriteFixed,
+    };
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    struct Transform {
         #[byteable(transparent)]
-        top_left: Point,
+        matrix: [[LittleEndian<f32>; 4]; 4],
+        #[byteable(big_endian)]
+        id: u16,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    struct Cell {
+        #[byteable(big_endian)]
+        value: u16,
+        tag: u8,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    struct Board {
         #[byteable(transparent)]
-        bottom_right: Point,
+        cells: [[Cell; 3]; 2],
+        #[byteable(try_transparent)]
+        flags: [[bool; 2]; 2],
+    }
+
+    fn identity() -> [[LittleEndian<f32>; 4]; 4] {
+        core::array::from_fn(|row| {
+            core::array::from_fn(|col| LittleEndian::new(if row == col { 1.0 } else { 0.0 }))
+        })
     }
 
     #[test]
-    fn safe_packet_roundtrip() {
-        let packet = SafePacket {
-            id: 42,
-            length: 1024,
-            checksum: 0x12345678,
-            data: [1, 2, 3, 4],
+    fn matrix_is_row_major_little_endian_floats() {
+        let mut matrix = identity();
+        matrix[3][0] = LittleEndian::new(-2.5);
+        let transform = Transform { matrix, id: 0x0102 };
+        assert_eq!(Transform::BYTE_SIZE, 16 * 4 + 2);
+
+        let bytes = transform.into_byte_array();
+        assert_eq!(&bytes[..4], &1.0f32.to_le_bytes());
+        assert_eq!(&bytes[4..8], &[0; 4]);
+        assert_eq!(&bytes[20..24], &1.0f32.to_le_bytes());
+        assert_eq!(&bytes[48..52], &(-2.5f32).to_le_bytes());
+        assert_eq!(&bytes[64..], &[0x01, 0x02]);
+        assert_eq!(Transform::from_byte_array(bytes), transform);
+    }
+
+    #[test]
+    fn matrix_roundtrips_through_io() {
+        let transform = Transform {
+            matrix: identity(),
+            id: 7,
         };
-        let bytes = packet.into_byte_array();
-        let restored = SafePacket::from_byte_array(bytes);
-        assert_eq!(packet.id, restored.id);
-        assert_eq!(packet.length, restored.length);
-        assert_eq!(packet.checksum, restored.checksum);
-        assert_eq!(packet.data, restored.data);
+        let mut buf = Vec::new();
+        buf.write_fixed(&transform).unwrap();
+        assert_eq!(buf.as_slice().read_fixed::<Transform>().unwrap(), transform);
     }
 
     #[test]
-    fn nested_safe_types_roundtrip() {
-        let shape = Shape {
-            id: 1,
-            top_left: Point { x: 0, y: 0 },
-            bottom_right: Point { x: 100, y: 200 },
+    fn nested_derived_structs_and_try_elements() {
+        let board = Board {
+            cells: [
+                [Cell { value: 1, tag: 2 }; 3],
+                [Cell {
+                    value: 0x0304,
+                    tag: 5,
+                }; 3],
+            ],
+            flags: [[true, false], [false, true]],
         };
-        let bytes = shape.into_byte_array();
-        let restored = Shape::from_byte_array(bytes);
-        assert_eq!(shape.id, restored.id);
-        assert_eq!(shape.top_left.x, restored.top_left.x);
-        assert_eq!(shape.top_left.y, restored.top_left.y);
-        assert_eq!(shape.bottom_right.x, restored.bottom_right.x);
-        assert_eq!(shape.bottom_right.y, restored.bottom_right.y);
+        assert_eq!(Board::BYTE_SIZE, 6 * 3 + 4);
+
+        let bytes = board.into_byte_array();
+        assert_eq!(&bytes[..3], &[0, 1, 2]);
+        assert_eq!(&bytes[9..12], &[3, 4, 5]);
+        assert_eq!(&bytes[18..], &[1, 0, 0, 1]);
+        assert_eq!(Board::try_from_byte_array(bytes), Ok(board));
+    }
+
+    #[test]
+    fn invalid_inner_element_is_rejected() {
+        let board = Board {
+            cells: [[Cell { value: 0, tag: 0 }; 3]; 2],
+            flags: [[false; 2]; 2],
+        };
+        let mut bytes = board.into_byte_array();
+        bytes[21] = 2;
+        // The index is the position within the outermost array.
+        assert_eq!(
+            Board::try_from_byte_array(bytes),
+            Err(DecodeError::ArrayElement {
+                index: 1,
+                kind: DecodeErrorKind::InvalidBool,
+            })
+        );
+    }
+}
+
+// ── Derives forwarded onto the raw struct ────────────────────────────────────
+
+mod raw_derive {
+    use byteable::{Byteable, RawRepr};
+    use std::collections::HashSet;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    #[byteable(raw_derive(Debug, PartialEq, Eq, Hash))]
+    struct Key {
+        #[byteable(big_endian)]
+        id: u32,
+        kind: u8,
+    }
+
+    #[derive(Clone, Copy, Byteable)]
+    #[byteable(raw_derive(Debug, PartialEq), little_endian)]
+    struct Coords(u16, u16);
+
+    #[derive(Clone, Copy, Byteable)]
+    #[byteable(raw_derive(Default, PartialEq))]
+    struct Marker;
+
+    #[derive(Clone, Copy, Byteable)]
+    #[byteable(raw_derive(std::fmt::Debug), magic = b"MK", crc32)]
+    struct Tagged(
+        #[byteable(big_endian)] u32,
+        #[byteable(pad_before = 1)] u8,
+        #[byteable(transparent)] byteable::BigEndian<u16>,
+    );
+
+    fn assert_hash_eq<T: std::hash::Hash + Eq + std::fmt::Debug>() {}
+
+    #[test]
+    fn forwarded_derives_are_present() {
+        assert_hash_eq::<<Key as RawRepr>::Raw>();
+    }
+
+    #[test]
+    fn raw_values_hash_by_wire_bytes() {
+        let keys = [
+            Key { id: 1, kind: 2 },
+            Key { id: 1, kind: 2 },
+            Key { id: 3, kind: 2 },
+        ];
+        let raws: HashSet<_> = keys.iter().map(RawRepr::to_raw).collect();
+        assert_eq!(raws.len(), 2);
+        assert!(raws.contains(&Key { id: 3, kind: 2 }.to_raw()));
+    }
+
+    #[test]
+    fn tuple_and_unit_raw_structs() {
+        assert_eq!(Coords(1, 2).to_raw(), Coords(1, 2).to_raw());
+        assert_ne!(Coords(1, 2).to_raw(), Coords(2, 1).to_raw());
+        assert!(!format!("{:?}", Coords(1, 2).to_raw()).is_empty());
+        assert!(Marker.to_raw() == Default::default());
+    }
+
+    #[test]
+    fn raw_debug_shows_native_values() {
+        let raw = Key {
+            id: 0x0102_0304,
+            kind: 2,
+        }
+        .to_raw();
+        assert_eq!(
+            format!("{raw:?}"),
+            "__byteable_raw_Key { id: 16909060, kind: 2 }"
+        );
+        assert_eq!(
+            format!("{:?}", Coords(1, 2).to_raw()),
+            "__byteable_raw_Coords(1, 2)"
+        );
+    }
+
+    #[test]
+    fn raw_debug_hides_generated_fields() {
+        let raw = Tagged(7, 8, byteable::BigEndian::new(9)).to_raw();
+        // Transparent fields keep their own `Debug`; only the attribute's byte order is undone.
+        assert_eq!(
+            format!("{raw:?}"),
+            "__byteable_raw_Tagged(7, 8, BigEndian(9), ..)"
+        );
+    }
+}
+
+// ── Reserved padding fields ──────────────────────────────────────────────────
+
+mod padding {
+    use byteable::{Byteable, FromByteArray, IntoByteArray};
+
+    const RESERVED: usize = 3;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    #[byteable(little_endian)]
+    struct Header {
+        version: u8,
+        #[byteable(pad_before = 1, pad_after = RESERVED)]
+        length: u16,
+        #[byteable(pad_after = 2)]
+        flags: u8,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    struct Tuple(
+        #[byteable(pad_before = 2)] u8,
+        #[byteable(big_endian, pad_after = 1)] u16,
+        u8,
+    );
+
+    #[test]
+    fn padding_bytes_are_zero_on_write() {
+        let header = Header {
+            version: 1,
+            length: 0x0203,
+            flags: 0xFF,
+        };
+        assert_eq!(Header::BYTE_SIZE, 10);
+        assert_eq!(
+            header.into_byte_array(),
+            [1, 0, 0x03, 0x02, 0, 0, 0, 0xFF, 0, 0]
+        );
+    }
+
+    #[test]
+    fn padding_bytes_are_ignored_on_read() {
+        let header =
+            Header::from_byte_array([1, 0xAA, 0x03, 0x02, 0xBB, 0xCC, 0xDD, 0xFF, 0xEE, 0xEE]);
+        assert_eq!(
+            header,
+            Header {
+                version: 1,
+                length: 0x0203,
+                flags: 0xFF,
+            }
+        );
+    }
+
+    #[test]
+    fn tuple_struct_padding_shifts_raw_positions() {
+        let value = Tuple(7, 0x0102, 9);
+        let bytes = value.into_byte_array();
+        assert_eq!(bytes, [0, 0, 7, 1, 2, 0, 9]);
+        assert_eq!(
+            Tuple::from_byte_array([0x55, 0x55, 7, 1, 2, 0x55, 9]),
+            value
+        );
+    }
+
+    // Matches the C layout: one padding byte aligns `id`, and none is needed before `value`.
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    #[byteable(little_endian, assert_size = 8)]
+    struct CRecord {
+        kind: u8,
+        #[byteable(pad_before = 1)]
+        id: u16,
+        value: u32,
+    }
+
+    #[test]
+    fn repr_c_padding_is_written_as_zeros() {
+        use byteable::ByteableLayout;
+        use core::mem::{offset_of, size_of};
+
+        let record = CRecord {
+            kind: 1,
+            id: 0x0302,
+            value: 0x0706_0504,
+        };
+        assert_eq!(record.into_byte_array(), [1, 0, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(CRecord::BYTE_SIZE, size_of::<CRecord>());
+        assert_eq!(
+            CRecord::BYTE_OFFSETS,
+            [
+                ("kind", offset_of!(CRecord, kind)),
+                ("id", offset_of!(CRecord, id)),
+                ("value", offset_of!(CRecord, value)),
+            ]
+        );
+        assert_eq!(
+            CRecord::from_byte_array([1, 0xFF, 2, 3, 4, 5, 6, 7]),
+            record
+        );
+    }
+}
+
+// ── Over-aligned structs ─────────────────────────────────────────────────────
+
+mod over_aligned {
+    use byteable::{Byteable, ByteableLayout, FromByteArray, IntoByteArray, ReadFixed, WriteFixed};
+
+    // `size_of` is 16 here, but the trailing alignment padding is not part of the wire format.
+    #[derive(Debug, Clone, Copy, PartialEq, Byteable)]
+    #[repr(C, align(16))]
+    struct Vertex {
+        #[byteable(little_endian)]
+        x: f32,
+        #[byteable(little_endian)]
+        index: u16,
+        flags: u8,
     }
 
-    /// `bool` does not implement `PlainOldData` (invalid bit patterns 2..=255).
-    ///
-    /// ```compile_fail
-    /// # #[cfg(feature = "derive")] {
-    /// use byteable::Byteable;
-    ///
-    /// #[derive(Clone, Copy, Byteable)]
-    /// struct Bad {
-    ///     id: u8,
-    ///     flag: bool,
-    /// }
-    /// # }
-    /// ```
-    ///
-    /// `char` does not implement `PlainOldData` (many code-points are invalid).
-    ///
-    /// ```compile_fail
-    /// # #[cfg(feature = "derive")] {
-    /// use byteable::Byteable;
-    ///
-    /// #[derive(Clone, Copy, Byteable)]
-    /// struct Bad {
-    ///     id: u8,
-    ///     letter: char,
-    /// }
-    /// # }
-    /// ```
-    ///
-    /// References do not implement `PlainOldData`.
-    ///
-    /// ```compile_fail
-    /// # #[cfg(feature = "derive")] {
-    /// use byteable::Byteable;
-    ///
-    /// #[derive(Clone, Copy, Byteable)]
-    /// struct Bad<'a> {
-    ///     id: u8,
-    ///     data_ref: &'a [u8],
-    /// }
-    /// # }
-    /// ```
-    ///
-    /// Unannotated multi-byte primitives (`u16` without an endian wrapper) are rejected
-    /// because their native byte order is platform-dependent.
-    ///
-    /// ```compile_fail
-    /// # #[cfg(feature = "derive")] {
-    /// use byteable::Byteable;
-    ///
-    /// #[derive(Clone, Copy, Byteable)]
-    /// struct Bad {
-    ///     value: u16,
-    /// }
-    /// # }
-    /// ```
-    #[test]
-    fn compile_fail_examples_documented_above() {}
+    #[derive(Debug, Clone, Copy, PartialEq, Byteable)]
+    #[repr(C, align(8))]
+    struct Mesh {
+        #[byteable(transparent)]
+        vertex: Vertex,
+        count: u8,
+    }
+
+    #[test]
+    fn byte_size_excludes_alignment_padding() {
+        assert_eq!(core::mem::size_of::<Vertex>(), 16);
+        assert_eq!(Vertex::BYTE_SIZE, 7);
+        assert_eq!(Mesh::BYTE_SIZE, 8);
+        assert_eq!(Mesh::BYTE_OFFSETS, &[("vertex", 0), ("count", 7)]);
+    }
+
+    #[test]
+    fn output_bytes_are_deterministic() {
+        let vertex = Vertex {
+            x: 1.0,
+            index: 0x0102,
+            flags: 0xFF,
+        };
+        assert_eq!(vertex.into_byte_array(), [0, 0, 0x80, 0x3F, 2, 1, 0xFF]);
+        assert_eq!(Vertex::from_byte_array(vertex.into_byte_array()), vertex);
+
+        let mesh = Mesh { vertex, count: 3 };
+        let mut buf = Vec::new();
+        buf.write_fixed(&mesh).unwrap();
+        assert_eq!(buf, [0, 0, 0x80, 0x3F, 2, 1, 0xFF, 3]);
+        assert_eq!(buf.as_slice().read_fixed::<Mesh>().unwrap(), mesh);
+    }
+}
+
+// ── Generic structs ──────────────────────────────────────────────────────────
+
+mod generics {
+    use byteable::{
+        ByteArray, Byteable, ByteableLayout, DecodeError, FlatByteable, FromByteArray, FromRawRepr,
+        IntoByteArray, PlainOldData, RawRepr, ReadFixed, ReadableError, TryFromByteArray,
+        TryFromRawRepr, WriteFixed,
+    };
+    use std::io::Cursor;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    struct Point {
+        #[byteable(big_endian)]
+        x: u16,
+        y: u16,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    struct Outer<T> {
+        tag: u8,
+        #[byteable(transparent)]
+        inner: T,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    struct Pair<A, B: Copy>(#[byteable(big_endian)] A, #[byteable(big_endian)] B)
+    where
+        A: Copy;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    struct Checked<T> {
+        #[byteable(try_transparent)]
+        value: T,
+    }
+
+    #[test]
+    fn transparent_generic_field_roundtrips() {
+        let outer = Outer {
+            tag: 9,
+            inner: Point {
+                x: 0x0102,
+                y: 0x0304,
+            },
+        };
+        let raw = outer.to_raw();
+        assert_eq!(raw.as_bytes(), &[9, 0x01, 0x02, 0x04, 0x03]);
+        assert_eq!(Outer::<Point>::from_raw(raw), outer);
+
+        let mut buf = Vec::new();
+        buf.write_fixed(&outer).unwrap();
+        assert_eq!(
+            Cursor::new(buf).read_fixed::<Outer<Point>>().unwrap(),
+            outer
+        );
+    }
+
+    #[test]
+    fn endianness_applies_to_generic_fields() {
+        let pair = Pair(0x0A0Bu16, 0x01020304u32);
+        assert_eq!(pair.to_raw().as_bytes(), &[0x0A, 0x0B, 1, 2, 3, 4]);
+        assert_eq!(Pair::<u16, u32>::from_raw(pair.to_raw()), pair);
+    }
+
+    #[test]
+    fn try_transparent_generic_field() {
+        let ok = Checked { value: true }.to_raw();
+        assert_eq!(
+            Checked::<bool>::try_from_raw(ok).unwrap(),
+            Checked { value: true }
+        );
+
+        let err = Cursor::new([2u8])
+            .read_fixed::<Checked<bool>>()
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ReadableError::DecodeError(DecodeError::InvalidBool(2))
+        ));
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    struct Twin<T> {
+        #[byteable(transparent)]
+        a: T,
+        #[byteable(transparent)]
+        b: T,
+    }
+
+    #[test]
+    fn generic_struct_byte_array_roundtrips() {
+        let twin = Twin {
+            a: 0x0102_0304u32,
+            b: 0x0506_0708,
+        };
+        let bytes = twin.into_byte_array();
+        assert_eq!(size_of_val(&bytes), 8);
+        assert_eq!(bytes.as_byte_slice(), &[4, 3, 2, 1, 8, 7, 6, 5]);
+        assert_eq!(Twin::<u32>::from_byte_array(bytes), twin);
+        assert_eq!(Twin::<u32>::try_from_byte_array(bytes).unwrap(), twin);
+        assert_eq!(twin.to_flat_bytes(), [4, 3, 2, 1, 8, 7, 6, 5]);
+    }
+
+    #[test]
+    fn nested_generic_struct_byte_array() {
+        let outer = Outer {
+            tag: 1,
+            inner: Outer {
+                tag: 2,
+                inner: Point {
+                    x: 0x0A0B,
+                    y: 0x0C0D,
+                },
+            },
+        };
+        let bytes = outer.into_byte_array();
+        assert_eq!(bytes.as_byte_slice(), &[1, 2, 0x0A, 0x0B, 0x0D, 0x0C]);
+        assert_eq!(Outer::<Outer<Point>>::from_byte_array(bytes), outer);
+    }
+
+    #[test]
+    fn generic_struct_try_from_byte_array() {
+        let bytes = Checked { value: true }.into_byte_array();
+        assert_eq!(
+            Checked::<bool>::try_from_byte_array(bytes).unwrap(),
+            Checked { value: true }
+        );
+        let mut invalid = bytes;
+        invalid.as_byte_slice_mut()[0] = 2;
+        assert_eq!(
+            Checked::<bool>::try_from_byte_array(invalid),
+            Err(DecodeError::InvalidBool(2))
+        );
+    }
+
+    #[test]
+    fn generic_struct_layout() {
+        assert_eq!(Outer::<Point>::BYTE_OFFSETS, &[("tag", 0), ("inner", 1)]);
+    }
+}
+
+// ── Trailer structs ──────────────────────────────────────────────────────────
+
+mod trailer {
+    use byteable::{
+        Byteable, DecodeError, HasTrailer, IntoByteArray, ReadFixed, ReadableError,
+        TryFromByteArray, WriteFixed,
+    };
+    use std::io::Cursor;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    struct Footer {
+        #[byteable(big_endian)]
+        checksum: u16,
+        #[byteable(big_endian)]
+        end_marker: u16,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    #[byteable(trailer = Footer)]
+    struct Record {
+        #[byteable(little_endian)]
+        id: u32,
+        kind: u8,
+    }
+
+    impl HasTrailer<Footer> for Record {
+        fn trailer(&self) -> Footer {
+            let sum = self
+                .id
+                .to_le_bytes()
+                .iter()
+                .chain([self.kind].iter())
+                .fold(0u16, |acc, b| acc.wrapping_add(u16::from(*b)));
+            Footer {
+                checksum: sum,
+                end_marker: 0xFFEE,
+            }
+        }
+
+        fn check_trailer(&self, trailer: &Footer) -> Result<(), DecodeError> {
+            if *trailer == self.trailer() {
+                Ok(())
+            } else {
+                Err(DecodeError::InvalidTrailer {
+                    type_name: "Record",
+                })
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    #[byteable(trailer = Footer)]
+    struct Unchecked(u8);
+
+    impl HasTrailer<Footer> for Unchecked {
+        fn trailer(&self) -> Footer {
+            Footer {
+                checksum: 0,
+                end_marker: 0,
+            }
+        }
+    }
+
+    #[test]
+    fn footer_is_appended_after_fields() {
+        let record = Record {
+            id: 0x0102,
+            kind: 3,
+        };
+        assert_eq!(Record::BYTE_SIZE, 9);
+        assert_eq!(
+            record.into_byte_array(),
+            [0x02, 0x01, 0, 0, 3, 0, 6, 0xFF, 0xEE]
+        );
+    }
+
+    #[test]
+    fn footer_is_validated_on_read() {
+        let record = Record {
+            id: 0x0102,
+            kind: 3,
+        };
+        let bytes = record.into_byte_array();
+        assert_eq!(Record::try_from_byte_array(bytes).unwrap(), record);
+
+        let mut corrupted = bytes;
+        corrupted[4] = 4;
+        assert_eq!(
+            Record::try_from_byte_array(corrupted).unwrap_err(),
+            DecodeError::InvalidTrailer {
+                type_name: "Record"
+            }
+        );
+    }
+
+    #[test]
+    fn footer_roundtrips_through_io() {
+        let record = Record { id: 7, kind: 1 };
+        let mut buf = Vec::new();
+        buf.write_fixed(&record).unwrap();
+        assert_eq!(buf.len(), 9);
+        assert_eq!(Cursor::new(&buf).read_fixed::<Record>().unwrap(), record);
+
+        buf[8] = 0;
+        assert!(matches!(
+            Cursor::new(&buf).read_fixed::<Record>(),
+            Err(ReadableError::DecodeError(
+                DecodeError::InvalidTrailer { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn default_check_accepts_any_trailer() {
+        assert_eq!(Unchecked(5).into_byte_array(), [5, 0, 0, 0, 0]);
+        assert_eq!(
+            Unchecked::try_from_byte_array([5, 1, 2, 3, 4]).unwrap(),
+            Unchecked(5)
+        );
+    }
+}
+
+// ── CRC-32 checksums ─────────────────────────────────────────────────────────
+
+mod crc32 {
+    use byteable::checksum::{CRC32_CASTAGNOLI, crc32, crc32_with_polynomial};
+    use byteable::{
+        Byteable, DecodeError, HasTrailer, IntoByteArray, ReadFixed, ReadableError,
+        TryFromByteArray, WriteFixed,
+    };
+    use std::io::Cursor;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    #[byteable(crc32)]
+    struct Frame {
+        #[byteable(big_endian)]
+        id: u16,
+        payload: [u8; 3],
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    #[byteable(crc32 = CRC32_CASTAGNOLI)]
+    struct Castagnoli(u8, #[byteable(little_endian)] u32);
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    struct End {
+        marker: u8,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    #[byteable(trailer = End, crc32)]
+    struct WithTrailer {
+        value: u8,
+    }
+
+    impl HasTrailer<End> for WithTrailer {
+        fn trailer(&self) -> End {
+            End { marker: 0xAA }
+        }
+    }
+
+    fn frame() -> Frame {
+        Frame {
+            id: 0x0102,
+            payload: [3, 4, 5],
+        }
+    }
+
+    #[test]
+    fn checksum_is_appended_little_endian() {
+        assert_eq!(Frame::BYTE_SIZE, 9);
+        let bytes = frame().into_byte_array();
+        assert_eq!(&bytes[..5], &[1, 2, 3, 4, 5]);
+        assert_eq!(&bytes[5..], &crc32(&bytes[..5]).to_le_bytes());
+    }
+
+    #[test]
+    fn roundtrip() {
+        let bytes = frame().into_byte_array();
+        assert_eq!(Frame::try_from_byte_array(bytes).unwrap(), frame());
+    }
+
+    #[test]
+    fn corrupted_body_is_rejected() {
+        let mut bytes = frame().into_byte_array();
+        let expected = crc32(&bytes[..5]);
+        bytes[2] ^= 0xFF;
+        assert_eq!(
+            Frame::try_from_byte_array(bytes),
+            Err(DecodeError::ChecksumMismatch {
+                expected,
+                computed: crc32(&bytes[..5]),
+            })
+        );
+    }
+
+    #[test]
+    fn custom_polynomial() {
+        let value = Castagnoli(7, 0x0A0B_0C0D);
+        let bytes = value.into_byte_array();
+        assert_eq!(
+            &bytes[5..],
+            &crc32_with_polynomial(CRC32_CASTAGNOLI, &bytes[..5]).to_le_bytes()
+        );
+        assert_eq!(Castagnoli::try_from_byte_array(bytes).unwrap(), value);
+    }
+
+    #[test]
+    fn checksum_covers_trailer() {
+        let bytes = WithTrailer { value: 1 }.into_byte_array();
+        assert_eq!(&bytes[..2], &[1, 0xAA]);
+        assert_eq!(&bytes[2..], &crc32(&[1, 0xAA]).to_le_bytes());
+    }
+
+    #[test]
+    fn mismatch_surfaces_through_io() {
+        let mut buf = Vec::new();
+        buf.write_fixed(&frame()).unwrap();
+        assert_eq!(Cursor::new(&buf).read_fixed::<Frame>().unwrap(), frame());
+
+        buf[8] ^= 1;
+        assert!(matches!(
+            Cursor::new(&buf).read_fixed::<Frame>(),
+            Err(ReadableError::DecodeError(
+                DecodeError::ChecksumMismatch { .. }
+            ))
+        ));
+    }
+}
+
+// ── Magic signatures ─────────────────────────────────────────────────────────
+
+mod magic {
+    use byteable::{
+        Byteable, DecodeError, IntoByteArray, ReadFixed, ReadableError, TryFromByteArray,
+    };
+    use std::io::Cursor;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    #[byteable(magic = b"RIFF")]
+    struct RiffHeader {
+        #[byteable(little_endian)]
+        size: u32,
+        #[byteable(magic = b"WAVE")]
+        channels: u8,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    #[byteable(magic = [0x89, b'P', b'N', b'G'])]
+    struct Png(#[byteable(big_endian)] u16);
+
+    #[test]
+    fn magic_is_written_without_a_field() {
+        let header = RiffHeader {
+            size: 36,
+            channels: 2,
+        };
+        assert_eq!(RiffHeader::BYTE_SIZE, 13);
+        assert_eq!(header.into_byte_array(), *b"RIFF\x24\0\0\0WAVE\x02");
+        assert_eq!(
+            RiffHeader::try_from_byte_array(header.into_byte_array()).unwrap(),
+            header
+        );
+    }
+
+    #[test]
+    fn array_literal_magic() {
+        let bytes = Png(0x0102).into_byte_array();
+        assert_eq!(bytes, [0x89, b'P', b'N', b'G', 1, 2]);
+        assert_eq!(Png::try_from_byte_array(bytes).unwrap(), Png(0x0102));
+    }
+
+    #[test]
+    fn wrong_magic_is_rejected() {
+        let err = Png::try_from_byte_array(*b"GIF89a").unwrap_err();
+        let DecodeError::BadMagic { expected, got } = err else {
+            panic!("expected BadMagic, got {err:?}");
+        };
+        assert_eq!(expected, &[0x89, b'P', b'N', b'G']);
+        assert_eq!(&*got, b"GIF8");
+    }
+
+    #[test]
+    fn field_level_magic_is_checked() {
+        let mut bytes = *b"RIFF\x24\0\0\0AVI \x01";
+        assert!(matches!(
+            RiffHeader::try_from_byte_array(bytes),
+            Err(DecodeError::BadMagic {
+                expected: b"WAVE",
+                ..
+            })
+        ));
+        bytes[8..12].copy_from_slice(b"WAVE");
+        assert!(RiffHeader::try_from_byte_array(bytes).is_ok());
+    }
+
+    #[test]
+    fn bad_magic_through_io() {
+        let result = Cursor::new(*b"\x7fELF\0\0").read_fixed::<Png>();
+        assert!(matches!(
+            result,
+            Err(ReadableError::DecodeError(DecodeError::BadMagic { .. }))
+        ));
+    }
+}
+
+// ── Validated structs ────────────────────────────────────────────────────────
+
+mod validate {
+    use byteable::{
+        Byteable, DecodeError, HasTrailer, IntoByteArray, ReadFixed, ReadableError,
+        TryFromByteArray,
+    };
+    use std::io::Cursor;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    #[byteable(big_endian, validate = Range::check)]
+    struct Range {
+        start: u16,
+        end: u16,
+    }
+
+    impl Range {
+        fn check(&self) -> Result<(), DecodeError> {
+            if self.start <= self.end {
+                Ok(())
+            } else {
+                Err(DecodeError::InvalidValue {
+                    type_name: "Range",
+                    reason: "start is after end",
+                })
+            }
+        }
+    }
+
+    fn known_channel(value: &Channel) -> Result<(), DecodeError> {
+        if [1, 2, 6].contains(&value.count) {
+            Ok(())
+        } else {
+            Err(DecodeError::InvalidValue {
+                type_name: "Channel",
+                reason: "unsupported channel count",
+            })
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    #[byteable(magic = b"CH", trailer = u8, validate = known_channel)]
+    struct Channel {
+        count: u8,
+        #[byteable(try_transparent)]
+        interleaved: bool,
+    }
+
+    impl HasTrailer<u8> for Channel {
+        fn trailer(&self) -> u8 {
+            0xFF
+        }
+
+        fn check_trailer(&self, trailer: &u8) -> Result<(), DecodeError> {
+            if *trailer == 0xFF {
+                Ok(())
+            } else {
+                Err(DecodeError::InvalidTrailer {
+                    type_name: "Channel",
+                })
+            }
+        }
+    }
+
+    #[test]
+    fn valid_values_decode() {
+        let range = Range { start: 2, end: 10 };
+        let bytes = range.into_byte_array();
+        assert_eq!(bytes, [0, 2, 0, 10]);
+        assert_eq!(Range::try_from_byte_array(bytes), Ok(range));
+    }
+
+    #[test]
+    fn validator_rejects_bit_valid_bytes() {
+        let err = Range::try_from_byte_array([0, 10, 0, 2]).unwrap_err();
+        assert_eq!(
+            err,
+            DecodeError::InvalidValue {
+                type_name: "Range",
+                reason: "start is after end",
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "invalid value for Range: start is after end"
+        );
+    }
+
+    #[test]
+    fn validator_runs_after_every_other_check() {
+        let channel = Channel {
+            count: 6,
+            interleaved: true,
+        };
+        let bytes = channel.into_byte_array();
+        assert_eq!(bytes, [b'C', b'H', 6, 1, 0xFF]);
+        assert_eq!(Channel::try_from_byte_array(bytes), Ok(channel));
+
+        assert!(matches!(
+            Channel::try_from_byte_array(*b"XX\x03\x01\xFF"),
+            Err(DecodeError::BadMagic { .. })
+        ));
+        assert_eq!(
+            Channel::try_from_byte_array(*b"CH\x03\x02\xFF"),
+            Err(DecodeError::InvalidBool(2))
+        );
+        assert_eq!(
+            Channel::try_from_byte_array(*b"CH\x03\x01\x00"),
+            Err(DecodeError::InvalidTrailer {
+                type_name: "Channel"
+            })
+        );
+        assert!(matches!(
+            Channel::try_from_byte_array(*b"CH\x03\x01\xFF"),
+            Err(DecodeError::InvalidValue {
+                type_name: "Channel",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn rejected_through_io() {
+        let result = Cursor::new([0u8, 5, 0, 4]).read_fixed::<Range>();
+        assert!(matches!(
+            result,
+            Err(ReadableError::DecodeError(DecodeError::InvalidValue { .. }))
+        ));
+    }
+}
+
+// ── Field layout ─────────────────────────────────────────────────────────────
+
+mod layout {
+    use byteable::{BigEndian, Byteable, ByteableLayout};
+
+    #[derive(Byteable, Clone, Copy)]
+    struct Inner {
+        a: u16,
+        b: u8,
+    }
+
+    #[derive(Byteable, Clone, Copy)]
+    #[byteable(magic = b"PK", crc32)]
+    struct Framed {
+        kind: u8,
+        #[byteable(big_endian, pad_after = 3)]
+        length: u32,
+        #[byteable(transparent)]
+        inner: Inner,
+        values: [u16; 2],
+    }
+
+    #[derive(Byteable, Clone, Copy)]
+    struct Pair(u32, #[byteable(pad_before = 1)] u8);
+
+    #[derive(Byteable, Clone, Copy)]
+    struct Unit;
+
+    #[derive(Byteable, Clone, Copy)]
+    struct Wrapper<T> {
+        tag: u8,
+        #[byteable(transparent)]
+        value: T,
+        tail: u16,
+    }
+
+    #[test]
+    fn offsets_skip_magic_and_padding() {
+        assert_eq!(
+            Framed::BYTE_OFFSETS,
+            [("kind", 2), ("length", 3), ("inner", 10), ("values", 13)]
+        );
+        assert_eq!(Framed::field_offset("values"), Some(13));
+        assert_eq!(Framed::field_offset("__byteable_crc32"), None);
+    }
+
+    #[test]
+    fn ranges_end_before_padding_and_checksum() {
+        assert_eq!(
+            Framed::BYTE_RANGES,
+            [
+                ("kind", 2..3),
+                ("length", 3..7),
+                ("inner", 10..13),
+                ("values", 13..17)
+            ]
+        );
+        assert!(Unit::BYTE_RANGES.is_empty());
+    }
+
+    #[test]
+    fn tuple_fields_are_named_by_index() {
+        assert_eq!(Pair::BYTE_OFFSETS, [("0", 0), ("1", 5)]);
+        assert_eq!(Pair::field_offset("1"), Some(5));
+    }
+
+    #[test]
+    fn unit_struct_has_no_fields() {
+        assert!(Unit::BYTE_OFFSETS.is_empty());
+    }
+
+    #[test]
+    fn generic_offsets_follow_the_type_argument() {
+        assert_eq!(
+            Wrapper::<u8>::BYTE_OFFSETS,
+            [("tag", 0), ("value", 1), ("tail", 2)]
+        );
+        assert_eq!(
+            Wrapper::<BigEndian<u64>>::BYTE_OFFSETS,
+            [("tag", 0), ("value", 1), ("tail", 9)]
+        );
+    }
+
+    #[test]
+    fn annotated_hexdump_starts_a_row_per_field() {
+        let framed = Framed {
+            kind: b'A',
+            length: 0x0102_0304,
+            inner: Inner { a: 0x4241, b: b'C' },
+            values: [0, 0],
+        };
+        let dump = framed.hexdump_annotated();
+        let rows: Vec<&str> = dump.lines().collect();
+        assert_eq!(rows.len(), 7);
+        assert_eq!(rows[0], format!("00000000  50 4b{:45}|PK|", ""));
+        assert_eq!(rows[1], format!("00000002  41{:48}|A|{:17}kind", "", ""));
+        assert!(rows[2].starts_with("00000003  01 02 03 04 "));
+        assert!(rows[2].ends_with("|....|              length"));
+        // Padding and the checksum belong to no field.
+        assert!(rows[3].starts_with("00000007  00 00 00 "));
+        assert!(rows[3].ends_with("|...|"));
+        assert!(rows[4].starts_with("0000000a  41 42 43 "));
+        assert!(rows[4].ends_with("|ABC|               inner"));
+        assert!(rows[5].starts_with("0000000d  00 00 00 00 "));
+        assert!(rows[5].ends_with("|....|              values"));
+        assert!(rows[6].starts_with("00000011  "));
+        assert!(rows[6].ends_with('|'));
+    }
+
+    #[test]
+    fn annotated_hexdump_labels_only_the_first_row_of_a_field() {
+        #[derive(Byteable, Clone, Copy)]
+        struct Blob {
+            id: u8,
+            data: [u8; 20],
+        }
+
+        let dump = Blob {
+            id: 1,
+            data: [b'x'; 20],
+        }
+        .hexdump_annotated();
+        let rows: Vec<&str> = dump.lines().collect();
+        assert_eq!(rows.len(), 3);
+        assert!(rows[0].ends_with("  id"));
+        assert!(rows[1].starts_with("00000001  78 78 78 78 78 78 78 78  78 "));
+        assert!(rows[1].ends_with("|xxxxxxxxxxxxxxxx|  data"));
+        assert_eq!(rows[2], format!("00000011  78 78 78 78{:39}|xxxx|", ""));
+    }
+}
+
+// ── Size assertions ──────────────────────────────────────────────────────────
+
+mod assert_size {
+    use byteable::{Byteable, IntoByteArray};
+
+    #[derive(Clone, Copy, Byteable)]
+    #[byteable(big_endian, assert_size = 8)]
+    struct CHeader {
+        magic: u32,
+        #[byteable(pad_after = 1)]
+        version: u8,
+        length: u16,
+    }
+
+    #[derive(Clone, Copy, Byteable)]
+    #[byteable(assert_size = 0)]
+    struct Empty;
+
+    #[derive(Clone, Copy, Byteable)]
+    #[byteable(magic = b"HD", crc32, assert_size = CHECKED_SIZE)]
+    struct Checked {
+        value: u8,
+    }
+
+    const CHECKED_SIZE: usize = 2 + 1 + 4;
+
+    #[test]
+    fn matching_sizes_compile() {
+        let header = CHeader {
+            magic: 1,
+            version: 2,
+            length: 3,
+        };
+        assert_eq!(header.into_byte_array().len(), 8);
+        assert_eq!(Empty.into_byte_array().len(), 0);
+        assert_eq!(Checked { value: 0 }.into_byte_array().len(), CHECKED_SIZE);
+    }
+}
+
+// ── Zeroed defaults ───────────────────────────────────────────────────────────
+
+mod zero_default {
+    use byteable::{Byteable, IntoByteArray};
+    use std::marker::PhantomData;
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[byteable(big_endian, default)]
+    struct Header {
+        id: u32,
+        #[byteable(pad_after = 2)]
+        kind: u8,
+        #[byteable(little_endian)]
+        length: u16,
+        scale: f32,
+    }
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[byteable(default)]
+    struct Outer {
+        #[byteable(transparent)]
+        header: Header,
+        tail: [u8; 3],
+    }
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[byteable(default)]
+    struct Tagged<T> {
+        value: u16,
+        marker: PhantomData<T>,
+    }
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[byteable(default)]
+    struct Unit;
+
+    #[test]
+    fn default_is_all_zero_bytes() {
+        let header = Header::default();
+        assert_eq!(
+            header,
+            Header {
+                id: 0,
+                kind: 0,
+                length: 0,
+                scale: 0.0,
+            }
+        );
+        assert!(header.into_byte_array().iter().all(|&b| b == 0));
+        assert!(Outer::default().into_byte_array().iter().all(|&b| b == 0));
+    }
+
+    fn zeroed<T: Default>() -> T {
+        T::default()
+    }
+
+    #[test]
+    fn generic_and_unit_structs() {
+        assert_eq!(zeroed::<Tagged<String>>().value, 0);
+        assert_eq!(zeroed::<Unit>(), Unit);
+    }
+
+    #[test]
+    fn incremental_building() {
+        let header = Header {
+            id: 0x0102_0304,
+            length: 5,
+            ..Header::default()
+        };
+        assert_eq!(
+            header.into_byte_array(),
+            [1, 2, 3, 4, 0, 0, 0, 5, 0, 0, 0, 0, 0]
+        );
+    }
+}
+
+// ── Byte equality ────────────────────────────────────────────────────────────
+
+mod byte_eq {
+    use byteable::Byteable;
+    use std::collections::HashMap;
+    use std::hash::{BuildHasher, RandomState};
+
+    #[derive(Byteable, Debug, Clone, Copy)]
+    #[byteable(big_endian, byte_eq)]
+    struct Key {
+        id: u16,
+        scale: f32,
+        offsets: [f64; 2],
+    }
+
+    #[derive(Byteable, Debug, Clone, Copy)]
+    #[byteable(byte_eq)]
+    struct Wrapper<T> {
+        inner: T,
+    }
+
+    fn key(scale: f32) -> Key {
+        Key {
+            id: 1,
+            scale,
+            offsets: [0.5, -0.5],
+        }
+    }
+
+    #[test]
+    fn nan_equals_itself() {
+        let nan = key(f32::NAN);
+        assert_eq!(nan, nan);
+        // A different NaN payload is a different byte pattern.
+        assert_ne!(nan, key(f32::from_bits(f32::NAN.to_bits() | 1)));
+    }
+
+    #[test]
+    fn signed_zeros_differ() {
+        assert_ne!(key(0.0), key(-0.0));
+        assert_eq!(key(1.5), key(1.5));
+    }
+
+    #[test]
+    fn usable_as_map_key() {
+        let mut cache = HashMap::new();
+        cache.insert(key(f32::NAN), "nan");
+        cache.insert(key(2.0), "two");
+        assert_eq!(cache[&key(f32::NAN)], "nan");
+        assert_eq!(cache.get(&key(-2.0)), None);
+    }
+
+    #[test]
+    fn equal_values_hash_equally() {
+        let state = RandomState::new();
+        assert_eq!(state.hash_one(key(f32::NAN)), state.hash_one(key(f32::NAN)));
+        assert_eq!(
+            state.hash_one(Wrapper { inner: f64::NAN }),
+            state.hash_one(Wrapper { inner: f64::NAN })
+        );
+        assert_eq!(Wrapper { inner: 3u32 }, Wrapper { inner: 3u32 });
+    }
+}
+
+// ── Exposed raw structs ──────────────────────────────────────────────────────
+
+mod expose_raw {
+    use byteable::{
+        BigEndian, Byteable, FromRawRepr, IntoByteArray, LittleEndian, PlainOldData, RawRepr,
+        TryFromRawRepr,
+    };
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[byteable(big_endian, expose_raw)]
+    pub struct Header {
+        pub id: u16,
+        #[byteable(little_endian)]
+        pub length: u32,
+    }
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[byteable(raw_name = WireSample, magic = b"S")]
+    pub struct Sample(pub u8, #[byteable(try_transparent)] pub bool);
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[byteable(expose_raw)]
+    pub struct Marker;
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[byteable(expose_raw)]
+    pub struct Pair<T> {
+        pub first: T,
+        pub second: T,
+    }
+
+    // Names the raw struct in a signature, as an FFI binding would.
+    fn encode(header: &Header) -> HeaderRaw {
+        header.to_raw()
+    }
+
+    #[test]
+    fn raw_struct_is_nameable_and_matches_raw_repr() {
+        let header = Header { id: 1, length: 2 };
+        let raw = encode(&header);
+        let id: BigEndian<u16> = raw.id;
+        let length: LittleEndian<u32> = raw.length;
+        assert_eq!((id.get(), length.get()), (1, 2));
+        assert_eq!(raw.as_bytes(), header.into_byte_array());
+        assert_eq!(Header::from_raw(raw), header);
+        assert_eq!(<HeaderRaw as PlainOldData>::BYTE_SIZE, 6);
+    }
+
+    #[test]
+    fn raw_name_picks_the_name() {
+        let raw: WireSample = Sample(3, true).to_raw();
+        assert_eq!(raw.as_bytes(), [b'S', 3, 1]);
+        assert_eq!(Sample::try_from_raw(raw), Ok(Sample(3, true)));
+    }
+
+    #[test]
+    fn unit_and_generic_structs() {
+        let _: MarkerRaw = Marker.to_raw();
+        let raw: PairRaw<u8> = Pair {
+            first: 1u8,
+            second: 2,
+        }
+        .to_raw();
+        assert_eq!((raw.first, raw.second), (1, 2));
+    }
+}
+
+// ── Custom field conversion ──────────────────────────────────────────────────
+
+mod with {
+    use byteable::{
+        BigEndian, Byteable, ByteableLayout, FromByteArray, IntoByteArray, LittleEndian, ReadFixed,
+        WriteFixed,
+    };
+    use std::io::Cursor;
+
+    /// A fixed-point `f32` stored as thousandths in a little-endian `i32`.
+    mod milli {
+        use byteable::LittleEndian;
+
+        pub fn to_raw(value: f32) -> LittleEndian<i32> {
+            LittleEndian::new((value * 1000.0).round() as i32)
+        }
+
+        pub fn from_raw(raw: LittleEndian<i32>) -> f32 {
+            raw.get() as f32 / 1000.0
+        }
+    }
+
+    /// A `bool` stored as the ASCII characters `Y` / `N`.
+    mod yes_no {
+        pub fn to_raw(value: bool) -> u8 {
+            if value { b'Y' } else { b'N' }
+        }
+
+        pub fn from_raw(raw: u8) -> bool {
+            raw == b'Y'
+        }
+    }
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[byteable(big_endian)]
+    struct Reading {
+        id: u16,
+        #[byteable(with = milli)]
+        level: f32,
+        #[byteable(with = yes_no, pad_before = 1)]
+        alarm: bool,
+    }
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct Pair(
+        #[byteable(with = milli)] f32,
+        #[byteable(with = self::yes_no)] bool,
+    );
+
+    #[test]
+    fn converted_fields_use_the_raw_layout() {
+        let reading = Reading {
+            id: 0x0102,
+            level: -1.5,
+            alarm: true,
+        };
+        assert_eq!(Reading::BYTE_SIZE, 8);
+        assert_eq!(
+            reading.into_byte_array(),
+            [0x01, 0x02, 0x24, 0xFA, 0xFF, 0xFF, 0x00, b'Y']
+        );
+        assert_eq!(Reading::from_byte_array(reading.into_byte_array()), reading);
+    }
+
+    #[test]
+    fn container_endianness_does_not_apply_to_converted_fields() {
+        let bytes = Reading {
+            id: 0,
+            level: 0.001,
+            alarm: false,
+        }
+        .into_byte_array();
+        assert_eq!(&bytes[2..6], &LittleEndian::new(1i32).into_byte_array());
+        assert_eq!(&bytes[2..6], &[1, 0, 0, 0]);
+        assert_ne!(&bytes[2..6], &BigEndian::new(1i32).into_byte_array());
+    }
+
+    #[test]
+    fn tuple_struct_roundtrip() {
+        let pair = Pair(2.25, false);
+        assert_eq!(pair.into_byte_array(), [0xCA, 0x08, 0, 0, b'N']);
+        assert_eq!(Pair::from_byte_array(pair.into_byte_array()), pair);
+    }
+
+    #[test]
+    fn offsets_cover_converted_fields() {
+        assert_eq!(
+            Reading::BYTE_OFFSETS,
+            [("id", 0), ("level", 2), ("alarm", 7)]
+        );
+    }
+
+    #[test]
+    fn io_roundtrip() {
+        let reading = Reading {
+            id: 7,
+            level: 12.345,
+            alarm: true,
+        };
+        let mut buf = Vec::new();
+        buf.write_fixed(&reading).unwrap();
+        let decoded: Reading = Cursor::new(&buf).read_fixed().unwrap();
+        assert_eq!(decoded, reading);
+    }
+}
+
+// ── Skipped fields ───────────────────────────────────────────────────────────
+
+mod skip {
+    use byteable::checksum::crc32;
+    use byteable::{
+        Byteable, ByteableLayout, DecodeError, FromByteArray, HasTrailer, IntoByteArray,
+        LittleEndian, PlainOldData, RawRepr, TryFromByteArray,
+    };
+    use std::marker::PhantomData;
+
+    /// A runtime-only handle: not `Copy`, not `Byteable`, but `Default`.
+    #[derive(Debug, Default, PartialEq)]
+    struct Handle(Option<String>);
+
+    #[derive(Byteable, Debug, PartialEq)]
+    #[byteable(big_endian, raw_derive(Debug))]
+    struct Session {
+        id: u32,
+        #[byteable(skip)]
+        cache: Vec<u8>,
+        flags: u16,
+        #[byteable(skip)]
+        handle: Handle,
+    }
+
+    #[derive(Byteable, Debug, PartialEq)]
+    struct Tagged(u8, #[byteable(skip)] String, #[byteable(little_endian)] u16);
+
+    #[derive(Byteable, Debug, PartialEq)]
+    struct Checked {
+        #[byteable(try_transparent)]
+        ok: bool,
+        #[byteable(skip)]
+        seen: usize,
+    }
+
+    #[derive(Byteable, Debug, PartialEq)]
+    struct Wrapper<T> {
+        #[byteable(transparent)]
+        value: LittleEndian<u16>,
+        #[byteable(skip)]
+        extra: T,
+        marker: PhantomData<T>,
+    }
+
+    #[derive(Byteable, Debug, PartialEq)]
+    #[byteable(crc32)]
+    struct Summed(u8, #[byteable(skip)] u32, #[byteable(little_endian)] u16);
+
+    #[derive(Byteable, Debug, PartialEq)]
+    #[byteable(trailer = u8, crc32)]
+    struct Framed(#[byteable(skip)] String, u8);
+
+    impl HasTrailer<u8> for Framed {
+        fn trailer(&self) -> u8 {
+            0xAA
+        }
+    }
+
+    #[test]
+    fn skipped_fields_take_no_bytes() {
+        assert_eq!(Session::BYTE_SIZE, 6);
+        assert_eq!(Tagged::BYTE_SIZE, 3);
+        assert_eq!(Checked::BYTE_SIZE, 1);
+    }
+
+    #[test]
+    fn encoding_ignores_skipped_fields() {
+        let session = Session {
+            id: 0x0102_0304,
+            cache: vec![9; 16],
+            flags: 0x0506,
+            handle: Handle(Some("open".into())),
+        };
+        assert_eq!(session.into_byte_array(), [1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn decoding_restores_defaults() {
+        assert_eq!(
+            Session::from_byte_array([1, 2, 3, 4, 5, 6]),
+            Session {
+                id: 0x0102_0304,
+                cache: Vec::new(),
+                flags: 0x0506,
+                handle: Handle::default(),
+            }
+        );
+    }
+
+    #[test]
+    fn tuple_struct_with_skipped_middle_field() {
+        let value = Tagged(7, "ignored".into(), 0x0201);
+        assert_eq!(value.into_byte_array(), [7, 1, 2]);
+        assert_eq!(
+            Tagged::from_byte_array([7, 1, 2]),
+            Tagged(7, String::new(), 0x0201)
+        );
+    }
+
+    #[test]
+    fn fallible_struct_with_skipped_field() {
+        assert_eq!(
+            Checked::try_from_byte_array([1]),
+            Ok(Checked { ok: true, seen: 0 })
+        );
+        assert!(Checked::try_from_byte_array([2]).is_err());
+    }
+
+    #[test]
+    fn generic_skipped_field_is_defaulted() {
+        let value = Wrapper {
+            value: LittleEndian::new(0x0201),
+            extra: String::from("runtime"),
+            marker: PhantomData,
+        };
+        assert_eq!(value.to_raw().as_bytes(), &[1, 2]);
+        let decoded = Wrapper::<String>::from_byte_array(value.into_byte_array());
+        assert_eq!(decoded.value.get(), 0x0201);
+        assert_eq!(decoded.extra, "");
+    }
+
+    #[test]
+    fn checksum_after_skipped_tuple_field() {
+        let bytes = Summed(7, 99, 0x0201).into_byte_array();
+        assert_eq!(&bytes[..3], &[7, 1, 2]);
+        assert_eq!(&bytes[3..], &crc32(&bytes[..3]).to_le_bytes());
+        assert_eq!(Summed::try_from_byte_array(bytes), Ok(Summed(7, 0, 0x0201)));
+
+        let mut corrupted = bytes;
+        corrupted[0] ^= 1;
+        assert!(matches!(
+            Summed::try_from_byte_array(corrupted),
+            Err(DecodeError::ChecksumMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn trailer_and_checksum_after_skipped_tuple_field() {
+        let bytes = Framed("ignored".into(), 5).into_byte_array();
+        assert_eq!(&bytes[..2], &[5, 0xAA]);
+        assert_eq!(&bytes[2..], &crc32(&bytes[..2]).to_le_bytes());
+        assert_eq!(
+            Framed::try_from_byte_array(bytes),
+            Ok(Framed(String::new(), 5))
+        );
+    }
+
+    #[test]
+    fn offsets_leave_out_skipped_fields() {
+        assert_eq!(Session::BYTE_OFFSETS, [("id", 0), ("flags", 4)]);
+    }
+
+    #[test]
+    fn raw_debug_lists_only_serialized_fields() {
+        let raw = RawRepr::to_raw(&Session::from_byte_array([0, 0, 0, 1, 0, 2]));
+        assert_eq!(
+            format!("{raw:?}"),
+            "__byteable_raw_Session { id: 1, flags: 2 }"
+        );
+    }
+}
+
+// ── Compile-time safety validation (PlainOldData) ────────────────────────────
+
+mod safety {
+    use byteable::{Byteable, FromByteArray, IntoByteArray};
+
+    #[derive(Clone, Copy, Byteable)]
+    pub struct SafePacket {
+        id: u8,
+        #[byteable(little_endian)]
+        length: u16,
+        #[byteable(big_endian)]
+        checksum: u32,
+        data: [u8; 4],
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    struct Point {
+        #[byteable(little_endian)]
+        x: i32,
+        #[byteable(little_endian)]
+        y: i32,
+    }
+
+    #[derive(Clone, Copy, Byteable)]
+    struct Shape {
+        id: u8,
+        #[byteable(transparent)]
+        top_left: Point,
+        #[byteable(transparent)]
+        bottom_right: Point,
+    }
+
+    #[test]
+    fn safe_packet_roundtrip() {
+        let packet = SafePacket {
+            id: 42,
+            length: 1024,
+            checksum: 0x12345678,
+            data: [1, 2, 3, 4],
+        };
+        let bytes = packet.into_byte_array();
+        let restored = SafePacket::from_byte_array(bytes);
+        assert_eq!(packet.id, restored.id);
+        assert_eq!(packet.length, restored.length);
+        assert_eq!(packet.checksum, restored.checksum);
+        assert_eq!(packet.data, restored.data);
+    }
+
+    #[test]
+    fn nested_safe_types_roundtrip() {
+        let shape = Shape {
+            id: 1,
+            top_left: Point { x: 0, y: 0 },
+            bottom_right: Point { x: 100, y: 200 },
+        };
+        let bytes = shape.into_byte_array();
+        let restored = Shape::from_byte_array(bytes);
+        assert_eq!(shape.id, restored.id);
+        assert_eq!(shape.top_left.x, restored.top_left.x);
+        assert_eq!(shape.top_left.y, restored.top_left.y);
+        assert_eq!(shape.bottom_right.x, restored.bottom_right.x);
+        assert_eq!(shape.bottom_right.y, restored.bottom_right.y);
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Byteable)]
+    #[byteable(magic = b"R", crc32)]
+    struct Wide {
+        tag: u8,
+        #[byteable(big_endian)]
+        big: u64,
+        #[byteable(little_endian, pad_before = 1)]
+        ratio: f64,
+        #[byteable(transparent)]
+        corner: Point,
+        #[byteable(try_transparent)]
+        flag: bool,
+    }
+
+    // Raw structs have alignment 1, so they can be viewed at any address; every field access
+    // the derive generates must then be an unaligned copy. Run under Miri to check.
+    #[test]
+    fn raw_structs_at_odd_addresses() {
+        use byteable::{ByteableRef, FromRawRepr, RawRepr, TryFromRawRepr};
+        use core::mem::align_of;
+
+        type WideRaw = <Wide as RawRepr>::Raw;
+        assert_eq!(align_of::<WideRaw>(), 1);
+        assert_eq!(align_of::<<Shape as RawRepr>::Raw>(), 1);
+
+        let wide = Wide {
+            tag: 3,
+            big: 0x0102_0304_0506_0708,
+            ratio: -0.25,
+            corner: Point { x: -1, y: 7 },
+            flag: true,
+        };
+        let shape = Shape {
+            id: 1,
+            top_left: Point { x: 3, y: 4 },
+            bottom_right: Point { x: 5, y: 6 },
+        };
+        let size = size_of::<WideRaw>();
+        for offset in 1..8 {
+            let mut buf = vec![0u8; offset + size];
+            buf[offset..].copy_from_slice(wide.into_byte_array().as_slice());
+
+            let raw = WideRaw::ref_from_bytes(&buf[offset..]).unwrap();
+            assert_eq!(Wide::try_from_raw(*raw).unwrap(), wide);
+
+            let raw = WideRaw::mut_from_bytes(&mut buf[offset..]).unwrap();
+            *raw = Wide { tag: 9, ..wide }.to_raw();
+            assert_eq!(buf[offset + 1], 9);
+
+            let mut buf = vec![0u8; offset + size_of::<<Shape as RawRepr>::Raw>()];
+            buf[offset..].copy_from_slice(&shape.into_byte_array());
+            let raw = <Shape as RawRepr>::Raw::ref_from_bytes(&buf[offset..]).unwrap();
+            let restored = Shape::from_raw(*raw);
+            assert_eq!(restored.bottom_right.y, 6);
+        }
+    }
 }
@@ -85,28 +85,6 @@ mod fixed_io {
         assert_both::<BigEndian<u16>>();
         assert_both::<Header>();
     }
-
-    /// Vec<u32> is NOT accessible via `AsyncReadFixed` or `AsyncWriteFixed`.
-    ///
-    /// ```compile_fail
-    /// use byteable::AsyncReadFixed;
-    /// use std::io::Cursor;
-    /// # #[tokio::main] async fn main() {
-    /// let mut cursor = Cursor::new(vec![0u8; 16]);
-    /// let _: Vec<u32> = cursor.read_fixed().await.unwrap();
-    /// # }
-    /// ```
-    ///
-    /// ```compile_fail
-    /// use byteable::AsyncWriteFixed;
-    /// use std::io::Cursor;
-    /// # #[tokio::main] async fn main() {
-    /// let mut cursor = Cursor::new(Vec::new());
-    /// cursor.write_fixed(&vec![1u32, 2, 3]).await.unwrap();
-    /// # }
-    /// ```
-    #[tokio::test]
-    async fn compile_fail_docs_exist() {}
 }
 
 // ── Async value / stream I/O ──────────────────────────────────────────────────
@@ -263,6 +241,220 @@ mod value_io {
     }
 }
 
+// ── Async counted writes ──────────────────────────────────────────────────────
+
+mod counted_io {
+    use byteable::{AsyncWriteFixed, AsyncWriteValue, BigEndian, Byteable, IntoByteArray};
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct Record {
+        #[byteable(big_endian)]
+        id: u32,
+        #[byteable(little_endian)]
+        value: u16,
+    }
+
+    #[tokio::test]
+    async fn write_fixed_counted_returns_byte_size() {
+        let mut buf = Vec::new();
+        let record = Record { id: 1, value: 2 };
+        let n = buf.write_fixed_counted(&record).await.unwrap();
+        assert_eq!(n, Record::BYTE_SIZE);
+        assert_eq!(buf.len(), n);
+    }
+
+    #[tokio::test]
+    async fn write_fixed_counted_builds_offsets() {
+        let mut buf = Vec::new();
+        let mut offsets = vec![0];
+        let n = buf.write_fixed_counted(&7u8).await.unwrap();
+        offsets.push(offsets[0] + n);
+        let n = buf
+            .write_fixed_counted(&BigEndian::new(9u64))
+            .await
+            .unwrap();
+        offsets.push(offsets[1] + n);
+        assert_eq!(offsets, [0, 1, 9]);
+        assert_eq!(buf.len(), 9);
+    }
+
+    #[tokio::test]
+    async fn write_fixed_iter_returns_total() {
+        let mut buf = Vec::new();
+        let records = (1..=3).map(|id| Record { id, value: 0 });
+        let n = buf.write_fixed_iter(records).await.unwrap();
+        assert_eq!(n, 3 * Record::BYTE_SIZE);
+        assert_eq!(buf.len(), n);
+        assert_eq!(&buf[..4], &[0, 0, 0, 1]);
+    }
+
+    #[tokio::test]
+    async fn write_fixed_all_matches_loop() {
+        let records: Vec<Record> = (1..=3).map(|id| Record { id, value: 0 }).collect();
+        let mut batched = Vec::new();
+        batched.write_fixed_all(&records).await.unwrap();
+        let mut looped = Vec::new();
+        for record in &records {
+            looped.write_fixed(record).await.unwrap();
+        }
+        assert_eq!(batched, looped);
+    }
+
+    #[tokio::test]
+    async fn write_value_counted_includes_length_prefix() {
+        let mut buf = Vec::new();
+        let n = buf.write_value_counted("hello").await.unwrap();
+        assert_eq!(n, 8 + 5);
+        assert_eq!(buf.len(), n);
+    }
+
+    #[tokio::test]
+    async fn write_value_counted_over_duplex() {
+        let (mut writer, _reader) = tokio::io::duplex(64);
+        let n = writer.write_value_counted(&vec![1u16, 2, 3]).await.unwrap();
+        assert_eq!(n, 8 + 6);
+    }
+}
+
+// ── Async record streams ─────────────────────────────────────────────────────
+
+mod record_stream {
+    use byteable::{
+        AsyncReadFixed, AsyncWriteFixed, Byteable, DecodeError, IntoByteArray, ReadableError,
+    };
+    use futures_util::{StreamExt, TryStreamExt, stream::FusedStream};
+    use std::io::{Cursor, ErrorKind};
+    use tokio::io::AsyncWriteExt;
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct Sample {
+        #[byteable(big_endian)]
+        time: u32,
+        #[byteable(try_transparent)]
+        valid: bool,
+    }
+
+    fn encode(samples: &[Sample]) -> Vec<u8> {
+        let mut buf = Vec::new();
+        for sample in samples {
+            buf.extend_from_slice(&sample.into_byte_array());
+        }
+        buf
+    }
+
+    #[tokio::test]
+    async fn yields_records_until_eof() {
+        let samples: Vec<Sample> = (0..4)
+            .map(|time| Sample {
+                time,
+                valid: time % 2 == 0,
+            })
+            .collect();
+        let mut stream = Cursor::new(encode(&samples)).byteable_stream::<Sample>();
+        let decoded: Vec<Sample> = (&mut stream).try_collect().await.unwrap();
+        assert_eq!(decoded, samples);
+        assert!(stream.is_terminated());
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn empty_input_is_an_empty_stream() {
+        let stream = Cursor::new(Vec::new()).byteable_stream::<Sample>();
+        assert_eq!(stream.count().await, 0);
+    }
+
+    #[tokio::test]
+    async fn composes_with_stream_adapters() {
+        let samples: Vec<Sample> = (0..10)
+            .map(|time| Sample {
+                time,
+                valid: time % 3 == 0,
+            })
+            .collect();
+        let times: Vec<u32> = Cursor::new(encode(&samples))
+            .byteable_stream::<Sample>()
+            .filter_map(|sample| async move { sample.ok().filter(|s| s.valid) })
+            .map(|sample| sample.time)
+            .collect()
+            .await;
+        assert_eq!(times, [0, 3, 6, 9]);
+    }
+
+    #[tokio::test]
+    async fn partial_record_is_an_error_then_ends() {
+        let mut bytes = encode(&[Sample {
+            time: 1,
+            valid: true,
+        }]);
+        bytes.extend_from_slice(&[0, 0]);
+        let mut stream = Cursor::new(bytes).byteable_stream::<Sample>();
+        assert!(stream.next().await.unwrap().is_ok());
+        match stream.next().await {
+            Some(Err(ReadableError::Io(e))) => assert_eq!(e.kind(), ErrorKind::UnexpectedEof),
+            other => panic!("expected UnexpectedEof, got {other:?}"),
+        }
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn decode_error_ends_the_stream() {
+        let mut stream = Cursor::new([0u8, 0, 0, 1, 7, 0, 0, 0, 2, 1]).byteable_stream::<Sample>();
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(ReadableError::DecodeError(DecodeError::InvalidBool(7))))
+        ));
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn records_split_across_writes_are_reassembled() {
+        let samples = [Sample {
+            time: 0x0102_0304,
+            valid: true,
+        }; 3];
+        let bytes = encode(&samples);
+        let (mut client, server) = tokio::io::duplex(64);
+        let writer = tokio::spawn(async move {
+            for chunk in bytes.chunks(2) {
+                client.write_all(chunk).await.unwrap();
+                tokio::task::yield_now().await;
+            }
+        });
+        let decoded: Vec<Sample> = server
+            .byteable_stream::<Sample>()
+            .try_collect()
+            .await
+            .unwrap();
+        writer.await.unwrap();
+        assert_eq!(decoded, samples);
+    }
+
+    #[tokio::test]
+    async fn dropped_next_keeps_partial_record() {
+        let (mut client, server) = tokio::io::duplex(64);
+        let mut stream = server.byteable_stream::<Sample>();
+
+        // Three of the five bytes arrive, then the pending `next()` is abandoned.
+        client.write_all(&[0, 0, 0]).await.unwrap();
+        tokio::select! {
+            biased;
+            _ = stream.next() => panic!("a partial record must not be yielded"),
+            () = tokio::task::yield_now() => {}
+        }
+
+        client.write_fixed(&[9u8, 1]).await.unwrap();
+        drop(client);
+        assert_eq!(
+            stream.next().await.unwrap().unwrap(),
+            Sample {
+                time: 9,
+                valid: true
+            }
+        );
+        assert!(stream.next().await.is_none());
+    }
+}
+
 // ── Async collection types ────────────────────────────────────────────────────
 
 mod collections {
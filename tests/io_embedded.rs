@@ -0,0 +1,159 @@
+//! Integration tests for the `embedded-io` based blocking I/O traits.
+//!
+//! Covers `EmbeddedReadFixed`/`EmbeddedWriteFixed` over byte slices and a failing writer,
+//! including end-of-input and decode errors.
+#![cfg(all(feature = "embedded-io", feature = "derive"))]
+
+use byteable::{
+    BigEndian, Byteable, DecodeError, EmbeddedFixedReadable, EmbeddedFixedWritable,
+    EmbeddedReadError, EmbeddedReadFixed, EmbeddedWriteFixed, LittleEndian,
+};
+use embedded_io::{ErrorKind, ErrorType, ReadExactError, Write};
+
+#[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+struct Header {
+    #[byteable(big_endian)]
+    magic: u32,
+    #[byteable(little_endian)]
+    version: u16,
+}
+
+#[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+enum Kind {
+    A = 1,
+    B = 2,
+}
+
+/// A writer that accepts `capacity` bytes and then reports an error.
+struct FailingWriter {
+    capacity: usize,
+}
+
+impl ErrorType for FailingWriter {
+    type Error = ErrorKind;
+}
+
+impl Write for FailingWriter {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, Self::Error> {
+        if self.capacity == 0 {
+            return Err(ErrorKind::Other);
+        }
+        let n = buf.len().min(self.capacity);
+        self.capacity -= n;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[test]
+fn primitive_roundtrip() {
+    let mut buf = [0u8; 7];
+    let mut writer = &mut buf[..];
+    writer.write_fixed_embedded(&0xABu8).unwrap();
+    writer
+        .write_fixed_embedded(&BigEndian::new(0x0102u16))
+        .unwrap();
+    writer
+        .write_fixed_embedded(&LittleEndian::new(0x0304_0506u32))
+        .unwrap();
+    assert!(writer.is_empty());
+    assert_eq!(buf, [0xAB, 0x01, 0x02, 0x06, 0x05, 0x04, 0x03]);
+
+    let mut reader = &buf[..];
+    assert_eq!(reader.read_fixed_embedded::<u8>().unwrap(), 0xAB);
+    assert_eq!(
+        reader
+            .read_fixed_embedded::<BigEndian<u16>>()
+            .unwrap()
+            .get(),
+        0x0102
+    );
+    assert_eq!(
+        reader
+            .read_fixed_embedded::<LittleEndian<u32>>()
+            .unwrap()
+            .get(),
+        0x0304_0506
+    );
+    assert!(reader.is_empty());
+}
+
+#[test]
+fn derived_struct_roundtrip() {
+    let header = Header {
+        magic: 0xCAFE_BABE,
+        version: 3,
+    };
+
+    let mut buf = [0u8; 6];
+    header.write_fixed_to(&mut &mut buf[..]).unwrap();
+    assert_eq!(buf, [0xCA, 0xFE, 0xBA, 0xBE, 3, 0]);
+
+    assert_eq!(Header::read_fixed_from(&mut &buf[..]).unwrap(), header);
+}
+
+#[test]
+fn derived_enum_roundtrip() {
+    let mut buf = [0u8; 2];
+    let mut writer = &mut buf[..];
+    writer.write_fixed_embedded(&Kind::B).unwrap();
+    writer.write_fixed_embedded(&Kind::A).unwrap();
+
+    let mut reader = &buf[..];
+    assert_eq!(reader.read_fixed_embedded::<Kind>().unwrap(), Kind::B);
+    assert_eq!(reader.read_fixed_embedded::<Kind>().unwrap(), Kind::A);
+}
+
+#[test]
+fn short_input_is_unexpected_eof() {
+    let mut reader = &[0xCA, 0xFE, 0xBA][..];
+    let err = reader.read_fixed_embedded::<Header>().unwrap_err();
+    assert!(matches!(
+        err,
+        EmbeddedReadError::Io(ReadExactError::UnexpectedEof)
+    ));
+}
+
+#[test]
+fn invalid_discriminant_is_decode_error() {
+    let mut reader = &[9u8][..];
+    let err = reader.read_fixed_embedded::<Kind>().unwrap_err();
+    assert!(matches!(
+        err,
+        EmbeddedReadError::DecodeError(DecodeError::InvalidDiscriminant { .. })
+    ));
+}
+
+#[test]
+fn writer_error_is_passed_through() {
+    let mut writer = FailingWriter { capacity: 3 };
+    let header = Header {
+        magic: 1,
+        version: 2,
+    };
+    assert_eq!(writer.write_fixed_embedded(&header), Err(ErrorKind::Other));
+}
+
+#[test]
+fn full_slice_reports_write_error() {
+    let mut buf = [0u8; 1];
+    let mut writer = &mut buf[..];
+    assert!(writer.write_fixed_embedded(&BigEndian::new(1u16)).is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn usable_alongside_std_traits() {
+    // `&[u8]` implements both `Read` traits; the method names must not collide.
+    use byteable::ReadFixed;
+
+    let buf = [0x01, 0x02, 0x03, 0x04];
+    let mut reader = &buf[..];
+    let a: BigEndian<u16> = reader.read_fixed().unwrap();
+    let b: BigEndian<u16> = reader.read_fixed_embedded().unwrap();
+    assert_eq!((a.get(), b.get()), (0x0102, 0x0304));
+}
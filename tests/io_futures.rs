@@ -0,0 +1,220 @@
+//! Integration tests for the `futures-io` based asynchronous I/O traits.
+//!
+//! Covers fixed-size I/O (`FuturesReadFixed`/`FuturesWriteFixed`) and value I/O
+//! (`FuturesReadValue`/`FuturesWriteValue`) over `futures_util::io::Cursor`.
+#![cfg(all(feature = "std", feature = "futures", feature = "derive"))]
+
+use byteable::{
+    BigEndian, Byteable, DecodeError, FuturesFixedReadable, FuturesFixedWritable, FuturesReadFixed,
+    FuturesReadValue, FuturesWriteFixed, FuturesWriteValue, LittleEndian, ReadableError,
+};
+use futures_executor::block_on;
+use futures_util::io::Cursor;
+
+#[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+struct Header {
+    #[byteable(big_endian)]
+    magic: u32,
+    #[byteable(little_endian)]
+    version: u16,
+}
+
+#[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+enum Kind {
+    A = 1,
+    B = 2,
+}
+
+#[test]
+fn primitive_roundtrip() {
+    block_on(async {
+        let original: LittleEndian<u32> = LittleEndian::new(0xDEADBEEF);
+        let mut buf = Cursor::new(Vec::new());
+        buf.write_fixed(&original).await.unwrap();
+        assert_eq!(buf.get_ref(), &[0xEF, 0xBE, 0xAD, 0xDE]);
+        let restored: LittleEndian<u32> = Cursor::new(buf.into_inner()).read_fixed().await.unwrap();
+        assert_eq!(restored.get(), original.get());
+    });
+}
+
+#[test]
+fn derived_struct_roundtrip() {
+    block_on(async {
+        let header = Header {
+            magic: 0x12345678,
+            version: 42,
+        };
+        let mut buf = Cursor::new(Vec::new());
+        buf.write_value(&header).await.unwrap();
+        assert_eq!(buf.get_ref(), &[0x12, 0x34, 0x56, 0x78, 42, 0]);
+        let restored: Header = Cursor::new(buf.into_inner()).read_value().await.unwrap();
+        assert_eq!(restored, header);
+    });
+}
+
+#[test]
+fn sequential_values() {
+    block_on(async {
+        let mut buf = Cursor::new(Vec::new());
+        buf.write_fixed(&BigEndian::new(1u16)).await.unwrap();
+        buf.write_fixed(&Kind::B).await.unwrap();
+        buf.write_fixed(&BigEndian::new(3u16)).await.unwrap();
+
+        let mut reader = Cursor::new(buf.into_inner());
+        assert_eq!(
+            reader.read_fixed::<BigEndian<u16>>().await.unwrap().get(),
+            1
+        );
+        assert_eq!(reader.read_fixed::<Kind>().await.unwrap(), Kind::B);
+        assert_eq!(
+            reader.read_fixed::<BigEndian<u16>>().await.unwrap().get(),
+            3
+        );
+    });
+}
+
+#[test]
+fn direct_trait_calls() {
+    block_on(async {
+        let mut buf = Cursor::new(Vec::new());
+        Kind::A.write_fixed_to(&mut buf).await.unwrap();
+        let mut reader = Cursor::new(buf.into_inner());
+        assert_eq!(Kind::read_fixed_from(&mut reader).await.unwrap(), Kind::A);
+    });
+}
+
+#[test]
+fn invalid_discriminant() {
+    block_on(async {
+        let result = Cursor::new(vec![9u8]).read_fixed::<Kind>().await;
+        assert!(matches!(
+            result,
+            Err(ReadableError::DecodeError(
+                DecodeError::InvalidDiscriminant { .. }
+            ))
+        ));
+    });
+}
+
+#[test]
+fn unexpected_eof() {
+    block_on(async {
+        let result = Cursor::new(vec![0x12, 0x34]).read_fixed::<Header>().await;
+        match result {
+            Err(ReadableError::Io(e)) => {
+                assert_eq!(e.kind(), std::io::ErrorKind::UnexpectedEof)
+            }
+            other => panic!("expected UnexpectedEof, got {other:?}"),
+        }
+    });
+}
+
+// ── Collection and pointer types ─────────────────────────────────────────────
+
+mod collections {
+    use byteable::{
+        DecodeError, FuturesReadValue, FuturesReadable, FuturesWritable, FuturesWriteValue,
+        ReadableError, WriteValue,
+    };
+    use futures_executor::block_on;
+    use futures_util::io::Cursor;
+    use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+    use std::ffi::CString;
+    use std::path::PathBuf;
+
+    fn roundtrip<T: FuturesWritable + FuturesReadable>(original: &T) -> T {
+        block_on(async {
+            let mut buf = Cursor::new(Vec::new());
+            buf.write_value(original).await.unwrap();
+            buf.set_position(0);
+            buf.read_value().await.unwrap()
+        })
+    }
+
+    #[test]
+    fn vec_roundtrip() {
+        let original: Vec<u32> = vec![0xDEAD, 0xBEEF, 0xCAFE];
+        assert_eq!(roundtrip(&original), original);
+    }
+
+    #[test]
+    fn vecdeque_roundtrip() {
+        let original: VecDeque<u32> = VecDeque::from([0xDEAD, 0xBEEF, 0xCAFE]);
+        assert_eq!(roundtrip(&original), original);
+    }
+
+    #[test]
+    fn hashmap_roundtrip() {
+        let original: HashMap<u8, u32> = HashMap::from([(1, 100), (2, 200)]);
+        assert_eq!(roundtrip(&original), original);
+    }
+
+    #[test]
+    fn hashset_roundtrip() {
+        let original: HashSet<u32> = HashSet::from([10, 20, 30]);
+        assert_eq!(roundtrip(&original), original);
+    }
+
+    #[test]
+    fn btreemap_roundtrip() {
+        let original: BTreeMap<u8, String> =
+            BTreeMap::from([(1, "one".to_string()), (2, "two".to_string())]);
+        assert_eq!(roundtrip(&original), original);
+    }
+
+    #[test]
+    fn btreeset_roundtrip() {
+        let original: BTreeSet<u32> = BTreeSet::from([5, 10, 15]);
+        assert_eq!(roundtrip(&original), original);
+    }
+
+    #[test]
+    fn option_and_result_roundtrip() {
+        assert_eq!(roundtrip(&Some(7u16)), Some(7));
+        assert_eq!(roundtrip(&None::<u16>), None);
+        assert_eq!(roundtrip(&Ok::<u8, String>(1)), Ok(1));
+        assert_eq!(
+            roundtrip(&Err::<u8, String>("bad".into())),
+            Err("bad".into())
+        );
+    }
+
+    #[test]
+    fn string_like_roundtrip() {
+        let original = String::from("futures hello!");
+        assert_eq!(roundtrip(&original), original);
+        let original = CString::new("c string").unwrap();
+        assert_eq!(roundtrip(&original), original);
+        let original = PathBuf::from("dir/file.bin");
+        assert_eq!(roundtrip(&original), original);
+    }
+
+    #[test]
+    fn wire_format_matches_std() {
+        let value: (Vec<String>, Option<u32>) = (vec!["a".into(), "bc".into()], Some(9));
+        let mut sync = Vec::new();
+        WriteValue::write_value(&mut sync, &value.0).unwrap();
+        WriteValue::write_value(&mut sync, &value.1).unwrap();
+
+        let mut buf = Cursor::new(Vec::new());
+        block_on(async {
+            buf.write_value(&value.0).await.unwrap();
+            buf.write_value(&value.1).await.unwrap();
+        });
+        assert_eq!(buf.into_inner(), sync);
+    }
+
+    #[test]
+    fn invalid_option_tag() {
+        let mut buf = Cursor::new(vec![2u8]);
+        let err = block_on(buf.read_value::<Option<u8>>()).unwrap_err();
+        assert!(matches!(
+            err,
+            ReadableError::DecodeError(DecodeError::InvalidTag {
+                raw: 2,
+                type_name: "Option"
+            })
+        ));
+    }
+}
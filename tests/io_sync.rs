@@ -9,8 +9,8 @@
 
 mod fixed_io {
     use byteable::{
-        BigEndian, Byteable, FixedReadable, FixedWritable, LittleEndian, ReadFixed, ReadValue,
-        WriteFixed, WriteValue,
+        BigEndian, Byteable, FixedReadable, FixedWritable, IntoByteArray, LittleEndian, ReadFixed,
+        ReadValue, WriteFixed, WriteValue,
     };
     use std::io::Cursor;
 
@@ -102,23 +102,47 @@ mod fixed_io {
         assert_both::<Header>();
     }
 
-    /// Vec<u32> does NOT implement `FixedReadable` or `FixedWritable`.
-    ///
-    /// ```compile_fail
-    /// use byteable::ReadFixed;
-    /// use std::io::Cursor;
-    /// let mut cursor = Cursor::new(vec![0u8; 16]);
-    /// let _: Vec<u32> = cursor.read_fixed().unwrap();
-    /// ```
-    ///
-    /// ```compile_fail
-    /// use byteable::WriteFixed;
-    /// use std::io::Cursor;
-    /// let mut cursor = Cursor::new(Vec::new());
-    /// cursor.write_fixed(&vec![1u32, 2, 3]).unwrap();
-    /// ```
-    #[test]
-    fn compile_fail_docs_exist() {}
+    #[test]
+    fn empty_array_reads_and_writes_nothing() {
+        assert_eq!(<[u8; 0] as IntoByteArray>::BYTE_SIZE, 0);
+        let mut cursor = Cursor::new(Vec::new());
+        cursor.write_fixed(&[0u8; 0]).unwrap();
+        assert!(cursor.get_ref().is_empty());
+
+        let mut reader = Cursor::new([7u8]);
+        let empty: [u8; 0] = reader.read_fixed().unwrap();
+        assert_eq!(empty, []);
+        assert_eq!(reader.position(), 0);
+        let empty: [LittleEndian<u32>; 0] = Cursor::new([]).read_fixed().unwrap();
+        assert_eq!(empty, []);
+    }
+
+    #[test]
+    fn large_arrays_on_a_small_stack() {
+        // Array conversions fill their result in place, so even in debug builds reading and
+        // writing 4 KiB arrays only needs a few copies of them on the stack.
+        std::thread::Builder::new()
+            .stack_size(96 * 1024)
+            .spawn(|| {
+                let data: Vec<u8> = (0..4096u32).map(|i| (i % 251) as u8).collect();
+                let mut cursor = Cursor::new(data.clone());
+                let page: [u8; 4096] = cursor.read_fixed().unwrap();
+                assert_eq!(page.as_slice(), data.as_slice());
+                assert_eq!(cursor.read_fixed::<u8>().ok(), None);
+
+                let words: [LittleEndian<u32>; 1024] = Cursor::new(&data).read_fixed().unwrap();
+                assert_eq!(words[1].get(), u32::from_le_bytes([4, 5, 6, 7]));
+
+                let mut out = Cursor::new(Vec::new());
+                out.write_fixed(&page).unwrap();
+                out.write_fixed(&words).unwrap();
+                assert_eq!(out.get_ref().len(), 8192);
+                assert_eq!(&out.get_ref()[4096..], data.as_slice());
+            })
+            .unwrap()
+            .join()
+            .unwrap();
+    }
 }
 
 // ── Value / stream I/O ────────────────────────────────────────────────────────
@@ -584,6 +608,40 @@ mod io_only_derive {
         assert_eq!(roundtrip(&original), original);
     }
 
+    #[derive(Byteable, Debug, PartialEq)]
+    #[byteable(io_only, big_endian)]
+    struct BigEndianRecord {
+        id: u32,
+        kind: u8,
+        #[byteable(little_endian)]
+        checksum: u16,
+        ratio: f32,
+        samples: [u16; 2],
+        name: String,
+    }
+
+    #[test]
+    fn struct_endianness_applies_to_multi_byte_fields() {
+        let original = BigEndianRecord {
+            id: 0x0102_0304,
+            kind: 5,
+            checksum: 0x0607,
+            ratio: 1.0,
+            samples: [0x0809, 0x0A0B],
+            name: "ab".into(),
+        };
+        let mut buf = Vec::new();
+        buf.write_value(&original).unwrap();
+        assert_eq!(&buf[0..4], &[0x01, 0x02, 0x03, 0x04]); // id big-endian
+        assert_eq!(buf[4], 5);
+        assert_eq!(&buf[5..7], &[0x07, 0x06]); // field-level override
+        assert_eq!(&buf[7..11], &1.0f32.to_be_bytes());
+        assert_eq!(&buf[11..15], &[0x08, 0x09, 0x0A, 0x0B]); // arrays element by element
+        assert_eq!(&buf[15..23], &2u64.to_le_bytes()); // String keeps its own prefix
+        assert_eq!(&buf[23..], b"ab");
+        assert_eq!(roundtrip(&original), original);
+    }
+
     #[derive(Byteable, Debug, PartialEq)]
     #[byteable(io_only)]
     struct UnitIo;
@@ -597,6 +655,95 @@ mod io_only_derive {
     }
 }
 
+// ── Count-delimited fields ───────────────────────────────────────────────────
+
+mod count_field {
+    use byteable::{BigEndian, Byteable, ReadValue, ReadableError, WriteValue};
+    use std::io::{self, Cursor};
+
+    #[derive(Byteable, Debug, Clone, PartialEq)]
+    #[byteable(io_only)]
+    struct Samples {
+        #[byteable(big_endian)]
+        n: u16,
+        flags: u8,
+        #[byteable(count = n)]
+        values: Vec<BigEndian<u16>>,
+    }
+
+    #[derive(Byteable, Debug, Clone, PartialEq)]
+    #[byteable(io_only)]
+    struct TwoLists {
+        a_len: u8,
+        b_len: u32,
+        #[byteable(count = a_len)]
+        a: Vec<u8>,
+        #[byteable(count = b_len)]
+        b: Vec<String>,
+    }
+
+    fn samples() -> Samples {
+        Samples {
+            n: 2,
+            flags: 0xF0,
+            values: vec![BigEndian::new(0x0102), BigEndian::new(0x0304)],
+        }
+    }
+
+    #[test]
+    fn elements_follow_without_length_prefix() {
+        let mut buf = Vec::new();
+        buf.write_value(&samples()).unwrap();
+        assert_eq!(buf, [0, 2, 0xF0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut buf = Vec::new();
+        buf.write_value(&samples()).unwrap();
+        let decoded: Samples = Cursor::new(&buf).read_value().unwrap();
+        assert_eq!(decoded, samples());
+    }
+
+    #[test]
+    fn several_counted_fields() {
+        let original = TwoLists {
+            a_len: 3,
+            b_len: 1,
+            a: vec![1, 2, 3],
+            b: vec!["x".into()],
+        };
+        let mut buf = Vec::new();
+        buf.write_value(&original).unwrap();
+        assert_eq!(Cursor::new(&buf).read_value::<TwoLists>().unwrap(), original);
+    }
+
+    #[test]
+    fn zero_count_reads_no_elements() {
+        let decoded: Samples = Cursor::new([0, 0, 7]).read_value().unwrap();
+        assert_eq!(decoded.values, vec![]);
+    }
+
+    #[test]
+    fn count_mismatch_is_rejected_before_writing() {
+        let mut bad = samples();
+        bad.n = 3;
+        let mut buf = Vec::new();
+        let err = buf.write_value(&bad).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn missing_elements_are_unexpected_eof() {
+        let err = Cursor::new([0, 2, 0, 1, 2]).read_value::<Samples>().unwrap_err();
+        match err {
+            ReadableError::Io(e) => assert_eq!(e.kind(), io::ErrorKind::UnexpectedEof),
+            other => panic!("expected an I/O error, got {other:?}"),
+        }
+    }
+}
+
 // ── Counted I/O ───────────────────────────────────────────────────────────────
 
 mod counted_io {
@@ -777,6 +924,1117 @@ mod counted_io {
     }
 }
 
+// ── Reads returning the source bytes ──────────────────────────────────────────
+
+mod read_with_bytes {
+    use byteable::{
+        ByteArray, Byteable, DecodeError, IntoByteArray, ReadFixed, ReadableError, WriteFixed,
+    };
+    use std::io::Cursor;
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct Record {
+        #[byteable(big_endian)]
+        id: u32,
+        #[byteable(try_transparent)]
+        flag: bool,
+    }
+
+    #[test]
+    fn returned_bytes_match_serialization() {
+        let record = Record { id: 0x01020304, flag: true };
+        let mut buf = Vec::new();
+        buf.write_fixed(&record).unwrap();
+        buf.write_fixed(&7u16).unwrap();
+
+        let mut cursor = Cursor::new(buf);
+        let (value, bytes) = cursor.read_fixed_with_bytes::<Record>().unwrap();
+        assert_eq!(value, record);
+        assert_eq!(bytes, record.into_byte_array());
+        assert_eq!(bytes.as_byte_slice(), &[1, 2, 3, 4, 1]);
+
+        // The reader is left right after the value.
+        let (next, next_bytes) = cursor.read_fixed_with_bytes::<u16>().unwrap();
+        assert_eq!(next, 7);
+        assert_eq!(next_bytes, [7, 0]);
+    }
+
+    #[test]
+    fn array_bytes_are_nested() {
+        let mut cursor = Cursor::new([1u8, 0, 2, 0]);
+        let (value, bytes) = cursor.read_fixed_with_bytes::<[u16; 2]>().unwrap();
+        assert_eq!(value, [1, 2]);
+        assert_eq!(bytes, [[1, 0], [2, 0]]);
+    }
+
+    #[test]
+    fn decode_error_is_reported() {
+        let mut cursor = Cursor::new([0u8, 0, 0, 1, 5]);
+        let err = cursor.read_fixed_with_bytes::<Record>().unwrap_err();
+        assert!(matches!(err, ReadableError::DecodeError(DecodeError::InvalidBool(5))));
+    }
+
+    #[test]
+    fn short_read_is_io_error() {
+        let mut cursor = Cursor::new([0u8, 0]);
+        let err = cursor.read_fixed_with_bytes::<Record>().unwrap_err();
+        assert!(matches!(err, ReadableError::Io(_)));
+    }
+}
+
+// ── Reading into an existing value ───────────────────────────────────────────
+
+mod read_into {
+    use byteable::{Byteable, DecodeError, ReadFixed, ReadableError, WriteFixed};
+    use std::io::Cursor;
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct Record {
+        #[byteable(big_endian)]
+        id: u32,
+        #[byteable(try_transparent)]
+        flag: bool,
+    }
+
+    #[test]
+    fn overwrites_destination_in_a_loop() {
+        let records = [
+            Record { id: 1, flag: true },
+            Record { id: 2, flag: false },
+            Record { id: 3, flag: true },
+        ];
+        let mut buf = Vec::new();
+        for record in &records {
+            buf.write_fixed(record).unwrap();
+        }
+
+        let mut cursor = Cursor::new(buf);
+        let mut dst = Record { id: 0, flag: false };
+        for record in &records {
+            cursor.read_fixed_into(&mut dst).unwrap();
+            assert_eq!(dst, *record);
+        }
+    }
+
+    #[test]
+    fn partial_read_leaves_destination_unchanged() {
+        let original = Record { id: 9, flag: true };
+        let mut dst = original;
+        let err = Cursor::new([0u8, 0, 0]).read_fixed_into(&mut dst).unwrap_err();
+        assert!(matches!(err, ReadableError::Io(_)));
+        assert_eq!(dst, original);
+    }
+
+    #[test]
+    fn decode_error_leaves_destination_unchanged() {
+        let original = Record { id: 9, flag: true };
+        let mut dst = original;
+        let err = Cursor::new([0u8, 0, 0, 1, 5]).read_fixed_into(&mut dst).unwrap_err();
+        assert!(matches!(err, ReadableError::DecodeError(DecodeError::InvalidBool(5))));
+        assert_eq!(dst, original);
+    }
+}
+
+// ── Reading primitives in a chosen byte order ────────────────────────────────
+
+mod read_endian {
+    use byteable::{ReadFixed, U24};
+    use std::io::{Cursor, ErrorKind};
+
+    #[test]
+    fn reads_each_width_in_the_requested_order() {
+        let mut cursor = Cursor::new([
+            0x01, 0x02, // u16
+            0x01, 0x02, 0x03, 0x04, // u32
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, // u64
+            0x01, 0x02, 0x03, // U24
+        ]);
+        assert_eq!(cursor.read_be::<u16>().unwrap(), 0x0102);
+        assert_eq!(cursor.read_le::<u32>().unwrap(), 0x0403_0201);
+        assert_eq!(cursor.read_be::<u64>().unwrap(), 0x0102_0304_0506_0708);
+        assert_eq!(cursor.read_be::<U24>().unwrap().get(), 0x01_0203);
+    }
+
+    #[test]
+    fn floats_and_signed_integers() {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&(-1.5f64).to_be_bytes());
+        bytes.extend_from_slice(&(-300i16).to_le_bytes());
+        let mut cursor = Cursor::new(bytes);
+        assert_eq!(cursor.read_be::<f64>().unwrap(), -1.5);
+        assert_eq!(cursor.read_le::<i16>().unwrap(), -300);
+    }
+
+    #[test]
+    fn short_input_is_unexpected_eof() {
+        let err = Cursor::new([1u8, 2, 3]).read_le::<u32>().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    }
+}
+
+// ── Conversion to io::Error ─────────────────────────────────────────────────
+
+mod into_io_error {
+    use byteable::{Byteable, DecodeError, Discriminant, ReadFixed, ReadableError};
+    use std::io::{self, Cursor};
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[repr(u8)]
+    enum Kind {
+        A = 1,
+        B = 2,
+    }
+
+    fn read_kinds(bytes: &[u8]) -> io::Result<(Kind, Kind)> {
+        let mut cursor = Cursor::new(bytes);
+        Ok((cursor.read_fixed()?, cursor.read_fixed()?))
+    }
+
+    #[test]
+    fn question_mark_into_io_result() {
+        assert_eq!(read_kinds(&[2, 1]).unwrap(), (Kind::B, Kind::A));
+    }
+
+    #[test]
+    fn decode_error_becomes_invalid_data() {
+        let err = read_kinds(&[1, 9]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        let source = err.get_ref().unwrap().downcast_ref::<DecodeError>().unwrap();
+        assert!(matches!(source, DecodeError::InvalidDiscriminant { raw: Discriminant::U8(9), .. }));
+        assert_eq!(err.to_string(), source.to_string());
+    }
+
+    #[test]
+    fn io_error_is_passed_through() {
+        let err = read_kinds(&[1]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+        let original = io::Error::new(io::ErrorKind::ConnectionReset, "reset");
+        let err = io::Error::from(ReadableError::Io(original));
+        assert_eq!(err.kind(), io::ErrorKind::ConnectionReset);
+        assert_eq!(err.to_string(), "reset");
+    }
+}
+
+// ── Reading until end of input ───────────────────────────────────────────────
+
+mod until_eof {
+    use byteable::{Byteable, DecodeError, ReadFixed, ReadableError, WriteFixed};
+    use std::io::{self, Cursor, Read};
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct Sample {
+        #[byteable(big_endian)]
+        time: u32,
+        #[byteable(try_transparent)]
+        valid: bool,
+    }
+
+    /// Returns at most one byte per `read` call to exercise partial reads.
+    struct Trickle<R>(R);
+
+    impl<R: Read> Read for Trickle<R> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let len = buf.len().min(1);
+            self.0.read(&mut buf[..len])
+        }
+    }
+
+    fn encode(samples: &[Sample]) -> Vec<u8> {
+        let mut buf = Vec::new();
+        for sample in samples {
+            buf.write_fixed(sample).unwrap();
+        }
+        buf
+    }
+
+    #[test]
+    fn reads_all_records() {
+        let samples = [
+            Sample { time: 1, valid: true },
+            Sample { time: 2, valid: false },
+            Sample { time: 3, valid: true },
+        ];
+        let values: Vec<Sample> = Cursor::new(encode(&samples)).read_fixed_until_eof().unwrap();
+        assert_eq!(values, samples);
+    }
+
+    #[test]
+    fn empty_input_yields_no_records() {
+        let values: Vec<Sample> = Cursor::new(Vec::new()).read_fixed_until_eof().unwrap();
+        assert!(values.is_empty());
+    }
+
+    #[test]
+    fn partial_reads_are_reassembled() {
+        let samples = [Sample { time: 0x01020304, valid: true }; 4];
+        let values: Vec<Sample> = Trickle(Cursor::new(encode(&samples)))
+            .read_fixed_until_eof()
+            .unwrap();
+        assert_eq!(values, samples);
+    }
+
+    #[test]
+    fn trailing_partial_record_is_an_error() {
+        let mut bytes = encode(&[Sample { time: 1, valid: true }]);
+        bytes.extend_from_slice(&[0, 0]);
+        let err = Cursor::new(bytes).read_fixed_until_eof::<Sample>().unwrap_err();
+        match err {
+            ReadableError::Io(e) => assert_eq!(e.kind(), io::ErrorKind::UnexpectedEof),
+            other => panic!("expected UnexpectedEof, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn invalid_record_is_a_decode_error() {
+        let err = Cursor::new([0u8, 0, 0, 1, 7])
+            .read_fixed_until_eof::<Sample>()
+            .unwrap_err();
+        assert!(matches!(err, ReadableError::DecodeError(DecodeError::InvalidBool(7))));
+    }
+
+    #[test]
+    fn iter_yields_each_record_lazily() {
+        let samples = [
+            Sample { time: 1, valid: true },
+            Sample { time: 2, valid: false },
+            Sample { time: 3, valid: true },
+        ];
+        let mut iter = Cursor::new(encode(&samples)).byteable_iter::<Sample>();
+        assert_eq!(iter.next().unwrap().unwrap(), samples[0]);
+        assert_eq!(iter.get_ref().position(), 5);
+
+        let rest: Vec<Sample> = iter.by_ref().collect::<Result<_, _>>().unwrap();
+        assert_eq!(rest, samples[1..]);
+        assert!(iter.next().is_none());
+        assert_eq!(iter.into_inner().position(), 15);
+    }
+
+    #[test]
+    fn iter_composes_with_adapters() {
+        let samples: Vec<Sample> =
+            (0..10).map(|time| Sample { time, valid: time % 3 == 0 }).collect();
+        let times: Vec<u32> = Trickle(Cursor::new(encode(&samples)))
+            .byteable_iter::<Sample>()
+            .map(Result::unwrap)
+            .filter(|sample| sample.valid)
+            .map(|sample| sample.time)
+            .collect();
+        assert_eq!(times, [0, 3, 6, 9]);
+    }
+
+    #[test]
+    fn iter_on_empty_input_is_empty() {
+        assert_eq!(Cursor::new(Vec::new()).byteable_iter::<Sample>().count(), 0);
+    }
+
+    #[test]
+    fn iter_yields_partial_record_error_then_stops() {
+        let mut bytes = encode(&[Sample { time: 1, valid: true }]);
+        bytes.extend_from_slice(&[0, 0]);
+        let mut iter = Cursor::new(bytes).byteable_iter::<Sample>();
+        assert!(iter.next().unwrap().is_ok());
+        match iter.next() {
+            Some(Err(ReadableError::Io(e))) => assert_eq!(e.kind(), io::ErrorKind::UnexpectedEof),
+            other => panic!("expected UnexpectedEof, got {other:?}"),
+        }
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn iter_yields_decode_error_then_stops() {
+        let mut iter = Cursor::new([0u8, 0, 0, 1, 7, 0, 0, 0, 2, 1]).byteable_iter::<Sample>();
+        assert!(matches!(
+            iter.next(),
+            Some(Err(ReadableError::DecodeError(DecodeError::InvalidBool(7))))
+        ));
+        assert!(iter.next().is_none());
+    }
+
+    /// A reader that fails every time it is read.
+    struct Broken;
+
+    impl Read for Broken {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::ConnectionReset, "reset"))
+        }
+    }
+
+    #[test]
+    fn iter_does_not_spin_on_a_failing_reader() {
+        let results: Vec<_> = Broken.byteable_iter::<Sample>().collect();
+        assert_eq!(results.len(), 1);
+        assert!(matches!(&results[0], Err(ReadableError::Io(e)) if e.kind() == io::ErrorKind::ConnectionReset));
+    }
+}
+
+// ── Length-prefixed sequences ────────────────────────────────────────────────
+
+mod prefixed {
+    use byteable::{
+        BigEndian, Byteable, LittleEndian, ReadFixed, ReadableError, Usize32, Usize64, WriteFixed,
+    };
+    use std::io::{Cursor, ErrorKind};
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct Point {
+        #[byteable(big_endian)]
+        x: i16,
+        #[byteable(big_endian)]
+        y: i16,
+    }
+
+    #[test]
+    fn big_endian_u32_prefix_roundtrip() {
+        let points = [Point { x: 1, y: -1 }, Point { x: 300, y: 2 }];
+        let mut buf = Vec::new();
+        buf.write_fixed_prefixed::<BigEndian<u32>, _>(&points).unwrap();
+        assert_eq!(&buf[..4], &[0, 0, 0, 2]);
+        assert_eq!(buf.len(), 4 + 2 * 4);
+
+        let read: Vec<Point> = Cursor::new(buf)
+            .read_fixed_prefixed::<BigEndian<u32>, _>(10)
+            .unwrap();
+        assert_eq!(read, points);
+    }
+
+    #[test]
+    fn primitive_and_little_endian_prefixes() {
+        let mut buf = Vec::new();
+        buf.write_fixed_prefixed::<u8, u8>(&[5, 6, 7]).unwrap();
+        assert_eq!(buf, [3, 5, 6, 7]);
+
+        let mut buf = Vec::new();
+        buf.write_fixed_prefixed::<LittleEndian<u16>, u8>(&[5]).unwrap();
+        assert_eq!(buf, [1, 0, 5]);
+        let read: Vec<u8> = Cursor::new(buf)
+            .read_fixed_prefixed::<LittleEndian<u16>, _>(1)
+            .unwrap();
+        assert_eq!(read, [5]);
+    }
+
+    #[test]
+    fn fixed_usize_prefixes() {
+        let mut buf = Vec::new();
+        buf.write_fixed_prefixed::<BigEndian<Usize64>, u8>(&[5, 6]).unwrap();
+        assert_eq!(buf, [0, 0, 0, 0, 0, 0, 0, 2, 5, 6]);
+        let read: Vec<u8> = Cursor::new(buf)
+            .read_fixed_prefixed::<BigEndian<Usize64>, _>(2)
+            .unwrap();
+        assert_eq!(read, [5, 6]);
+
+        let mut buf = Vec::new();
+        buf.write_fixed_prefixed::<Usize32, u8>(&[9]).unwrap();
+        assert_eq!(buf, [1, 0, 0, 0, 9]);
+    }
+
+    #[test]
+    fn empty_sequence() {
+        let mut buf = Vec::new();
+        buf.write_fixed_prefixed::<u16, Point>(&[]).unwrap();
+        assert_eq!(buf, [0, 0]);
+        let read: Vec<Point> = Cursor::new(buf).read_fixed_prefixed::<u16, _>(0).unwrap();
+        assert!(read.is_empty());
+    }
+
+    #[test]
+    fn count_too_large_for_prefix() {
+        let items = [0u8; 256];
+        let err = Vec::new().write_fixed_prefixed::<u8, u8>(&items).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn count_above_max_is_rejected_before_reading_items() {
+        // Claims four billion elements but carries none.
+        let buf = [0xFF, 0xFF, 0xFF, 0xFF];
+        match Cursor::new(buf).read_fixed_prefixed::<BigEndian<u32>, u8>(1024) {
+            Err(ReadableError::Io(e)) => assert_eq!(e.kind(), ErrorKind::InvalidData),
+            other => panic!("expected InvalidData, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn truncated_items_are_unexpected_eof() {
+        let buf = [0, 0, 0, 2, 0, 1, 0, 1];
+        match Cursor::new(buf).read_fixed_prefixed::<BigEndian<u32>, Point>(10) {
+            Err(ReadableError::Io(e)) => assert_eq!(e.kind(), ErrorKind::UnexpectedEof),
+            other => panic!("expected UnexpectedEof, got {other:?}"),
+        }
+    }
+}
+
+// ── Random access ────────────────────────────────────────────────────────────
+
+mod seek_read {
+    use byteable::{Byteable, ReadFixed, ReadableError, SeekReadFixed, WriteFixed};
+    use std::io::{Cursor, ErrorKind};
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[byteable(little_endian)]
+    struct Entry {
+        key: u32,
+        value: i16,
+    }
+
+    fn table(count: u32) -> Cursor<Vec<u8>> {
+        let mut file = Cursor::new(Vec::new());
+        file.write_fixed_iter((0..count).map(|key| Entry {
+            key,
+            value: -(key as i16),
+        }))
+        .unwrap();
+        file
+    }
+
+    #[test]
+    fn read_by_index_in_any_order() {
+        let mut file = table(16);
+        for index in [15, 0, 7, 3] {
+            let entry: Entry = file.read_fixed_index(index).unwrap();
+            assert_eq!(entry.key, index as u32);
+            assert_eq!(entry.value, -(index as i16));
+        }
+    }
+
+    #[test]
+    fn read_at_leaves_position_after_record() {
+        let mut file = table(4);
+        let entry: Entry = file.read_fixed_at(6).unwrap();
+        assert_eq!(entry.key, 1);
+        assert_eq!(file.position(), 12);
+        assert_eq!(file.read_fixed::<Entry>().unwrap().key, 2);
+    }
+
+    #[test]
+    fn past_the_end_is_unexpected_eof() {
+        let mut file = table(2);
+        match file.read_fixed_index::<Entry>(2) {
+            Err(ReadableError::Io(e)) => assert_eq!(e.kind(), ErrorKind::UnexpectedEof),
+            other => panic!("expected UnexpectedEof, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn overflowing_index_is_invalid_input() {
+        match table(1).read_fixed_index::<Entry>(u64::MAX) {
+            Err(ReadableError::Io(e)) => assert_eq!(e.kind(), ErrorKind::InvalidInput),
+            other => panic!("expected InvalidInput, got {other:?}"),
+        }
+    }
+}
+
+// ── Limited reads ─────────────────────────────────────────────────────────────
+
+mod read_limited {
+    use byteable::{BigEndian, ReadFixed, ReadableError};
+    use std::io::{Cursor, ErrorKind};
+
+    #[test]
+    fn reads_within_limit() {
+        let mut cursor = Cursor::new([0, 0, 0, 7, 0, 0, 0, 9, 1]);
+        let values: Vec<BigEndian<u32>> = cursor.read_fixed_n_limited(2, 8).unwrap();
+        assert_eq!(values, [BigEndian::new(7), BigEndian::new(9)]);
+        assert_eq!(cursor.position(), 8);
+    }
+
+    #[test]
+    fn over_limit_is_rejected_before_reading() {
+        let mut cursor = Cursor::new([0u8; 16]);
+        match cursor.read_fixed_n_limited::<u32>(3, 11) {
+            Err(ReadableError::Io(e)) => assert_eq!(e.kind(), ErrorKind::InvalidData),
+            other => panic!("expected InvalidData, got {other:?}"),
+        }
+        assert_eq!(cursor.position(), 0);
+    }
+
+    #[test]
+    fn overflowing_count_is_rejected() {
+        match Cursor::new([]).read_fixed_n_limited::<u64>(usize::MAX, usize::MAX) {
+            Err(ReadableError::Io(e)) => assert_eq!(e.kind(), ErrorKind::InvalidData),
+            other => panic!("expected InvalidData, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn truncated_input_is_unexpected_eof() {
+        match Cursor::new([1u8, 2, 3]).read_fixed_n_limited::<u16>(2, 64) {
+            Err(ReadableError::Io(e)) => assert_eq!(e.kind(), ErrorKind::UnexpectedEof),
+            other => panic!("expected UnexpectedEof, got {other:?}"),
+        }
+    }
+}
+
+// ── Streaming iterators ───────────────────────────────────────────────────────
+
+mod write_iter {
+    use byteable::{BigEndian, Byteable, IntoByteArray, ReadFixed, WriteFixed};
+    use std::io::{Cursor, ErrorKind, Write};
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct Record {
+        #[byteable(big_endian)]
+        id: u32,
+        value: u8,
+    }
+
+    #[test]
+    fn writes_every_item_and_returns_total() {
+        let mut buf = Vec::new();
+        let n = buf
+            .write_fixed_iter((0..4).map(|id| Record { id, value: id as u8 }))
+            .unwrap();
+        assert_eq!(n, 4 * Record::BYTE_SIZE);
+        assert_eq!(buf.len(), n);
+
+        let mut cursor = Cursor::new(buf);
+        for id in 0..4 {
+            let record: Record = cursor.read_fixed().unwrap();
+            assert_eq!(record, Record { id, value: id as u8 });
+        }
+    }
+
+    #[test]
+    fn accepts_arrays_and_empty_iterators() {
+        let ids = [BigEndian::new(1u16), BigEndian::new(2)];
+        let mut buf = Vec::new();
+        assert_eq!(buf.write_fixed_iter(ids).unwrap(), 4);
+        assert_eq!(buf, [0, 1, 0, 2]);
+        assert_eq!(buf.write_fixed_iter(std::iter::empty::<u8>()).unwrap(), 0);
+        assert_eq!(buf.len(), 4);
+    }
+
+    #[test]
+    fn stops_at_first_error() {
+        // Accepts three bytes, then fails every write.
+        struct Limited(Vec<u8>);
+
+        impl Write for Limited {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                if self.0.len() + buf.len() > 3 {
+                    return Err(ErrorKind::StorageFull.into());
+                }
+                self.0.extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut pulled = 0;
+        let mut out = Limited(Vec::new());
+        let err = out
+            .write_fixed_iter((0..10u8).inspect(|_| pulled += 1))
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::StorageFull);
+        assert_eq!(out.0, [0, 1, 2]);
+        assert_eq!(pulled, 4);
+    }
+}
+
+// ── Coalesced writes ──────────────────────────────────────────────────────────
+
+mod write_all {
+    use byteable::{Byteable, IntoByteArray, ReadFixed, WriteFixed};
+    use std::io::{Cursor, Write};
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct Record {
+        #[byteable(big_endian)]
+        id: u32,
+        value: u8,
+    }
+
+    // Records the size of every write call.
+    #[derive(Default)]
+    struct Calls {
+        data: Vec<u8>,
+        writes: Vec<usize>,
+    }
+
+    impl Write for Calls {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.data.extend_from_slice(buf);
+            self.writes.push(buf.len());
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn matches_one_write_per_item() {
+        let records: Vec<Record> = (0..100).map(|id| Record { id, value: id as u8 }).collect();
+        let mut batched = Vec::new();
+        batched.write_fixed_all(&records).unwrap();
+        let mut looped = Vec::new();
+        for record in &records {
+            looped.write_fixed(record).unwrap();
+        }
+        assert_eq!(batched, looped);
+
+        let mut cursor = Cursor::new(batched);
+        let back: Vec<Record> = cursor.read_fixed_until_eof().unwrap();
+        assert_eq!(back, records);
+    }
+
+    #[test]
+    fn small_batches_take_a_single_write() {
+        let mut out = Calls::default();
+        out.write_fixed_all(&[1u8, 2, 3]).unwrap();
+        assert_eq!(out.writes, [3]);
+        assert_eq!(out.data, [1, 2, 3]);
+    }
+
+    #[test]
+    fn large_batches_are_chunked() {
+        let records = vec![Record { id: 7, value: 1 }; 5000];
+        let mut out = Calls::default();
+        out.write_fixed_all(&records).unwrap();
+        assert_eq!(out.data.len(), 5000 * Record::BYTE_SIZE);
+        assert!(out.writes.len() > 1);
+        assert!(out.writes.iter().all(|&len| len <= 8 * 1024));
+    }
+
+    #[test]
+    fn empty_slice_writes_nothing() {
+        let mut out = Calls::default();
+        out.write_fixed_all::<u32>(&[]).unwrap();
+        assert!(out.writes.is_empty());
+    }
+}
+
+// ── Offset indexing ───────────────────────────────────────────────────────────
+
+mod indexing_writer {
+    use byteable::{Byteable, IndexingWriter, IntoByteArray, ReadFixed, WriteFixed};
+    use std::io::{Cursor, Seek, SeekFrom};
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct Record {
+        #[byteable(big_endian)]
+        id: u32,
+        #[byteable(little_endian)]
+        value: u16,
+    }
+
+    fn record(id: u32) -> Record {
+        Record {
+            id,
+            value: id as u16 * 10,
+        }
+    }
+
+    #[test]
+    fn offsets_are_byte_size_multiples() {
+        let mut writer = IndexingWriter::new(Cursor::new(Vec::new()));
+        for id in 0..5 {
+            writer.write_fixed(&record(id)).unwrap();
+        }
+        let expected: Vec<u64> = (0..5).map(|i| i * Record::BYTE_SIZE as u64).collect();
+        assert_eq!(writer.offsets(), expected.as_slice());
+    }
+
+    #[test]
+    fn offsets_allow_random_access() {
+        let mut writer = IndexingWriter::new(Cursor::new(Vec::new()));
+        writer.write_value("header").unwrap();
+        for id in 0..3 {
+            writer.write_fixed(&record(id)).unwrap();
+        }
+        let (cursor, offsets) = writer.into_inner();
+        assert_eq!(offsets[1], 8 + 6);
+
+        let mut reader = Cursor::new(cursor.into_inner());
+        reader.seek(SeekFrom::Start(offsets[3])).unwrap();
+        assert_eq!(reader.read_fixed::<Record>().unwrap(), record(2));
+    }
+
+    #[test]
+    fn offsets_are_absolute_and_skip_unindexed_writes() {
+        let mut cursor = Cursor::new(Vec::new());
+        cursor.write_fixed(&0u64).unwrap();
+        let mut writer = IndexingWriter::new(cursor);
+        writer.write_fixed(&record(1)).unwrap();
+        writer.get_mut().write_fixed(&0u8).unwrap();
+        writer.write_fixed(&record(2)).unwrap();
+        assert_eq!(writer.offsets(), &[8, 15]);
+        assert_eq!(writer.get_ref().get_ref().len(), 21);
+    }
+}
+
+// ── Position counting ─────────────────────────────────────────────────────────
+
+mod counting {
+    use byteable::{
+        BigEndian, CountingReader, CountingWriter, ReadFixed, ReadValue, WriteFixed, WriteValue,
+    };
+    use std::io::{Read, Write};
+
+    #[test]
+    fn writer_position_tracks_fixed_and_value_writes() {
+        let mut writer = CountingWriter::new(Vec::new());
+        assert_eq!(writer.position(), 0);
+        writer.write_fixed(&BigEndian::new(1u32)).unwrap();
+        assert_eq!(writer.position(), 4);
+        writer.write_value(&vec![1u16, 2, 3]).unwrap();
+        assert_eq!(writer.position(), 4 + 8 + 6);
+        writer.write_all(&[0; 5]).unwrap();
+        assert_eq!(writer.position(), 23);
+        assert_eq!(writer.into_inner().len(), 23);
+    }
+
+    #[test]
+    fn writer_records_offsets_for_a_later_table() {
+        let mut writer = CountingWriter::new(Vec::new());
+        let mut offsets = Vec::new();
+        for name in ["a", "bcd", ""] {
+            offsets.push(writer.position());
+            writer.write_value(name).unwrap();
+        }
+        assert_eq!(offsets, [0, 9, 20]);
+    }
+
+    #[test]
+    fn reader_position_tracks_reads() {
+        let mut bytes = Vec::new();
+        bytes.write_fixed(&BigEndian::new(7u16)).unwrap();
+        bytes.write_value("hello").unwrap();
+        bytes.push(0xff);
+
+        let mut reader = CountingReader::new(bytes.as_slice());
+        assert_eq!(reader.read_fixed::<BigEndian<u16>>().unwrap().get(), 7);
+        assert_eq!(reader.position(), 2);
+        assert_eq!(reader.read_value::<String>().unwrap(), "hello");
+        assert_eq!(reader.position(), 15);
+        let mut rest = Vec::new();
+        reader.read_to_end(&mut rest).unwrap();
+        assert_eq!(rest, [0xff]);
+        assert_eq!(reader.position(), 16);
+    }
+
+    #[test]
+    fn bytes_through_get_mut_are_not_counted() {
+        let mut writer = CountingWriter::new(Vec::new());
+        writer.get_mut().write_fixed(&0u32).unwrap();
+        writer.write_fixed(&0u8).unwrap();
+        assert_eq!(writer.position(), 1);
+        assert_eq!(writer.get_ref().len(), 5);
+
+        let data = [1u8, 2, 3];
+        let mut reader = CountingReader::new(&data[..]);
+        reader.get_mut().read_fixed::<u8>().unwrap();
+        assert_eq!(reader.read_fixed::<u8>().unwrap(), 2);
+        assert_eq!(reader.position(), 1);
+    }
+}
+
+// ── Buffered reading ──────────────────────────────────────────────────────────
+
+mod byteable_reader {
+    use byteable::{BigEndian, Byteable, ByteableReader, ReadFixed, ReadValue};
+    use std::io::{self, Cursor, Read, Seek, SeekFrom};
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct Record {
+        #[byteable(big_endian)]
+        id: u32,
+        flag: u8,
+    }
+
+    /// A reader that counts how often the underlying source is read from.
+    struct CountingSource {
+        data: Cursor<Vec<u8>>,
+        reads: usize,
+    }
+
+    impl Read for CountingSource {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.reads += 1;
+            self.data.read(buf)
+        }
+    }
+
+    impl Seek for CountingSource {
+        fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+            self.data.seek(pos)
+        }
+    }
+
+    fn source(count: u32) -> CountingSource {
+        let mut data = Vec::new();
+        for id in 0..count {
+            data.extend_from_slice(&id.to_be_bytes());
+            data.push(id as u8 % 2);
+        }
+        CountingSource {
+            data: Cursor::new(data),
+            reads: 0,
+        }
+    }
+
+    #[test]
+    fn small_reads_are_batched() {
+        let mut reader = ByteableReader::with_capacity(64, source(100));
+        for id in 0..100 {
+            let record: Record = reader.read_fixed().unwrap();
+            assert_eq!(
+                record,
+                Record {
+                    id,
+                    flag: id as u8 % 2
+                }
+            );
+        }
+        // 500 bytes through a 64-byte buffer.
+        assert_eq!(reader.get_ref().reads, 8);
+    }
+
+    #[test]
+    fn unbuffered_reads_hit_the_source_every_time() {
+        let mut unbuffered = source(100);
+        for _ in 0..100 {
+            unbuffered.read_fixed::<Record>().unwrap();
+        }
+        assert_eq!(unbuffered.reads, 100);
+    }
+
+    #[test]
+    fn mixed_fixed_and_value_reads() {
+        let mut data = Vec::new();
+        data.extend_from_slice(&7u32.to_be_bytes());
+        data.extend_from_slice(&2u64.to_le_bytes());
+        data.extend_from_slice(b"hi");
+        data.push(9);
+        let mut reader = ByteableReader::with_capacity(4, &data[..]);
+        assert_eq!(reader.read_fixed::<BigEndian<u32>>().unwrap().get(), 7);
+        assert_eq!(reader.read_value::<String>().unwrap(), "hi");
+        assert_eq!(reader.read_fixed::<u8>().unwrap(), 9);
+        assert!(reader.read_fixed::<u8>().is_err());
+    }
+
+    #[test]
+    fn zero_capacity_still_reads() {
+        let mut reader = ByteableReader::with_capacity(0, &[1u8, 2, 3][..]);
+        assert_eq!(reader.capacity(), 1);
+        let mut line = Vec::new();
+        std::io::BufRead::read_until(&mut reader, 2, &mut line).unwrap();
+        assert_eq!(line, [1, 2]);
+        assert_eq!(reader.read_fixed::<u8>().unwrap(), 3);
+    }
+
+    #[test]
+    fn seek_accounts_for_buffered_bytes() {
+        let mut reader = ByteableReader::new(source(10));
+        reader.read_fixed::<Record>().unwrap();
+        assert_eq!(reader.buffer().len(), 45);
+        assert_eq!(reader.stream_position().unwrap(), 5);
+
+        reader.seek(SeekFrom::Start(5 * 7)).unwrap();
+        assert!(reader.buffer().is_empty());
+        assert_eq!(reader.read_fixed::<Record>().unwrap().id, 7);
+    }
+
+    #[test]
+    fn into_inner_returns_the_source() {
+        let reader = ByteableReader::new(&[1u8, 2, 3][..]);
+        assert_eq!(reader.capacity(), 8 * 1024);
+        assert_eq!(reader.into_inner(), &[1, 2, 3]);
+    }
+}
+
+// ── Slice reading ─────────────────────────────────────────────────────────────
+
+mod slice_reader {
+    use byteable::{
+        BigEndian, Byteable, PeekFixed, ReadFixed, ReadValue, ReadableError, SliceReader,
+    };
+    use std::io::{BufRead, ErrorKind};
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[byteable(big_endian)]
+    struct Header {
+        kind: u8,
+        len: u16,
+    }
+
+    /// The tail borrows the buffer, not the reader, so it can outlive it.
+    fn split_header(frame: &[u8]) -> (Header, &[u8]) {
+        let mut reader = SliceReader::new(frame);
+        let header = reader.read_value().unwrap();
+        (header, reader.remaining())
+    }
+
+    #[test]
+    fn header_then_hand_off_the_rest() {
+        let frame = [7, 0, 3, b'a', b'b', b'c'];
+        let (header, body) = split_header(&frame);
+        assert_eq!(header, Header { kind: 7, len: 3 });
+        assert_eq!(body, b"abc");
+    }
+
+    #[test]
+    fn reads_dynamic_values() {
+        let mut data = 2u64.to_le_bytes().to_vec();
+        data.extend_from_slice(b"hi!");
+        let mut reader = SliceReader::from(&data[..]);
+        assert_eq!(reader.read_value::<String>().unwrap(), "hi");
+        assert_eq!(reader.remaining(), b"!");
+    }
+
+    #[test]
+    fn is_empty_after_the_last_value() {
+        let mut reader = SliceReader::new(&[1, 2]);
+        assert!(!reader.is_empty());
+        assert_eq!(reader.read_fixed::<[u8; 2]>().unwrap(), [1, 2]);
+        assert!(reader.is_empty());
+        assert!(SliceReader::default().is_empty());
+    }
+
+    #[test]
+    fn short_buffer_is_an_eof_error() {
+        let mut reader = SliceReader::new(&[0, 1]);
+        match reader.read_value::<BigEndian<u32>>() {
+            Err(ReadableError::Io(e)) => assert_eq!(e.kind(), ErrorKind::UnexpectedEof),
+            other => panic!("expected EOF, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn peek_and_consume() {
+        let mut reader = SliceReader::new(&[0, 9, 1, 2]);
+        assert_eq!(reader.peek_fixed::<BigEndian<u16>>().unwrap().get(), 9);
+        assert_eq!(reader.len(), 4);
+        reader.consume(3);
+        assert_eq!(reader.fill_buf().unwrap(), [2]);
+        reader.consume(10);
+        assert!(reader.is_empty());
+    }
+}
+
+// ── Peeking ───────────────────────────────────────────────────────────────────
+
+mod peek {
+    use byteable::{BigEndian, Byteable, ByteableReader, PeekFixed, ReadFixed, ReadableError};
+    use std::io::{self, Cursor, ErrorKind, Read};
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[byteable(big_endian)]
+    struct Ping {
+        tag: u8,
+        seq: u32,
+    }
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[byteable(big_endian)]
+    struct Data {
+        tag: u8,
+        len: u16,
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Message {
+        Ping(Ping),
+        Data(Data),
+    }
+
+    fn dispatch(reader: &mut (impl Read + PeekFixed)) -> Result<Message, ReadableError> {
+        Ok(match reader.peek_fixed::<u8>()? {
+            1 => Message::Ping(reader.read_fixed()?),
+            _ => Message::Data(reader.read_fixed()?),
+        })
+    }
+
+    fn messages() -> Vec<u8> {
+        vec![1, 0, 0, 0, 9, 2, 0, 3, 1, 0, 0, 1, 0]
+    }
+
+    fn expected() -> [Message; 3] {
+        [
+            Message::Ping(Ping { tag: 1, seq: 9 }),
+            Message::Data(Data { tag: 2, len: 3 }),
+            Message::Ping(Ping { tag: 1, seq: 256 }),
+        ]
+    }
+
+    /// Hands out at most two bytes per read.
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = buf.len().min(2).min(self.0.len());
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn slice_and_cursor() {
+        let data = messages();
+        let mut slice = &data[..];
+        let mut cursor = Cursor::new(data.clone());
+        for message in expected() {
+            assert_eq!(dispatch(&mut slice).unwrap(), message);
+            assert_eq!(dispatch(&mut cursor).unwrap(), message);
+        }
+        assert!(slice.is_empty());
+    }
+
+    #[test]
+    fn byteable_reader_tops_up_its_buffer() {
+        let data = messages();
+        // A 3-byte buffer is smaller than a `Ping`, and the source trickles bytes in.
+        let mut reader = ByteableReader::with_capacity(3, Trickle(&data));
+        for message in expected() {
+            assert_eq!(dispatch(&mut reader).unwrap(), message);
+        }
+    }
+
+    #[test]
+    fn peek_does_not_consume() {
+        let mut reader = ByteableReader::with_capacity(2, &[0u8, 0, 0, 7][..]);
+        let first: BigEndian<u32> = reader.peek_fixed().unwrap();
+        let second: BigEndian<u32> = reader.peek_fixed().unwrap();
+        assert_eq!(first, second);
+        // The buffer grew to hold the peeked value.
+        assert_eq!(reader.capacity(), 4);
+        assert_eq!(reader.read_fixed::<BigEndian<u32>>().unwrap().get(), 7);
+    }
+
+    #[test]
+    fn short_input_is_unexpected_eof() {
+        let mut cursor = Cursor::new([1u8, 2, 3]);
+        cursor.set_position(2);
+        assert_eq!(
+            cursor.peek_bytes(2).unwrap_err().kind(),
+            ErrorKind::UnexpectedEof
+        );
+
+        let mut reader = ByteableReader::new(&[1u8, 2][..]);
+        match reader.peek_fixed::<u32>() {
+            Err(ReadableError::Io(e)) => assert_eq!(e.kind(), ErrorKind::UnexpectedEof),
+            other => panic!("expected UnexpectedEof, got {other:?}"),
+        }
+        // The bytes that were available are still there.
+        assert_eq!(reader.read_fixed::<[u8; 2]>().unwrap(), [1, 2]);
+    }
+
+    #[test]
+    fn invalid_value_is_decode_error() {
+        let mut slice = &[2u8][..];
+        assert!(matches!(
+            slice.peek_fixed::<bool>(),
+            Err(ReadableError::DecodeError(_))
+        ));
+        assert_eq!(slice, [2]);
+    }
+}
+
 // ── Collection types ──────────────────────────────────────────────────────────
 
 mod collections {
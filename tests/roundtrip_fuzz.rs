@@ -0,0 +1,255 @@
+//! Randomized round-trip tests for deeply nested `transparent` / `try_transparent` structs.
+//!
+//! Values are generated with [`arbitrary`] from a deterministic pseudo-random byte stream, so
+//! every run covers the same cases and a failure can be reproduced from the printed seed.
+//! Two properties are checked for every type:
+//!
+//! - **value → bytes → value**: encoding any value and decoding it yields the same value.
+//! - **bytes → value → bytes**: a valid encoding with one byte overwritten at random either
+//!   fails to decode or re-encodes to exactly the same bytes, so decoding never silently
+//!   normalizes or drops data.
+#![cfg(all(feature = "std", feature = "derive"))]
+
+use arbitrary::{Arbitrary, Unstructured};
+use byteable::{
+    ByteArray, Byteable, FixedReadable, FixedWritable, IntoByteArray, ReadFixed, TryFromByteArray,
+    WriteFixed,
+};
+use core::fmt::Debug;
+use std::io::Cursor;
+
+// ── Generator ─────────────────────────────────────────────────────────────────
+
+/// Number of random values checked per type and property.
+const ITERATIONS: u64 = 2_000;
+
+/// SplitMix64: small, fast, and good enough to feed `Unstructured`.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn fill(&mut self, buf: &mut [u8]) {
+        for chunk in buf.chunks_mut(8) {
+            let word = self.next().to_le_bytes();
+            chunk.copy_from_slice(&word[..chunk.len()]);
+        }
+    }
+}
+
+/// Random bytes for `seed`, long enough to build any of the types below.
+fn random_bytes(seed: u64) -> Vec<u8> {
+    let mut buf = vec![0u8; 512];
+    SplitMix64(seed).fill(&mut buf);
+    buf
+}
+
+/// Overwrites one byte of `bytes` with a random value.
+///
+/// Starting from a valid encoding keeps the other fields valid, so the result decodes often
+/// enough to exercise both the accepting and the rejecting paths of fallible types.
+fn mutate_one_byte<B: ByteArray>(mut bytes: B, seed: u64) -> B {
+    let mut rng = SplitMix64(!seed);
+    let slice = bytes.as_byte_slice_mut();
+    let index = (rng.next() % slice.len() as u64) as usize;
+    slice[index] = rng.next() as u8;
+    bytes
+}
+
+/// Checks both round-trip properties for `ITERATIONS` random inputs.
+fn check_roundtrip<T>()
+where
+    T: for<'a> Arbitrary<'a>
+        + IntoByteArray
+        + TryFromByteArray
+        + FixedReadable
+        + FixedWritable
+        + PartialEq
+        + Debug,
+{
+    for seed in 0..ITERATIONS {
+        let data = random_bytes(seed);
+
+        // value → bytes → value, through both the byte-array and the I/O paths.
+        let value = T::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        let bytes = value.into_byte_array();
+        let decoded = T::try_from_byte_array(bytes);
+        assert_eq!(
+            decoded.as_ref(),
+            Ok(&value),
+            "seed {seed}: byte-array round trip"
+        );
+
+        let mut buf = Vec::new();
+        buf.write_fixed(&value).unwrap();
+        assert_eq!(buf, bytes.as_byte_slice(), "seed {seed}: write_fixed bytes");
+        let read: T = Cursor::new(&buf).read_fixed().unwrap();
+        assert_eq!(read, value, "seed {seed}: I/O round trip");
+
+        // bytes → value → bytes.
+        let raw = mutate_one_byte(bytes, seed);
+        if let Ok(value) = T::try_from_byte_array(raw) {
+            assert_eq!(
+                value.into_byte_array().as_byte_slice(),
+                raw.as_byte_slice(),
+                "seed {seed}: decoded value re-encodes differently"
+            );
+        }
+    }
+}
+
+// ── Nested types ──────────────────────────────────────────────────────────────
+
+#[derive(Byteable, Arbitrary, Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+enum Mode {
+    Off = 0,
+    Standby = 1,
+    Active = 7,
+}
+
+#[derive(Byteable, Arbitrary, Debug, Clone, Copy, PartialEq)]
+#[repr(u16)]
+#[byteable(big_endian)]
+enum Opcode {
+    Read = 0x0001,
+    Write = 0x0102,
+    Erase = 0xFF00,
+}
+
+/// Innermost infallible leaf: mixed field endianness.
+#[derive(Byteable, Arbitrary, Debug, Clone, Copy, PartialEq)]
+struct Leaf {
+    a: u8,
+    #[byteable(little_endian)]
+    b: u16,
+    #[byteable(big_endian)]
+    c: u32,
+    #[byteable(little_endian)]
+    d: i64,
+}
+
+/// Fallible leaf: `bool`, `char`, and enums all reject some byte patterns.
+#[derive(Byteable, Arbitrary, Debug, Clone, Copy, PartialEq)]
+struct FallibleLeaf {
+    #[byteable(try_transparent)]
+    flag: bool,
+    #[byteable(try_transparent)]
+    letter: char,
+    #[byteable(try_transparent)]
+    mode: Mode,
+    #[byteable(try_transparent)]
+    opcode: Opcode,
+}
+
+/// Container endianness inherited by a transparent child.
+#[derive(Byteable, Arbitrary, Debug, Clone, Copy, PartialEq)]
+#[byteable(big_endian)]
+struct BigEndianNode {
+    id: u32,
+    #[byteable(transparent)]
+    leaf: Leaf,
+    #[byteable(little_endian)]
+    weight: u16,
+}
+
+/// Two levels deep, mixing infallible and fallible children.
+#[derive(Byteable, Arbitrary, Debug, Clone, Copy, PartialEq)]
+struct Branch {
+    #[byteable(transparent)]
+    node: BigEndianNode,
+    #[byteable(try_transparent)]
+    fallible: FallibleLeaf,
+    tag: [u8; 3],
+}
+
+/// Arrays of nested structs and enums.
+#[derive(Byteable, Arbitrary, Debug, Clone, Copy, PartialEq)]
+struct Forest {
+    #[byteable(transparent)]
+    leaves: [Leaf; 3],
+    #[byteable(try_transparent)]
+    fallible: [FallibleLeaf; 2],
+    #[byteable(try_transparent)]
+    modes: [Mode; 4],
+}
+
+/// Tuple struct wrapping the deeper levels.
+#[derive(Byteable, Arbitrary, Debug, Clone, Copy, PartialEq)]
+struct Pair(
+    #[byteable(try_transparent)] Branch,
+    #[byteable(little_endian)] u64,
+);
+
+/// Four levels of nesting: `Root` → `Pair` → `Branch` → `BigEndianNode` → `Leaf`.
+#[derive(Byteable, Arbitrary, Debug, Clone, Copy, PartialEq)]
+#[byteable(little_endian)]
+struct Root {
+    version: u16,
+    #[byteable(try_transparent)]
+    pair: Pair,
+    #[byteable(try_transparent)]
+    forest: Forest,
+    #[byteable(big_endian)]
+    trailer: u32,
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn leaf_roundtrip() {
+    check_roundtrip::<Leaf>();
+}
+
+#[test]
+fn fallible_leaf_roundtrip() {
+    check_roundtrip::<FallibleLeaf>();
+}
+
+#[test]
+fn big_endian_node_roundtrip() {
+    check_roundtrip::<BigEndianNode>();
+}
+
+#[test]
+fn branch_roundtrip() {
+    check_roundtrip::<Branch>();
+}
+
+#[test]
+fn forest_roundtrip() {
+    check_roundtrip::<Forest>();
+}
+
+#[test]
+fn pair_roundtrip() {
+    check_roundtrip::<Pair>();
+}
+
+#[test]
+fn root_roundtrip() {
+    check_roundtrip::<Root>();
+}
+
+#[test]
+fn mutated_bytes_hit_both_outcomes() {
+    // Sanity check for the bytes → value property: mutated input must exercise both the
+    // accepting and the rejecting decode paths, or the property above is vacuous.
+    let (mut ok, mut err) = (0, 0);
+    for seed in 0..ITERATIONS {
+        let data = random_bytes(seed);
+        let value = FallibleLeaf::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        let raw = mutate_one_byte(value.into_byte_array(), seed);
+        match FallibleLeaf::try_from_byte_array(raw) {
+            Ok(_) => ok += 1,
+            Err(_) => err += 1,
+        }
+    }
+    assert!(ok > 0 && err > 0, "ok = {ok}, err = {err}");
+}
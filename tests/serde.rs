@@ -0,0 +1,49 @@
+#![cfg(feature = "serde")]
+//! Tests for the `serde` impls on `BigEndian<T>` / `LittleEndian<T>`.
+
+use byteable::{BigEndian, IntoByteArray, LittleEndian};
+use serde::{Deserialize, Serialize};
+
+#[test]
+fn endian_wrappers_serialize_as_native_value() {
+    assert_eq!(
+        serde_json::to_string(&BigEndian::new(1000u16)).unwrap(),
+        "1000"
+    );
+    assert_eq!(
+        serde_json::to_string(&LittleEndian::new(-5i32)).unwrap(),
+        "-5"
+    );
+    assert_eq!(
+        serde_json::to_string(&BigEndian::new(1.5f64)).unwrap(),
+        "1.5"
+    );
+}
+
+#[test]
+fn endian_wrappers_deserialize_into_stored_byte_order() {
+    let be: BigEndian<u32> = serde_json::from_str("305419896").unwrap();
+    assert_eq!(be.get(), 0x1234_5678);
+    assert_eq!(be.into_byte_array(), [0x12, 0x34, 0x56, 0x78]);
+
+    let le: LittleEndian<u32> = serde_json::from_str("305419896").unwrap();
+    assert_eq!(le.get(), 0x1234_5678);
+    assert_eq!(le.into_byte_array(), [0x78, 0x56, 0x34, 0x12]);
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Header {
+    magic: BigEndian<u32>,
+    length: LittleEndian<u16>,
+}
+
+#[test]
+fn endian_wrappers_roundtrip_inside_derived_struct() {
+    let header = Header {
+        magic: BigEndian::new(0xCAFE_BABE),
+        length: LittleEndian::new(512),
+    };
+    let json = serde_json::to_string(&header).unwrap();
+    assert_eq!(json, r#"{"magic":3405691582,"length":512}"#);
+    assert_eq!(serde_json::from_str::<Header>(&json).unwrap(), header);
+}
@@ -0,0 +1,96 @@
+#![cfg(all(feature = "test-util", feature = "derive"))]
+//! Tests for the round-trip assertions in `byteable::testing`.
+
+use byteable::testing::{assert_roundtrip, assert_try_roundtrip};
+use byteable::{Byteable, DecodeError, FromByteArray, IntoByteArray, TryFromByteArray};
+
+#[derive(Byteable, Debug, PartialEq, Clone, Copy)]
+struct Header {
+    #[byteable(big_endian)]
+    id: u16,
+    #[byteable(little_endian)]
+    length: u32,
+    #[byteable(try_transparent)]
+    enabled: bool,
+}
+
+#[derive(Byteable, Debug, PartialEq, Clone, Copy)]
+#[byteable(little_endian)]
+struct Sample(u32, f64);
+
+/// Drops the low bit when decoding, so odd values do not survive a round trip.
+#[derive(Debug, PartialEq)]
+struct Even(u8);
+
+impl IntoByteArray for Even {
+    type ByteArray = [u8; 1];
+
+    fn into_byte_array(&self) -> [u8; 1] {
+        [self.0]
+    }
+}
+
+impl FromByteArray for Even {
+    fn from_byte_array(bytes: [u8; 1]) -> Self {
+        Even(bytes[0] & !1)
+    }
+}
+
+/// Encodes to bytes that decoding always rejects.
+#[derive(Debug, PartialEq)]
+struct WriteOnly;
+
+impl IntoByteArray for WriteOnly {
+    type ByteArray = [u8; 1];
+
+    fn into_byte_array(&self) -> [u8; 1] {
+        [0xEE]
+    }
+}
+
+impl TryFromByteArray for WriteOnly {
+    fn try_from_byte_array(bytes: [u8; 1]) -> Result<Self, DecodeError> {
+        Err(DecodeError::InvalidTag {
+            raw: bytes[0],
+            type_name: "WriteOnly",
+        })
+    }
+}
+
+#[test]
+fn lossless_values_pass() {
+    assert_roundtrip(Sample(7, -2.5));
+    assert_roundtrip(Even(4));
+    assert_try_roundtrip(Header {
+        id: 0x0102,
+        length: 9,
+        enabled: true,
+    });
+    assert_try_roundtrip(Sample(u32::MAX, f64::MIN_POSITIVE));
+}
+
+#[test]
+#[should_panic(expected = "original: Even(5)\n  decoded: Even(4)")]
+fn changed_value_panics_with_both_values() {
+    assert_roundtrip(Even(5));
+}
+
+#[test]
+#[should_panic(expected = "first differing byte at offset 0x0")]
+fn changed_bytes_are_located() {
+    assert_roundtrip(Even(3));
+}
+
+#[test]
+#[should_panic(expected = "the bytes are identical")]
+fn unequal_values_with_equal_bytes_panic() {
+    assert_roundtrip(Sample(0, f64::NAN));
+}
+
+#[test]
+#[should_panic(
+    expected = "did not decode: invalid tag 238 for WriteOnly\nencoded bytes:\n00000000  ee"
+)]
+fn decode_errors_panic_with_the_bytes() {
+    assert_try_roundtrip(WriteOnly);
+}
@@ -387,3 +387,41 @@ mod command_packet_tests {
         }
     }
 }
+
+// ── bool and char fields ──────────────────────────────────────────────────────
+
+mod bool_and_char_fields {
+    use byteable::{Byteable, DecodeError, IntoByteArray, TryFromByteArray};
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[byteable(big_endian)]
+    struct Flags {
+        id: u16,
+        // Not affected by the container endianness: `try_transparent` keeps the
+        // type's own layout.
+        #[byteable(try_transparent)]
+        enabled: bool,
+        #[byteable(try_transparent)]
+        initial: char,
+    }
+
+    #[test]
+    fn try_transparent_bool_and_char_under_container_endian() {
+        let flags = Flags {
+            id: 0x0102,
+            enabled: true,
+            initial: 'A',
+        };
+        let bytes = flags.into_byte_array();
+        assert_eq!(bytes, [0x01, 0x02, 1, 0x41, 0, 0, 0]);
+        assert_eq!(Flags::try_from_byte_array(bytes).unwrap(), flags);
+    }
+
+    #[test]
+    fn invalid_bool_is_rejected() {
+        assert_eq!(
+            Flags::try_from_byte_array([0, 0, 2, 0x41, 0, 0, 0]),
+            Err(DecodeError::InvalidBool(2))
+        );
+    }
+}
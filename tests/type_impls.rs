@@ -1,9 +1,14 @@
 //! Tests for `ByteRepr`/`IntoByteArray`/`TryFromByteArray` implementations on
 //! standard-library and primitive types: numeric primitives, arrays, endian
-//! wrappers, `PhantomData`, `u128`/`i128`, `NonZero*`, network types,
-//! `Duration`, `SystemTime`, range types, `bool`, and `char`.
-
-use byteable::{BigEndian, FromByteArray, IntoByteArray, LittleEndian, TryFromByteArray};
+//! wrappers, `BigEndianTry`/`LittleEndianTry`, `PhantomData`, `()`, `u128`/`i128`, `U24`/`I24`, `NonZero*`,
+//! `Wrapping`/`Saturating`, network types, `Duration`, `SystemTime`, range types, `bool`,
+//! `char`, `BitFlags*`, `FixedDecimal`, `Fixed`, `FixedStr`, `FixedAscii`, `Tagged2`, `FixedOption`, and tuples.
+
+use byteable::{
+    BigEndian, ByteArray, ByteableRef, DecodeError, DecodeErrorKind, FromByteArray, FromHexError,
+    HasEndianRepr, IntoByteArray, LittleEndian, RawRepr, TryFromByteArray, TryFromEndianRepr,
+    from_be_array, from_le_array, to_be_array, to_le_array,
+};
 use core::marker::PhantomData;
 use core::net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
 use core::num::{NonZeroI32, NonZeroU8, NonZeroU32, NonZeroU64};
@@ -38,7 +43,406 @@ fn endian_wrapper_byte_sizes() {
     assert_eq!(LittleEndian::<u64>::BYTE_SIZE, 8);
 }
 
-// ── PhantomData ───────────────────────────────────────────────────────────────
+#[test]
+fn endian_wrappers_in_const_context() {
+    const PORTS: [BigEndian<u16>; 2] = [
+        BigEndian::<u16>::new_const(80),
+        BigEndian::<u16>::new_const(443),
+    ];
+    static MAGIC: LittleEndian<u32> = LittleEndian::<u32>::new_const(0xCAFE_BABE);
+    const PI: BigEndian<f64> = BigEndian::<f64>::new_const(core::f64::consts::PI);
+    const FIRST: u16 = PORTS[0].get_const();
+
+    assert_eq!(FIRST, 80);
+    assert_eq!(PORTS[1].into_byte_array(), [0x01, 0xBB]);
+    assert_eq!(PORTS[1], BigEndian::new(443));
+    assert_eq!(MAGIC.into_byte_array(), [0xBE, 0xBA, 0xFE, 0xCA]);
+    assert_eq!(MAGIC.get_const(), MAGIC.get());
+    assert_eq!(PI.into_byte_array(), core::f64::consts::PI.to_be_bytes());
+    assert_eq!(PI.get_const(), core::f64::consts::PI);
+    assert_eq!(LittleEndian::<i64>::new_const(-2).get(), -2);
+}
+
+#[test]
+fn endian_wrappers_from_and_into_raw_bytes() {
+    let wire = BigEndian::<u32>::from_raw_bytes([0xCA, 0xFE, 0xBA, 0xBE]);
+    assert_eq!(wire.get(), 0xCAFE_BABE);
+    assert_eq!(wire.into_raw_bytes(), [0xCA, 0xFE, 0xBA, 0xBE]);
+    assert_eq!(wire.into_raw_bytes(), wire.into_byte_array());
+
+    let le = LittleEndian::<u16>::from_raw_bytes([0x34, 0x12]);
+    assert_eq!(le, LittleEndian::new(0x1234));
+    assert_eq!(le.into_raw_bytes(), [0x34, 0x12]);
+
+    let bytes = (-0.75f64).to_be_bytes();
+    assert_eq!(BigEndian::<f64>::from_raw_bytes(bytes).get(), -0.75);
+    assert_eq!(
+        LittleEndian::<i128>::from_raw_bytes((-3i128).to_le_bytes()).get(),
+        -3
+    );
+}
+
+#[test]
+fn swapping_byte_order_keeps_the_value() {
+    let be = BigEndian::new(0x0102_0304u32);
+    let le = be.to_little();
+    assert_eq!(le.get(), 0x0102_0304);
+    assert_eq!(le.into_byte_array(), [4, 3, 2, 1]);
+    assert_eq!(le.to_big(), be);
+    assert_eq!(LittleEndian::from(be), le);
+    assert_eq!(BigEndian::from(le), be);
+
+    let value = -1.5f64;
+    let swapped = LittleEndian::new(value).to_big();
+    assert_eq!(swapped.get(), value);
+    assert_eq!(swapped.into_byte_array(), value.to_be_bytes());
+
+    let wide = BigEndian::new(-7i128);
+    assert_eq!(wide.to_little().to_big(), wide);
+}
+
+#[test]
+fn endian_wrappers_map_keeps_byte_order() {
+    let port = BigEndian::new(8080u16).map(|p| p + 1);
+    assert_eq!(port.into_byte_array(), 8081u16.to_be_bytes());
+
+    let widened: LittleEndian<u64> = LittleEndian::new(7u32).map(u64::from);
+    assert_eq!(widened.into_byte_array(), 7u64.to_le_bytes());
+}
+
+#[test]
+fn endian_wrappers_borrow_their_bytes() {
+    fn bytes_of(value: &impl AsRef<[u8]>) -> Vec<u8> {
+        value.as_ref().to_vec()
+    }
+
+    assert_eq!(bytes_of(&BigEndian::new(0x0102_0304u32)), [1, 2, 3, 4]);
+    assert_eq!(bytes_of(&LittleEndian::new(0x0102_0304u32)), [4, 3, 2, 1]);
+    assert_eq!(BigEndian::new(1.0f32).as_ref(), &1.0f32.to_be_bytes());
+    assert_eq!(
+        LittleEndian::new(-2i128).as_ref(),
+        &LittleEndian::new(-2i128).into_byte_array()
+    );
+
+    let mut out = Vec::new();
+    std::io::Write::write_all(&mut out, BigEndian::new(0xCAFEu16).as_ref()).unwrap();
+    assert_eq!(out, [0xCA, 0xFE]);
+}
+
+#[test]
+fn primitive_arrays_in_chosen_byte_order() {
+    let values = [0x0102u16, 0x0304, 0xA0B0];
+
+    let be = to_be_array(values);
+    assert_eq!(be.as_byte_slice(), &[0x01, 0x02, 0x03, 0x04, 0xA0, 0xB0]);
+    assert_eq!(from_be_array::<u16, 3>(be), values);
+
+    let le = to_le_array(values);
+    assert_eq!(le.as_byte_slice(), &[0x02, 0x01, 0x04, 0x03, 0xB0, 0xA0]);
+    assert_eq!(from_le_array::<u16, 3>(le), values);
+}
+
+// ── Hex dumps ────────────────────────────────────────────────────────────────
+
+#[test]
+fn hexdump_rows_of_sixteen_bytes() {
+    let value: [u8; 20] = *b"Hello, hexdump!\0\x01\x7f ~";
+    assert_eq!(
+        value.hexdump(),
+        "00000000  48 65 6c 6c 6f 2c 20 68  65 78 64 75 6d 70 21 00  |Hello, hexdump!.|\n\
+         00000010  01 7f 20 7e                                       |.. ~|\n"
+    );
+}
+
+#[test]
+fn hexdump_of_an_empty_value_is_empty() {
+    assert_eq!(().hexdump(), "");
+    assert_eq!(PhantomData::<u32>.hexdump(), "");
+}
+
+// ── Hex strings ──────────────────────────────────────────────────────────────
+
+#[test]
+fn hex_roundtrip() {
+    let value = (BigEndian::new(0xDEAD_BEEFu32), 7u8, [true, false]);
+    let hex = value.to_hex();
+    assert_eq!(hex, "deadbeef070100");
+    assert_eq!(<(BigEndian<u32>, u8, [bool; 2])>::from_hex(&hex), Ok(value));
+    assert_eq!(
+        <(BigEndian<u32>, u8, [bool; 2])>::from_hex("DEADBEEF070100"),
+        Ok(value)
+    );
+    assert_eq!(().to_hex(), "");
+    assert_eq!(<()>::from_hex(""), Ok(()));
+}
+
+#[test]
+fn from_hex_rejects_wrong_length() {
+    assert_eq!(
+        u16::from_hex("123"),
+        Err(FromHexError::InvalidLength {
+            expected: 4,
+            found: 3
+        })
+    );
+    assert_eq!(
+        u16::from_hex("123456"),
+        Err(FromHexError::InvalidLength {
+            expected: 4,
+            found: 6
+        })
+    );
+}
+
+#[test]
+fn from_hex_rejects_non_hex_characters() {
+    assert_eq!(
+        u16::from_hex("12g4"),
+        Err(FromHexError::InvalidChar {
+            index: 2,
+            found: 'g'
+        })
+    );
+    // A multi-byte character is reported whole, at its first byte.
+    assert_eq!(
+        u16::from_hex("1é4"),
+        Err(FromHexError::InvalidChar {
+            index: 1,
+            found: 'é'
+        })
+    );
+    assert_eq!(
+        u16::from_hex("0x12").unwrap_err().to_string(),
+        "invalid hex digit 'x' at index 1"
+    );
+}
+
+#[test]
+fn from_hex_reports_decode_errors() {
+    assert_eq!(
+        char::from_hex("00d80000"),
+        Err(FromHexError::Decode(DecodeError::InvalidChar(0xD800)))
+    );
+}
+
+// ── Slice reads and writes ───────────────────────────────────────────────────
+
+#[test]
+fn slice_roundtrip_of_consecutive_values() {
+    let mut buf = [0xAAu8; 16];
+    let mut len = 0;
+    len += BigEndian::new(0xCAFEu16)
+        .write_to_slice(&mut buf[len..])
+        .unwrap();
+    len += (true, 'x').write_to_slice(&mut buf[len..]).unwrap();
+    assert_eq!(len, 7);
+    assert_eq!(buf[..7], [0xCA, 0xFE, 1, b'x', 0, 0, 0]);
+    // Bytes past the last value are untouched.
+    assert!(buf[7..].iter().all(|&b| b == 0xAA));
+
+    let (tag, rest) = BigEndian::<u16>::read_from_slice(&buf).unwrap();
+    let (pair, rest) = <(bool, char)>::read_from_slice(rest).unwrap();
+    assert_eq!(tag.get(), 0xCAFE);
+    assert_eq!(pair, (true, 'x'));
+    assert_eq!(rest.len(), 9);
+}
+
+#[test]
+fn slice_too_short() {
+    let mut buf = [0u8; 3];
+    assert_eq!(
+        0u32.write_to_slice(&mut buf),
+        Err(byteable::SliceError::TooShort {
+            needed: 4,
+            available: 3
+        })
+    );
+    assert_eq!(buf, [0; 3]);
+    let err = u32::read_from_slice(&buf).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "slice too short: need 4 bytes, 3 available"
+    );
+    assert_eq!(().write_to_slice(&mut []), Ok(0));
+    assert_eq!(<()>::read_from_slice(&[]), Ok(((), &[][..])));
+}
+
+#[test]
+fn read_from_slice_reports_decode_errors() {
+    assert_eq!(
+        <[bool; 2]>::read_from_slice(&[1, 5, 0]),
+        Err(byteable::SliceError::Decode(DecodeError::ArrayElement {
+            index: 1,
+            kind: DecodeErrorKind::InvalidBool,
+        }))
+    );
+}
+
+// ── Arrays and nested arrays ──────────────────────────────────────────────────
+
+#[test]
+fn nested_array_byte_array_type_and_size() {
+    let value: [[LittleEndian<u16>; 2]; 3] = [
+        [LittleEndian::new(0x0102), LittleEndian::new(0x0304)],
+        [LittleEndian::new(0x0506), LittleEndian::new(0x0708)],
+        [LittleEndian::new(0x090A), LittleEndian::new(0x0B0C)],
+    ];
+    let bytes: [[[u8; 2]; 2]; 3] = value.into_byte_array();
+    assert_eq!(
+        <[[LittleEndian<u16>; 2]; 3] as IntoByteArray>::BYTE_SIZE,
+        12
+    );
+    assert_eq!(<[[[u8; 2]; 2]; 3] as ByteArray>::BYTE_SIZE, 12);
+    assert_eq!(
+        bytes.as_byte_slice(),
+        &[2, 1, 4, 3, 6, 5, 8, 7, 0x0A, 9, 0x0C, 0x0B]
+    );
+}
+
+#[test]
+fn nested_array_flattening_matches_raw_layout() {
+    use byteable::PlainOldData;
+
+    // The element-wise `map` and the in-memory raw layout must agree byte for byte.
+    let value = [[1u32, 2, 3], [0xAABB_CCDD, 5, 6]];
+    assert_eq!(
+        value.into_byte_array().as_byte_slice(),
+        value.to_raw().as_bytes()
+    );
+    assert_eq!(value.to_raw().as_bytes().len(), 24);
+}
+
+#[test]
+fn nested_array_roundtrip() {
+    let value = [
+        [BigEndian::new(1u16), BigEndian::new(2)],
+        [BigEndian::new(3), BigEndian::new(4)],
+    ];
+    let mut bytes = value.into_byte_array();
+    assert_eq!(bytes.as_byte_slice(), &[0, 1, 0, 2, 0, 3, 0, 4]);
+    assert_eq!(
+        <[[BigEndian<u16>; 2]; 2]>::try_from_byte_array(bytes).unwrap(),
+        value
+    );
+
+    bytes.as_byte_slice_mut()[7] = 9;
+    assert_eq!(
+        <[[BigEndian<u16>; 2]; 2]>::try_from_byte_array(bytes).unwrap()[1][1].get(),
+        9
+    );
+}
+
+#[test]
+fn array_decoding_propagates_element_errors() {
+    assert_eq!(
+        <[bool; 3]>::try_from_byte_array([[1], [0], [1]]).unwrap(),
+        [true, false, true]
+    );
+    assert!(matches!(
+        <[bool; 3]>::try_from_byte_array([[1], [2], [1]]),
+        Err(DecodeError::ArrayElement {
+            index: 1,
+            kind: DecodeErrorKind::InvalidBool,
+        })
+    ));
+}
+
+// ── Smart pointers (`alloc` feature) ──────────────────────────────────────────
+
+#[cfg(feature = "alloc")]
+#[test]
+fn smart_pointers_use_inner_raw_repr() {
+    extern crate alloc;
+    use alloc::{boxed::Box, rc::Rc, sync::Arc};
+    use byteable::{FromRawRepr, PlainOldData, TryFromRawRepr};
+
+    let boxed = Box::new(BigEndian::new(0x0102u16));
+    assert_eq!(boxed.to_raw().as_bytes(), &[1, 2]);
+    assert_eq!(
+        <Box<u16> as FromRawRepr>::from_raw(0x0304u16.to_raw()),
+        Box::new(0x0304)
+    );
+    assert_eq!(Rc::new(7u8).to_raw(), 7u8.to_raw());
+    assert_eq!(
+        Arc::<bool>::try_from_raw(2u8.to_raw()),
+        Err(DecodeError::InvalidBool(2))
+    );
+}
+
+// ── Flat byte arrays ──────────────────────────────────────────────────────────
+
+#[test]
+fn nested_array_to_flat_bytes() {
+    use byteable::FlatByteable;
+
+    let value = [[LittleEndian::new(0x0102u16); 2]; 3];
+    let flat: [u8; 12] = value.to_flat_bytes();
+    assert_eq!(flat, [2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1]);
+    assert_eq!(&flat, value.into_byte_array().as_byte_slice());
+    assert_eq!(
+        <[[LittleEndian<u16>; 2]; 3]>::try_from_flat_bytes(flat),
+        Ok(value)
+    );
+}
+
+#[test]
+fn endian_array_flat_bytes_concatenate_elements() {
+    use byteable::FlatByteable;
+
+    let values = [0x0102u16, 0x0304, 0x0506, 0x0708];
+    let le = values.map(LittleEndian::new);
+    let flat: [u8; 8] = le.to_flat_bytes();
+    let concatenated: Vec<u8> = le.iter().flat_map(|v| v.into_byte_array()).collect();
+    assert_eq!(flat.as_slice(), concatenated.as_slice());
+    assert_eq!(flat, [2, 1, 4, 3, 6, 5, 8, 7]);
+    assert_eq!(<[LittleEndian<u16>; 4]>::try_from_flat_bytes(flat), Ok(le));
+
+    let be = values.map(BigEndian::new);
+    let flat: [u8; 8] = be.to_flat_bytes();
+    assert_eq!(flat, [1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(<[BigEndian<u16>; 4]>::try_from_flat_bytes(flat), Ok(be));
+}
+
+#[test]
+fn try_from_flat_bytes_propagates_errors() {
+    use byteable::FlatByteable;
+
+    assert_eq!(
+        <[bool; 3]>::try_from_flat_bytes([1, 0, 1]),
+        Ok([true, false, true])
+    );
+    assert_eq!(
+        <[bool; 3]>::try_from_flat_bytes([1, 5, 1]),
+        Err(DecodeError::ArrayElement {
+            index: 1,
+            kind: DecodeErrorKind::InvalidBool,
+        })
+    );
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn derived_struct_flat_bytes() {
+    use byteable::{Byteable, FlatByteable};
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct Sample {
+        #[byteable(big_endian)]
+        id: u16,
+        #[byteable(transparent)]
+        readings: [LittleEndian<u16>; 2],
+    }
+
+    let sample = Sample {
+        id: 7,
+        readings: [LittleEndian::new(1), LittleEndian::new(2)],
+    };
+    let flat: [u8; 6] = sample.to_flat_bytes();
+    assert_eq!(flat, [0, 7, 1, 0, 2, 0]);
+    assert_eq!(Sample::from_flat_bytes(flat), sample);
+}
+
+// ── PhantomData and () ───────────────────────────────────────────────────────
 
 #[test]
 fn phantom_data_byte_size() {
@@ -54,6 +458,15 @@ fn phantom_data_roundtrip() {
     let _restored = PhantomData::<u64>::from_byte_array(bytes);
 }
 
+#[test]
+fn unit_roundtrip() {
+    assert_eq!(<() as IntoByteArray>::BYTE_SIZE, 0);
+    let bytes = ().into_byte_array();
+    assert_eq!(bytes, [0u8; 0]);
+    <()>::from_byte_array(bytes);
+    assert_eq!(<()>::try_from_byte_array([]), Ok(()));
+}
+
 // ── u128 / i128 primitives ────────────────────────────────────────────────────
 
 #[test]
@@ -90,6 +503,107 @@ fn i128_large_negative() {
     assert_eq!(i128::from_byte_array(bytes), val);
 }
 
+// ── 24-bit integers ───────────────────────────────────────────────────────────
+
+#[test]
+fn int24_byte_size() {
+    use byteable::{I24, U24};
+    assert_eq!(U24::BYTE_SIZE, 3);
+    assert_eq!(I24::BYTE_SIZE, 3);
+    assert_eq!(BigEndian::<U24>::BYTE_SIZE, 3);
+}
+
+#[test]
+fn u24_range_checking() {
+    use byteable::{Int24OutOfRange, U24};
+    assert_eq!(U24::MIN.get(), 0);
+    assert_eq!(U24::MAX.get(), 0xFF_FFFF);
+    assert_eq!(U24::try_from(0xFF_FFFFu32).unwrap().get(), 0xFF_FFFF);
+    assert_eq!(
+        U24::try_from(0x100_0000u32),
+        Err(Int24OutOfRange { value: 0x100_0000 })
+    );
+    assert_eq!(U24::new_wrapping(0x1FF_FFFF), U24::MAX);
+    assert_eq!(u32::from(U24::from(0xABCDu16)), 0xABCD);
+}
+
+#[test]
+fn i24_range_checking_and_sign_extension() {
+    use byteable::I24;
+    assert_eq!(I24::MIN.get(), -0x80_0000);
+    assert_eq!(I24::MAX.get(), 0x7F_FFFF);
+    assert!(I24::new(0x80_0000).is_none());
+    assert!(I24::new(-0x80_0001).is_none());
+    assert_eq!(I24::new(-1).unwrap().into_byte_array(), [0xFF; 3]);
+    assert_eq!(I24::from_byte_array([0x00, 0x00, 0x80]), I24::MIN);
+    assert_eq!(i64::from(I24::from(-300i16)), -300);
+    assert!(I24::MIN < I24::from(0i8));
+}
+
+#[test]
+fn int24_endian_wrappers() {
+    use byteable::{I24, U24};
+    let value = U24::new(0x01_0203).unwrap();
+    assert_eq!(value.into_byte_array(), [0x03, 0x02, 0x01]);
+    assert_eq!(
+        LittleEndian::new(value).into_byte_array(),
+        [0x03, 0x02, 0x01]
+    );
+    assert_eq!(BigEndian::new(value).into_byte_array(), [0x01, 0x02, 0x03]);
+    assert_eq!(
+        BigEndian::<U24>::from_byte_array([0x01, 0x02, 0x03]).get(),
+        value
+    );
+    assert_eq!(LittleEndian::new(value).to_big(), BigEndian::new(value));
+
+    let negative = I24::new(-0x01_0203).unwrap();
+    assert_eq!(
+        BigEndian::<I24>::from_byte_array(BigEndian::new(negative).into_byte_array()).get(),
+        negative
+    );
+}
+
+// ── Fixed-width usize ─────────────────────────────────────────────────────────
+
+#[test]
+fn fixed_usize_byte_size_and_encoding() {
+    use byteable::{Usize32, Usize64};
+    assert_eq!(Usize32::BYTE_SIZE, 4);
+    assert_eq!(Usize64::BYTE_SIZE, 8);
+
+    let len = Usize64::new(0x0102).unwrap();
+    assert_eq!(len.into_byte_array(), [0x02, 0x01, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(
+        BigEndian::new(len).into_byte_array(),
+        [0, 0, 0, 0, 0, 0, 0x01, 0x02]
+    );
+    assert_eq!(Usize64::from_byte_array(len.into_byte_array()), len);
+    assert_eq!(Usize32::from_byte_array([7, 0, 0, 0]).get(), Some(7));
+}
+
+#[test]
+fn fixed_usize_range_checking() {
+    use byteable::{Usize32, Usize64, UsizeOutOfRange};
+    assert_eq!(usize::try_from(Usize32::try_from(5usize).unwrap()), Ok(5));
+    assert_eq!(Usize64::new(usize::MAX).unwrap().get(), Some(usize::MAX));
+    if usize::BITS > 32 {
+        let too_big = u32::MAX as usize + 1;
+        assert_eq!(
+            Usize32::try_from(too_big),
+            Err(UsizeOutOfRange {
+                value: too_big as u64
+            })
+        );
+    } else {
+        let stored = Usize64::from_bits(u64::MAX);
+        assert_eq!(stored.get(), None);
+        assert_eq!(
+            usize::try_from(stored),
+            Err(UsizeOutOfRange { value: u64::MAX })
+        );
+    }
+}
+
 // ── NonZero types ─────────────────────────────────────────────────────────────
 
 #[test]
@@ -126,6 +640,49 @@ fn nonzero_zero_is_err() {
     assert!(NonZeroU32::try_from_byte_array([0, 0, 0, 0]).is_err());
 }
 
+// ── Wrapping / Saturating ─────────────────────────────────────────────────────
+
+#[test]
+fn wrapping_serializes_like_inner_type() {
+    use core::num::Wrapping;
+    assert_eq!(
+        Wrapping(0x0102_0304u32).into_byte_array(),
+        0x0102_0304u32.into_byte_array()
+    );
+    assert_eq!(Wrapping(-2i8).into_byte_array(), [0xFE]);
+    assert_eq!(
+        Wrapping::<u16>::try_from_byte_array([0x34, 0x12]).unwrap(),
+        Wrapping(0x1234)
+    );
+    assert_eq!(Wrapping::<u64>::BYTE_SIZE, 8);
+}
+
+#[test]
+fn saturating_serializes_like_inner_type() {
+    use core::num::Saturating;
+    let value = Saturating(u16::MAX) + Saturating(1);
+    assert_eq!(value.into_byte_array(), u16::MAX.into_byte_array());
+    assert_eq!(
+        Saturating::<i32>::from_byte_array((-5i32).into_byte_array()),
+        Saturating(-5)
+    );
+}
+
+#[test]
+fn num_wrappers_compose_with_endian_wrappers() {
+    use core::num::{Saturating, Wrapping};
+    let be = BigEndian::new(Wrapping(0x0102_0304u32));
+    assert_eq!(be.into_byte_array(), [0x01, 0x02, 0x03, 0x04]);
+    assert_eq!(be.get(), Wrapping(0x0102_0304));
+
+    let le = LittleEndian::new(Saturating(0x0102i16));
+    assert_eq!(le.into_byte_array(), [0x02, 0x01]);
+    assert_eq!(
+        LittleEndian::<Saturating<i16>>::from_byte_array([0x02, 0x01]).get(),
+        Saturating(0x0102)
+    );
+}
+
 // ── Network types ─────────────────────────────────────────────────────────────
 
 #[test]
@@ -138,239 +695,1310 @@ fn ipv4_addr_roundtrip() {
 }
 
 #[test]
-fn ipv4_addr_byte_layout() {
-    assert_eq!(Ipv4Addr::new(10, 0, 0, 1).into_byte_array(), [10, 0, 0, 1]);
+fn ipv4_addr_byte_layout() {
+    assert_eq!(Ipv4Addr::new(10, 0, 0, 1).into_byte_array(), [10, 0, 0, 1]);
+}
+
+#[test]
+fn ipv6_addr_roundtrip() {
+    let original = Ipv6Addr::new(0x2001, 0x0db8, 0, 0, 0, 0, 0, 1);
+    assert_eq!(
+        Ipv6Addr::from_byte_array(original.into_byte_array()),
+        original
+    );
+}
+
+#[test]
+fn ipv6_addr_byte_layout() {
+    let bytes = Ipv6Addr::new(0x2001, 0x0db8, 0, 0, 0, 0, 0, 1).into_byte_array();
+    assert_eq!(&bytes[..4], &[0x20, 0x01, 0x0d, 0xb8]);
+    assert_eq!(bytes[15], 1);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn ip_addrs_as_struct_fields() {
+    use byteable::Byteable;
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct Route {
+        v4: Ipv4Addr,
+        v6: Ipv6Addr,
+        #[byteable(big_endian)]
+        metric: u16,
+    }
+
+    let route = Route {
+        v4: Ipv4Addr::new(10, 0, 0, 1),
+        v6: Ipv6Addr::LOCALHOST,
+        metric: 0x0102,
+    };
+    let bytes = route.into_byte_array();
+    assert_eq!(bytes.len(), 4 + 16 + 2);
+    assert_eq!(&bytes[..4], &[10, 0, 0, 1]);
+    assert_eq!(&bytes[4..20], &Ipv6Addr::LOCALHOST.octets());
+    assert_eq!(&bytes[20..], &[1, 2]);
+    assert_eq!(Route::from_byte_array(bytes), route);
+}
+
+#[test]
+fn socket_addr_v4_roundtrip() {
+    let original = SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 8080);
+    let restored = SocketAddrV4::from_byte_array(original.into_byte_array());
+    assert_eq!(original.ip(), restored.ip());
+    assert_eq!(original.port(), restored.port());
+}
+
+#[test]
+fn socket_addr_v6_roundtrip() {
+    let original = SocketAddrV6::new(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1), 443, 0, 0);
+    let restored = SocketAddrV6::from_byte_array(original.into_byte_array());
+    assert_eq!(original.ip(), restored.ip());
+    assert_eq!(original.port(), restored.port());
+    assert_eq!(original.flowinfo(), restored.flowinfo());
+    assert_eq!(original.scope_id(), restored.scope_id());
+}
+
+#[test]
+fn socket_addr_byte_layouts() {
+    let v4 = SocketAddrV4::new(Ipv4Addr::new(192, 168, 0, 1), 0x1F90);
+    assert_eq!(v4.into_byte_array(), [192, 168, 0, 1, 0x90, 0x1F]);
+
+    let v6 = SocketAddrV6::new(Ipv6Addr::LOCALHOST, 0x0102, 0x0304_0506, 7);
+    let bytes = v6.into_byte_array();
+    assert_eq!(bytes.len(), 26);
+    assert_eq!(&bytes[..16], &Ipv6Addr::LOCALHOST.octets());
+    assert_eq!(
+        &bytes[16..],
+        &[0x02, 0x01, 0x06, 0x05, 0x04, 0x03, 7, 0, 0, 0]
+    );
+}
+
+#[test]
+fn socket_addr_v6_roundtrip_keeps_flowinfo_and_scope() {
+    let original = SocketAddrV6::new(
+        Ipv6Addr::new(0xfe80, 0, 0, 0, 0x1234, 0x5678, 0x9abc, 0xdef0),
+        51413,
+        0x000F_FFFF,
+        u32::MAX,
+    );
+    assert_eq!(
+        SocketAddrV6::from_byte_array(original.into_byte_array()),
+        original
+    );
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn socket_addrs_in_network_byte_order() {
+    use byteable::Byteable;
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct Peers {
+        #[byteable(big_endian)]
+        v4: SocketAddrV4,
+        #[byteable(big_endian)]
+        v6: SocketAddrV6,
+    }
+
+    let peers = Peers {
+        v4: SocketAddrV4::new(Ipv4Addr::new(10, 0, 0, 2), 6881),
+        v6: SocketAddrV6::new(Ipv6Addr::LOCALHOST, 443, 0x0001_0203, 9),
+    };
+    let bytes = peers.into_byte_array();
+    assert_eq!(bytes.len(), 6 + 26);
+    assert_eq!(&bytes[..6], &[10, 0, 0, 2, 0x1A, 0xE1]);
+    assert_eq!(&bytes[6..22], &Ipv6Addr::LOCALHOST.octets());
+    assert_eq!(&bytes[22..], &[0x01, 0xBB, 0, 1, 2, 3, 0, 0, 0, 9]);
+    assert_eq!(Peers::from_byte_array(bytes), peers);
+}
+
+// ── Duration ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn duration_byte_size() {
+    assert_eq!(Duration::BYTE_SIZE, 12); // u64 secs (8) + u32 nanos (4)
+}
+
+#[test]
+fn duration_roundtrip() {
+    for d in [
+        Duration::ZERO,
+        Duration::from_secs(3600),
+        Duration::new(1, 500_000_000),
+    ] {
+        assert_eq!(Duration::try_from_byte_array(d.into_byte_array()), Ok(d));
+    }
+}
+
+#[test]
+fn duration_byte_layout() {
+    let d = Duration::new(0x0102_0304_0506_0708, 0x0A0B_0C0D);
+    assert_eq!(
+        d.into_byte_array(),
+        [8, 7, 6, 5, 4, 3, 2, 1, 0x0D, 0x0C, 0x0B, 0x0A]
+    );
+    assert_eq!(
+        byteable::PlainOldData::as_bytes(&d.to_big_endian()),
+        [1, 2, 3, 4, 5, 6, 7, 8, 0x0A, 0x0B, 0x0C, 0x0D]
+    );
+    assert_eq!(Duration::try_from_big_endian(d.to_big_endian()), Ok(d));
+}
+
+#[test]
+fn duration_extremes_roundtrip() {
+    let max = Duration::MAX;
+    assert_eq!(
+        Duration::try_from_byte_array(max.into_byte_array()),
+        Ok(max)
+    );
+    assert_eq!(Duration::try_from_big_endian(max.to_big_endian()), Ok(max));
+}
+
+#[test]
+fn duration_rejects_out_of_range_nanos() {
+    let mut bytes = Duration::from_secs(u64::MAX).into_byte_array();
+    bytes[8..].copy_from_slice(&1_000_000_000u32.to_le_bytes());
+    assert_eq!(
+        Duration::try_from_byte_array(bytes),
+        Err(DecodeError::InvalidNanos(1_000_000_000))
+    );
+
+    let mut be = Duration::ZERO.to_big_endian();
+    byteable::PlainOldData::as_bytes_mut(&mut be)[8..].copy_from_slice(&u32::MAX.to_be_bytes());
+    assert_eq!(
+        Duration::try_from_big_endian(be),
+        Err(DecodeError::InvalidNanos(u32::MAX))
+    );
+}
+
+// ── SystemTime ────────────────────────────────────────────────────────────────
+
+#[cfg(feature = "std")]
+mod system_time_tests {
+    use byteable::{FromByteArray, IntoByteArray};
+    use std::time::{Duration, SystemTime};
+
+    #[test]
+    fn system_time_byte_size() {
+        assert_eq!(SystemTime::BYTE_SIZE, 12); // i64 secs (8) + u32 nanos (4)
+    }
+
+    #[test]
+    fn system_time_roundtrip() {
+        for t in [
+            SystemTime::UNIX_EPOCH,
+            SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000),
+            SystemTime::UNIX_EPOCH - Duration::from_secs(86400),
+            SystemTime::UNIX_EPOCH - Duration::from_millis(500), // nanos-carry path
+        ] {
+            assert_eq!(SystemTime::from_byte_array(t.into_byte_array()), t);
+        }
+    }
+}
+
+// ── Range types ───────────────────────────────────────────────────────────────
+
+#[test]
+fn range_roundtrips() {
+    let r: Range<u8> = 10..200;
+    assert_eq!(Range::<u8>::from_byte_array(r.clone().into_byte_array()), r);
+
+    let r: Range<u32> = 0..0xDEAD_BEEF;
+    assert_eq!(
+        Range::<u32>::from_byte_array(r.clone().into_byte_array()),
+        r
+    );
+
+    let r: RangeInclusive<u32> = 1..=0xFFFF_FFFF;
+    assert_eq!(
+        RangeInclusive::<u32>::from_byte_array(r.clone().into_byte_array()),
+        r
+    );
+}
+
+#[test]
+fn range_from_roundtrip() {
+    let r: RangeFrom<u32> = 42..;
+    let restored = RangeFrom::<u32>::from_byte_array(r.clone().into_byte_array());
+    assert_eq!(r.start, restored.start);
+}
+
+#[test]
+fn range_to_roundtrips() {
+    let r: RangeTo<u32> = ..999;
+    assert_eq!(
+        RangeTo::<u32>::from_byte_array(r.into_byte_array()).end,
+        999
+    );
+
+    let r: RangeToInclusive<u32> = ..=1000;
+    assert_eq!(
+        RangeToInclusive::<u32>::from_byte_array(r.into_byte_array()).end,
+        1000
+    );
+}
+
+#[test]
+fn range_full_is_zero_sized() {
+    assert_eq!(RangeFull::BYTE_SIZE, 0);
+    let bytes = RangeFull.into_byte_array();
+    assert_eq!(bytes, [0u8; 0]);
+    let _restored = RangeFull::from_byte_array(bytes);
+}
+
+// ── bool ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn bool_byte_size() {
+    assert_eq!(bool::BYTE_SIZE, 1);
+}
+
+#[test]
+#[allow(clippy::bool_assert_comparison)]
+fn bool_roundtrip() {
+    assert_eq!(true.into_byte_array(), [1]);
+    assert_eq!(false.into_byte_array(), [0]);
+    assert_eq!(bool::try_from_byte_array([1]).unwrap(), true);
+    assert_eq!(bool::try_from_byte_array([0]).unwrap(), false);
+}
+
+#[test]
+fn bool_invalid_bytes_are_err() {
+    for invalid in [2u8, 3, 10, 42, 100, 255] {
+        let _err = bool::try_from_byte_array([invalid]).unwrap_err();
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn bool_error_implements_std_error() {
+    use std::error::Error;
+    let err = bool::try_from_byte_array([2]).unwrap_err();
+    let _: &dyn Error = &err;
+}
+
+#[test]
+fn bool_array_bitmap_roundtrip() {
+    let mut caps = [false; 64];
+    caps[0] = true;
+    caps[63] = true;
+    let bytes = caps.into_byte_array();
+    assert_eq!(bytes.as_byte_slice().len(), 64);
+    assert_eq!(<[bool; 64]>::try_from_byte_array(bytes).unwrap(), caps);
+}
+
+#[test]
+fn bool_array_reports_first_invalid_index() {
+    let mut bytes = [[0u8]; 64];
+    bytes[40] = [7];
+    bytes[50] = [9];
+    let err = <[bool; 64]>::try_from_byte_array(bytes).unwrap_err();
+    assert_eq!(
+        err,
+        DecodeError::ArrayElement {
+            index: 40,
+            kind: DecodeErrorKind::InvalidBool,
+        }
+    );
+    assert_eq!(err.to_string(), "invalid bool at array index 40");
+}
+
+#[test]
+fn bool_array_via_raw_repr_reports_index() {
+    use byteable::TryFromRawRepr;
+    assert_eq!(
+        <[bool; 4]>::try_from_raw([1, 0, 0, 3]),
+        Err(DecodeError::ArrayElement {
+            index: 3,
+            kind: DecodeErrorKind::InvalidBool,
+        })
+    );
+}
+
+#[test]
+fn nested_bool_array_reports_outer_index() {
+    assert_eq!(
+        <[[bool; 2]; 2]>::try_from_byte_array([[[0], [1]], [[1], [4]]]),
+        Err(DecodeError::ArrayElement {
+            index: 1,
+            kind: DecodeErrorKind::InvalidBool,
+        })
+    );
+}
+
+// ── char ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn char_byte_size() {
+    assert_eq!(char::BYTE_SIZE, 4);
+}
+
+#[test]
+fn char_ascii_byte_layout() {
+    // 'A' = U+0041, stored as little-endian u32
+    assert_eq!('A'.into_byte_array(), [0x41, 0x00, 0x00, 0x00]);
+}
+
+#[test]
+fn char_roundtrip() {
+    let chars = [
+        'A',
+        'z',
+        '0',
+        '9',
+        ' ',
+        '!',
+        '\0',
+        '\n',
+        '€',
+        '£',
+        '¥',
+        '©',
+        'α',
+        'β',
+        'γ',
+        'π',
+        'Σ',
+        '中',
+        '日',
+        '本',
+        '語',
+        '🦀',
+        '🚀',
+        '🌟',
+        '❤',
+        '✓',
+        '\u{10FFFF}',
+    ];
+    for c in chars {
+        let bytes = c.into_byte_array();
+        assert_eq!(char::try_from_byte_array(bytes).unwrap(), c);
+    }
+}
+
+#[test]
+fn char_specific_byte_layouts() {
+    // '🦀' = U+1F980 in little-endian u32
+    assert_eq!('🦀'.into_byte_array(), [0x80, 0xF9, 0x01, 0x00]);
+    // '€' = U+20AC
+    assert_eq!('€'.into_byte_array(), [0xAC, 0x20, 0x00, 0x00]);
+    // '\u{10FFFF}' = max valid codepoint
+    assert_eq!('\u{10FFFF}'.into_byte_array(), [0xFF, 0xFF, 0x10, 0x00]);
+}
+
+#[test]
+fn char_invalid_codepoints_are_err() {
+    let invalid = [
+        [0x00, 0xD8, 0x00, 0x00], // U+D800 — surrogate
+        [0xFF, 0xDF, 0x00, 0x00], // U+DFFF — surrogate
+        [0x00, 0x00, 0x11, 0x00], // U+110000 — out of range
+        [0xFF, 0xFF, 0xFF, 0xFF], // 0xFFFFFFFF — out of range
+    ];
+    for bytes in invalid {
+        let _ = char::try_from_byte_array(bytes).expect_err("expected error for {bytes:?}");
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn char_error_implements_std_error() {
+    use std::error::Error;
+    let err = char::try_from_byte_array([0xFF, 0xFF, 0xFF, 0xFF]).unwrap_err();
+    let _: &dyn Error = &err;
+}
+
+#[test]
+fn char_array_roundtrip() {
+    let word = ['c', 'r', 'a', 'b', '🦀'];
+    let bytes = word.into_byte_array();
+    assert_eq!(bytes[4], [0x80, 0xF9, 0x01, 0x00]);
+    assert_eq!(<[char; 5]>::try_from_byte_array(bytes).unwrap(), word);
+}
+
+#[test]
+fn char_array_reports_first_invalid_index() {
+    let mut bytes = ['a', 'b', 'c'].into_byte_array();
+    bytes[2] = 0xD800u32.to_le_bytes();
+    let err = <[char; 3]>::try_from_byte_array(bytes).unwrap_err();
+    assert_eq!(
+        err,
+        DecodeError::ArrayElement {
+            index: 2,
+            kind: DecodeErrorKind::InvalidChar,
+        }
+    );
+    assert_eq!(err.to_string(), "invalid char at array index 2");
+}
+
+// ── BitFlags ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn bitflags_byte_sizes() {
+    use byteable::{BitFlags8, BitFlags16, BitFlags32};
+    assert_eq!(BitFlags8::BYTE_SIZE, 1);
+    assert_eq!(BitFlags16::BYTE_SIZE, 2);
+    assert_eq!(BitFlags32::BYTE_SIZE, 4);
+}
+
+#[test]
+fn bitflags_get_set_toggle() {
+    use byteable::BitFlags8;
+    let mut flags = BitFlags8::EMPTY;
+    flags.set(0, true);
+    flags.set(7, true);
+    assert_eq!(flags.bits(), 0b1000_0001);
+    assert!(flags.get(0) && flags.get(7) && !flags.get(3));
+
+    flags.set(0, false);
+    flags.toggle(3);
+    flags.toggle(7);
+    assert_eq!(flags.bits(), 0b0000_1000);
+    assert_eq!(flags.count_ones(), 1);
+}
+
+#[test]
+#[should_panic(expected = "bit index out of range")]
+fn bitflags_out_of_range_bit_panics() {
+    byteable::BitFlags16::EMPTY.get(16);
+}
+
+#[test]
+fn bitflags_operators() {
+    use byteable::BitFlags32;
+    let a = BitFlags32::new(0b1100);
+    let b = BitFlags32::new(0b1010);
+    assert_eq!((a | b).bits(), 0b1110);
+    assert_eq!((a & b).bits(), 0b1000);
+
+    let mut c = a;
+    c |= BitFlags32::new(1);
+    c &= BitFlags32::new(0b0101);
+    assert_eq!(c, BitFlags32::from(0b0101));
+    assert_eq!(u32::from(c), 0b0101);
+    assert_eq!(format!("{c:#06b}"), "0b0101");
+}
+
+#[test]
+fn bitflags_are_stored_little_endian() {
+    use byteable::{BitFlags16, BitFlags32};
+    assert_eq!(BitFlags16::new(0x8001).into_byte_array(), [0x01, 0x80]);
+    assert_eq!(
+        BitFlags32::new(0x0102_0304).into_byte_array(),
+        [0x04, 0x03, 0x02, 0x01]
+    );
+    assert_eq!(
+        BitFlags32::try_from_byte_array([0x04, 0x03, 0x02, 0x01]).unwrap(),
+        BitFlags32::new(0x0102_0304)
+    );
+}
+
+// ── FixedDecimal ─────────────────────────────────────────────────────────────
+
+type Cents = byteable::FixedDecimal<2>;
+
+#[test]
+fn fixed_decimal_layout() {
+    assert_eq!(Cents::BYTE_SIZE, 8);
+    let value = Cents::from_units(-1234);
+    assert_eq!(value.into_byte_array(), (-1234i64).to_le_bytes());
+    assert_eq!(Cents::from_byte_array(value.into_byte_array()), value);
+}
+
+#[test]
+fn fixed_decimal_f64_conversions() {
+    assert_eq!(Cents::from_f64(12.34).units(), 1234);
+    assert_eq!(Cents::from_f64(-0.07).units(), -7);
+    assert_eq!(Cents::from_units(250).to_f64(), 2.5);
+    assert_eq!(Cents::from_f64(f64::NAN).units(), 0);
+    assert_eq!(Cents::from_f64(1e300).units(), i64::MAX);
+}
+
+#[test]
+fn fixed_decimal_rounds_half_away_from_zero() {
+    assert_eq!(Cents::from_f64(0.125).units(), 13);
+    assert_eq!(Cents::from_f64(-0.125).units(), -13);
+    assert_eq!(Cents::from_f64(0.124).units(), 12);
+    assert_eq!(Cents::from_f64(0.0049).units(), 0);
+}
+
+#[test]
+fn fixed_decimal_rounds_exactly() {
+    type Units = byteable::FixedDecimal<0>;
+    // The largest `f64` below one half, and an odd integer above `2^52`.
+    assert_eq!(Units::from_f64(0.49999999999999994).units(), 0);
+    assert_eq!(Units::from_f64(-0.49999999999999994).units(), 0);
+    assert_eq!(
+        Units::from_f64(4503599627370497.0).units(),
+        4503599627370497
+    );
+    assert_eq!(
+        Units::from_f64(-4503599627370497.0).units(),
+        -4503599627370497
+    );
+    assert_eq!(Units::from_f64(2.5).units(), 3);
+    assert_eq!(Units::from_f64(-2.5).units(), -3);
+    assert_eq!(Units::from_f64(f64::NEG_INFINITY).units(), i64::MIN);
+}
+
+#[test]
+fn fixed_decimal_display() {
+    assert_eq!(Cents::from_units(123456).to_string(), "1234.56");
+    assert_eq!(Cents::from_units(5).to_string(), "0.05");
+    assert_eq!(Cents::from_units(-5).to_string(), "-0.05");
+    assert_eq!(Cents::from_units(-100).to_string(), "-1.00");
+    assert_eq!(Cents::default().to_string(), "0.00");
+    assert_eq!(
+        Cents::from_units(i64::MIN).to_string(),
+        "-92233720368547758.08"
+    );
+    assert_eq!(
+        byteable::FixedDecimal::<0>::from_units(42).to_string(),
+        "42"
+    );
+    assert_eq!(
+        byteable::FixedDecimal::<3>::from_units(1).to_string(),
+        "0.001"
+    );
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn fixed_decimal_as_struct_field() {
+    use byteable::Byteable;
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct LineItem {
+        quantity: u8,
+        price: Cents,
+    }
+
+    let item = LineItem {
+        quantity: 3,
+        price: Cents::from_f64(9.99),
+    };
+    let bytes = item.into_byte_array();
+    assert_eq!(bytes[0], 3);
+    assert_eq!(&bytes[1..], &999i64.to_le_bytes());
+    assert_eq!(LineItem::from_byte_array(bytes), item);
+}
+
+// ── Fixed ────────────────────────────────────────────────────────────────────
+
+type Q16 = byteable::Fixed<i32, 16>;
+
+#[test]
+fn fixed_point_layout() {
+    assert_eq!(Q16::BYTE_SIZE, 4);
+    let value = Q16::from_f64(-1.5);
+    assert_eq!(value.to_bits(), -0x18000);
+    assert_eq!(value.into_byte_array(), (-0x18000i32).to_le_bytes());
+    assert_eq!(
+        BigEndian::new(value).into_byte_array(),
+        (-0x18000i32).to_be_bytes()
+    );
+    assert_eq!(
+        LittleEndian::<Q16>::from_byte_array((-0x18000i32).to_le_bytes()).get(),
+        value
+    );
+}
+
+#[test]
+fn fixed_point_bits_round_trip_exactly() {
+    for bits in [0, 1, -1, i32::MIN, i32::MAX, 0x7FC0_0001, -0x0123_4567] {
+        let value = Q16::from_bits(bits);
+        assert_eq!(
+            Q16::from_byte_array(value.into_byte_array()).to_bits(),
+            bits
+        );
+        assert_eq!(
+            BigEndian::<Q16>::from_byte_array(BigEndian::new(value).into_byte_array())
+                .get()
+                .to_bits(),
+            bits
+        );
+    }
+    let max = byteable::Fixed::<u64, 64>::from_bits(u64::MAX);
+    assert_eq!(
+        byteable::Fixed::<u64, 64>::from_byte_array(max.into_byte_array()),
+        max
+    );
+}
+
+#[test]
+fn fixed_point_f64_conversions() {
+    assert_eq!(Q16::from_f64(0.5).to_bits(), 0x8000);
+    assert_eq!(Q16::from_bits(0x0001_4000).to_f64(), 1.25);
+    // 1 / 2^17 is halfway between 0 and the smallest step, and rounds away from zero.
+    assert_eq!(Q16::from_f64(1.0 / 131072.0).to_bits(), 1);
+    assert_eq!(Q16::from_f64(-1.0 / 131072.0).to_bits(), -1);
+    assert_eq!(Q16::from_f64(f64::NAN).to_bits(), 0);
+    assert_eq!(Q16::from_f64(1e300).to_bits(), i32::MAX);
+    assert_eq!(byteable::Fixed::<u16, 8>::from_f64(-3.0).to_bits(), 0);
+    // Rounding is shared with `FixedDecimal::from_f64`.
+    assert_eq!(
+        byteable::Fixed::<i64, 0>::from_f64(0.49999999999999994).to_bits(),
+        0
+    );
+    assert_eq!(Q16::from_f64(2.75).to_string(), "2.75");
+}
+
+#[test]
+fn fixed_point_arithmetic() {
+    let a = Q16::from_f64(1.5);
+    let b = Q16::from_f64(-0.25);
+    assert_eq!((a + b).to_f64(), 1.25);
+    assert_eq!((a - b).to_f64(), 1.75);
+    assert_eq!((a * b).to_f64(), -0.375);
+    assert_eq!((a / b).to_f64(), -6.0);
+
+    let mut sum = Q16::default();
+    sum += a;
+    sum -= b;
+    assert_eq!(sum.to_f64(), 1.75);
+
+    // Rounding toward negative infinity, for either sign of divisor.
+    let tiny = Q16::from_bits(1);
+    assert_eq!((tiny / Q16::from_f64(2.0)).to_bits(), 0);
+    assert_eq!((tiny / Q16::from_f64(-2.0)).to_bits(), -1);
+    assert_eq!((tiny * Q16::from_f64(-0.5)).to_bits(), -1);
+}
+
+#[test]
+fn fixed_point_checked_arithmetic() {
+    let max = Q16::from_bits(i32::MAX);
+    assert_eq!(max.checked_add(Q16::from_bits(1)), None);
+    assert_eq!(
+        Q16::from_bits(i32::MIN).checked_sub(Q16::from_bits(1)),
+        None
+    );
+    assert_eq!(max.checked_mul(Q16::from_f64(2.0)), None);
+    assert_eq!(max.checked_div(Q16::from_f64(0.5)), None);
+    assert_eq!(max.checked_div(Q16::default()), None);
+    assert_eq!(max.checked_mul(Q16::from_f64(1.0)), Some(max));
+}
+
+#[test]
+#[should_panic(expected = "fixed-point division by zero")]
+fn fixed_point_division_by_zero_panics() {
+    let _ = Q16::from_f64(1.0) / Q16::default();
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn fixed_point_as_struct_field() {
+    use byteable::Byteable;
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct Sample {
+        #[byteable(big_endian)]
+        level: byteable::Fixed<i16, 8>,
+        gain: Q16,
+    }
+
+    let sample = Sample {
+        level: byteable::Fixed::<i16, 8>::from_f64(-1.5),
+        gain: Q16::from_f64(0.5),
+    };
+    let bytes = sample.into_byte_array();
+    assert_eq!(bytes, [0xFE, 0x80, 0x00, 0x80, 0x00, 0x00]);
+    assert_eq!(Sample::from_byte_array(bytes), sample);
+}
+
+// ── FixedStr ─────────────────────────────────────────────────────────────────
+
+type DeviceName = byteable::FixedStr<16>;
+
+#[test]
+fn fixed_str_is_null_padded() {
+    let name = DeviceName::try_from("sda1").unwrap();
+    assert_eq!(DeviceName::BYTE_SIZE, 16);
+    assert_eq!(name.into_byte_array(), *b"sda1\0\0\0\0\0\0\0\0\0\0\0\0");
+    assert_eq!(name.as_str(), Ok("sda1"));
+    assert_eq!(name.to_string(), "sda1");
+    assert_eq!(format!("{name:?}"), "FixedStr(\"sda1\")");
+}
+
+#[test]
+fn fixed_str_full_width_has_no_terminator() {
+    let name = byteable::FixedStr::<4>::try_from("abcd").unwrap();
+    assert_eq!(name.into_byte_array(), *b"abcd");
+    assert_eq!(name.as_str(), Ok("abcd"));
+}
+
+#[test]
+fn fixed_str_too_long() {
+    let err = byteable::FixedStr::<4>::try_from("abcde").unwrap_err();
+    assert_eq!(err.len, 5);
+    assert_eq!(err.capacity, 4);
+    assert_eq!(
+        err.to_string(),
+        "string of 5 bytes does not fit in a FixedStr<4>"
+    );
+}
+
+#[test]
+fn fixed_str_stops_at_first_nul() {
+    let name = byteable::FixedStr::<8>::from_byte_array(*b"ab\0cd\0\0\0");
+    assert_eq!(name.trimmed_bytes(), b"ab");
+    assert_eq!(name.as_str(), Ok("ab"));
+    assert_eq!(name.as_array(), b"ab\0cd\0\0\0");
+}
+
+#[test]
+fn fixed_str_invalid_utf8() {
+    let name = byteable::FixedStr::<4>::from_byte_array([b'a', 0xFF, b'b', 0]);
+    assert!(name.as_str().is_err());
+    assert_eq!(name.to_string(), "a\u{FFFD}b");
+    assert_eq!(format!("{name:?}"), "FixedStr(\"a\\xffb\")");
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn fixed_str_as_struct_field() {
+    use byteable::Byteable;
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct Device {
+        #[byteable(little_endian)]
+        id: u16,
+        name: DeviceName,
+    }
+
+    let device = Device {
+        id: 1,
+        name: DeviceName::try_from("eth0").unwrap(),
+    };
+    let bytes = device.into_byte_array();
+    assert_eq!(bytes.len(), 18);
+    assert_eq!(&bytes[2..6], b"eth0");
+    assert_eq!(Device::from_byte_array(bytes), device);
+}
+
+// ── Fallible endian wrappers ─────────────────────────────────────────────────
+
+#[test]
+fn endian_try_char_byte_order() {
+    use byteable::{BigEndianTry, LittleEndianTry};
+
+    assert_eq!(BigEndianTry::<char>::BYTE_SIZE, 4);
+    assert_eq!(BigEndianTry::new('€').into_byte_array(), [0, 0, 0x20, 0xAC]);
+    assert_eq!(
+        LittleEndianTry::new('€').into_byte_array(),
+        [0xAC, 0x20, 0, 0]
+    );
+    assert_eq!(
+        BigEndianTry::<char>::try_from_byte_array([0, 0, 0x20, 0xAC]).map(BigEndianTry::get),
+        Ok('€')
+    );
+    assert_eq!(
+        LittleEndianTry::<char>::try_from_byte_array([0, 0, 0x11, 0]),
+        Err(DecodeError::InvalidChar(0x11_0000))
+    );
+}
+
+#[test]
+fn endian_try_bool() {
+    use byteable::BigEndianTry;
+
+    assert_eq!(BigEndianTry::new(true).into_byte_array(), [1]);
+    assert_eq!(
+        BigEndianTry::<bool>::try_from_byte_array([0]),
+        Ok(BigEndianTry::new(false))
+    );
+    assert_eq!(
+        BigEndianTry::<bool>::try_from_byte_array([2]),
+        Err(DecodeError::InvalidBool(2))
+    );
+}
+
+#[test]
+fn endian_try_arrays_name_the_element() {
+    use byteable::BigEndianTry;
+
+    let word = ['h', 'i'].map(BigEndianTry::new);
+    let bytes = word.into_byte_array();
+    assert_eq!(bytes, [[0, 0, 0, b'h'], [0, 0, 0, b'i']]);
+    assert_eq!(
+        <[BigEndianTry<char>; 2]>::try_from_byte_array(bytes),
+        Ok(word)
+    );
+    assert_eq!(
+        <[BigEndianTry<char>; 2]>::try_from_byte_array([[0, 0, 0, b'h'], [0, 0, 0xDF, 0xFF]]),
+        Err(DecodeError::ArrayElement {
+            index: 1,
+            kind: DecodeErrorKind::InvalidChar,
+        })
+    );
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn endian_try_fields_are_try_transparent() {
+    use byteable::{BigEndianTry, Byteable, LittleEndianTry};
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[byteable(little_endian)]
+    struct Glyph {
+        id: u16,
+        #[byteable(try_transparent)]
+        big: BigEndianTry<char>,
+        #[byteable(try_transparent)]
+        little: LittleEndianTry<char>,
+        #[byteable(try_transparent)]
+        bold: BigEndianTry<bool>,
+    }
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[repr(u8)]
+    #[byteable(tagged)]
+    enum Input {
+        Key(#[byteable(try_transparent)] BigEndianTry<char>) = 1,
+    }
+
+    let glyph = Glyph {
+        id: 1,
+        big: BigEndianTry::new('A'),
+        little: LittleEndianTry::new('B'),
+        bold: BigEndianTry::new(true),
+    };
+    let mut bytes = glyph.into_byte_array();
+    assert_eq!(bytes, [1, 0, 0, 0, 0, b'A', b'B', 0, 0, 0, 1]);
+    assert_eq!(Glyph::try_from_byte_array(bytes), Ok(glyph));
+    bytes[10] = 7;
+    assert_eq!(
+        Glyph::try_from_byte_array(bytes),
+        Err(DecodeError::InvalidBool(7))
+    );
+
+    let key = Input::Key(BigEndianTry::new('z'));
+    let bytes = key.into_byte_array();
+    assert_eq!(&bytes[..5], &[1, 0, 0, 0, b'z']);
+    assert_eq!(Input::try_from_byte_array(bytes), Ok(key));
+}
+
+// ── FixedAscii ───────────────────────────────────────────────────────────────
+
+type Callsign = byteable::FixedAscii<8>;
+
+#[test]
+fn fixed_ascii_is_null_padded() {
+    let sign = Callsign::try_from("N0CALL").unwrap();
+    assert_eq!(Callsign::BYTE_SIZE, 8);
+    assert_eq!(sign.into_byte_array(), *b"N0CALL\0\0");
+    assert_eq!(sign.as_str(), "N0CALL");
+    assert_eq!(sign.to_string(), "N0CALL");
+    assert_eq!(format!("{sign:?}"), "FixedAscii(\"N0CALL\")");
+    assert_eq!(Callsign::try_from_byte_array(*b"N0CALL\0\0"), Ok(sign));
+}
+
+#[test]
+fn fixed_ascii_rejects_non_ascii_strings() {
+    let err = Callsign::try_from("größe").unwrap_err();
+    assert_eq!(err, byteable::FixedAsciiError::NonAscii { index: 2 });
+    assert_eq!(
+        err.to_string(),
+        "string is not ASCII: non-ASCII character at byte 2"
+    );
+}
+
+#[test]
+fn fixed_ascii_too_long() {
+    let err = byteable::FixedAscii::<4>::try_from("abcde").unwrap_err();
+    assert_eq!(
+        err,
+        byteable::FixedAsciiError::TooLong {
+            len: 5,
+            capacity: 4
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "string of 5 bytes does not fit in a FixedAscii<4>"
+    );
+    assert!(byteable::FixedAscii::<4>::try_from("abcd").is_ok());
+}
+
+#[test]
+fn fixed_ascii_rejects_high_bytes_anywhere() {
+    // Latin-1 "é" in the text, and garbage after the terminator: both are rejected.
+    assert_eq!(
+        byteable::FixedAscii::<4>::try_from_byte_array([b'c', 0xE9, 0, 0]),
+        Err(DecodeError::InvalidAscii {
+            index: 1,
+            value: 0xE9
+        })
+    );
+    let err = byteable::FixedAscii::<4>::try_from_byte_array([b'a', 0, 0, 0xFF]).unwrap_err();
+    assert_eq!(
+        err,
+        DecodeError::InvalidAscii {
+            index: 3,
+            value: 0xFF
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "invalid value 0xff for ASCII at byte index 3"
+    );
+}
+
+#[test]
+fn fixed_ascii_stops_at_first_nul() {
+    let sign = byteable::FixedAscii::<8>::from_bytes(*b"ab\0cd\0\0\0").unwrap();
+    assert_eq!(sign.trimmed_bytes(), b"ab");
+    assert_eq!(sign.as_str(), "ab");
+    assert_eq!(sign.as_array(), b"ab\0cd\0\0\0");
+    assert_eq!(byteable::FixedAscii::<4>::default().as_str(), "");
 }
 
+#[cfg(feature = "derive")]
 #[test]
-fn ipv6_addr_roundtrip() {
-    let original = Ipv6Addr::new(0x2001, 0x0db8, 0, 0, 0, 0, 0, 1);
+fn fixed_ascii_as_struct_field() {
+    use byteable::Byteable;
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct Station {
+        #[byteable(little_endian)]
+        id: u16,
+        #[byteable(try_transparent)]
+        sign: Callsign,
+    }
+
+    let station = Station {
+        id: 7,
+        sign: Callsign::try_from("W1AW").unwrap(),
+    };
+    let mut bytes = station.into_byte_array();
+    assert_eq!(bytes.len(), 10);
+    assert_eq!(&bytes[2..6], b"W1AW");
+    assert_eq!(Station::try_from_byte_array(bytes), Ok(station));
+
+    bytes[4] = 0xC1;
     assert_eq!(
-        Ipv6Addr::from_byte_array(original.into_byte_array()),
-        original
+        Station::try_from_byte_array(bytes),
+        Err(DecodeError::InvalidAscii {
+            index: 2,
+            value: 0xC1
+        })
     );
 }
 
+// ── Tagged2 ──────────────────────────────────────────────────────────────────
+
+type Tagged = byteable::Tagged2<u8, BigEndian<u32>>;
+
 #[test]
-fn socket_addr_v4_roundtrip() {
-    let original = SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 8080);
-    let restored = SocketAddrV4::from_byte_array(original.into_byte_array());
-    assert_eq!(original.ip(), restored.ip());
-    assert_eq!(original.port(), restored.port());
+fn tagged2_byte_size_is_tag_plus_larger_payload() {
+    assert_eq!(Tagged::BYTE_SIZE, 5);
+    assert_eq!(byteable::Tagged2::<u16, u16>::BYTE_SIZE, 3);
+    assert_eq!(byteable::Tagged2::<[u8; 8], u8>::BYTE_SIZE, 9);
 }
 
 #[test]
-fn socket_addr_v6_roundtrip() {
-    let original = SocketAddrV6::new(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1), 443, 0, 0);
-    let restored = SocketAddrV6::from_byte_array(original.into_byte_array());
-    assert_eq!(original.ip(), restored.ip());
-    assert_eq!(original.port(), restored.port());
-    assert_eq!(original.flowinfo(), restored.flowinfo());
-    assert_eq!(original.scope_id(), restored.scope_id());
+fn tagged2_first_is_zero_padded() {
+    let value = Tagged::first(0xAB);
+    assert_eq!(value.tag(), 0);
+    assert_eq!(value.as_first(), Some(&0xAB));
+    assert_eq!(value.as_second(), None);
+    let bytes = value.into_byte_array();
+    assert_eq!(bytes.as_byte_slice(), &[0, 0xAB, 0, 0, 0]);
+    assert_eq!(Tagged::try_from_byte_array(bytes).unwrap(), value);
 }
 
-// ── Duration ──────────────────────────────────────────────────────────────────
+#[test]
+fn tagged2_second_roundtrip() {
+    let value = Tagged::second(BigEndian::new(0xDEAD_BEEF));
+    assert_eq!(value.tag(), 1);
+    let bytes = value.into_byte_array();
+    assert_eq!(bytes.as_byte_slice(), &[1, 0xDE, 0xAD, 0xBE, 0xEF]);
+    assert_eq!(Tagged::try_from_byte_array(bytes).unwrap(), value);
+}
 
 #[test]
-fn duration_byte_size() {
-    assert_eq!(Duration::BYTE_SIZE, 12); // u64 secs (8) + u32 nanos (4)
+fn tagged2_invalid_tag_is_err() {
+    let mut bytes = Tagged::first(1).into_byte_array();
+    bytes.as_byte_slice_mut()[0] = 2;
+    assert_eq!(
+        Tagged::try_from_byte_array(bytes),
+        Err(DecodeError::InvalidTag {
+            raw: 2,
+            type_name: "Tagged2"
+        })
+    );
 }
 
 #[test]
-fn duration_roundtrip() {
-    for d in [
-        Duration::ZERO,
-        Duration::from_secs(3600),
-        Duration::new(1, 500_000_000),
-    ] {
-        assert_eq!(Duration::from_byte_array(d.into_byte_array()), d);
-    }
+fn tagged2_nonzero_bytes_after_payload_are_err() {
+    let mut bytes = Tagged::first(1).into_byte_array();
+    bytes.as_byte_slice_mut()[4] = 9;
+    assert_eq!(
+        Tagged::try_from_byte_array(bytes),
+        Err(DecodeError::InvalidValue {
+            type_name: "Tagged2",
+            reason: "the bytes after the payload are not zeroed"
+        })
+    );
+    // The larger payload has no bytes after it.
+    let bytes = Tagged::second(BigEndian::new(u32::MAX)).into_byte_array();
+    assert!(Tagged::try_from_byte_array(bytes).is_ok());
 }
 
-// ── SystemTime ────────────────────────────────────────────────────────────────
+#[test]
+fn tagged2_payload_errors_propagate() {
+    let mut bytes = byteable::Tagged2::<bool, u8>::first(true).into_byte_array();
+    bytes.as_byte_slice_mut()[1] = 7;
+    assert_eq!(
+        byteable::Tagged2::<bool, u8>::try_from_byte_array(bytes),
+        Err(DecodeError::InvalidBool(7))
+    );
+}
 
-#[cfg(feature = "std")]
-mod system_time_tests {
-    use byteable::{FromByteArray, IntoByteArray};
-    use std::time::{Duration, SystemTime};
+#[cfg(all(feature = "derive", feature = "std"))]
+#[test]
+fn tagged2_as_struct_field() {
+    use byteable::{Byteable, ReadFixed, WriteFixed};
 
-    #[test]
-    fn system_time_byte_size() {
-        assert_eq!(SystemTime::BYTE_SIZE, 12); // i64 secs (8) + u32 nanos (4)
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct Message {
+        id: u8,
+        #[byteable(try_transparent)]
+        body: Tagged,
     }
 
-    #[test]
-    fn system_time_roundtrip() {
-        for t in [
-            SystemTime::UNIX_EPOCH,
-            SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000),
-            SystemTime::UNIX_EPOCH - Duration::from_secs(86400),
-            SystemTime::UNIX_EPOCH - Duration::from_millis(500), // nanos-carry path
-        ] {
-            assert_eq!(SystemTime::from_byte_array(t.into_byte_array()), t);
-        }
-    }
+    let msg = Message {
+        id: 9,
+        body: Tagged::second(BigEndian::new(1)),
+    };
+    assert_eq!(msg.into_byte_array(), [9, 1, 0, 0, 0, 1]);
+
+    let mut buf = Vec::new();
+    buf.write_fixed(&msg).unwrap();
+    let read: Message = std::io::Cursor::new(buf).read_fixed().unwrap();
+    assert_eq!(read, msg);
 }
 
-// ── Range types ───────────────────────────────────────────────────────────────
+// ── FixedOption ──────────────────────────────────────────────────────────────
 
-#[test]
-fn range_roundtrips() {
-    let r: Range<u8> = 10..200;
-    assert_eq!(Range::<u8>::from_byte_array(r.clone().into_byte_array()), r);
+type MaybePort = byteable::FixedOption<BigEndian<u16>>;
 
-    let r: Range<u32> = 0..0xDEAD_BEEF;
+#[test]
+fn fixed_option_always_reserves_the_payload() {
+    assert_eq!(MaybePort::BYTE_SIZE, 3);
+    assert_eq!(byteable::FixedOption::<[u8; 8]>::BYTE_SIZE, 9);
     assert_eq!(
-        Range::<u32>::from_byte_array(r.clone().into_byte_array()),
-        r
+        MaybePort::default().into_byte_array().as_byte_slice(),
+        &[0, 0, 0]
     );
-
-    let r: RangeInclusive<u32> = 1..=0xFFFF_FFFF;
     assert_eq!(
-        RangeInclusive::<u32>::from_byte_array(r.clone().into_byte_array()),
-        r
+        MaybePort::from(Some(BigEndian::new(443)))
+            .into_byte_array()
+            .as_byte_slice(),
+        &[1, 0x01, 0xBB]
     );
 }
 
 #[test]
-fn range_from_roundtrip() {
-    let r: RangeFrom<u32> = 42..;
-    let restored = RangeFrom::<u32>::from_byte_array(r.clone().into_byte_array());
-    assert_eq!(r.start, restored.start);
+fn fixed_option_roundtrip() {
+    for value in [None, Some(BigEndian::new(8080))] {
+        let bytes = byteable::FixedOption(value).into_byte_array();
+        assert_eq!(
+            MaybePort::try_from_byte_array(bytes).unwrap().into_inner(),
+            value
+        );
+    }
 }
 
 #[test]
-fn range_to_roundtrips() {
-    let r: RangeTo<u32> = ..999;
+fn fixed_option_invalid_tag_is_err() {
+    let mut bytes = MaybePort::default().into_byte_array();
+    bytes.as_byte_slice_mut()[0] = 2;
     assert_eq!(
-        RangeTo::<u32>::from_byte_array(r.into_byte_array()).end,
-        999
+        MaybePort::try_from_byte_array(bytes),
+        Err(DecodeError::InvalidTag {
+            raw: 2,
+            type_name: "FixedOption"
+        })
     );
+}
 
-    let r: RangeToInclusive<u32> = ..=1000;
+#[test]
+fn fixed_option_none_with_payload_is_err() {
+    let mut bytes = MaybePort::default().into_byte_array();
+    bytes.as_byte_slice_mut()[2] = 1;
     assert_eq!(
-        RangeToInclusive::<u32>::from_byte_array(r.into_byte_array()).end,
-        1000
+        MaybePort::try_from_byte_array(bytes),
+        Err(DecodeError::InvalidValue {
+            type_name: "FixedOption",
+            reason: "the payload of None is not zeroed"
+        })
     );
 }
 
 #[test]
-fn range_full_is_zero_sized() {
-    assert_eq!(RangeFull::BYTE_SIZE, 0);
-    let bytes = RangeFull.into_byte_array();
-    assert_eq!(bytes, [0u8; 0]);
-    let _restored = RangeFull::from_byte_array(bytes);
+fn fixed_option_payload_errors_propagate() {
+    let mut bytes = byteable::FixedOption(Some(true)).into_byte_array();
+    bytes.as_byte_slice_mut()[1] = 7;
+    assert_eq!(
+        byteable::FixedOption::<bool>::try_from_byte_array(bytes),
+        Err(DecodeError::InvalidBool(7))
+    );
 }
 
-// ── bool ─────────────────────────────────────────────────────────────────────
-
+#[cfg(all(feature = "derive", feature = "std"))]
 #[test]
-fn bool_byte_size() {
-    assert_eq!(bool::BYTE_SIZE, 1);
+fn fixed_option_as_struct_field() {
+    use byteable::{Byteable, FixedOption, ReadFixed, WriteFixed};
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct Endpoint {
+        id: u8,
+        #[byteable(try_transparent)]
+        port: MaybePort,
+    }
+
+    let records = [
+        Endpoint {
+            id: 1,
+            port: FixedOption(None),
+        },
+        Endpoint {
+            id: 2,
+            port: FixedOption(Some(BigEndian::new(22))),
+        },
+    ];
+    assert_eq!(records[0].into_byte_array(), [1, 0, 0, 0]);
+    assert_eq!(records[1].into_byte_array(), [2, 1, 0, 22]);
+
+    let mut buf = Vec::new();
+    buf.write_fixed(&records).unwrap();
+    assert_eq!(buf.len(), 8);
+    let read: [Endpoint; 2] = std::io::Cursor::new(buf).read_fixed().unwrap();
+    assert_eq!(read, records);
 }
 
+// ── Tuples ────────────────────────────────────────────────────────────────────
+
 #[test]
-fn bool_roundtrip() {
-    assert_eq!(true.into_byte_array(), [1]);
-    assert_eq!(false.into_byte_array(), [0]);
-    assert_eq!(bool::try_from_byte_array([1]).unwrap(), true);
-    assert_eq!(bool::try_from_byte_array([0]).unwrap(), false);
+fn tuple_byte_size_is_sum_of_elements() {
+    assert_eq!(<(u8,)>::BYTE_SIZE, 1);
+    assert_eq!(<(u8, LittleEndian<u16>, [u8; 4])>::BYTE_SIZE, 7);
+    assert_eq!(
+        <(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u64)>::BYTE_SIZE,
+        19
+    );
 }
 
 #[test]
-fn bool_invalid_bytes_are_err() {
-    for invalid in [2u8, 3, 10, 42, 100, 255] {
-        let _err = bool::try_from_byte_array([invalid]).unwrap_err();
-    }
+fn tuple_bytes_follow_field_order() {
+    // `u8` then `u64` would be padded in Rust's tuple layout; the encoding is not.
+    let value = (1u8, BigEndian::new(0x0203_0405_0607_0809u64), [10u8, 11]);
+    let bytes = value.into_byte_array();
+    assert_eq!(bytes.as_byte_slice(), &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+    assert_eq!(
+        <(u8, BigEndian<u64>, [u8; 2])>::try_from_byte_array(bytes).unwrap(),
+        value
+    );
 }
 
-#[cfg(feature = "std")]
 #[test]
-fn bool_error_implements_std_error() {
-    use std::error::Error;
-    let err = bool::try_from_byte_array([2]).unwrap_err();
-    let _: &dyn Error = &err;
+fn tuple_element_errors_propagate() {
+    let mut bytes = (7u8, true, 'x').into_byte_array();
+    bytes.as_byte_slice_mut()[1] = 2;
+    assert_eq!(
+        <(u8, bool, char)>::try_from_byte_array(bytes),
+        Err(DecodeError::InvalidBool(2))
+    );
 }
 
-// ── char ─────────────────────────────────────────────────────────────────────
-
 #[test]
-fn char_byte_size() {
-    assert_eq!(char::BYTE_SIZE, 4);
+fn tuple_raw_repr_roundtrip() {
+    use byteable::FromRawRepr;
+
+    let value = (LittleEndian::new(0x1234u16), 9u8);
+    let raw = value.to_raw();
+    assert_eq!(raw.as_byte_slice(), &[0x34, 0x12, 9]);
+    assert_eq!(<(LittleEndian<u16>, u8)>::from_raw(raw), value);
 }
 
+#[cfg(feature = "std")]
 #[test]
-fn char_ascii_byte_layout() {
-    // 'A' = U+0041, stored as little-endian u32
-    assert_eq!('A'.into_byte_array(), [0x41, 0x00, 0x00, 0x00]);
+fn tuple_read_write_fixed() {
+    use byteable::{ReadFixed, WriteFixed};
+
+    let mut buf = Vec::new();
+    buf.write_fixed(&(0xAAu8, LittleEndian::new(0x0102u16), *b"abcd"))
+        .unwrap();
+    assert_eq!(buf, [0xAA, 0x02, 0x01, b'a', b'b', b'c', b'd']);
+
+    let read: (u8, LittleEndian<u16>, [u8; 4]) = std::io::Cursor::new(buf).read_fixed().unwrap();
+    assert_eq!(read, (0xAA, LittleEndian::new(0x0102), *b"abcd"));
 }
 
+#[cfg(feature = "derive")]
 #[test]
-fn char_roundtrip() {
-    let chars = [
-        'A',
-        'z',
-        '0',
-        '9',
-        ' ',
-        '!',
-        '\0',
-        '\n',
-        '€',
-        '£',
-        '¥',
-        '©',
-        'α',
-        'β',
-        'γ',
-        'π',
-        'Σ',
-        '中',
-        '日',
-        '本',
-        '語',
-        '🦀',
-        '🚀',
-        '🌟',
-        '❤',
-        '✓',
-        '\u{10FFFF}',
-    ];
-    for c in chars {
-        let bytes = c.into_byte_array();
-        assert_eq!(char::try_from_byte_array(bytes).unwrap(), c);
+fn tuple_as_struct_field() {
+    use byteable::Byteable;
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct Sample {
+        #[byteable(transparent)]
+        position: (LittleEndian<i16>, LittleEndian<i16>),
+        #[byteable(try_transparent)]
+        flags: (bool, u8),
     }
+
+    let sample = Sample {
+        position: (LittleEndian::new(-1), LittleEndian::new(2)),
+        flags: (true, 3),
+    };
+    let bytes = sample.into_byte_array();
+    assert_eq!(bytes, [0xFF, 0xFF, 2, 0, 1, 3]);
+    assert_eq!(Sample::try_from_byte_array(bytes).unwrap(), sample);
 }
 
+// ── Zero-copy references ──────────────────────────────────────────────────────
+
+#[repr(C, align(8))]
+struct Aligned([u8; 16]);
+
 #[test]
-fn char_specific_byte_layouts() {
-    // '🦀' = U+1F980 in little-endian u32
-    assert_eq!('🦀'.into_byte_array(), [0x80, 0xF9, 0x01, 0x00]);
-    // '€' = U+20AC
-    assert_eq!('€'.into_byte_array(), [0xAC, 0x20, 0x00, 0x00]);
-    // '\u{10FFFF}' = max valid codepoint
-    assert_eq!('\u{10FFFF}'.into_byte_array(), [0xFF, 0xFF, 0x10, 0x00]);
+fn ref_from_bytes_checks_length() {
+    let buf = Aligned([1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    let pair = <[LittleEndian<u32>; 2]>::ref_from_bytes(&buf.0[..8]).unwrap();
+    assert_eq!([pair[0].get(), pair[1].get()], [1, 2]);
+
+    assert!(<[LittleEndian<u32>; 2]>::ref_from_bytes(&buf.0[..7]).is_none());
+    assert!(<[LittleEndian<u32>; 2]>::ref_from_bytes(&buf.0[..12]).is_none());
 }
 
 #[test]
-fn char_invalid_codepoints_are_err() {
-    let invalid = [
-        [0x00, 0xD8, 0x00, 0x00], // U+D800 — surrogate
-        [0xFF, 0xDF, 0x00, 0x00], // U+DFFF — surrogate
-        [0x00, 0x00, 0x11, 0x00], // U+110000 — out of range
-        [0xFF, 0xFF, 0xFF, 0xFF], // 0xFFFFFFFF — out of range
-    ];
-    for bytes in invalid {
-        let _ = char::try_from_byte_array(bytes).expect_err("expected error for {bytes:?}");
+fn ref_from_bytes_rejects_misaligned_slices() {
+    let buf = Aligned([0; 16]);
+    assert!(u64::ref_from_bytes(&buf.0[..8]).is_some());
+    for offset in 1..8 {
+        assert!(u64::ref_from_bytes(&buf.0[offset..offset + 8]).is_none());
     }
+    // Byte-aligned types can start anywhere.
+    assert!(<[u8; 8]>::ref_from_bytes(&buf.0[3..11]).is_some());
 }
 
-#[cfg(feature = "std")]
 #[test]
-fn char_error_implements_std_error() {
-    use std::error::Error;
-    let err = char::try_from_byte_array([0xFF, 0xFF, 0xFF, 0xFF]).unwrap_err();
-    let _: &dyn Error = &err;
+fn mut_from_bytes_writes_through() {
+    let mut buf = Aligned([0; 16]);
+    *BigEndian::<u32>::mut_from_bytes(&mut buf.0[4..8]).unwrap() = BigEndian::new(0x0A0B_0C0D);
+    assert_eq!(&buf.0[..8], &[0, 0, 0, 0, 0x0A, 0x0B, 0x0C, 0x0D]);
+    assert!(BigEndian::<u32>::mut_from_bytes(&mut buf.0[5..9]).is_none());
+}
+
+#[test]
+fn ref_from_prefix_returns_rest() {
+    let buf = Aligned([0, 1, 0, 2, 9, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    let (value, rest) = BigEndian::<u16>::ref_from_prefix(&buf.0[..5]).unwrap();
+    assert_eq!(value.get(), 1);
+    assert_eq!(rest, &[0, 2, 9]);
+
+    assert!(u64::ref_from_prefix(&buf.0[..7]).is_none());
 }
 
 // ── Derive-macro integration ──────────────────────────────────────────────────
@@ -551,4 +2179,95 @@ mod derive_std_types {
         assert_eq!(bytes.len(), 7); // bool(1) + char(4) + u8(1) + bool(1)
         assert_eq!(MixedPrimitives::try_from_byte_array(bytes).unwrap(), s);
     }
+
+    // ── BitFlags in a derived struct ──────────────────────────────────────
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct Capabilities {
+        version: u8,
+        small: byteable::BitFlags8,
+        #[byteable(big_endian)]
+        wide: byteable::BitFlags16,
+        #[byteable(little_endian)]
+        wider: byteable::BitFlags32,
+    }
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[byteable(big_endian)]
+    struct BigEndianFlags {
+        #[byteable(transparent)]
+        small: byteable::BitFlags8,
+        #[byteable(big_endian)]
+        wide: byteable::BitFlags16,
+    }
+
+    #[test]
+    fn bitflags_fields_follow_field_endianness() {
+        let caps = Capabilities {
+            version: 2,
+            small: byteable::BitFlags8::new(0x81),
+            wide: byteable::BitFlags16::new(0x0102),
+            wider: byteable::BitFlags32::new(0x0304_0506),
+        };
+        let bytes = caps.into_byte_array();
+        assert_eq!(bytes, [2, 0x81, 0x01, 0x02, 0x06, 0x05, 0x04, 0x03]);
+        assert_eq!(Capabilities::try_from_byte_array(bytes).unwrap(), caps);
+    }
+
+    #[test]
+    fn bitflags_fields_in_big_endian_container() {
+        let flags = BigEndianFlags {
+            small: byteable::BitFlags8::new(1),
+            wide: byteable::BitFlags16::new(0x0102),
+        };
+        assert_eq!(flags.into_byte_array(), [1, 0x01, 0x02]);
+    }
+
+    // ── 24-bit integers in a derived struct ───────────────────────────────
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct PcmFrame {
+        left: byteable::I24,
+        #[byteable(big_endian)]
+        right: byteable::I24,
+        #[byteable(big_endian)]
+        position: byteable::U24,
+    }
+
+    #[test]
+    fn int24_fields_roundtrip() {
+        let frame = PcmFrame {
+            left: byteable::I24::new(-2).unwrap(),
+            right: byteable::I24::new(0x12_3456).unwrap(),
+            position: byteable::U24::new(7).unwrap(),
+        };
+        let bytes = frame.into_byte_array();
+        assert_eq!(bytes, [0xFE, 0xFF, 0xFF, 0x12, 0x34, 0x56, 0, 0, 7]);
+        assert_eq!(PcmFrame::try_from_byte_array(bytes).unwrap(), frame);
+    }
+
+    // ── Wrapping / Saturating in a derived struct ─────────────────────────
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[byteable(big_endian)]
+    struct Counters {
+        #[byteable(big_endian)]
+        sequence: core::num::Wrapping<u32>,
+        #[byteable(transparent)]
+        retries: core::num::Saturating<u8>,
+        #[byteable(little_endian)]
+        errors: core::num::Saturating<u16>,
+    }
+
+    #[test]
+    fn num_wrapper_fields_roundtrip() {
+        let counters = Counters {
+            sequence: core::num::Wrapping(u32::MAX) + core::num::Wrapping(2),
+            retries: core::num::Saturating(3),
+            errors: core::num::Saturating(0x0102),
+        };
+        let bytes = counters.into_byte_array();
+        assert_eq!(bytes, [0, 0, 0, 1, 3, 0x02, 0x01]);
+        assert_eq!(Counters::try_from_byte_array(bytes).unwrap(), counters);
+    }
 }
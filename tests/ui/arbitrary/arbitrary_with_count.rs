@@ -0,0 +1,13 @@
+// A generated `Vec` would not match its `count` field.
+
+use byteable::Byteable;
+
+#[derive(Byteable)]
+#[byteable(io_only, arbitrary)]
+struct Samples {
+    n: u8,
+    #[byteable(count = n)]
+    values: Vec<u16>,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+// Generated values would skip the validator, so `arbitrary` and `validate` are exclusive.
+
+use byteable::{Byteable, DecodeError};
+
+#[derive(Byteable, Clone, Copy)]
+#[byteable(little_endian, arbitrary, validate = Range::check)]
+struct Range {
+    start: u32,
+    end: u32,
+}
+
+impl Range {
+    fn check(&self) -> Result<(), DecodeError> {
+        Ok(())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+// A size that drifts from the assertion fails the build.
+
+use byteable::Byteable;
+
+#[derive(Clone, Copy, Byteable)]
+#[byteable(little_endian, assert_size = 8)]
+struct Drifted {
+    magic: u32,
+    length: u16,
+}
+
+fn main() {}
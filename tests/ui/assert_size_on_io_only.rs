@@ -0,0 +1,11 @@
+// `io_only` structs have no fixed size to check.
+
+use byteable::Byteable;
+
+#[derive(Byteable)]
+#[byteable(io_only, assert_size = 4)]
+struct Dynamic {
+    name: String,
+}
+
+fn main() {}
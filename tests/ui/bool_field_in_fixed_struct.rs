@@ -0,0 +1,11 @@
+// `bool` does not implement `PlainOldData` (invalid bit patterns 2..=255).
+
+use byteable::Byteable;
+
+#[derive(Clone, Copy, Byteable)]
+struct Bad {
+    id: u8,
+    flag: bool,
+}
+
+fn main() {}
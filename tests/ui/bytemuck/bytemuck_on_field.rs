@@ -0,0 +1,11 @@
+// `bytemuck` is a container-level option and is rejected elsewhere.
+
+use byteable::Byteable;
+
+#[derive(Byteable)]
+struct Field {
+    #[byteable(bytemuck)]
+    a: u8,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+// `bytemuck` is a container-level option and is rejected elsewhere.
+
+use byteable::Byteable;
+
+#[derive(Byteable)]
+#[byteable(io_only, bytemuck)]
+struct Streamed {
+    a: u8,
+}
+
+fn main() {}
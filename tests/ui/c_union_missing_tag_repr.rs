@@ -0,0 +1,11 @@
+// `payload_size` needs `tag_repr`.
+
+use byteable::Byteable;
+
+#[derive(Byteable, Clone, Copy)]
+#[byteable(c_union, payload_size = 4)]
+enum MissingTag {
+    A(u32),
+}
+
+fn main() {}
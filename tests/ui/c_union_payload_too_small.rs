@@ -0,0 +1,11 @@
+// Every variant must fit in the payload.
+
+use byteable::Byteable;
+
+#[derive(Byteable, Clone, Copy)]
+#[byteable(c_union, tag_repr = u8, payload_size = 2)]
+enum TooBig {
+    A(u32),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+// `char` does not implement `PlainOldData` (many code-points are invalid).
+
+use byteable::Byteable;
+
+#[derive(Clone, Copy, Byteable)]
+struct Bad {
+    id: u8,
+    letter: char,
+}
+
+fn main() {}
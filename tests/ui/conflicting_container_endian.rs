@@ -0,0 +1,11 @@
+// Two byte orders on one field are rejected, whether in one attribute or several.
+
+use byteable::Byteable;
+
+#[derive(Clone, Copy, Byteable)]
+#[byteable(big_endian, little_endian)]
+struct Bad {
+    value: u32,
+}
+
+fn main() {}
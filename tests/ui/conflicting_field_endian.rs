@@ -0,0 +1,12 @@
+// Two byte orders on one field are rejected, whether in one attribute or several.
+
+use byteable::Byteable;
+
+#[derive(Clone, Copy, Byteable)]
+struct Bad {
+    #[byteable(little_endian)]
+    #[byteable(big_endian)]
+    value: u32,
+}
+
+fn main() {}
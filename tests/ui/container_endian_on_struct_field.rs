@@ -0,0 +1,18 @@
+// A container endianness only applies to multi-byte integer and float fields: a nested
+// struct keeps its own layout and must say so with `transparent`.
+
+use byteable::Byteable;
+
+#[derive(Clone, Copy, Byteable)]
+struct Child {
+    a: u8,
+}
+
+#[derive(Clone, Copy, Byteable)]
+#[byteable(big_endian)]
+struct Bad {
+    len: u16,
+    child: Child,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+// The count must name a field declared before the counted one.
+
+use byteable::Byteable;
+
+#[derive(Byteable)]
+#[byteable(io_only)]
+struct Bad {
+    #[byteable(count = n)]
+    values: Vec<u8>,
+    n: u8,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+// Fixed-size structs cannot hold a variable number of elements.
+
+use byteable::Byteable;
+
+#[derive(Clone, Copy, Byteable)]
+struct Bad {
+    n: u8,
+    #[byteable(count = n)]
+    values: [u8; 4],
+}
+
+fn main() {}
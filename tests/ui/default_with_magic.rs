@@ -0,0 +1,11 @@
+// A magic signature, trailer or checksum is rejected for the same reason.
+
+use byteable::Byteable;
+
+#[derive(Clone, Copy, Byteable)]
+#[byteable(magic = b"PK", default)]
+struct Signed {
+    value: u8,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+// Zero bytes are not a valid value for a struct whose decoding can fail, so no `Default`
+// is generated and the derive is rejected.
+
+use byteable::Byteable;
+
+#[derive(Clone, Copy, Byteable)]
+#[byteable(default)]
+struct Flagged {
+    #[byteable(try_transparent)]
+    enabled: bool,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+// A bare `default` may only be given once, like every other container option.
+
+use byteable::Byteable;
+
+#[derive(Clone, Copy, Byteable)]
+#[byteable(default)]
+#[byteable(default)]
+struct Header {
+    version: u8,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+// An endianness on a `transparent` field is contradictory: the field's bytes are
+// fixed by its own type.
+
+use byteable::Byteable;
+
+#[derive(Clone, Copy, Byteable)]
+struct Child {
+    a: u8,
+}
+
+#[derive(Clone, Copy, Byteable)]
+struct Bad {
+    #[byteable(transparent)]
+    #[byteable(big_endian)]
+    child: Child,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+// Zero-sized fields have no byte order.
+
+use byteable::Byteable;
+
+#[derive(Clone, Copy, Byteable)]
+struct Bad {
+    #[byteable(big_endian)]
+    unit: (),
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+// `default` must name a variant, and is only accepted on unit-only enums.
+
+use byteable::Byteable;
+
+#[derive(Byteable)]
+#[byteable(default = A)]
+enum E {
+    A,
+    B(u8),
+}
+
+fn main() {}
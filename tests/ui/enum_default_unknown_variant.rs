@@ -0,0 +1,12 @@
+// `default` must name a variant, and is only accepted on unit-only enums.
+
+use byteable::Byteable;
+
+#[derive(Byteable, Clone, Copy)]
+#[byteable(default = Missing)]
+enum E {
+    A,
+    B,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+// `io_only` structs have no raw struct to expose.
+
+use byteable::Byteable;
+
+#[derive(Byteable)]
+#[byteable(io_only, expose_raw)]
+struct Bad {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+// Only one name may be given.
+
+use byteable::Byteable;
+
+#[derive(Clone, Copy, Byteable)]
+#[byteable(expose_raw, raw_name = Other)]
+struct Bad {
+    value: u8,
+}
+
+fn main() {}
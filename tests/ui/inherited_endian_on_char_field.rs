@@ -0,0 +1,13 @@
+// Endianness attributes on `bool` or `char` fields are rejected with a hint to use
+// `try_transparent` instead, whether the attribute is on the field or inherited from
+// the container.
+
+use byteable::Byteable;
+
+#[derive(Byteable)]
+#[byteable(big_endian)]
+struct Bad {
+    c: char,
+}
+
+fn main() {}
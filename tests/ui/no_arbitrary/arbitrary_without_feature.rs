@@ -0,0 +1,12 @@
+// `#[byteable(arbitrary)]` needs the `arbitrary` feature of byteable.
+
+use byteable::Byteable;
+
+#[derive(Byteable, Clone, Copy)]
+#[byteable(arbitrary)]
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+fn main() {}
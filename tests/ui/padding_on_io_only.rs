@@ -0,0 +1,12 @@
+// Padding needs a raw struct, so `io_only` structs reject it.
+
+use byteable::Byteable;
+
+#[derive(Byteable)]
+#[byteable(io_only)]
+struct Streamed {
+    #[byteable(pad_before = 2)]
+    a: u8,
+}
+
+fn main() {}
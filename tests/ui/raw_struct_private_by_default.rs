@@ -0,0 +1,12 @@
+// Without either attribute, the raw struct has no public name.
+
+use byteable::Byteable;
+
+#[derive(Clone, Copy, Byteable)]
+struct Plain {
+    value: u8,
+}
+
+fn main() {
+    let _: PlainRaw = unimplemented!();
+}
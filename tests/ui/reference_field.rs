@@ -0,0 +1,13 @@
+// References do not implement `PlainOldData`, so the derived impls never apply.
+
+use byteable::{Byteable, IntoByteArray};
+
+#[derive(Clone, Copy, Byteable)]
+struct Bad<'a> {
+    id: u8,
+    data_ref: &'a [u8],
+}
+
+fn main() {
+    let _ = Bad { id: 0, data_ref: &[] }.into_byte_array();
+}
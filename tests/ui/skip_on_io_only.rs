@@ -0,0 +1,13 @@
+// `io_only` structs encode every field in turn and do not support `skip`.
+
+use byteable::Byteable;
+
+#[derive(Byteable)]
+#[byteable(io_only)]
+struct Bad {
+    id: u8,
+    #[byteable(skip)]
+    cache: Vec<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+// A skipped field has no bytes for a layout attribute to describe.
+
+use byteable::Byteable;
+
+#[derive(Byteable)]
+struct Bad {
+    #[byteable(skip, big_endian)]
+    value: u32,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+// The field is rebuilt with `Default`, so its type must implement it.
+
+use byteable::Byteable;
+
+struct NoDefault;
+
+#[derive(Byteable)]
+struct Bad {
+    id: u8,
+    #[byteable(skip)]
+    value: NoDefault,
+}
+
+fn main() {}
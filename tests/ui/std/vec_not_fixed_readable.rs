@@ -0,0 +1,9 @@
+// Vec<u32> has no fixed size, so it cannot be read as a fixed value.
+
+use byteable::FixedReadable;
+
+fn assert_readable<T: FixedReadable>() {}
+
+fn main() {
+    assert_readable::<Vec<u32>>();
+}
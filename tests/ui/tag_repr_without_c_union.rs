@@ -0,0 +1,11 @@
+// `tag_repr` is only accepted with `c_union`.
+
+use byteable::Byteable;
+
+#[derive(Byteable, Clone, Copy)]
+#[byteable(tag_repr = u8)]
+enum NoCUnion {
+    A(u32),
+}
+
+fn main() {}
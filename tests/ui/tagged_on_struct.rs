@@ -0,0 +1,11 @@
+// `tagged` is enum-only and picks its own payload size.
+
+use byteable::Byteable;
+
+#[derive(Byteable, Clone, Copy)]
+#[byteable(tagged)]
+struct NotAnEnum {
+    a: u8,
+}
+
+fn main() {}
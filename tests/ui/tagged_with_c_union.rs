@@ -0,0 +1,11 @@
+// `tagged` is enum-only and picks its own payload size.
+
+use byteable::Byteable;
+
+#[derive(Byteable, Clone, Copy)]
+#[byteable(tagged, c_union, tag_repr = u8, payload_size = 4)]
+enum Both {
+    A(u32),
+}
+
+fn main() {}
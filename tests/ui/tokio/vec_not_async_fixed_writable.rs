@@ -0,0 +1,9 @@
+// Vec<u32> has no fixed size, so it cannot be written as a fixed value.
+
+use byteable::AsyncFixedWritable;
+
+fn assert_writable<T: AsyncFixedWritable>() {}
+
+fn main() {
+    assert_writable::<Vec<u32>>();
+}
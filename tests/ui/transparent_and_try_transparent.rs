@@ -0,0 +1,11 @@
+// So are `transparent` and `try_transparent` together.
+
+use byteable::Byteable;
+
+#[derive(Clone, Copy, Byteable)]
+struct Bad {
+    #[byteable(transparent, try_transparent)]
+    flag: bool,
+}
+
+fn main() {}
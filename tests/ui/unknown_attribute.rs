@@ -0,0 +1,11 @@
+// A misspelled attribute is rejected with the list of valid ones.
+
+use byteable::Byteable;
+
+#[derive(Byteable)]
+struct Packet {
+    #[byteable(big_endain)]
+    len: u16,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+// Validators only run on fixed-size structs.
+
+use byteable::{Byteable, DecodeError};
+
+fn check(_: &Named) -> Result<(), DecodeError> {
+    Ok(())
+}
+
+#[derive(Byteable)]
+#[byteable(io_only, validate = check)]
+struct Named {
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+// Validators cannot be combined with a zeroed `Default`, since decoding can now fail.
+
+use byteable::{Byteable, DecodeError};
+
+fn check(_: &Level) -> Result<(), DecodeError> {
+    Ok(())
+}
+
+#[derive(Clone, Copy, Byteable)]
+#[byteable(default, validate = check)]
+struct Level {
+    value: u8,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+// The conversion functions decide the bytes, so a layout attribute on the same field
+// is contradictory.
+
+use byteable::Byteable;
+
+mod id {
+    pub fn to_raw(v: u32) -> u32 { v }
+    pub fn from_raw(v: u32) -> u32 { v }
+}
+
+#[derive(Clone, Copy, Byteable)]
+struct Bad {
+    #[byteable(with = id, big_endian)]
+    value: u32,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+// `io_only` structs have no raw struct to hold the converted value.
+
+use byteable::Byteable;
+
+mod id {
+    pub fn to_raw(v: u32) -> u32 { v }
+    pub fn from_raw(v: u32) -> u32 { v }
+}
+
+#[derive(Byteable)]
+#[byteable(io_only)]
+struct Bad {
+    #[byteable(with = id)]
+    value: u32,
+}
+
+fn main() {}
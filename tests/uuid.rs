@@ -0,0 +1,135 @@
+//! Integration tests for uuid support.
+#![cfg(feature = "uuid")]
+
+use byteable::{ByteableRef, FromByteArray, IntoByteArray, PlainOldData};
+use uuid::Uuid;
+
+const TEXT: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+const BYTES: [u8; 16] = [
+    0x67, 0xE5, 0x50, 0x44, 0x10, 0xB1, 0x42, 0x6F, 0x92, 0x47, 0xBB, 0x68, 0x0E, 0x5F, 0xE0, 0xC8,
+];
+
+// --- Uuid ---
+
+#[test]
+fn uuid_roundtrip() {
+    let id = Uuid::parse_str(TEXT).unwrap();
+    let bytes = id.into_byte_array();
+    let restored = Uuid::from_byte_array(bytes);
+    assert_eq!(restored, id);
+    assert_eq!(restored.to_string(), TEXT);
+}
+
+#[test]
+fn uuid_uses_rfc_4122_byte_order() {
+    // The bytes follow the textual form, most significant first: not the GUID layout.
+    let id = Uuid::parse_str(TEXT).unwrap();
+    assert_eq!(id.into_byte_array(), BYTES);
+    assert_ne!(id.into_byte_array(), id.to_bytes_le());
+}
+
+#[test]
+fn uuid_byte_size() {
+    assert_eq!(
+        core::mem::size_of::<<Uuid as IntoByteArray>::ByteArray>(),
+        16
+    );
+}
+
+// --- PlainOldData ---
+
+#[test]
+fn uuid_transmute_safe() {
+    // Compile-time check: Uuid implements PlainOldData
+    fn assert_transmute_safe<T: PlainOldData>() {}
+    assert_transmute_safe::<Uuid>();
+}
+
+#[test]
+fn uuid_ref_from_bytes() {
+    let id = Uuid::ref_from_bytes(&BYTES).unwrap();
+    assert_eq!(id.to_string(), TEXT);
+}
+
+// --- #[derive(Byteable)] integration ---
+
+#[cfg(feature = "derive")]
+mod derive_tests {
+    use super::{BYTES, TEXT};
+    use byteable::{Byteable, FromByteArray, IntoByteArray};
+    use uuid::Uuid;
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    struct Plain {
+        id: Uuid,
+        flags: u8,
+    }
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[byteable(little_endian)]
+    struct Session {
+        #[byteable(transparent)]
+        id: Uuid,
+        seq: u32,
+    }
+
+    #[derive(Byteable, Debug, Clone, Copy, PartialEq)]
+    #[byteable(big_endian)]
+    struct Link {
+        seq: u16,
+        #[byteable(transparent)]
+        parent: Uuid,
+    }
+
+    #[test]
+    fn derive_plain_field_roundtrip() {
+        let value = Plain {
+            id: Uuid::parse_str(TEXT).unwrap(),
+            flags: 3,
+        };
+        let bytes = value.into_byte_array();
+        assert_eq!(bytes[..16], BYTES);
+        assert_eq!(bytes[16], 3);
+        assert_eq!(Plain::from_byte_array(bytes), value);
+    }
+
+    #[test]
+    fn derive_little_endian_container_keeps_uuid_order() {
+        let value = Session {
+            id: Uuid::parse_str(TEXT).unwrap(),
+            seq: 1,
+        };
+        let bytes = value.into_byte_array();
+        assert_eq!(bytes[..16], BYTES);
+        assert_eq!(bytes[16..], [1, 0, 0, 0]);
+        assert_eq!(Session::from_byte_array(bytes), value);
+    }
+
+    #[test]
+    fn derive_big_endian_container_keeps_uuid_order() {
+        let value = Link {
+            seq: 0x0102,
+            parent: Uuid::parse_str(TEXT).unwrap(),
+        };
+        let bytes = value.into_byte_array();
+        assert_eq!(bytes[..2], [0x01, 0x02]);
+        assert_eq!(bytes[2..], BYTES);
+        assert_eq!(Link::from_byte_array(bytes), value);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn derive_read_write_fixed() {
+        use byteable::{ReadFixed, WriteFixed};
+
+        let value = Session {
+            id: Uuid::parse_str(TEXT).unwrap(),
+            seq: 42,
+        };
+        let mut buf = Vec::new();
+        buf.write_fixed(&value).unwrap();
+        assert_eq!(buf.len(), 20);
+        let restored: Session = buf.as_slice().read_fixed().unwrap();
+        assert_eq!(restored, value);
+    }
+}